@@ -275,6 +275,24 @@ impl AssemblerX64 {
         self.emit_u8(src.uint8());
     }
 
+    pub fn movw_ar(&mut self, dest: Address, src: Register) {
+        self.emit_u8(0x66);
+        self.emit_rex32_modrm_address(src, dest);
+        self.emit_u8(0x89);
+        self.emit_address(src.low_bits(), dest);
+    }
+
+    pub fn movw_ai(&mut self, dest: Address, src: Immediate) {
+        assert!(src.is_int16() || src.is_uint16());
+        self.emit_u8(0x66);
+        self.emit_rex32_address_optional(dest);
+        self.emit_u8(0xc7);
+        self.emit_address(0b000, dest);
+        let value = src.uint16();
+        self.emit_u8(value as u8);
+        self.emit_u8((value >> 8) as u8);
+    }
+
     pub fn movq_ar(&mut self, dest: Address, src: Register) {
         self.emit_rex64_modrm_address(src, dest);
         self.emit_u8(0x89);
@@ -336,6 +354,34 @@ impl AssemblerX64 {
         self.emit_address(dest.low_bits(), src);
     }
 
+    pub fn movzxw_rr(&mut self, dest: Register, src: Register) {
+        self.emit_rex32_optional(dest, src);
+        self.emit_u8(0x0f);
+        self.emit_u8(0xb7);
+        self.emit_modrm_registers(dest, src);
+    }
+
+    pub fn movzxw_ra(&mut self, dest: Register, src: Address) {
+        self.emit_rex32_modrm_address(dest, src);
+        self.emit_u8(0x0f);
+        self.emit_u8(0xb7);
+        self.emit_address(dest.low_bits(), src);
+    }
+
+    pub fn movsxwl_rr(&mut self, dest: Register, src: Register) {
+        self.emit_rex32_optional(dest, src);
+        self.emit_u8(0x0f);
+        self.emit_u8(0xbf);
+        self.emit_modrm_registers(dest, src);
+    }
+
+    pub fn movsxwq_rr(&mut self, dest: Register, src: Register) {
+        self.emit_rex64_modrm(dest, src);
+        self.emit_u8(0x0f);
+        self.emit_u8(0xbf);
+        self.emit_modrm_registers(dest, src);
+    }
+
     pub fn movsxbl_rr(&mut self, dest: Register, src: Register) {
         self.emit_rex32_byte_optional(dest, src);
         self.emit_u8(0x0f);
@@ -869,6 +915,18 @@ impl AssemblerX64 {
         self.emit_modrm_opcode(0b111, src);
     }
 
+    pub fn divl_r(&mut self, reg: Register) {
+        self.emit_rex32_rm_optional(reg);
+        self.emit_u8(0xF7);
+        self.emit_modrm_opcode(0b110, reg);
+    }
+
+    pub fn divq_r(&mut self, src: Register) {
+        self.emit_rex64_rm(src);
+        self.emit_u8(0xF7);
+        self.emit_modrm_opcode(0b110, src);
+    }
+
     pub fn call_r(&mut self, reg: Register) {
         self.emit_rex32_rm_optional(reg);
         self.emit_u8(0xFF);
@@ -1591,6 +1649,11 @@ impl Immediate {
         -limit <= self.0 && self.0 < limit
     }
 
+    pub fn is_int16(&self) -> bool {
+        let limit = 1i64 << 15;
+        -limit <= self.0 && self.0 < limit
+    }
+
     pub fn is_int32(&self) -> bool {
         let limit = 1i64 << 31;
         -limit <= self.0 && self.0 < limit
@@ -1600,6 +1663,10 @@ impl Immediate {
         0 <= self.0 && self.0 < 256
     }
 
+    pub fn is_uint16(&self) -> bool {
+        0 <= self.0 && self.0 < 65536
+    }
+
     pub fn is_uint32(&self) -> bool {
         let limit = 1i64 << 32;
         0 <= self.0 && self.0 < limit
@@ -1613,6 +1680,10 @@ impl Immediate {
         self.0 as i8
     }
 
+    pub fn uint16(&self) -> u16 {
+        self.0 as u16
+    }
+
     pub fn int32(&self) -> i32 {
         self.0 as i32
     }
@@ -2038,6 +2109,18 @@ mod tests {
         assert_emit!(0x49, 0xf7, 0xff; idivq_r(R15));
     }
 
+    #[test]
+    fn test_divl_r() {
+        assert_emit!(0xf7, 0xf0; divl_r(RAX));
+        assert_emit!(0x41, 0xf7, 0xf7; divl_r(R15));
+    }
+
+    #[test]
+    fn test_divq_r() {
+        assert_emit!(0x48, 0xf7, 0xf0; divq_r(RAX));
+        assert_emit!(0x49, 0xf7, 0xf7; divq_r(R15));
+    }
+
     #[test]
     fn test_call_r() {
         assert_emit!(0xff, 0xd0; call_r(RAX));
@@ -2291,6 +2374,39 @@ mod tests {
         assert_emit!(0x41, 0x0f, 0xb6, 0x00; movzxb_ra(RAX, Address::offset(R8, 0)));
     }
 
+    #[test]
+    fn test_movzxw_ra() {
+        assert_emit!(0x0f, 0xb7, 0x00; movzxw_ra(RAX, Address::offset(RAX, 0)));
+        assert_emit!(0x44, 0x0f, 0xb7, 0x00; movzxw_ra(R8, Address::offset(RAX, 0)));
+        assert_emit!(0x41, 0x0f, 0xb7, 0x00; movzxw_ra(RAX, Address::offset(R8, 0)));
+    }
+
+    #[test]
+    fn test_movzxw_rr() {
+        assert_emit!(0x0f, 0xb7, 0xc1; movzxw_rr(RAX, RCX));
+        assert_emit!(0x44, 0x0f, 0xb7, 0xf8; movzxw_rr(R15, RAX));
+        assert_emit!(0x41, 0x0f, 0xb7, 0xc7; movzxw_rr(RAX, R15));
+    }
+
+    #[test]
+    fn test_movsxw_rr() {
+        assert_emit!(0x0f, 0xbf, 0xc1; movsxwl_rr(RAX, RCX));
+        assert_emit!(0x44, 0x0f, 0xbf, 0xf8; movsxwl_rr(R15, RAX));
+        assert_emit!(0x48, 0x0f, 0xbf, 0xc1; movsxwq_rr(RAX, RCX));
+        assert_emit!(0x49, 0x0f, 0xbf, 0xc7; movsxwq_rr(RAX, R15));
+    }
+
+    #[test]
+    fn test_movw_ar() {
+        assert_emit!(0x66, 0x89, 0x04, 0x24; movw_ar(Address::offset(RSP, 0), RAX));
+        assert_emit!(0x66, 0x44, 0x89, 0x04, 0x24; movw_ar(Address::offset(RSP, 0), R8));
+    }
+
+    #[test]
+    fn test_movw_ai() {
+        assert_emit!(0x66, 0xc7, 0x04, 0x24, 0x01, 0x02; movw_ai(Address::offset(RSP, 0), Immediate(0x201)));
+    }
+
     #[test]
     fn test_movsxlq_rr() {
         assert_emit!(0x4c, 0x63, 0xf8; movsxlq_rr(R15, RAX));
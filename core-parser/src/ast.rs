@@ -318,6 +318,7 @@ pub struct EnumVariant {
     pub span: Span,
     pub name: Name,
     pub types: Option<Vec<Type>>,
+    pub discriminant: Option<Box<Expr>>,
 }
 
 #[derive(Clone, Debug)]
@@ -326,7 +327,8 @@ pub struct Alias {
     pub pos: Position,
     pub span: Span,
     pub name: Name,
-    pub ty: Type,
+    pub type_params: Option<Vec<TypeParam>>,
+    pub ty: Option<Type>,
     pub visibility: Visibility,
 }
 
@@ -368,13 +370,13 @@ pub struct UnionVariant {
     pub id: NodeId,
     pub pos: Position,
     pub span: Span,
-    pub name: Name,
-    pub types: Option<Vec<Type>>,
+    pub data_type: Type,
 }
 
 #[derive(Clone, Debug)]
 pub enum Type {
     This(TypeSelfType),
+    Assoc(TypeAssocType),
     Basic(TypeBasicType),
     Tuple(TypeTupleType),
     Lambda(TypeLambdaType),
@@ -387,6 +389,15 @@ pub struct TypeSelfType {
     pub span: Span,
 }
 
+#[derive(Clone, Debug)]
+pub struct TypeAssocType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+
+    pub name: Name,
+}
+
 #[derive(Clone, Debug)]
 pub struct TypeTupleType {
     pub id: NodeId,
@@ -414,6 +425,18 @@ pub struct TypeBasicType {
 
     pub path: Path,
     pub params: Vec<Box<Type>>,
+    pub bindings: Vec<TypeBinding>,
+}
+
+// Binding of an associated type, e.g. `Item = Int32` in `Iterator[Item = Int32]`.
+#[derive(Clone, Debug)]
+pub struct TypeBinding {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+
+    pub name: Name,
+    pub ty: Box<Type>,
 }
 
 impl TypeBasicType {
@@ -428,12 +451,22 @@ impl Type {
         Type::This(TypeSelfType { id, pos, span })
     }
 
+    pub fn create_assoc(id: NodeId, pos: Position, span: Span, name: Name) -> Type {
+        Type::Assoc(TypeAssocType {
+            id,
+            pos,
+            span,
+            name,
+        })
+    }
+
     pub fn create_basic(
         id: NodeId,
         pos: Position,
         span: Span,
         path: Path,
         params: Vec<Box<Type>>,
+        bindings: Vec<TypeBinding>,
     ) -> Type {
         Type::Basic(TypeBasicType {
             id,
@@ -441,6 +474,7 @@ impl Type {
             span,
             path,
             params,
+            bindings,
         })
     }
 
@@ -501,6 +535,7 @@ impl Type {
     pub fn to_string(&self, interner: &Interner) -> String {
         match *self {
             Type::This(_) => "Self".into(),
+            Type::Assoc(ref val) => format!("Self::{}", *interner.str(val.name)),
             Type::Basic(ref val) => format!("{}", *interner.str(val.name())),
 
             Type::Tuple(ref val) => {
@@ -522,6 +557,7 @@ impl Type {
     pub fn pos(&self) -> Position {
         match *self {
             Type::This(ref val) => val.pos,
+            Type::Assoc(ref val) => val.pos,
             Type::Basic(ref val) => val.pos,
             Type::Tuple(ref val) => val.pos,
             Type::Lambda(ref val) => val.pos,
@@ -531,6 +567,7 @@ impl Type {
     pub fn id(&self) -> NodeId {
         match *self {
             Type::This(ref val) => val.id,
+            Type::Assoc(ref val) => val.id,
             Type::Basic(ref val) => val.id,
             Type::Tuple(ref val) => val.id,
             Type::Lambda(ref val) => val.id,
@@ -547,6 +584,7 @@ pub struct Impl {
     pub type_params: Option<Vec<TypeParam>>,
     pub trait_type: Option<Type>,
     pub extended_type: Type,
+    pub aliases: Vec<Arc<Alias>>,
     pub methods: Vec<Arc<Function>>,
 }
 
@@ -555,8 +593,10 @@ pub struct Trait {
     pub id: NodeId,
     pub name: Name,
     pub type_params: Option<Vec<TypeParam>>,
+    pub super_traits: Vec<Type>,
     pub pos: Position,
     pub span: Span,
+    pub aliases: Vec<Arc<Alias>>,
     pub methods: Vec<Arc<Function>>,
     pub visibility: Visibility,
 }
@@ -568,10 +608,12 @@ pub struct Class {
     pub pos: Position,
     pub span: Span,
     pub internal: bool,
+    pub is_open: bool,
     pub visibility: Visibility,
 
     pub fields: Vec<Field>,
     pub type_params: Option<Vec<TypeParam>>,
+    pub parent_class: Option<Type>,
 }
 
 #[derive(Clone, Debug)]
@@ -663,6 +705,9 @@ pub struct Function {
     pub is_test: bool,
     pub internal: bool,
     pub is_constructor: bool,
+    pub is_open: bool,
+    pub is_override: bool,
+    pub is_final: bool,
 
     pub params: Vec<Param>,
     pub is_nullary: bool,
@@ -751,6 +796,9 @@ pub enum Modifier {
     Static,
     Test,
     OptimizeImmediately,
+    Open,
+    Override,
+    Final,
 }
 
 impl Modifier {
@@ -761,6 +809,9 @@ impl Modifier {
             "static" => Some(Modifier::Static),
             "test" => Some(Modifier::Test),
             "optimizeImmediately" => Some(Modifier::OptimizeImmediately),
+            "open" => Some(Modifier::Open),
+            "override" => Some(Modifier::Override),
+            "final" => Some(Modifier::Final),
             _ => None,
         }
     }
@@ -772,6 +823,9 @@ impl Modifier {
             Modifier::Static => "static",
             Modifier::Test => "test",
             Modifier::OptimizeImmediately => "optimizeImmediately",
+            Modifier::Open => "open",
+            Modifier::Override => "override",
+            Modifier::Final => "final",
         }
     }
 }
@@ -783,8 +837,10 @@ pub struct Param {
     pub name: Name,
     pub pos: Position,
     pub span: Span,
-    pub data_type: Type,
+    // only parameters of lambdas can omit their type
+    pub data_type: Option<Type>,
     pub variadic: bool,
+    pub default_value: Option<Box<Expr>>,
 }
 
 #[derive(Clone, Debug)]
@@ -794,6 +850,8 @@ pub enum Stmt {
     Expr(StmtExprType),
     Return(StmtReturnType),
     For(StmtForType),
+    Break(StmtBreakType),
+    Continue(StmtContinueType),
 }
 
 impl Stmt {
@@ -822,6 +880,7 @@ impl Stmt {
         id: NodeId,
         pos: Position,
         span: Span,
+        label: Option<Name>,
         pattern: Box<LetPattern>,
         expr: Box<Expr>,
         block: Box<Stmt>,
@@ -831,6 +890,7 @@ impl Stmt {
             pos,
             span,
 
+            label,
             pattern,
             expr,
             block,
@@ -841,6 +901,7 @@ impl Stmt {
         id: NodeId,
         pos: Position,
         span: Span,
+        label: Option<Name>,
         cond: Box<Expr>,
         block: Box<Stmt>,
     ) -> Stmt {
@@ -849,6 +910,7 @@ impl Stmt {
             pos,
             span,
 
+            label,
             cond,
             block,
         })
@@ -874,6 +936,26 @@ impl Stmt {
         })
     }
 
+    pub fn create_break(id: NodeId, pos: Position, span: Span, label: Option<Name>) -> Stmt {
+        Stmt::Break(StmtBreakType {
+            id,
+            pos,
+            span,
+
+            label,
+        })
+    }
+
+    pub fn create_continue(id: NodeId, pos: Position, span: Span, label: Option<Name>) -> Stmt {
+        Stmt::Continue(StmtContinueType {
+            id,
+            pos,
+            span,
+
+            label,
+        })
+    }
+
     pub fn id(&self) -> NodeId {
         match *self {
             Stmt::Let(ref stmt) => stmt.id,
@@ -881,6 +963,8 @@ impl Stmt {
             Stmt::For(ref stmt) => stmt.id,
             Stmt::Expr(ref stmt) => stmt.id,
             Stmt::Return(ref stmt) => stmt.id,
+            Stmt::Break(ref stmt) => stmt.id,
+            Stmt::Continue(ref stmt) => stmt.id,
         }
     }
 
@@ -891,6 +975,8 @@ impl Stmt {
             Stmt::For(ref stmt) => stmt.pos,
             Stmt::Expr(ref stmt) => stmt.pos,
             Stmt::Return(ref stmt) => stmt.pos,
+            Stmt::Break(ref stmt) => stmt.pos,
+            Stmt::Continue(ref stmt) => stmt.pos,
         }
     }
 
@@ -901,6 +987,8 @@ impl Stmt {
             Stmt::For(ref stmt) => stmt.span,
             Stmt::Expr(ref stmt) => stmt.span,
             Stmt::Return(ref stmt) => stmt.span,
+            Stmt::Break(ref stmt) => stmt.span,
+            Stmt::Continue(ref stmt) => stmt.span,
         }
     }
 
@@ -973,6 +1061,34 @@ impl Stmt {
             _ => false,
         }
     }
+
+    pub fn to_break(&self) -> Option<&StmtBreakType> {
+        match *self {
+            Stmt::Break(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn is_break(&self) -> bool {
+        match *self {
+            Stmt::Break(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn to_continue(&self) -> Option<&StmtContinueType> {
+        match *self {
+            Stmt::Continue(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn is_continue(&self) -> bool {
+        match *self {
+            Stmt::Continue(_) => true,
+            _ => false,
+        }
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -1068,6 +1184,7 @@ pub struct StmtForType {
     pub pos: Position,
     pub span: Span,
 
+    pub label: Option<Name>,
     pub pattern: Box<LetPattern>,
     pub expr: Box<Expr>,
     pub block: Box<Stmt>,
@@ -1079,6 +1196,7 @@ pub struct StmtWhileType {
     pub pos: Position,
     pub span: Span,
 
+    pub label: Option<Name>,
     pub cond: Box<Expr>,
     pub block: Box<Stmt>,
 }
@@ -1101,6 +1219,24 @@ pub struct StmtReturnType {
     pub expr: Option<Box<Expr>>,
 }
 
+#[derive(Clone, Debug)]
+pub struct StmtBreakType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+
+    pub label: Option<Name>,
+}
+
+#[derive(Clone, Debug)]
+pub struct StmtContinueType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+
+    pub label: Option<Name>,
+}
+
 #[derive(PartialEq, Eq, Debug, Copy, Clone)]
 pub enum UnOp {
     Plus,
@@ -1206,12 +1342,15 @@ pub enum Expr {
     Path(ExprPathType),
     Dot(ExprDotType),
     This(ExprSelfType),
+    Super(ExprSuperType),
     Conv(ExprConvType),
     Lambda(Arc<Function>),
     Block(ExprBlockType),
     If(ExprIfType),
+    Match(ExprMatchType),
     Tuple(ExprTupleType),
     Paren(ExprParenType),
+    Try(ExprTryType),
 }
 
 impl Expr {
@@ -1250,6 +1389,22 @@ impl Expr {
         })
     }
 
+    pub fn create_match(
+        id: NodeId,
+        pos: Position,
+        span: Span,
+        expr: Box<Expr>,
+        cases: Vec<MatchCaseType>,
+    ) -> Expr {
+        Expr::Match(ExprMatchType {
+            id,
+            pos,
+            span,
+            expr,
+            cases,
+        })
+    }
+
     pub fn create_un(id: NodeId, pos: Position, span: Span, op: UnOp, opnd: Box<Expr>) -> Expr {
         Expr::Un(ExprUnType {
             id,
@@ -1287,6 +1442,7 @@ impl Expr {
         span: Span,
         object: Box<Expr>,
         data_type: Box<Type>,
+        is: bool,
     ) -> Expr {
         Expr::Conv(ExprConvType {
             id,
@@ -1295,6 +1451,17 @@ impl Expr {
 
             object,
             data_type,
+            is,
+        })
+    }
+
+    pub fn create_try(id: NodeId, pos: Position, span: Span, expr: Box<Expr>) -> Expr {
+        Expr::Try(ExprTryType {
+            id,
+            pos,
+            span,
+
+            expr,
         })
     }
 
@@ -1377,6 +1544,10 @@ impl Expr {
         Expr::This(ExprSelfType { id, pos, span })
     }
 
+    pub fn create_super(id: NodeId, pos: Position, span: Span) -> Expr {
+        Expr::Super(ExprSuperType { id, pos, span })
+    }
+
     pub fn create_ident(
         id: NodeId,
         pos: Position,
@@ -1695,6 +1866,10 @@ impl Expr {
         }
     }
 
+    pub fn is_super(&self) -> bool {
+        matches!(*self, Expr::Super(_))
+    }
+
     pub fn to_conv(&self) -> Option<&ExprConvType> {
         match *self {
             Expr::Conv(ref val) => Some(val),
@@ -1709,6 +1884,17 @@ impl Expr {
         }
     }
 
+    pub fn to_try(&self) -> Option<&ExprTryType> {
+        match *self {
+            Expr::Try(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn is_try(&self) -> bool {
+        matches!(*self, Expr::Try(_))
+    }
+
     pub fn to_lambda(&self) -> Option<Arc<Function>> {
         match *self {
             Expr::Lambda(ref val) => Some(val.clone()),
@@ -1765,6 +1951,20 @@ impl Expr {
         }
     }
 
+    pub fn to_match(&self) -> Option<&ExprMatchType> {
+        match *self {
+            Expr::Match(ref val) => Some(val),
+            _ => None,
+        }
+    }
+
+    pub fn is_match(&self) -> bool {
+        match *self {
+            Expr::Match(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn pos(&self) -> Position {
         match *self {
             Expr::Un(ref val) => val.pos,
@@ -1781,12 +1981,15 @@ impl Expr {
             Expr::Path(ref val) => val.pos,
             Expr::Dot(ref val) => val.pos,
             Expr::This(ref val) => val.pos,
+            Expr::Super(ref val) => val.pos,
             Expr::Conv(ref val) => val.pos,
             Expr::Lambda(ref val) => val.pos,
             Expr::Block(ref val) => val.pos,
             Expr::If(ref val) => val.pos,
+            Expr::Match(ref val) => val.pos,
             Expr::Tuple(ref val) => val.pos,
             Expr::Paren(ref val) => val.pos,
+            Expr::Try(ref val) => val.pos,
         }
     }
 
@@ -1806,12 +2009,15 @@ impl Expr {
             Expr::Path(ref val) => val.span,
             Expr::Dot(ref val) => val.span,
             Expr::This(ref val) => val.span,
+            Expr::Super(ref val) => val.span,
             Expr::Conv(ref val) => val.span,
             Expr::Lambda(ref val) => val.span,
             Expr::Block(ref val) => val.span,
             Expr::If(ref val) => val.span,
+            Expr::Match(ref val) => val.span,
             Expr::Tuple(ref val) => val.span,
             Expr::Paren(ref val) => val.span,
+            Expr::Try(ref val) => val.span,
         }
     }
 
@@ -1831,12 +2037,15 @@ impl Expr {
             Expr::Path(ref val) => val.id,
             Expr::Dot(ref val) => val.id,
             Expr::This(ref val) => val.id,
+            Expr::Super(ref val) => val.id,
             Expr::Conv(ref val) => val.id,
             Expr::Lambda(ref val) => val.id,
             Expr::Block(ref val) => val.id,
             Expr::If(ref val) => val.id,
+            Expr::Match(ref val) => val.id,
             Expr::Tuple(ref val) => val.id,
             Expr::Paren(ref val) => val.id,
+            Expr::Try(ref val) => val.id,
         }
     }
 }
@@ -1864,6 +2073,16 @@ pub struct ExprConvType {
 
     pub object: Box<Expr>,
     pub data_type: Box<Type>,
+    pub is: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct ExprTryType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+
+    pub expr: Box<Expr>,
 }
 
 #[derive(Clone, Debug)]
@@ -1962,6 +2181,13 @@ pub struct ExprSelfType {
     pub span: Span,
 }
 
+#[derive(Clone, Debug)]
+pub struct ExprSuperType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+}
+
 #[derive(Clone, Debug)]
 pub struct ExprIdentType {
     pub id: NodeId,
@@ -2043,6 +2269,123 @@ pub enum IfCaseData {
     Patterns(Vec<IfPattern>),
 }
 
+#[derive(Clone, Debug)]
+pub struct ExprMatchType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+
+    pub expr: Box<Expr>,
+    pub cases: Vec<MatchCaseType>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MatchCaseType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+
+    pub pattern: Box<MatchPattern>,
+    pub guard: Option<Box<Expr>>,
+    pub value: Box<Expr>,
+}
+
+#[derive(Clone, Debug)]
+pub enum MatchPattern {
+    Underscore(MatchPatternUnderscoreType),
+    Lit(MatchPatternLitType),
+    Ident(MatchPatternIdentType),
+    Tuple(MatchPatternTupleType),
+    Constructor(MatchPatternConstructorType),
+}
+
+impl MatchPattern {
+    pub fn id(&self) -> NodeId {
+        match self {
+            MatchPattern::Underscore(ref p) => p.id,
+            MatchPattern::Lit(ref p) => p.id,
+            MatchPattern::Ident(ref p) => p.id,
+            MatchPattern::Tuple(ref p) => p.id,
+            MatchPattern::Constructor(ref p) => p.id,
+        }
+    }
+
+    pub fn pos(&self) -> Position {
+        match self {
+            MatchPattern::Underscore(ref p) => p.pos,
+            MatchPattern::Lit(ref p) => p.pos,
+            MatchPattern::Ident(ref p) => p.pos,
+            MatchPattern::Tuple(ref p) => p.pos,
+            MatchPattern::Constructor(ref p) => p.pos,
+        }
+    }
+
+    pub fn to_ident(&self) -> Option<&MatchPatternIdentType> {
+        match self {
+            MatchPattern::Ident(ref ident) => Some(ident),
+            _ => None,
+        }
+    }
+
+    pub fn to_tuple(&self) -> Option<&MatchPatternTupleType> {
+        match self {
+            MatchPattern::Tuple(ref tuple) => Some(tuple),
+            _ => None,
+        }
+    }
+
+    pub fn to_constructor(&self) -> Option<&MatchPatternConstructorType> {
+        match self {
+            MatchPattern::Constructor(ref ctor) => Some(ctor),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct MatchPatternUnderscoreType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+}
+
+// Literal patterns are stored as expressions, negative numbers are
+// represented as a negated literal.
+#[derive(Clone, Debug)]
+pub struct MatchPatternLitType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+    pub expr: Box<Expr>,
+}
+
+// A single name is either a binding or an enum variant without payload,
+// this is only known after name resolution.
+#[derive(Clone, Debug)]
+pub struct MatchPatternIdentType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+    pub name: Name,
+}
+
+#[derive(Clone, Debug)]
+pub struct MatchPatternTupleType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+    pub parts: Vec<MatchPattern>,
+}
+
+#[derive(Clone, Debug)]
+pub struct MatchPatternConstructorType {
+    pub id: NodeId,
+    pub pos: Position,
+    pub span: Span,
+    pub path: Path,
+    pub params: Option<Vec<MatchPattern>>,
+}
+
 #[derive(Clone, Debug)]
 pub struct IfPattern {
     pub id: NodeId,
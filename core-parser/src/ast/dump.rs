@@ -128,7 +128,9 @@ impl<'a> AstDumper<'a> {
         );
 
         self.indent(|d| {
-            d.dump_type(&alias.ty);
+            if let Some(ref ty) = alias.ty {
+                d.dump_type(ty);
+            }
         });
     }
 
@@ -176,6 +178,10 @@ impl<'a> AstDumper<'a> {
                 }
             });
         }
+
+        if let Some(ref discriminant) = value.discriminant {
+            self.indent(|d| d.dump_expr(discriminant));
+        }
     }
 
     fn dump_impl(&mut self, impl_: &Impl) {
@@ -189,6 +195,10 @@ impl<'a> AstDumper<'a> {
 
             d.dump_type(&impl_.extended_type);
 
+            for alias in &impl_.aliases {
+                d.dump_alias(alias);
+            }
+
             for mtd in &impl_.methods {
                 d.dump_fct(mtd);
             }
@@ -239,26 +249,21 @@ impl<'a> AstDumper<'a> {
     }
 
     fn dump_union_variant(&mut self, variant: &UnionVariant) {
-        dump!(
-            self,
-            "{} {} {}",
-            variant.pos,
-            variant.id,
-            self.str(variant.name)
-        );
-
-        if let Some(ref types) = variant.types {
-            self.indent(|d| {
-                for ty in types {
-                    d.dump_type(ty);
-                }
-            });
-        }
+        dump!(self, "{} {}", variant.pos, variant.id);
+        self.indent(|d| d.dump_type(&variant.data_type));
     }
 
     fn dump_trait(&mut self, t: &Trait) {
         dump!(self, "trait {} @ {} {}", self.str(t.name), t.pos, t.id);
         self.indent(|d| {
+            for super_trait in &t.super_traits {
+                d.dump_type(super_trait);
+            }
+
+            for alias in &t.aliases {
+                d.dump_alias(alias);
+            }
+
             for m in &t.methods {
                 d.dump_fct(m);
             }
@@ -376,7 +381,13 @@ impl<'a> AstDumper<'a> {
             param.id
         );
 
-        self.indent(|d| d.dump_type(&param.data_type));
+        if let Some(ref data_type) = param.data_type {
+            self.indent(|d| d.dump_type(data_type));
+        }
+
+        if let Some(ref default_value) = param.default_value {
+            self.indent(|d| d.dump_expr(default_value));
+        }
     }
 
     fn dump_type(&mut self, ty: &Type) {
@@ -396,6 +407,24 @@ impl<'a> AstDumper<'a> {
             Stmt::Let(ref stmt) => self.dump_stmt_let(stmt),
             Stmt::While(ref stmt) => self.dump_stmt_while(stmt),
             Stmt::For(ref stmt) => self.dump_stmt_for(stmt),
+            Stmt::Break(ref stmt) => self.dump_stmt_break(stmt),
+            Stmt::Continue(ref stmt) => self.dump_stmt_continue(stmt),
+        }
+    }
+
+    fn dump_stmt_break(&mut self, stmt: &StmtBreakType) {
+        dump!(self, "break @ {} {}", stmt.pos, stmt.id);
+        self.dump_loop_label(stmt.label);
+    }
+
+    fn dump_stmt_continue(&mut self, stmt: &StmtContinueType) {
+        dump!(self, "continue @ {} {}", stmt.pos, stmt.id);
+        self.dump_loop_label(stmt.label);
+    }
+
+    fn dump_loop_label(&mut self, label: Option<Name>) {
+        if let Some(label) = label {
+            self.indent(|d| dump!(d, "label {}", d.str(label)));
         }
     }
 
@@ -441,6 +470,7 @@ impl<'a> AstDumper<'a> {
 
     fn dump_stmt_for(&mut self, stmt: &StmtForType) {
         dump!(self, "for @ {} {}", stmt.pos, stmt.id);
+        self.dump_loop_label(stmt.label);
 
         self.indent(|d| {
             d.dump_stmt_let_pattern(&stmt.pattern);
@@ -457,6 +487,7 @@ impl<'a> AstDumper<'a> {
 
     fn dump_stmt_while(&mut self, stmt: &StmtWhileType) {
         dump!(self, "while @ {} {}", stmt.pos, stmt.id);
+        self.dump_loop_label(stmt.label);
 
         self.indent(|d| {
             dump!(d, "cond");
@@ -506,12 +537,15 @@ impl<'a> AstDumper<'a> {
             Expr::TypeParam(ref expr) => self.dump_expr_type_param(expr),
             Expr::Path(ref path) => self.dump_expr_path(path),
             Expr::This(ref selfie) => self.dump_expr_self(selfie),
+            Expr::Super(ref expr) => self.dump_expr_super(expr),
             Expr::Conv(ref expr) => self.dump_expr_conv(expr),
             Expr::Lambda(ref expr) => self.dump_expr_lambda(expr),
             Expr::Block(ref expr) => self.dump_expr_block(expr),
             Expr::If(ref expr) => self.dump_expr_if(expr),
+            Expr::Match(ref expr) => self.dump_expr_match(expr),
             Expr::Tuple(ref expr) => self.dump_expr_tuple(expr),
             Expr::Paren(ref expr) => self.dump_expr_paren(expr),
+            Expr::Try(ref expr) => self.dump_expr_try(expr),
         }
     }
 
@@ -562,9 +596,61 @@ impl<'a> AstDumper<'a> {
         });
     }
 
+    fn dump_expr_match(&mut self, expr: &ExprMatchType) {
+        dump!(self, "match @ {} {}", expr.pos, expr.id);
+        self.indent(|d| {
+            d.dump_expr(&expr.expr);
+            for case in &expr.cases {
+                dump!(d, "case @ {} {}", case.pos, case.id);
+                d.indent(|d| {
+                    d.dump_match_pattern(&case.pattern);
+
+                    if let Some(ref guard) = case.guard {
+                        dump!(d, "guard");
+                        d.indent(|d| d.dump_expr(guard));
+                    }
+
+                    dump!(d, "then");
+                    d.indent(|d| d.dump_expr(&case.value));
+                });
+            }
+        });
+    }
+
+    fn dump_match_pattern(&mut self, pattern: &MatchPattern) {
+        match pattern {
+            MatchPattern::Underscore(_) => dump!(self, "_"),
+            MatchPattern::Lit(ref lit) => self.dump_expr(&lit.expr),
+            MatchPattern::Ident(ref ident) => dump!(self, "ident {}", self.str(ident.name)),
+            MatchPattern::Tuple(ref tuple) => {
+                dump!(self, "tuple");
+                self.indent(|d| {
+                    for part in &tuple.parts {
+                        d.dump_match_pattern(part);
+                    }
+                });
+            }
+            MatchPattern::Constructor(ref ctor) => {
+                let names = ctor
+                    .path
+                    .names
+                    .iter()
+                    .map(|&name| self.str(name).to_string())
+                    .collect::<Vec<_>>();
+                dump!(self, "constructor {}", names.join("::"));
+                self.indent(|d| {
+                    for param in ctor.params.iter().flatten() {
+                        d.dump_match_pattern(param);
+                    }
+                });
+            }
+        }
+    }
+
     fn dump_expr_conv(&mut self, expr: &ExprConvType) {
         self.indent(|d| d.dump_expr(&expr.object));
-        dump!(self, "as @ {} {}", expr.pos, expr.id);
+        let op = if expr.is { "is" } else { "as" };
+        dump!(self, "{} @ {} {}", op, expr.pos, expr.id);
         self.indent(|d| d.dump_type(&expr.data_type));
     }
 
@@ -572,6 +658,10 @@ impl<'a> AstDumper<'a> {
         dump!(self, "self @ {} {}", selfie.pos, selfie.id);
     }
 
+    fn dump_expr_super(&mut self, expr: &ExprSuperType) {
+        dump!(self, "super @ {} {}", expr.pos, expr.id);
+    }
+
     fn dump_expr_lit_char(&mut self, lit: &ExprLitCharType) {
         dump!(
             self,
@@ -683,6 +773,13 @@ impl<'a> AstDumper<'a> {
         });
     }
 
+    fn dump_expr_try(&mut self, expr: &ExprTryType) {
+        dump!(self, "try @ {} {}", expr.pos, expr.id);
+        self.indent(|d| {
+            d.dump_expr(&expr.expr);
+        });
+    }
+
     fn dump_expr_type_param(&mut self, expr: &ExprTypeParamType) {
         dump!(self, "type param @ {} {}", expr.pos, expr.id);
 
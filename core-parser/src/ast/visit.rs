@@ -152,7 +152,9 @@ pub fn walk_enum<V: Visitor>(_v: &mut V, _e: &Arc<Enum>) {
 }
 
 pub fn walk_alias<V: Visitor>(v: &mut V, a: &Arc<Alias>) {
-    v.visit_type(&a.ty);
+    if let Some(ref ty) = a.ty {
+        v.visit_type(ty);
+    }
 }
 
 pub fn walk_module<V: Visitor>(v: &mut V, node: &Arc<Module>) {
@@ -206,12 +208,19 @@ pub fn walk_fct<V: Visitor>(v: &mut V, f: &Function) {
 }
 
 pub fn walk_param<V: Visitor>(v: &mut V, p: &Param) {
-    v.visit_type(&p.data_type);
+    if let Some(ref data_type) = p.data_type {
+        v.visit_type(data_type);
+    }
+
+    if let Some(ref default_value) = p.default_value {
+        v.visit_expr(default_value);
+    }
 }
 
 pub fn walk_type<V: Visitor>(v: &mut V, t: &Type) {
     match *t {
         Type::This(_) => {}
+        Type::Assoc(_) => {}
         Type::Basic(_) => {}
         Type::Tuple(ref tuple) => {
             for ty in &tuple.subtypes {
@@ -260,6 +269,8 @@ pub fn walk_stmt<V: Visitor>(v: &mut V, s: &Stmt) {
                 v.visit_expr(e);
             }
         }
+
+        Stmt::Break(_) | Stmt::Continue(_) => {}
     }
 }
 
@@ -333,6 +344,10 @@ pub fn walk_expr<V: Visitor>(v: &mut V, e: &Expr) {
             v.visit_expr(&value.expr);
         }
 
+        Expr::Try(ref value) => {
+            v.visit_expr(&value.expr);
+        }
+
         Expr::If(ref value) => {
             v.visit_stmt(&Stmt::Let(value.cond.as_ref().clone()));
             for case in &value.cases {
@@ -349,7 +364,20 @@ pub fn walk_expr<V: Visitor>(v: &mut V, e: &Expr) {
             }
         }
 
+        Expr::Match(ref value) => {
+            v.visit_expr(&value.expr);
+
+            for case in &value.cases {
+                if let Some(ref guard) = case.guard {
+                    v.visit_expr(guard);
+                }
+
+                v.visit_expr(&case.value);
+            }
+        }
+
         Expr::This(_) => {}
+        Expr::Super(_) => {}
         Expr::LitChar(_) => {}
         Expr::LitInt(_) => {}
         Expr::LitFloat(_) => {}
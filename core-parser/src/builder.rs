@@ -90,6 +90,9 @@ impl<'a> BuilderFct {
             internal: false,
             is_constructor: self.is_constructor,
             is_test: false,
+            is_open: false,
+            is_override: false,
+            is_final: false,
             params: self.params,
             is_nullary: false,
             return_type: self.return_type,
@@ -22,6 +22,8 @@ pub enum ParseError {
     UnclosedStringTemplate,
     ExpectedIdentifier(String),
     InvalidSuffix(String),
+    ExpectedLoop(String),
+    ExpectedPattern(String),
 }
 
 impl ParseError {
@@ -58,6 +60,8 @@ impl ParseError {
                 format!("identifier expected but got {}.", tok)
             }
             ParseError::InvalidSuffix(ref suffix) => format!("invalid suffix `{}`", suffix),
+            ParseError::ExpectedLoop(ref got) => format!("loop expected but got {}.", got),
+            ParseError::ExpectedPattern(ref got) => format!("pattern expected but got {}.", got),
         }
     }
 }
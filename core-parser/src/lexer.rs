@@ -302,6 +302,9 @@ impl Lexer {
                     } else {
                         TokenKind::EqEq
                     }
+                } else if nch == '>' {
+                    self.read_char();
+                    TokenKind::Arrow
                 } else {
                     TokenKind::Eq
                 }
@@ -339,6 +342,7 @@ impl Lexer {
                 }
             }
             '@' => TokenKind::At,
+            '?' => TokenKind::QuestionMark,
 
             _ => {
                 return Err(ParseErrorAndPos::new(pos, ParseError::UnknownChar(ch)));
@@ -388,9 +392,14 @@ impl Lexer {
             let suffix = self.read_identifier_as_string();
 
             match suffix.as_str() {
-                "u8" => TokenKind::LitInt(value, base, IntSuffix::UInt8),
+                "i8" => TokenKind::LitInt(value, base, IntSuffix::Int8),
+                "i16" => TokenKind::LitInt(value, base, IntSuffix::Int16),
                 "i32" => TokenKind::LitInt(value, base, IntSuffix::Int32),
                 "i64" => TokenKind::LitInt(value, base, IntSuffix::Int64),
+                "u8" => TokenKind::LitInt(value, base, IntSuffix::UInt8),
+                "u16" => TokenKind::LitInt(value, base, IntSuffix::UInt16),
+                "u32" => TokenKind::LitInt(value, base, IntSuffix::UInt32),
+                "u64" => TokenKind::LitInt(value, base, IntSuffix::UInt64),
                 "f32" if base == IntBase::Dec => TokenKind::LitFloat(value, FloatSuffix::Float32),
                 "f64" if base == IntBase::Dec => TokenKind::LitFloat(value, FloatSuffix::Float64),
                 _ => {
@@ -516,7 +525,7 @@ fn is_char_quote(ch: Option<char>) -> bool {
 }
 
 fn is_operator(ch: Option<char>) -> bool {
-    ch.map(|ch| "^+-*/&|,=!~;:.()[]{}<>@".contains(ch))
+    ch.map(|ch| "^+-*/&|,=!~;:.()[]{}<>@?".contains(ch))
         .unwrap_or(false)
 }
 
@@ -563,6 +572,8 @@ fn keywords_in_map() -> HashMap<&'static str, TokenKind> {
     keywords.insert("while", TokenKind::While);
     keywords.insert("for", TokenKind::For);
     keywords.insert("in", TokenKind::In);
+    keywords.insert("break", TokenKind::Break);
+    keywords.insert("continue", TokenKind::Continue);
 
     // qualifiers
     keywords.insert("self", TokenKind::This);
@@ -570,6 +581,7 @@ fn keywords_in_map() -> HashMap<&'static str, TokenKind> {
 
     // pattern matching
     keywords.insert("is", TokenKind::Is);
+    keywords.insert("match", TokenKind::Match);
 
     // casting
     keywords.insert("as", TokenKind::As);
@@ -712,6 +724,39 @@ mod tests {
             10,
         );
         assert_end(&mut reader, 1, 14);
+
+        let mut reader = Lexer::from_str("1i8 2i16 3u16 4u32 5u64");
+        assert_tok(
+            &mut reader,
+            TokenKind::LitInt("1".into(), IntBase::Dec, IntSuffix::Int8),
+            1,
+            1,
+        );
+        assert_tok(
+            &mut reader,
+            TokenKind::LitInt("2".into(), IntBase::Dec, IntSuffix::Int16),
+            1,
+            5,
+        );
+        assert_tok(
+            &mut reader,
+            TokenKind::LitInt("3".into(), IntBase::Dec, IntSuffix::UInt16),
+            1,
+            10,
+        );
+        assert_tok(
+            &mut reader,
+            TokenKind::LitInt("4".into(), IntBase::Dec, IntSuffix::UInt32),
+            1,
+            15,
+        );
+        assert_tok(
+            &mut reader,
+            TokenKind::LitInt("5".into(), IntBase::Dec, IntSuffix::UInt64),
+            1,
+            20,
+        );
+        assert_end(&mut reader, 1, 24);
     }
 
     #[test]
@@ -1056,6 +1101,14 @@ mod tests {
         assert_tok(&mut reader, TokenKind::Else, 1, 10);
         assert_tok(&mut reader, TokenKind::Return, 1, 15);
 
+        let mut reader = Lexer::from_str("break continue");
+        assert_tok(&mut reader, TokenKind::Break, 1, 1);
+        assert_tok(&mut reader, TokenKind::Continue, 1, 7);
+
+        let mut reader = Lexer::from_str("match is");
+        assert_tok(&mut reader, TokenKind::Match, 1, 1);
+        assert_tok(&mut reader, TokenKind::Is, 1, 7);
+
         let mut reader = Lexer::from_str("self class super mod");
         assert_tok(&mut reader, TokenKind::This, 1, 1);
         assert_tok(&mut reader, TokenKind::Class, 1, 6);
@@ -1081,7 +1134,7 @@ mod tests {
 
     #[test]
     fn test_operators() {
-        let mut reader = Lexer::from_str("==-*/.@...,");
+        let mut reader = Lexer::from_str("==-*/.@...,?");
         assert_tok(&mut reader, TokenKind::EqEq, 1, 1);
         assert_tok(&mut reader, TokenKind::Sub, 1, 3);
         assert_tok(&mut reader, TokenKind::Mul, 1, 4);
@@ -1090,6 +1143,7 @@ mod tests {
         assert_tok(&mut reader, TokenKind::At, 1, 7);
         assert_tok(&mut reader, TokenKind::DotDotDot, 1, 8);
         assert_tok(&mut reader, TokenKind::Comma, 1, 11);
+        assert_tok(&mut reader, TokenKind::QuestionMark, 1, 12);
 
         let mut reader = Lexer::from_str("<=<>=><");
         assert_tok(&mut reader, TokenKind::Le, 1, 1);
@@ -1105,6 +1159,10 @@ mod tests {
         let mut reader = Lexer::from_str("!=");
         assert_tok(&mut reader, TokenKind::NotEq, 1, 1);
 
+        let mut reader = Lexer::from_str("=>==");
+        assert_tok(&mut reader, TokenKind::Arrow, 1, 1);
+        assert_tok(&mut reader, TokenKind::EqEq, 1, 3);
+
         let mut reader = Lexer::from_str("_::");
         assert_tok(&mut reader, TokenKind::Underscore, 1, 1);
         assert_tok(&mut reader, TokenKind::ColonColon, 1, 2);
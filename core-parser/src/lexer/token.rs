@@ -41,6 +41,8 @@ pub enum TokenKind {
     While,
     For,
     In,
+    Break,
+    Continue,
 
     // qualifiers
     This,
@@ -48,6 +50,7 @@ pub enum TokenKind {
 
     // pattern matching
     Is,
+    Match,
 
     // casting
     As,
@@ -85,8 +88,10 @@ pub enum TokenKind {
     Dot,
     DotDotDot,
     Colon,
+    Arrow,
     ColonColon,
     At,
+    QuestionMark,
 
     // brackets
     LParen,
@@ -110,9 +115,14 @@ impl TokenKind {
             TokenKind::StringTail(_) => "string tail",
             TokenKind::StringExpr(_) => "string epxr",
             TokenKind::LitInt(_, _, suffix) => match suffix {
-                IntSuffix::UInt8 => "byte number",
+                IntSuffix::Int8 => "int8 number",
+                IntSuffix::Int16 => "int16 number",
                 IntSuffix::Int32 => "int32 number",
                 IntSuffix::Int64 => "int64 number",
+                IntSuffix::UInt8 => "byte number",
+                IntSuffix::UInt16 => "uint16 number",
+                IntSuffix::UInt32 => "uint32 number",
+                IntSuffix::UInt64 => "uint64 number",
                 IntSuffix::None => "untyped number",
             },
             TokenKind::LitChar(_) => "char",
@@ -149,6 +159,8 @@ impl TokenKind {
             TokenKind::While => "while",
             TokenKind::For => "for",
             TokenKind::In => "in",
+            TokenKind::Break => "break",
+            TokenKind::Continue => "continue",
 
             // qualifiers
             TokenKind::This => "self",
@@ -156,6 +168,7 @@ impl TokenKind {
 
             // pattern matching
             TokenKind::Is => "is",
+            TokenKind::Match => "match",
 
             // casting
             TokenKind::As => "as",
@@ -193,8 +206,10 @@ impl TokenKind {
             TokenKind::Dot => ".",
             TokenKind::DotDotDot => "...",
             TokenKind::Colon => ":",
+            TokenKind::Arrow => "=>",
             TokenKind::ColonColon => "::",
             TokenKind::At => "@",
+            TokenKind::QuestionMark => "?",
 
             // brackets
             TokenKind::LParen => "(",
@@ -218,9 +233,14 @@ impl TokenKind {
 
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum IntSuffix {
-    UInt8,
+    Int8,
+    Int16,
     Int32,
     Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
     None,
 }
 
@@ -265,9 +285,14 @@ impl Token {
         match self.kind {
             TokenKind::LitInt(ref val, _, suffix) => {
                 let suffix = match suffix {
-                    IntSuffix::UInt8 => "B",
+                    IntSuffix::Int8 => "i8",
+                    IntSuffix::Int16 => "i16",
                     IntSuffix::Int32 => "",
                     IntSuffix::Int64 => "L",
+                    IntSuffix::UInt8 => "B",
+                    IntSuffix::UInt16 => "u16",
+                    IntSuffix::UInt32 => "u32",
+                    IntSuffix::UInt64 => "u64",
                     IntSuffix::None => "",
                 };
 
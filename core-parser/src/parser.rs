@@ -31,6 +31,11 @@ enum StmtOrExpr {
     Expr(Box<Expr>),
 }
 
+enum TypeArgument {
+    Type(Type),
+    Binding(TypeBinding),
+}
+
 impl<'a> Parser<'a> {
     pub fn from_string(code: &'static str, interner: &'a mut Interner) -> Parser<'a> {
         let reader = Reader::from_string(code);
@@ -101,7 +106,8 @@ impl<'a> Parser<'a> {
             }
 
             TokenKind::Class => {
-                self.restrict_modifiers(&modifiers, &[Modifier::Internal, Modifier::Pub])?;
+                let mods = &[Modifier::Internal, Modifier::Pub, Modifier::Open];
+                self.restrict_modifiers(&modifiers, mods)?;
                 let class = self.parse_class(&modifiers)?;
                 Ok(Elem::Class(Arc::new(class)))
             }
@@ -135,7 +141,7 @@ impl<'a> Parser<'a> {
                 Ok(Elem::Annotation(Arc::new(annotation)))
             }
 
-            TokenKind::Alias => {
+            TokenKind::Type | TokenKind::Alias => {
                 self.restrict_modifiers(&modifiers, &[Modifier::Pub])?;
                 let alias = self.parse_alias(&modifiers)?;
                 Ok(Elem::Alias(Arc::new(alias)))
@@ -353,6 +359,13 @@ impl<'a> Parser<'a> {
             None
         };
 
+        let discriminant = if self.token.is(TokenKind::Eq) {
+            self.advance_token()?;
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
         let span = self.span_from(start);
 
         Ok(EnumVariant {
@@ -361,6 +374,7 @@ impl<'a> Parser<'a> {
             span,
             name,
             types,
+            discriminant,
         })
     }
 
@@ -404,11 +418,27 @@ impl<'a> Parser<'a> {
 
         self.expect_token(TokenKind::LBrace)?;
 
+        let mut aliases = Vec::new();
         let mut methods = Vec::new();
 
         while !self.token.is(TokenKind::RBrace) {
             let modifiers = self.parse_annotation_usages()?;
-            let mods = &[Modifier::Static, Modifier::Internal, Modifier::Pub];
+
+            if self.token.is(TokenKind::Type) || self.token.is(TokenKind::Alias) {
+                self.restrict_modifiers(&modifiers, &[])?;
+                let alias = self.parse_assoc_alias(true)?;
+                aliases.push(Arc::new(alias));
+                continue;
+            }
+
+            let mods = &[
+                Modifier::Static,
+                Modifier::Internal,
+                Modifier::Pub,
+                Modifier::Open,
+                Modifier::Override,
+                Modifier::Final,
+            ];
             self.restrict_modifiers(&modifiers, mods)?;
 
             let method = self.parse_function(&modifiers)?;
@@ -425,6 +455,7 @@ impl<'a> Parser<'a> {
             type_params,
             trait_type,
             extended_type: class_type,
+            aliases,
             methods,
         })
     }
@@ -475,12 +506,28 @@ impl<'a> Parser<'a> {
         let ident = self.expect_identifier()?;
         let type_params = self.parse_type_params()?;
 
+        let super_traits = if self.token.is(TokenKind::Colon) {
+            self.advance_token()?;
+            self.parse_bounds()?
+        } else {
+            Vec::new()
+        };
+
         self.expect_token(TokenKind::LBrace)?;
 
+        let mut aliases = Vec::new();
         let mut methods = Vec::new();
 
         while !self.token.is(TokenKind::RBrace) {
             let modifiers = self.parse_annotation_usages()?;
+
+            if self.token.is(TokenKind::Type) || self.token.is(TokenKind::Alias) {
+                self.restrict_modifiers(&modifiers, &[])?;
+                let alias = self.parse_assoc_alias(false)?;
+                aliases.push(Arc::new(alias));
+                continue;
+            }
+
             let mods = &[Modifier::Static];
             self.restrict_modifiers(&modifiers, mods)?;
 
@@ -495,8 +542,10 @@ impl<'a> Parser<'a> {
             id: self.generate_id(),
             name: ident,
             type_params,
+            super_traits,
             pos,
             span,
+            aliases,
             methods,
             visibility: Visibility::from_modifiers(modifiers),
         })
@@ -590,23 +639,14 @@ impl<'a> Parser<'a> {
     fn parse_union_variant(&mut self) -> Result<UnionVariant, ParseErrorAndPos> {
         let start = self.token.span.start();
         let pos = self.token.position;
-        let name = self.expect_identifier()?;
-
-        let types = if self.token.is(TokenKind::LParen) {
-            self.advance_token()?;
-            Some(self.parse_list(TokenKind::Comma, TokenKind::RParen, |p| p.parse_type())?)
-        } else {
-            None
-        };
-
+        let data_type = self.parse_type()?;
         let span = self.span_from(start);
 
         Ok(UnionVariant {
             id: self.generate_id(),
             pos,
             span,
-            name,
-            types,
+            data_type,
         })
     }
 
@@ -631,6 +671,13 @@ impl<'a> Parser<'a> {
             Vec::new()
         };
 
+        let parent_class = if self.token.is(TokenKind::Colon) {
+            self.advance_token()?;
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
         let span = self.span_from(start);
 
         Ok(Class {
@@ -639,9 +686,11 @@ impl<'a> Parser<'a> {
             pos,
             span,
             internal: modifiers.contains(Modifier::Internal),
+            is_open: modifiers.contains(Modifier::Open),
             visibility: Visibility::from_modifiers(modifiers),
             fields,
             type_params,
+            parent_class,
         })
     }
 
@@ -734,8 +783,10 @@ impl<'a> Parser<'a> {
 
     fn parse_alias(&mut self, modifiers: &Modifiers) -> Result<Alias, ParseErrorAndPos> {
         let start = self.token.span.start();
-        let pos = self.expect_token(TokenKind::Alias)?.position;
+        let pos = self.token.position;
+        self.advance_token()?;
         let name = self.expect_identifier()?;
+        let type_params = self.parse_type_params()?;
         self.expect_token(TokenKind::Eq)?;
         let ty = self.parse_type()?;
         self.expect_semicolon()?;
@@ -746,11 +797,41 @@ impl<'a> Parser<'a> {
             pos,
             name,
             span,
-            ty,
+            type_params,
+            ty: Some(ty),
             visibility: Visibility::from_modifiers(modifiers),
         })
     }
 
+    // Associated type in a trait (`type Item`) or its definition in an
+    // impl (`type Item = Int32`).
+    fn parse_assoc_alias(&mut self, with_type: bool) -> Result<Alias, ParseErrorAndPos> {
+        let start = self.token.span.start();
+        let pos = self.token.position;
+        self.advance_token()?;
+        let name = self.expect_identifier()?;
+
+        let ty = if with_type {
+            self.expect_token(TokenKind::Eq)?;
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        self.skip_semicolon()?;
+        let span = self.span_from(start);
+
+        Ok(Alias {
+            id: self.generate_id(),
+            pos,
+            name,
+            span,
+            type_params: None,
+            ty,
+            visibility: Visibility::Public,
+        })
+    }
+
     fn parse_type_params(&mut self) -> Result<Option<Vec<TypeParam>>, ParseErrorAndPos> {
         if self.token.is(TokenKind::LBracket) {
             self.advance_token()?;
@@ -771,20 +852,7 @@ impl<'a> Parser<'a> {
 
         let bounds = if self.token.is(TokenKind::Colon) {
             self.advance_token()?;
-
-            let mut bounds = Vec::new();
-
-            loop {
-                bounds.push(self.parse_type()?);
-
-                if self.token.is(TokenKind::Add) {
-                    self.advance_token()?;
-                } else {
-                    break;
-                }
-            }
-
-            bounds
+            self.parse_bounds()?
         } else {
             Vec::new()
         };
@@ -799,6 +867,22 @@ impl<'a> Parser<'a> {
         })
     }
 
+    fn parse_bounds(&mut self) -> Result<Vec<Type>, ParseErrorAndPos> {
+        let mut bounds = Vec::new();
+
+        loop {
+            bounds.push(self.parse_type()?);
+
+            if self.token.is(TokenKind::Add) {
+                self.advance_token()?;
+            } else {
+                break;
+            }
+        }
+
+        Ok(bounds)
+    }
+
     fn parse_annotation_usages(&mut self) -> Result<Modifiers, ParseErrorAndPos> {
         let mut modifiers = Modifiers::new();
         loop {
@@ -813,6 +897,9 @@ impl<'a> Parser<'a> {
                 "static" => Modifier::Static,
                 "test" => Modifier::Test,
                 "optimizeImmediately" => Modifier::OptimizeImmediately,
+                "open" => Modifier::Open,
+                "override" => Modifier::Override,
+                "final" => Modifier::Final,
                 annotation => {
                     return Err(ParseErrorAndPos::new(
                         self.token.position,
@@ -878,6 +965,9 @@ impl<'a> Parser<'a> {
             internal: modifiers.contains(Modifier::Internal),
             is_constructor: false,
             is_test: modifiers.contains(Modifier::Test),
+            is_open: modifiers.contains(Modifier::Open),
+            is_override: modifiers.contains(Modifier::Override),
+            is_final: modifiers.contains(Modifier::Final),
             is_nullary,
             params,
             return_type,
@@ -944,7 +1034,7 @@ impl<'a> Parser<'a> {
 
         self.expect_token(TokenKind::Colon)?;
 
-        let data_type = self.parse_type()?;
+        let data_type = Some(self.parse_type()?);
 
         let variadic = if self.token.is(TokenKind::DotDotDot) {
             self.advance_token()?;
@@ -953,6 +1043,13 @@ impl<'a> Parser<'a> {
             false
         };
 
+        let default_value = if self.token.is(TokenKind::Eq) {
+            self.advance_token()?;
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
         let span = self.span_from(start);
 
         Ok(Param {
@@ -963,6 +1060,33 @@ impl<'a> Parser<'a> {
             pos,
             span,
             data_type,
+            default_value,
+        })
+    }
+
+    fn parse_lambda_param(&mut self) -> Result<Param, ParseErrorAndPos> {
+        let start = self.token.span.start();
+        let pos = self.token.position;
+        let name = self.expect_identifier()?;
+
+        let data_type = if self.token.is(TokenKind::Colon) {
+            self.advance_token()?;
+            Some(self.parse_type()?)
+        } else {
+            None
+        };
+
+        let span = self.span_from(start);
+
+        Ok(Param {
+            id: self.generate_id(),
+            idx: self.param_idx - 1,
+            variadic: false,
+            name,
+            pos,
+            span,
+            data_type,
+            default_value: None,
         })
     }
 
@@ -1033,9 +1157,18 @@ impl<'a> Parser<'a> {
         match self.token.kind {
             TokenKind::CapitalThis => {
                 let pos = self.token.position;
+                let start = self.token.span.start();
                 let span = self.token.span;
                 self.advance_token()?;
-                Ok(Type::create_self(self.generate_id(), pos, span))
+
+                if self.token.is(TokenKind::ColonColon) {
+                    self.advance_token()?;
+                    let name = self.expect_identifier()?;
+                    let span = self.span_from(start);
+                    Ok(Type::create_assoc(self.generate_id(), pos, span, name))
+                } else {
+                    Ok(Type::create_self(self.generate_id(), pos, span))
+                }
             }
 
             TokenKind::Identifier(_) | TokenKind::Is | TokenKind::Value => {
@@ -1043,14 +1176,22 @@ impl<'a> Parser<'a> {
                 let start = self.token.span.start();
                 let path = self.parse_path()?;
 
-                let params = if self.token.is(TokenKind::LBracket) {
+                let mut params = Vec::new();
+                let mut bindings = Vec::new();
+
+                if self.token.is(TokenKind::LBracket) {
                     self.advance_token()?;
-                    self.parse_list(TokenKind::Comma, TokenKind::RBracket, |p| {
-                        Ok(Box::new(p.parse_type()?))
-                    })?
-                } else {
-                    Vec::new()
-                };
+                    let args = self.parse_list(TokenKind::Comma, TokenKind::RBracket, |p| {
+                        p.parse_type_argument()
+                    })?;
+
+                    for arg in args {
+                        match arg {
+                            TypeArgument::Type(ty) => params.push(Box::new(ty)),
+                            TypeArgument::Binding(binding) => bindings.push(binding),
+                        }
+                    }
+                }
 
                 let span = self.span_from(start);
                 Ok(Type::create_basic(
@@ -1059,6 +1200,7 @@ impl<'a> Parser<'a> {
                     span,
                     path,
                     params,
+                    bindings,
                 ))
             }
 
@@ -1101,6 +1243,27 @@ impl<'a> Parser<'a> {
         }
     }
 
+    fn parse_type_argument(&mut self) -> Result<TypeArgument, ParseErrorAndPos> {
+        if self.token.is_identifier() && self.lexer.peek_next_token()?.is(TokenKind::Eq) {
+            let start = self.token.span.start();
+            let pos = self.token.position;
+            let name = self.expect_identifier()?;
+            self.expect_token(TokenKind::Eq)?;
+            let ty = self.parse_type()?;
+            let span = self.span_from(start);
+
+            Ok(TypeArgument::Binding(TypeBinding {
+                id: self.generate_id(),
+                pos,
+                span,
+                name,
+                ty: Box::new(ty),
+            }))
+        } else {
+            Ok(TypeArgument::Type(self.parse_type()?))
+        }
+    }
+
     fn parse_path(&mut self) -> Result<Path, ParseErrorAndPos> {
         let pos = self.token.position;
         let start = self.token.span.start();
@@ -1282,13 +1445,18 @@ impl<'a> Parser<'a> {
     fn parse_statement_or_expression(&mut self) -> StmtOrExprResult {
         match self.token.kind {
             TokenKind::Let | TokenKind::Var => Ok(StmtOrExpr::Stmt(self.parse_let()?)),
-            TokenKind::While => Ok(StmtOrExpr::Stmt(self.parse_while()?)),
+            TokenKind::While => Ok(StmtOrExpr::Stmt(self.parse_while(None)?)),
             TokenKind::Return => Ok(StmtOrExpr::Stmt(self.parse_return()?)),
+            TokenKind::Break => Ok(StmtOrExpr::Stmt(self.parse_break()?)),
+            TokenKind::Continue => Ok(StmtOrExpr::Stmt(self.parse_continue()?)),
             TokenKind::Else => Err(ParseErrorAndPos::new(
                 self.token.position,
                 ParseError::MisplacedElse,
             )),
-            TokenKind::For => Ok(StmtOrExpr::Stmt(self.parse_for()?)),
+            TokenKind::For => Ok(StmtOrExpr::Stmt(self.parse_for(None)?)),
+            TokenKind::Identifier(_) if self.lexer.peek_next_token()?.is(TokenKind::Colon) => {
+                Ok(StmtOrExpr::Stmt(self.parse_labeled_loop()?))
+            }
             _ => {
                 let expr = self.parse_expression()?;
                 self.skip_semicolon()?;
@@ -1468,7 +1636,216 @@ impl<'a> Parser<'a> {
         })
     }
 
-    fn parse_for(&mut self) -> StmtResult {
+    fn parse_match(&mut self) -> ExprResult {
+        let start = self.token.span.start();
+        let pos = self.expect_token(TokenKind::Match)?.position;
+
+        let expr = self.parse_expression()?;
+        self.expect_token(TokenKind::LBrace)?;
+
+        let mut cases = Vec::new();
+
+        while !self.token.is(TokenKind::RBrace) && !self.token.is_eof() {
+            let case = self.parse_match_case()?;
+            let value_is_block = case.value.is_block();
+            cases.push(case);
+
+            // The comma after an arm may be omitted when its value is a block.
+            if self.token.is(TokenKind::Comma) {
+                self.advance_token()?;
+            } else if !value_is_block && !self.token.is(TokenKind::RBrace) {
+                return Err(ParseErrorAndPos::new(
+                    self.token.position,
+                    ParseError::ExpectedToken(TokenKind::Comma.name().into(), self.token.name()),
+                ));
+            }
+        }
+
+        self.expect_token(TokenKind::RBrace)?;
+        let span = self.span_from(start);
+
+        Ok(Box::new(Expr::create_match(
+            self.generate_id(),
+            pos,
+            span,
+            expr,
+            cases,
+        )))
+    }
+
+    fn parse_match_case(&mut self) -> Result<MatchCaseType, ParseErrorAndPos> {
+        let start = self.token.span.start();
+        let pos = self.token.position;
+
+        let pattern = self.parse_match_pattern()?;
+
+        let guard = if self.token.is(TokenKind::If) {
+            self.advance_token()?;
+            Some(self.parse_expression()?)
+        } else {
+            None
+        };
+
+        self.expect_token(TokenKind::Arrow)?;
+        let value = self.parse_expression()?;
+        let span = self.span_from(start);
+
+        Ok(MatchCaseType {
+            id: self.generate_id(),
+            pos,
+            span,
+            pattern,
+            guard,
+            value,
+        })
+    }
+
+    fn parse_match_pattern(&mut self) -> Result<Box<MatchPattern>, ParseErrorAndPos> {
+        let start = self.token.span.start();
+        let pos = self.token.position;
+
+        let pattern = match self.token.kind {
+            TokenKind::Underscore => {
+                self.advance_token()?;
+
+                MatchPattern::Underscore(MatchPatternUnderscoreType {
+                    id: self.generate_id(),
+                    pos,
+                    span: self.span_from(start),
+                })
+            }
+
+            TokenKind::LParen => {
+                self.advance_token()?;
+                let mut parts = self.parse_list(TokenKind::Comma, TokenKind::RParen, |p| {
+                    p.parse_match_pattern().map(|pattern| *pattern)
+                })?;
+
+                if parts.len() == 1 {
+                    return Ok(Box::new(parts.pop().unwrap()));
+                }
+
+                MatchPattern::Tuple(MatchPatternTupleType {
+                    id: self.generate_id(),
+                    pos,
+                    span: self.span_from(start),
+                    parts,
+                })
+            }
+
+            TokenKind::LitChar(_)
+            | TokenKind::LitInt(_, _, _)
+            | TokenKind::LitFloat(_, _)
+            | TokenKind::StringTail(_)
+            | TokenKind::True
+            | TokenKind::False
+            | TokenKind::Sub => {
+                let expr = self.parse_match_pattern_lit()?;
+
+                MatchPattern::Lit(MatchPatternLitType {
+                    id: self.generate_id(),
+                    pos,
+                    span: self.span_from(start),
+                    expr,
+                })
+            }
+
+            TokenKind::Identifier(_) => {
+                let path = self.parse_path()?;
+
+                if self.token.is(TokenKind::LParen) {
+                    self.advance_token()?;
+                    let params = self.parse_list(TokenKind::Comma, TokenKind::RParen, |p| {
+                        p.parse_match_pattern().map(|pattern| *pattern)
+                    })?;
+
+                    MatchPattern::Constructor(MatchPatternConstructorType {
+                        id: self.generate_id(),
+                        pos,
+                        span: self.span_from(start),
+                        path,
+                        params: Some(params),
+                    })
+                } else if path.names.len() == 1 {
+                    MatchPattern::Ident(MatchPatternIdentType {
+                        id: self.generate_id(),
+                        pos,
+                        span: self.span_from(start),
+                        name: path.names[0],
+                    })
+                } else {
+                    MatchPattern::Constructor(MatchPatternConstructorType {
+                        id: self.generate_id(),
+                        pos,
+                        span: self.span_from(start),
+                        path,
+                        params: None,
+                    })
+                }
+            }
+
+            _ => {
+                return Err(ParseErrorAndPos::new(
+                    pos,
+                    ParseError::ExpectedPattern(self.token.name()),
+                ));
+            }
+        };
+
+        Ok(Box::new(pattern))
+    }
+
+    fn parse_match_pattern_lit(&mut self) -> ExprResult {
+        match self.token.kind {
+            TokenKind::LitChar(_) => self.parse_lit_char(),
+            TokenKind::LitInt(_, _, _) => self.parse_lit_int(),
+            TokenKind::LitFloat(_, _) => self.parse_lit_float(),
+            TokenKind::StringTail(_) => self.parse_string(),
+            TokenKind::True | TokenKind::False => self.parse_bool_literal(),
+            TokenKind::Sub => {
+                let start = self.token.span.start();
+                let pos = self.advance_token()?.position;
+
+                let opnd = match self.token.kind {
+                    TokenKind::LitInt(_, _, _) => self.parse_lit_int()?,
+                    TokenKind::LitFloat(_, _) => self.parse_lit_float()?,
+                    _ => {
+                        return Err(ParseErrorAndPos::new(
+                            self.token.position,
+                            ParseError::ExpectedPattern(self.token.name()),
+                        ));
+                    }
+                };
+
+                let span = self.span_from(start);
+
+                Ok(Box::new(Expr::create_un(
+                    self.generate_id(),
+                    pos,
+                    span,
+                    UnOp::Neg,
+                    opnd,
+                )))
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    fn parse_labeled_loop(&mut self) -> StmtResult {
+        let label = self.expect_identifier()?;
+        self.expect_token(TokenKind::Colon)?;
+
+        match self.token.kind {
+            TokenKind::While => self.parse_while(Some(label)),
+            TokenKind::For => self.parse_for(Some(label)),
+            _ => Err(ParseErrorAndPos::new(
+                self.token.position,
+                ParseError::ExpectedLoop(self.token.name()),
+            )),
+        }
+    }
+
+    fn parse_for(&mut self, label: Option<Name>) -> StmtResult {
         let start = self.token.span.start();
         let pos = self.expect_token(TokenKind::For)?.position;
         let pattern = self.parse_let_pattern()?;
@@ -1481,13 +1858,14 @@ impl<'a> Parser<'a> {
             self.generate_id(),
             pos,
             span,
+            label,
             pattern,
             expr,
             block,
         )))
     }
 
-    fn parse_while(&mut self) -> StmtResult {
+    fn parse_while(&mut self, label: Option<Name>) -> StmtResult {
         let start = self.token.span.start();
         let pos = self.expect_token(TokenKind::While)?.position;
         let expr = self.parse_expression()?;
@@ -1498,6 +1876,7 @@ impl<'a> Parser<'a> {
             self.generate_id(),
             pos,
             span,
+            label,
             expr,
             block,
         )))
@@ -1522,10 +1901,50 @@ impl<'a> Parser<'a> {
         )))
     }
 
+    fn parse_break(&mut self) -> StmtResult {
+        let start = self.token.span.start();
+        let pos = self.expect_token(TokenKind::Break)?.position;
+        let label = self.parse_loop_label(pos)?;
+        self.skip_semicolon()?;
+        let span = self.span_from(start);
+
+        Ok(Box::new(Stmt::create_break(
+            self.generate_id(),
+            pos,
+            span,
+            label,
+        )))
+    }
+
+    fn parse_continue(&mut self) -> StmtResult {
+        let start = self.token.span.start();
+        let pos = self.expect_token(TokenKind::Continue)?.position;
+        let label = self.parse_loop_label(pos)?;
+        self.skip_semicolon()?;
+        let span = self.span_from(start);
+
+        Ok(Box::new(Stmt::create_continue(
+            self.generate_id(),
+            pos,
+            span,
+            label,
+        )))
+    }
+
+    // Semicolons are optional, an identifier on the next line starts a new statement.
+    fn parse_loop_label(&mut self, pos: Position) -> Result<Option<Name>, ParseErrorAndPos> {
+        if self.token.is_identifier() && self.token.position.line == pos.line {
+            Ok(Some(self.expect_identifier()?))
+        } else {
+            Ok(None)
+        }
+    }
+
     fn parse_expression(&mut self) -> ExprResult {
         let result = match self.token.kind {
             TokenKind::LBrace => self.parse_block(),
             TokenKind::If => self.parse_if(),
+            TokenKind::Match => self.parse_match(),
             _ => self.parse_binary(0),
         };
 
@@ -1551,7 +1970,7 @@ impl<'a> Parser<'a> {
                 | TokenKind::NeEqEq => 4,
                 TokenKind::Add | TokenKind::Sub | TokenKind::Or | TokenKind::Caret => 5,
                 TokenKind::Mul | TokenKind::Div | TokenKind::And => 6,
-                TokenKind::As => 7,
+                TokenKind::As | TokenKind::Is => 7,
                 _ => {
                     return Ok(left);
                 }
@@ -1564,11 +1983,12 @@ impl<'a> Parser<'a> {
             let tok = self.advance_token()?;
 
             left = match tok.kind {
-                TokenKind::As => {
+                TokenKind::As | TokenKind::Is => {
+                    let is = tok.is(TokenKind::Is);
                     let right = Box::new(self.parse_type()?);
                     let span = self.span_from(start);
                     let expr =
-                        Expr::create_conv(self.generate_id(), tok.position, span, left, right);
+                        Expr::create_conv(self.generate_id(), tok.position, span, left, right, is);
 
                     Box::new(expr)
                 }
@@ -1671,6 +2091,18 @@ impl<'a> Parser<'a> {
                     ))
                 }
 
+                TokenKind::QuestionMark => {
+                    let tok = self.advance_token()?;
+                    let span = self.span_from(start);
+
+                    Box::new(Expr::create_try(
+                        self.generate_id(),
+                        tok.position,
+                        span,
+                        left,
+                    ))
+                }
+
                 _ => {
                     return Ok(left);
                 }
@@ -1751,6 +2183,7 @@ impl<'a> Parser<'a> {
             TokenKind::LParen => self.parse_parentheses(),
             TokenKind::LBrace => self.parse_block(),
             TokenKind::If => self.parse_if(),
+            TokenKind::Match => self.parse_match(),
             TokenKind::DotDotDot => self.parse_dotdotdot(),
             TokenKind::LitChar(_) => self.parse_lit_char(),
             TokenKind::LitInt(_, _, _) => self.parse_lit_int(),
@@ -1760,6 +2193,7 @@ impl<'a> Parser<'a> {
             TokenKind::True => self.parse_bool_literal(),
             TokenKind::False => self.parse_bool_literal(),
             TokenKind::This => self.parse_this(),
+            TokenKind::Super => self.parse_super(),
             TokenKind::Or | TokenKind::OrOr => self.parse_lambda(),
             _ => Err(ParseErrorAndPos::new(
                 self.token.position,
@@ -2011,6 +2445,17 @@ impl<'a> Parser<'a> {
         )))
     }
 
+    fn parse_super(&mut self) -> ExprResult {
+        let span = self.token.span;
+        let tok = self.advance_token()?;
+
+        Ok(Box::new(Expr::create_super(
+            self.generate_id(),
+            tok.position,
+            span,
+        )))
+    }
+
     fn parse_lambda(&mut self) -> ExprResult {
         let start = self.token.span.start();
         let tok = self.advance_token()?;
@@ -2023,18 +2468,16 @@ impl<'a> Parser<'a> {
             self.param_idx = 0;
             self.parse_list(TokenKind::Comma, TokenKind::Or, |p| {
                 p.param_idx += 1;
-                p.parse_function_param()
+                p.parse_lambda_param()
             })?
         };
 
+        // parameter and return types of lambdas are inferred when omitted
         let return_type = if self.token.is(TokenKind::Colon) {
             self.advance_token()?;
-            self.parse_type()?
+            Some(self.parse_type()?)
         } else {
-            return Err(ParseErrorAndPos::new(
-                self.token.position,
-                ParseError::ExpectedType(self.token.name()),
-            ));
+            None
         };
 
         let block = self.parse_block()?;
@@ -2061,9 +2504,12 @@ impl<'a> Parser<'a> {
             internal: false,
             is_constructor: false,
             is_test: false,
+            is_open: false,
+            is_override: false,
+            is_final: false,
             params,
             is_nullary: false,
-            return_type: Some(return_type),
+            return_type,
             block,
             type_params: None,
         });
@@ -2348,6 +2794,16 @@ mod tests {
         assert!(expr.is_this());
     }
 
+    #[test]
+    fn parse_super() {
+        let (expr, _) = parse_expr("super");
+        assert!(expr.is_super());
+
+        let (expr, _) = parse_expr("super.foo()");
+        let call = expr.to_call().unwrap();
+        assert!(call.object().unwrap().is_super());
+    }
+
     #[test]
     fn parse_neg() {
         let (expr, _) = parse_expr("-1");
@@ -2682,11 +3138,11 @@ mod tests {
 
         assert_eq!(
             "int",
-            *interner1.str(p1.data_type.to_basic().unwrap().name())
+            *interner1.str(p1.data_type.as_ref().unwrap().to_basic().unwrap().name())
         );
         assert_eq!(
             "int",
-            *interner2.str(p2.data_type.to_basic().unwrap().name())
+            *interner2.str(p2.data_type.as_ref().unwrap().to_basic().unwrap().name())
         );
     }
 
@@ -2711,23 +3167,41 @@ mod tests {
 
         assert_eq!(
             "int",
-            *interner1.str(p1a.data_type.to_basic().unwrap().name())
+            *interner1.str(p1a.data_type.as_ref().unwrap().to_basic().unwrap().name())
         );
         assert_eq!(
             "int",
-            *interner2.str(p2a.data_type.to_basic().unwrap().name())
+            *interner2.str(p2a.data_type.as_ref().unwrap().to_basic().unwrap().name())
         );
 
         assert_eq!(
             "str",
-            *interner1.str(p1b.data_type.to_basic().unwrap().name())
+            *interner1.str(p1b.data_type.as_ref().unwrap().to_basic().unwrap().name())
         );
         assert_eq!(
             "str",
-            *interner2.str(p2b.data_type.to_basic().unwrap().name())
+            *interner2.str(p2b.data_type.as_ref().unwrap().to_basic().unwrap().name())
         );
     }
 
+    #[test]
+    fn parse_function_with_default_values() {
+        let (prog, interner) = parse("fun f(a: Int32, b: Bool = false, c: Int32 = 1): Unit { }");
+        let fct = prog.fct0();
+
+        assert_eq!(fct.params.len(), 3);
+        assert!(fct.params[0].default_value.is_none());
+
+        let b = &fct.params[1];
+        assert_eq!("b", *interner.str(b.name));
+        let value = b.default_value.as_ref().unwrap().to_lit_bool().unwrap();
+        assert!(!value.value);
+
+        let c = &fct.params[2];
+        assert_eq!("c", *interner.str(c.name));
+        assert!(c.default_value.as_ref().unwrap().is_lit_int());
+    }
+
     #[test]
     fn parse_let_without_type() {
         let stmt = parse_stmt("let a = 1;");
@@ -2956,6 +3430,31 @@ mod tests {
         assert_eq!("bla", *interner.str(basic.path.names[1]));
     }
 
+    #[test]
+    fn parse_type_with_bindings() {
+        let (ty, interner) = parse_type("Iterator[Item = Int32]");
+        let basic = ty.to_basic().unwrap();
+
+        assert_eq!(0, basic.params.len());
+        assert_eq!(1, basic.bindings.len());
+        assert_eq!("Item", *interner.str(basic.bindings[0].name));
+        assert_eq!("Int32", basic.bindings[0].ty.to_string(&interner));
+
+        let (ty, _) = parse_type("Foo[A, Item = B]");
+        let basic = ty.to_basic().unwrap();
+        assert_eq!(1, basic.params.len());
+        assert_eq!(1, basic.bindings.len());
+    }
+
+    #[test]
+    fn parse_type_assoc() {
+        let (ty, interner) = parse_type("Self::Item");
+        assert_eq!("Self::Item", ty.to_string(&interner));
+
+        let (ty, interner) = parse_type("Self");
+        assert_eq!("Self", ty.to_string(&interner));
+    }
+
     #[test]
     fn parse_type_basic_with_params() {
         let (ty, interner) = parse_type("Foo[A, B]");
@@ -3075,6 +3574,48 @@ mod tests {
         assert!(class.fields.is_empty());
     }
 
+    #[test]
+    fn parse_class_with_parent() {
+        let (prog, _) = parse("@open class Foo");
+        let class = prog.cls0();
+        assert!(class.is_open);
+        assert!(class.parent_class.is_none());
+
+        let (prog, _) = parse("class Foo(a: Int64): Bar");
+        let class = prog.cls0();
+        assert!(!class.is_open);
+        assert_eq!(class.fields.len(), 1);
+        assert!(class.parent_class.is_some());
+
+        let (prog, _) = parse("class Foo[T] { a: T }: Bar[T]");
+        let class = prog.cls0();
+        assert_eq!(class.fields.len(), 1);
+        assert!(class.parent_class.is_some());
+
+        let (prog, _) = parse("class Foo: Bar");
+        let class = prog.cls0();
+        assert!(class.fields.is_empty());
+        assert!(class.parent_class.is_some());
+    }
+
+    #[test]
+    fn parse_method_modifiers() {
+        let (prog, _) = parse(
+            "impl Foo { @open fun a(): Unit {} @override @final fun b(): Unit {} fun c(): Unit {} }",
+        );
+        let impl_ = prog.impl0();
+        assert!(impl_.methods[0].is_open);
+        assert!(impl_.methods[1].is_override && impl_.methods[1].is_final);
+        assert!(!impl_.methods[2].is_open && !impl_.methods[2].is_override);
+
+        parse_err(
+            "@override fun f(): Unit {}",
+            ParseError::MisplacedAnnotation("override".into()),
+            1,
+            11,
+        );
+    }
+
     #[test]
     fn parse_method_invocation() {
         let (expr, _) = parse_expr("a.foo()");
@@ -3124,6 +3665,35 @@ mod tests {
         let (expr, _) = parse_expr("a as String");
         let expr = expr.to_conv().unwrap();
         assert_eq!(true, expr.object.is_ident());
+        assert!(!expr.is);
+    }
+
+    #[test]
+    fn parse_is_expr() {
+        let (expr, _) = parse_expr("a is String");
+        let expr = expr.to_conv().unwrap();
+        assert_eq!(true, expr.object.is_ident());
+        assert!(expr.is);
+
+        let (expr, _) = parse_expr("a is Foo && b");
+        let expr = expr.to_bin().unwrap();
+        assert!(expr.lhs.to_conv().unwrap().is);
+    }
+
+    #[test]
+    fn parse_try_expr() {
+        let (expr, _) = parse_expr("a?");
+        let expr = expr.to_try().unwrap();
+        assert!(expr.expr.is_ident());
+        assert_eq!(2, expr.pos.column);
+
+        let (expr, _) = parse_expr("a.b()?.c");
+        let expr = expr.to_dot().unwrap();
+        assert!(expr.lhs.to_try().unwrap().expr.is_call());
+
+        let (expr, _) = parse_expr("a? + b");
+        let expr = expr.to_bin().unwrap();
+        assert!(expr.lhs.is_try());
     }
 
     #[test]
@@ -3288,6 +3858,17 @@ mod tests {
         assert_eq!(false, trait_.methods[0].is_static);
     }
 
+    #[test]
+    fn parse_trait_with_assoc_type() {
+        let (prog, interner) = parse("trait Foo { type Item; fun next(): Self::Item; }");
+        let trait_ = prog.trait0();
+
+        assert_eq!(1, trait_.aliases.len());
+        assert_eq!("Item", *interner.str(trait_.aliases[0].name));
+        assert!(trait_.aliases[0].ty.is_none());
+        assert_eq!(1, trait_.methods.len());
+    }
+
     #[test]
     fn parse_trait_with_static_function() {
         let (prog, interner) = parse("trait Foo { @static fun empty(): Unit; }");
@@ -3298,6 +3879,17 @@ mod tests {
         assert_eq!(true, trait_.methods[0].is_static);
     }
 
+    #[test]
+    fn parse_trait_with_super_traits() {
+        let (prog, interner) = parse("trait Foo[T]: Bar + Baz[T] { }");
+        let trait_ = prog.trait0();
+
+        assert_eq!("Foo", *interner.str(trait_.name));
+        assert_eq!(2, trait_.super_traits.len());
+        assert_eq!("Bar", trait_.super_traits[0].to_string(&interner));
+        assert_eq!("Baz", trait_.super_traits[1].to_string(&interner));
+    }
+
     #[test]
     fn parse_empty_impl() {
         let (prog, interner) = parse("impl Foo for A {}");
@@ -3325,6 +3917,27 @@ mod tests {
         assert_eq!(false, impl_.methods[0].is_static);
     }
 
+    #[test]
+    fn parse_impl_with_assoc_type() {
+        let (prog, interner) = parse("impl Bar for B { type Item = Int32 fun foo(): Unit; }");
+        let impl_ = prog.impl0();
+
+        assert_eq!(1, impl_.aliases.len());
+        assert_eq!("Item", *interner.str(impl_.aliases[0].name));
+        assert_eq!(
+            "Int32",
+            impl_.aliases[0].ty.as_ref().unwrap().to_string(&interner)
+        );
+        assert_eq!(1, impl_.methods.len());
+
+        parse_err(
+            "impl Bar for B { type Item; }",
+            ParseError::ExpectedToken("=".into(), ";".into()),
+            1,
+            27,
+        );
+    }
+
     #[test]
     fn parse_impl_with_static_function() {
         let (prog, interner) = parse("impl Bar for B { @static fun foo(): Unit; }");
@@ -3434,13 +4047,31 @@ mod tests {
     }
 
     #[test]
-    fn parse_lambda_no_params_missing_return_type() {
-        parse_err(
-            " fun x(): Unit { let y = || {}; }",
-            ParseError::ExpectedType("{".into()),
-            1,
-            29,
-        );
+    fn parse_lambda_no_params_without_return_type() {
+        let (expr, _) = parse_expr("|| {}");
+        let lambda = expr.to_lambda().unwrap();
+
+        assert!(lambda.params.is_empty());
+        assert!(lambda.return_type.is_none());
+    }
+
+    #[test]
+    fn parse_lambda_params_without_types() {
+        let (expr, interner) = parse_expr("|a, b: B| {}");
+        let lambda = expr.to_lambda().unwrap();
+
+        assert_eq!(2, lambda.params.len());
+
+        let param = &lambda.params[0];
+        assert_eq!("a", *interner.str(param.name));
+        assert!(param.data_type.is_none());
+
+        let param = &lambda.params[1];
+        assert_eq!("b", *interner.str(param.name));
+        let basic = param.data_type.as_ref().unwrap().to_basic().unwrap();
+        assert_eq!("B", *interner.str(basic.name()));
+
+        assert!(lambda.return_type.is_none());
     }
 
     #[test]
@@ -3481,7 +4112,7 @@ mod tests {
 
         let param = &lambda.params[0];
         assert_eq!("a", *interner.str(param.name));
-        let basic = param.data_type.to_basic().unwrap();
+        let basic = param.data_type.as_ref().unwrap().to_basic().unwrap();
         assert_eq!("A", *interner.str(basic.name()));
 
         let ret = lambda.return_type.as_ref().unwrap();
@@ -3499,12 +4130,12 @@ mod tests {
 
         let param = &lambda.params[0];
         assert_eq!("a", *interner.str(param.name));
-        let basic = param.data_type.to_basic().unwrap();
+        let basic = param.data_type.as_ref().unwrap().to_basic().unwrap();
         assert_eq!("A", *interner.str(basic.name()));
 
         let param = &lambda.params[1];
         assert_eq!("b", *interner.str(param.name));
-        let basic = param.data_type.to_basic().unwrap();
+        let basic = param.data_type.as_ref().unwrap().to_basic().unwrap();
         assert_eq!("B", *interner.str(basic.name()));
 
         let ret = lambda.return_type.as_ref().unwrap();
@@ -3519,6 +4150,89 @@ mod tests {
         assert!(stmt.is_for());
     }
 
+    #[test]
+    fn parse_match() {
+        let (expr, _) = parse_expr("match x { 1 => a, _ => b }");
+        let m = expr.to_match().unwrap();
+        assert!(m.expr.is_ident());
+        assert_eq!(2, m.cases.len());
+
+        let (expr, _) = parse_expr("match x { Foo::A(a, (b, _)) if a > b => { a }, Foo::B => 1, }");
+        let m = expr.to_match().unwrap();
+        assert_eq!(2, m.cases.len());
+        let ctor = m.cases[0].pattern.to_constructor().unwrap();
+        assert_eq!(2, ctor.path.names.len());
+        let params = ctor.params.as_ref().unwrap();
+        assert!(params[0].to_ident().is_some());
+        assert_eq!(2, params[1].to_tuple().unwrap().parts.len());
+        assert!(m.cases[0].guard.is_some());
+        assert!(m.cases[1]
+            .pattern
+            .to_constructor()
+            .unwrap()
+            .params
+            .is_none());
+
+        let (expr, _) = parse_expr("match x { -1 => a, \"abc\" => b, 'c' => c, true => d }");
+        let m = expr.to_match().unwrap();
+        assert_eq!(4, m.cases.len());
+
+        let (expr, _) = parse_expr("match x { A => { 1 } B => { 2 } }");
+        assert_eq!(2, expr.to_match().unwrap().cases.len());
+
+        err_expr(
+            "match x { a => 1 b => 2 }",
+            ParseError::ExpectedToken(",".into(), "b".into()),
+            1,
+            18,
+        );
+        err_expr(
+            "match x { + => 1 }",
+            ParseError::ExpectedPattern("+".into()),
+            1,
+            11,
+        );
+    }
+
+    #[test]
+    fn parse_break_and_continue() {
+        let stmt = parse_stmt("break;");
+        assert!(stmt.to_break().unwrap().label.is_none());
+
+        let stmt = parse_stmt("continue;");
+        assert!(stmt.to_continue().unwrap().label.is_none());
+
+        let stmt = parse_stmt("break outer;");
+        assert!(stmt.to_break().unwrap().label.is_some());
+
+        let stmt = parse_stmt("continue outer;");
+        assert!(stmt.to_continue().unwrap().label.is_some());
+
+        let (expr, _) = parse_expr("{ break\ni = 10i32 }");
+        let block = expr.to_block().unwrap();
+        assert!(block.stmts[0].to_break().unwrap().label.is_none());
+        assert!(block.expr.as_ref().unwrap().is_bin());
+
+        let (expr, _) = parse_expr("{ continue\nouter }");
+        let block = expr.to_block().unwrap();
+        assert!(block.stmts[0].to_continue().unwrap().label.is_none());
+        assert!(block.expr.as_ref().unwrap().is_ident());
+    }
+
+    #[test]
+    fn parse_labeled_loop() {
+        let stmt = parse_stmt("outer: while true { break outer; }");
+        assert!(stmt.to_while().unwrap().label.is_some());
+
+        let stmt = parse_stmt("outer: for i in a { continue outer; }");
+        assert!(stmt.to_for().unwrap().label.is_some());
+
+        let stmt = parse_stmt("for i in a {}");
+        assert!(stmt.to_for().unwrap().label.is_none());
+
+        err_stmt("outer: 1;", ParseError::ExpectedLoop("1".into()), 1, 8);
+    }
+
     #[test]
     fn parse_new_call_ident() {
         let (expr, _interner) = parse_expr("i");
@@ -3632,6 +4346,21 @@ mod tests {
         assert_eq!(union_.variants.len(), 3);
     }
 
+    #[test]
+    fn parse_union_with_type_params() {
+        let (prog, interner) = parse("union Foo[T] of A[T], foo::B, C;");
+        let union_ = prog.union0();
+        assert_eq!(union_.variants.len(), 3);
+
+        let first = union_.variants[0].data_type.to_basic().unwrap();
+        assert_eq!("A", *interner.str(first.name()));
+        assert_eq!(1, first.params.len());
+
+        let second = union_.variants[1].data_type.to_basic().unwrap();
+        assert_eq!(2, second.path.names.len());
+        assert_eq!(0, second.params.len());
+    }
+
     #[test]
     fn parse_enum() {
         let (prog, _) = parse("enum Foo { A, B, C }");
@@ -3648,12 +4377,37 @@ mod tests {
         assert_eq!(enum_.variants[1].types.as_ref().unwrap().len(), 1);
     }
 
+    #[test]
+    fn parse_enum_with_discriminants() {
+        let (prog, _) = parse("enum Op { Add = 1, Sub = 2 + 1, Mul }");
+        let enum_ = prog.enum0();
+        assert_eq!(enum_.variants.len(), 3);
+        assert!(enum_.variants[0]
+            .discriminant
+            .as_ref()
+            .unwrap()
+            .is_lit_int());
+        assert!(enum_.variants[1].discriminant.as_ref().unwrap().is_bin());
+        assert!(enum_.variants[2].discriminant.is_none());
+    }
+
     #[test]
     fn parse_alias() {
         let (prog, _) = parse("alias NewType = Int;");
         let _alias = prog.alias0();
     }
 
+    #[test]
+    fn parse_type_alias() {
+        let (prog, interner) = parse("type Map[V] = HashMap[String, V];");
+        let alias = prog.alias0();
+        assert_eq!("Map", *interner.str(alias.name));
+        assert_eq!(1, alias.type_params.as_ref().unwrap().len());
+
+        let ty = alias.ty.as_ref().unwrap().to_basic().unwrap();
+        assert_eq!(2, ty.params.len());
+    }
+
     #[test]
     fn parse_module() {
         let (prog, _) = parse("mod foo { fun bar(): Unit {} fun baz(): Unit {} }");
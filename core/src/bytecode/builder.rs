@@ -8,7 +8,7 @@ use crate::bytecode::{
 };
 use crate::language::sem_analysis::{
     ClassDefinitionId, EnumDefinitionId, FctDefinitionId, FieldId, GlobalDefinitionId, SemAnalysis,
-    TraitDefinitionId, TypeParamId, ValueDefinitionFieldId, ValueDefinitionId,
+    TraitDefinitionId, TypeParamId, UnionDefinitionId, ValueDefinitionFieldId, ValueDefinitionId,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::vm::ClassInstanceId;
@@ -81,6 +81,25 @@ impl BytecodeBuilder {
         self.writer.add_const(ConstPoolEntry::Enum(id, type_params))
     }
 
+    pub fn add_const_union(
+        &mut self,
+        id: UnionDefinitionId,
+        type_params: SourceTypeArray,
+    ) -> ConstPoolIdx {
+        self.writer
+            .add_const(ConstPoolEntry::Union(id, type_params))
+    }
+
+    pub fn add_const_union_variant(
+        &mut self,
+        id: UnionDefinitionId,
+        type_params: SourceTypeArray,
+        variant_idx: usize,
+    ) -> ConstPoolIdx {
+        self.writer
+            .add_const(ConstPoolEntry::UnionVariant(id, type_params, variant_idx))
+    }
+
     pub fn add_const_enum_variant(
         &mut self,
         id: EnumDefinitionId,
@@ -270,16 +289,41 @@ impl BytecodeBuilder {
         self.writer.emit_const_uint8(dest, value);
     }
 
+    pub fn emit_const_int8(&mut self, dest: Register, value: i8) {
+        assert!(self.def(dest));
+        self.writer.emit_const_int8(dest, value);
+    }
+
+    pub fn emit_const_int16(&mut self, dest: Register, value: i16) {
+        assert!(self.def(dest));
+        self.writer.emit_const_int16(dest, value);
+    }
+
+    pub fn emit_const_uint16(&mut self, dest: Register, value: u16) {
+        assert!(self.def(dest));
+        self.writer.emit_const_uint16(dest, value);
+    }
+
     pub fn emit_const_int32(&mut self, dest: Register, value: i32) {
         assert!(self.def(dest));
         self.writer.emit_const_int32(dest, value);
     }
 
+    pub fn emit_const_uint32(&mut self, dest: Register, value: u32) {
+        assert!(self.def(dest));
+        self.writer.emit_const_uint32(dest, value);
+    }
+
     pub fn emit_const_int64(&mut self, dest: Register, value: i64) {
         assert!(self.def(dest));
         self.writer.emit_const_int64(dest, value);
     }
 
+    pub fn emit_const_uint64(&mut self, dest: Register, value: u64) {
+        assert!(self.def(dest));
+        self.writer.emit_const_uint64(dest, value);
+    }
+
     pub fn emit_const_float32(&mut self, dest: Register, value: f32) {
         assert!(self.def(dest));
         self.writer.emit_const_float32(dest, value);
@@ -404,6 +448,38 @@ impl BytecodeBuilder {
         self.writer.emit_load_enum_variant(dest, src, idx);
     }
 
+    pub fn emit_load_union_variant(
+        &mut self,
+        dest: Register,
+        src: Register,
+        idx: ConstPoolIdx,
+        pos: Position,
+    ) {
+        assert!(self.def(dest) && self.used(src));
+        self.writer.set_position(pos);
+        self.writer.emit_load_union_variant(dest, src, idx);
+    }
+
+    pub fn emit_load_union_value(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        assert!(self.def(dest) && self.used(src));
+        self.writer.emit_load_union_value(dest, src, idx);
+    }
+
+    pub fn emit_load_trait_object_value(
+        &mut self,
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    ) {
+        assert!(self.def(dest) && self.used(object));
+        self.writer.emit_load_trait_object_value(dest, object, idx);
+    }
+
+    pub fn emit_instance_of(&mut self, dest: Register, object: Register, idx: ConstPoolIdx) {
+        assert!(self.def(dest) && self.used(object));
+        self.writer.emit_instance_of(dest, object, idx);
+    }
+
     pub fn emit_ret(&mut self, src: Register) {
         assert!(self.used(src));
         self.writer.emit_ret(src);
@@ -547,6 +623,17 @@ impl BytecodeBuilder {
         self.writer.set_position(pos);
         self.writer.emit_new_trait_object(dest, idx, src);
     }
+    pub fn emit_new_union_value(
+        &mut self,
+        dest: Register,
+        idx: ConstPoolIdx,
+        src: Register,
+        pos: Position,
+    ) {
+        assert!(self.def(dest) && self.used(src));
+        self.writer.set_position(pos);
+        self.writer.emit_new_union_value(dest, idx, src);
+    }
     pub fn emit_new_lambda(&mut self, dest: Register, idx: ConstPoolIdx, pos: Position) {
         assert!(self.def(dest));
         self.writer.set_position(pos);
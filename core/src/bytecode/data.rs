@@ -5,7 +5,7 @@ use core_parser::lexer::position::Position;
 use crate::bytecode::{BytecodeReader, BytecodeType};
 use crate::language::sem_analysis::{
     ClassDefinitionId, EnumDefinitionId, FctDefinitionId, FieldId, GlobalDefinitionId,
-    TraitDefinitionId, TypeParamId, ValueDefinitionFieldId, ValueDefinitionId,
+    TraitDefinitionId, TypeParamId, UnionDefinitionId, ValueDefinitionFieldId, ValueDefinitionId,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::utils::enumeration;
@@ -28,10 +28,15 @@ impl BytecodeOffset {
 pub enum BytecodeTypeKind {
     Unit,
     Bool,
+    Int8,
+    Int16,
     UInt8,
+    UInt16,
     Char,
     Int32,
+    UInt32,
     Int64,
+    UInt64,
     Float32,
     Float64,
     Ptr,
@@ -66,7 +71,10 @@ enumeration!(BytecodeOpcode {
     LoadTupleElement,
     LoadEnumElement,
     LoadEnumVariant,
+    LoadUnionVariant,
+    LoadUnionValue,
     LoadValueField,
+    LoadTraitObjectValue,
 
     LoadField,
     StoreField,
@@ -80,12 +88,19 @@ enumeration!(BytecodeOpcode {
     ConstFalse,
     ConstUInt8,
     ConstChar,
+    ConstInt8,
+    ConstInt16,
+    ConstUInt16,
     ConstInt32,
+    ConstUInt32,
     ConstInt64,
+    ConstUInt64,
     ConstFloat32,
     ConstFloat64,
     ConstString,
 
+    InstanceOf,
+
     TestIdentity,
     TestEq,
     TestNe,
@@ -120,6 +135,7 @@ enumeration!(BytecodeOpcode {
     NewEnum,
     NewValue,
     NewTraitObject,
+    NewUnionValue,
     NewLambda,
 
     ArrayLength,
@@ -229,8 +245,12 @@ impl BytecodeOpcode {
             | BytecodeOpcode::LoadGlobal
             | BytecodeOpcode::StoreGlobal
             | BytecodeOpcode::ConstChar
+            | BytecodeOpcode::ConstInt16
+            | BytecodeOpcode::ConstUInt16
             | BytecodeOpcode::ConstInt32
+            | BytecodeOpcode::ConstUInt32
             | BytecodeOpcode::ConstInt64
+            | BytecodeOpcode::ConstUInt64
             | BytecodeOpcode::ConstFloat32
             | BytecodeOpcode::ConstFloat64
             | BytecodeOpcode::ConstString
@@ -261,7 +281,11 @@ impl BytecodeOpcode {
             | BytecodeOpcode::Shr
             | BytecodeOpcode::Sar
             | BytecodeOpcode::LoadEnumVariant
+            | BytecodeOpcode::LoadUnionVariant
+            | BytecodeOpcode::LoadUnionValue
             | BytecodeOpcode::LoadValueField
+            | BytecodeOpcode::LoadTraitObjectValue
+            | BytecodeOpcode::InstanceOf
             | BytecodeOpcode::LoadField
             | BytecodeOpcode::StoreField
             | BytecodeOpcode::TestEq
@@ -273,13 +297,16 @@ impl BytecodeOpcode {
             | BytecodeOpcode::LoadArray
             | BytecodeOpcode::StoreArray
             | BytecodeOpcode::NewArray
-            | BytecodeOpcode::NewTraitObject => opcode_size(width) + 3 * operand_size(width),
+            | BytecodeOpcode::NewTraitObject
+            | BytecodeOpcode::NewUnionValue => opcode_size(width) + 3 * operand_size(width),
 
             BytecodeOpcode::LoadTupleElement | BytecodeOpcode::LoadEnumElement => {
                 opcode_size(width) + 4 * operand_size(width)
             }
 
-            BytecodeOpcode::ConstUInt8 => opcode_size(width) + operand_size(width) + 1,
+            BytecodeOpcode::ConstUInt8 | BytecodeOpcode::ConstInt8 => {
+                opcode_size(width) + operand_size(width) + 1
+            }
 
             _ => unreachable!(),
         }
@@ -304,6 +331,7 @@ impl BytecodeOpcode {
             | BytecodeOpcode::NewTuple
             | BytecodeOpcode::NewValue
             | BytecodeOpcode::NewTraitObject
+            | BytecodeOpcode::NewUnionValue
             | BytecodeOpcode::NewLambda
             | BytecodeOpcode::ArrayLength
             | BytecodeOpcode::LoadArray
@@ -312,6 +340,7 @@ impl BytecodeOpcode {
             | BytecodeOpcode::LoadStringUInt8
             | BytecodeOpcode::LoadEnumElement
             | BytecodeOpcode::LoadEnumVariant
+            | BytecodeOpcode::LoadUnionVariant
             | BytecodeOpcode::Add
             | BytecodeOpcode::Sub
             | BytecodeOpcode::Mul => true,
@@ -414,11 +443,26 @@ pub enum BytecodeInstruction {
         src: Register,
         idx: ConstPoolIdx,
     },
+    LoadUnionVariant {
+        dest: Register,
+        src: Register,
+        idx: ConstPoolIdx,
+    },
+    LoadUnionValue {
+        dest: Register,
+        src: Register,
+        idx: ConstPoolIdx,
+    },
     LoadValueField {
         dest: Register,
         obj: Register,
         field: ConstPoolIdx,
     },
+    LoadTraitObjectValue {
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    },
 
     LoadField {
         dest: Register,
@@ -458,14 +502,34 @@ pub enum BytecodeInstruction {
         dest: Register,
         idx: ConstPoolIdx,
     },
+    ConstInt8 {
+        dest: Register,
+        value: i8,
+    },
+    ConstInt16 {
+        dest: Register,
+        idx: ConstPoolIdx,
+    },
+    ConstUInt16 {
+        dest: Register,
+        idx: ConstPoolIdx,
+    },
     ConstInt32 {
         dest: Register,
         idx: ConstPoolIdx,
     },
+    ConstUInt32 {
+        dest: Register,
+        idx: ConstPoolIdx,
+    },
     ConstInt64 {
         dest: Register,
         idx: ConstPoolIdx,
     },
+    ConstUInt64 {
+        dest: Register,
+        idx: ConstPoolIdx,
+    },
     ConstFloat32 {
         dest: Register,
         idx: ConstPoolIdx,
@@ -479,6 +543,12 @@ pub enum BytecodeInstruction {
         idx: ConstPoolIdx,
     },
 
+    InstanceOf {
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    },
+
     TestIdentity {
         dest: Register,
         lhs: Register,
@@ -606,6 +676,11 @@ pub enum BytecodeInstruction {
         idx: ConstPoolIdx,
         src: Register,
     },
+    NewUnionValue {
+        dest: Register,
+        idx: ConstPoolIdx,
+        src: Register,
+    },
     NewLambda {
         dest: Register,
         idx: ConstPoolIdx,
@@ -782,6 +857,8 @@ enumeration!(ConstPoolOpcode {
     Enum,
     EnumVariant,
     EnumElement,
+    Union,
+    UnionVariant,
     Value,
     ValueField,
     Trait,
@@ -809,6 +886,8 @@ pub enum ConstPoolEntry {
     Enum(EnumDefinitionId, SourceTypeArray),
     EnumVariant(EnumDefinitionId, SourceTypeArray, usize),
     EnumElement(EnumDefinitionId, SourceTypeArray, usize, usize),
+    Union(UnionDefinitionId, SourceTypeArray),
+    UnionVariant(UnionDefinitionId, SourceTypeArray, usize),
     Value(ValueDefinitionId, SourceTypeArray),
     ValueField(ValueDefinitionId, SourceTypeArray, ValueDefinitionFieldId),
     Trait(TraitDefinitionId, SourceTypeArray, SourceType),
@@ -896,9 +975,14 @@ enumeration!(SourceTypeOpcode {
     // primitives
     Bool,
     Char,
+    Int8,
+    Int16,
     UInt8,
+    UInt16,
     Int32,
+    UInt32,
     Int64,
+    UInt64,
     Float32,
     Float64,
 
@@ -930,7 +1014,10 @@ enumeration!(SourceTypeOpcode {
     Lambda,
 
     // some enum
-    Enum
+    Enum,
+
+    // some union
+    Union
 });
 
 #[rustfmt::skip]
@@ -110,6 +110,28 @@ pub fn dump(vm: &SemAnalysis, fct: Option<&FctDefinition>, bc: &BytecodeFunction
                     element_idx,
                 )
             }
+            ConstPoolEntry::Union(union_id, type_params) => {
+                let union_ = &vm.unions[*union_id];
+                let union_ = union_.read();
+                println!(
+                    "{}{} => Union {}",
+                    align,
+                    idx,
+                    union_.name_with_params(vm, type_params)
+                )
+            }
+            ConstPoolEntry::UnionVariant(union_id, type_params, variant_idx) => {
+                let union_ = &vm.unions[*union_id];
+                let union_ = union_.read();
+                let variant = union_.variant_type(vm, type_params, *variant_idx);
+                println!(
+                    "{}{} => UnionVariant {} of {}",
+                    align,
+                    idx,
+                    variant.name(vm),
+                    union_.name_with_params(vm, type_params),
+                )
+            }
             ConstPoolEntry::Field(cls_id, type_params, field_id) => {
                 let cls = vm.classes.idx(*cls_id);
                 let cls = cls.read();
@@ -294,6 +316,90 @@ impl<'a> BytecodeDumper<'a> {
         .expect("write! failed");
     }
 
+    fn emit_union_variant(&mut self, name: &str, r1: Register, r2: Register, idx: ConstPoolIdx) {
+        self.emit_start(name);
+        let (union_id, type_params) = match self.bc.const_pool(idx) {
+            ConstPoolEntry::Union(union_id, type_params) => (*union_id, type_params),
+            _ => unreachable!(),
+        };
+        let union_ = &self.sa.unions[union_id];
+        let union_ = union_.read();
+        let union_name = union_.name_with_params(self.sa, type_params);
+        writeln!(
+            self.w,
+            " {}, {}, ConstPoolIdx({}) # {}",
+            r1,
+            r2,
+            idx.to_usize(),
+            union_name,
+        )
+        .expect("write! failed");
+    }
+
+    fn emit_union_value(&mut self, name: &str, r1: Register, r2: Register, idx: ConstPoolIdx) {
+        self.emit_start(name);
+        writeln!(
+            self.w,
+            " {}, {}, ConstPoolIdx({}) # {}",
+            r1,
+            r2,
+            idx.to_usize(),
+            self.union_variant_name(idx),
+        )
+        .expect("write! failed");
+    }
+
+    fn emit_new_union_value(&mut self, name: &str, r1: Register, idx: ConstPoolIdx, r2: Register) {
+        self.emit_start(name);
+        writeln!(
+            self.w,
+            " {}, ConstPoolIdx({}), {} # {}",
+            r1,
+            idx.to_usize(),
+            r2,
+            self.union_variant_name(idx),
+        )
+        .expect("write! failed");
+    }
+
+    fn union_variant_name(&self, idx: ConstPoolIdx) -> String {
+        let (union_id, type_params, variant_idx) = match self.bc.const_pool(idx) {
+            ConstPoolEntry::UnionVariant(union_id, type_params, variant_idx) => {
+                (*union_id, type_params, *variant_idx)
+            }
+            _ => unreachable!(),
+        };
+        let union_ = &self.sa.unions[union_id];
+        let union_ = union_.read();
+        let variant = union_.variant_type(self.sa, type_params, variant_idx);
+        format!(
+            "{} of {}",
+            variant.name(self.sa),
+            union_.name_with_params(self.sa, type_params)
+        )
+    }
+
+    fn emit_trait_object(&mut self, name: &str, r1: Register, r2: Register, idx: ConstPoolIdx) {
+        self.emit_start(name);
+        let (trait_id, type_params, actual_ty) = match self.bc.const_pool(idx) {
+            ConstPoolEntry::Trait(trait_id, type_params, ty) => (*trait_id, type_params, ty),
+            _ => unreachable!(),
+        };
+        let trait_ = self.sa.traits.idx(trait_id);
+        let trait_ = trait_.read();
+        let trait_name = trait_.name_with_params(self.sa, type_params);
+        writeln!(
+            self.w,
+            " {}, {}, ConstPoolIdx({}) # {} wrapping {}",
+            r1,
+            r2,
+            idx.to_usize(),
+            trait_name,
+            actual_ty.name(self.sa),
+        )
+        .expect("write! failed");
+    }
+
     fn emit_reg1(&mut self, name: &str, r1: Register) {
         self.emit_start(name);
         writeln!(self.w, " {}", r1).expect("write! failed");
@@ -634,6 +740,22 @@ impl<'a> BytecodeVisitor for BytecodeDumper<'a> {
         self.emit_enum_variant("LoadEnumVariant", dest, src, idx);
     }
 
+    fn visit_load_union_variant(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        self.emit_union_variant("LoadUnionVariant", dest, src, idx);
+    }
+    fn visit_load_union_value(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        self.emit_union_value("LoadUnionValue", dest, src, idx);
+    }
+
+    fn visit_load_trait_object_value(
+        &mut self,
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    ) {
+        self.emit_trait_object("LoadTraitObjectValue", dest, object, idx);
+    }
+
     fn visit_load_field(&mut self, dest: Register, obj: Register, field_idx: ConstPoolIdx) {
         self.emit_field("LoadField", dest, obj, field_idx);
     }
@@ -699,6 +821,34 @@ impl<'a> BytecodeVisitor for BytecodeDumper<'a> {
         self.emit_start("ConstUInt8");
         writeln!(self.w, " {}, {}", dest, value).expect("write! failed");
     }
+    fn visit_const_int8(&mut self, dest: Register, value: i8) {
+        self.emit_start("ConstInt8");
+        writeln!(self.w, " {}, {}", dest, value).expect("write! failed");
+    }
+    fn visit_const_int16(&mut self, dest: Register, idx: ConstPoolIdx) {
+        self.emit_start("ConstInt16");
+        let value = self.bc.const_pool(idx).to_int32().expect("int32 expected") as i16;
+        writeln!(
+            self.w,
+            " {}, ConstPoolIdx({}) # {}",
+            dest,
+            idx.to_usize(),
+            value
+        )
+        .expect("write! failed");
+    }
+    fn visit_const_uint16(&mut self, dest: Register, idx: ConstPoolIdx) {
+        self.emit_start("ConstUInt16");
+        let value = self.bc.const_pool(idx).to_int32().expect("int32 expected") as u16;
+        writeln!(
+            self.w,
+            " {}, ConstPoolIdx({}) # {}",
+            dest,
+            idx.to_usize(),
+            value
+        )
+        .expect("write! failed");
+    }
     fn visit_const_int32(&mut self, dest: Register, idx: ConstPoolIdx) {
         self.emit_start("ConstInt32");
         let value = self.bc.const_pool(idx).to_int32().expect("int32 expected");
@@ -711,6 +861,18 @@ impl<'a> BytecodeVisitor for BytecodeDumper<'a> {
         )
         .expect("write! failed");
     }
+    fn visit_const_uint32(&mut self, dest: Register, idx: ConstPoolIdx) {
+        self.emit_start("ConstUInt32");
+        let value = self.bc.const_pool(idx).to_int32().expect("int32 expected") as u32;
+        writeln!(
+            self.w,
+            " {}, ConstPoolIdx({}) # {}",
+            dest,
+            idx.to_usize(),
+            value
+        )
+        .expect("write! failed");
+    }
     fn visit_const_int64(&mut self, dest: Register, idx: ConstPoolIdx) {
         self.emit_start("ConstInt64");
         let value = self.bc.const_pool(idx).to_int64().expect("int64 expected");
@@ -723,6 +885,18 @@ impl<'a> BytecodeVisitor for BytecodeDumper<'a> {
         )
         .expect("write! failed");
     }
+    fn visit_const_uint64(&mut self, dest: Register, idx: ConstPoolIdx) {
+        self.emit_start("ConstUInt64");
+        let value = self.bc.const_pool(idx).to_int64().expect("int64 expected") as u64;
+        writeln!(
+            self.w,
+            " {}, ConstPoolIdx({}) # {}",
+            dest,
+            idx.to_usize(),
+            value
+        )
+        .expect("write! failed");
+    }
     fn visit_const_float32(&mut self, dest: Register, idx: ConstPoolIdx) {
         self.emit_start("ConstFloat32");
         let value = self
@@ -793,6 +967,9 @@ impl<'a> BytecodeVisitor for BytecodeDumper<'a> {
     fn visit_test_identity(&mut self, dest: Register, lhs: Register, rhs: Register) {
         self.emit_reg3("TestIdentity", dest, lhs, rhs);
     }
+    fn visit_instance_of(&mut self, dest: Register, object: Register, idx: ConstPoolIdx) {
+        self.emit_trait_object("InstanceOf", dest, object, idx);
+    }
 
     fn visit_jump_if_false(&mut self, opnd: Register, offset: u32) {
         self.emit_cond_jump("JumpIfFalse", opnd, offset as i32);
@@ -852,6 +1029,9 @@ impl<'a> BytecodeVisitor for BytecodeDumper<'a> {
     fn visit_new_trait_object(&mut self, dest: Register, idx: ConstPoolIdx, src: Register) {
         self.emit_new_trait_object("NewTraitObject", dest, idx, src);
     }
+    fn visit_new_union_value(&mut self, dest: Register, idx: ConstPoolIdx, src: Register) {
+        self.emit_new_union_value("NewUnionValue", dest, idx, src);
+    }
     fn visit_new_lambda(&mut self, dest: Register, idx: ConstPoolIdx) {
         self.emit_new_lambda("NewLambda", dest, idx);
     }
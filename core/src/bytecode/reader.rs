@@ -156,6 +156,27 @@ impl<'a> BytecodeReader<'a> {
                 BytecodeInstruction::LoadEnumVariant { dest, src, idx }
             }
 
+            BytecodeOpcode::LoadUnionVariant => {
+                let dest = self.read_register();
+                let src = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::LoadUnionVariant { dest, src, idx }
+            }
+
+            BytecodeOpcode::LoadUnionValue => {
+                let dest = self.read_register();
+                let src = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::LoadUnionValue { dest, src, idx }
+            }
+
+            BytecodeOpcode::LoadTraitObjectValue => {
+                let dest = self.read_register();
+                let object = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::LoadTraitObjectValue { dest, object, idx }
+            }
+
             BytecodeOpcode::LoadValueField => {
                 let dest = self.read_register();
                 let obj = self.read_register();
@@ -212,16 +233,41 @@ impl<'a> BytecodeReader<'a> {
                 let value = self.read_byte() as u8;
                 BytecodeInstruction::ConstUInt8 { dest, value }
             }
+            BytecodeOpcode::ConstInt8 => {
+                let dest = self.read_register();
+                let value = self.read_byte() as i8;
+                BytecodeInstruction::ConstInt8 { dest, value }
+            }
+            BytecodeOpcode::ConstInt16 => {
+                let dest = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::ConstInt16 { dest, idx }
+            }
+            BytecodeOpcode::ConstUInt16 => {
+                let dest = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::ConstUInt16 { dest, idx }
+            }
             BytecodeOpcode::ConstInt32 => {
                 let dest = self.read_register();
                 let idx = self.read_const_pool_idx();
                 BytecodeInstruction::ConstInt32 { dest, idx }
             }
+            BytecodeOpcode::ConstUInt32 => {
+                let dest = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::ConstUInt32 { dest, idx }
+            }
             BytecodeOpcode::ConstInt64 => {
                 let dest = self.read_register();
                 let idx = self.read_const_pool_idx();
                 BytecodeInstruction::ConstInt64 { dest, idx }
             }
+            BytecodeOpcode::ConstUInt64 => {
+                let dest = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::ConstUInt64 { dest, idx }
+            }
             BytecodeOpcode::ConstFloat32 => {
                 let dest = self.read_register();
                 let idx = self.read_const_pool_idx();
@@ -238,6 +284,13 @@ impl<'a> BytecodeReader<'a> {
                 BytecodeInstruction::ConstString { dest, idx }
             }
 
+            BytecodeOpcode::InstanceOf => {
+                let dest = self.read_register();
+                let object = self.read_register();
+                let idx = self.read_const_pool_idx();
+                BytecodeInstruction::InstanceOf { dest, object, idx }
+            }
+
             BytecodeOpcode::TestIdentity => {
                 let dest = self.read_register();
                 let lhs = self.read_register();
@@ -388,6 +441,12 @@ impl<'a> BytecodeReader<'a> {
                 let src = self.read_register();
                 BytecodeInstruction::NewTraitObject { dest, idx, src }
             }
+            BytecodeOpcode::NewUnionValue => {
+                let dest = self.read_register();
+                let idx = self.read_const_pool_idx();
+                let src = self.read_register();
+                BytecodeInstruction::NewUnionValue { dest, idx, src }
+            }
             BytecodeOpcode::NewLambda => {
                 let dest = self.read_register();
                 let idx = self.read_const_pool_idx();
@@ -594,6 +653,18 @@ where
                 self.visitor.visit_load_enum_variant(dest, src, idx);
             }
 
+            BytecodeInstruction::LoadUnionVariant { dest, src, idx } => {
+                self.visitor.visit_load_union_variant(dest, src, idx);
+            }
+
+            BytecodeInstruction::LoadUnionValue { dest, src, idx } => {
+                self.visitor.visit_load_union_value(dest, src, idx);
+            }
+
+            BytecodeInstruction::LoadTraitObjectValue { dest, object, idx } => {
+                self.visitor
+                    .visit_load_trait_object_value(dest, object, idx);
+            }
             BytecodeInstruction::LoadValueField { dest, obj, field } => {
                 self.visitor.visit_load_value_field(dest, obj, field);
             }
@@ -630,12 +701,27 @@ where
             BytecodeInstruction::ConstUInt8 { dest, value } => {
                 self.visitor.visit_const_uint8(dest, value as u8);
             }
+            BytecodeInstruction::ConstInt8 { dest, value } => {
+                self.visitor.visit_const_int8(dest, value);
+            }
+            BytecodeInstruction::ConstInt16 { dest, idx } => {
+                self.visitor.visit_const_int16(dest, idx);
+            }
+            BytecodeInstruction::ConstUInt16 { dest, idx } => {
+                self.visitor.visit_const_uint16(dest, idx);
+            }
             BytecodeInstruction::ConstInt32 { dest, idx } => {
                 self.visitor.visit_const_int32(dest, idx);
             }
+            BytecodeInstruction::ConstUInt32 { dest, idx } => {
+                self.visitor.visit_const_uint32(dest, idx);
+            }
             BytecodeInstruction::ConstInt64 { dest, idx } => {
                 self.visitor.visit_const_int64(dest, idx);
             }
+            BytecodeInstruction::ConstUInt64 { dest, idx } => {
+                self.visitor.visit_const_uint64(dest, idx);
+            }
             BytecodeInstruction::ConstFloat32 { dest, idx } => {
                 self.visitor.visit_const_float32(dest, idx);
             }
@@ -646,6 +732,9 @@ where
                 self.visitor.visit_const_string(dest, idx);
             }
 
+            BytecodeInstruction::InstanceOf { dest, object, idx } => {
+                self.visitor.visit_instance_of(dest, object, idx);
+            }
             BytecodeInstruction::TestIdentity { dest, lhs, rhs } => {
                 self.visitor.visit_test_identity(dest, lhs, rhs);
             }
@@ -738,6 +827,9 @@ where
             BytecodeInstruction::NewTraitObject { dest, idx, src } => {
                 self.visitor.visit_new_trait_object(dest, idx, src);
             }
+            BytecodeInstruction::NewUnionValue { dest, idx, src } => {
+                self.visitor.visit_new_union_value(dest, idx, src);
+            }
             BytecodeInstruction::NewLambda { dest, idx } => {
                 self.visitor.visit_new_lambda(dest, idx);
             }
@@ -838,6 +930,23 @@ pub trait BytecodeVisitor {
         unimplemented!();
     }
 
+    fn visit_load_union_variant(&mut self, _dest: Register, _src: Register, _idx: ConstPoolIdx) {
+        unimplemented!();
+    }
+
+    fn visit_load_union_value(&mut self, _dest: Register, _src: Register, _idx: ConstPoolIdx) {
+        unimplemented!();
+    }
+
+    fn visit_load_trait_object_value(
+        &mut self,
+        _dest: Register,
+        _object: Register,
+        _idx: ConstPoolIdx,
+    ) {
+        unimplemented!();
+    }
+
     fn visit_load_value_field(&mut self, _dest: Register, _obj: Register, _field: ConstPoolIdx) {
         unimplemented!();
     }
@@ -892,12 +1001,27 @@ pub trait BytecodeVisitor {
     fn visit_const_uint8(&mut self, _dest: Register, _value: u8) {
         unimplemented!();
     }
+    fn visit_const_int8(&mut self, _dest: Register, _value: i8) {
+        unimplemented!();
+    }
+    fn visit_const_int16(&mut self, _dest: Register, _value: ConstPoolIdx) {
+        unimplemented!();
+    }
+    fn visit_const_uint16(&mut self, _dest: Register, _value: ConstPoolIdx) {
+        unimplemented!();
+    }
     fn visit_const_int32(&mut self, _dest: Register, _value: ConstPoolIdx) {
         unimplemented!();
     }
+    fn visit_const_uint32(&mut self, _dest: Register, _value: ConstPoolIdx) {
+        unimplemented!();
+    }
     fn visit_const_int64(&mut self, _dest: Register, _value: ConstPoolIdx) {
         unimplemented!();
     }
+    fn visit_const_uint64(&mut self, _dest: Register, _value: ConstPoolIdx) {
+        unimplemented!();
+    }
     fn visit_const_float32(&mut self, _dest: Register, _value: ConstPoolIdx) {
         unimplemented!();
     }
@@ -908,6 +1032,10 @@ pub trait BytecodeVisitor {
         unimplemented!();
     }
 
+    fn visit_instance_of(&mut self, _dest: Register, _object: Register, _idx: ConstPoolIdx) {
+        unimplemented!();
+    }
+
     fn visit_test_identity(&mut self, _dest: Register, _lhs: Register, _rhs: Register) {
         unimplemented!();
     }
@@ -1006,6 +1134,9 @@ pub trait BytecodeVisitor {
     fn visit_new_trait_object(&mut self, _dest: Register, _idx: ConstPoolIdx, _src: Register) {
         unimplemented!();
     }
+    fn visit_new_union_value(&mut self, _dest: Register, _idx: ConstPoolIdx, _src: Register) {
+        unimplemented!();
+    }
     fn visit_new_lambda(&mut self, _dest: Register, _idx: ConstPoolIdx) {
         unimplemented!();
     }
@@ -10,10 +10,15 @@ use crate::language::ty::{SourceType, SourceTypeArray};
 pub enum BytecodeType {
     Unit,
     Bool,
+    Int8,
+    Int16,
     UInt8,
+    UInt16,
     Char,
     Int32,
+    UInt32,
     Int64,
+    UInt64,
     Float32,
     Float64,
     Ptr,
@@ -31,10 +36,15 @@ impl BytecodeType {
         match self {
             BytecodeType::Unit => BytecodeTypeKind::Unit,
             BytecodeType::Bool => BytecodeTypeKind::Bool,
+            BytecodeType::Int8 => BytecodeTypeKind::Int8,
+            BytecodeType::Int16 => BytecodeTypeKind::Int16,
             BytecodeType::UInt8 => BytecodeTypeKind::UInt8,
+            BytecodeType::UInt16 => BytecodeTypeKind::UInt16,
             BytecodeType::Char => BytecodeTypeKind::Char,
             BytecodeType::Int32 => BytecodeTypeKind::Int32,
+            BytecodeType::UInt32 => BytecodeTypeKind::UInt32,
             BytecodeType::Int64 => BytecodeTypeKind::Int64,
+            BytecodeType::UInt64 => BytecodeTypeKind::UInt64,
             BytecodeType::Float32 => BytecodeTypeKind::Float32,
             BytecodeType::Float64 => BytecodeTypeKind::Float64,
             BytecodeType::Ptr => BytecodeTypeKind::Ptr,
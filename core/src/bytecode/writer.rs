@@ -139,16 +139,40 @@ impl BytecodeWriter {
         self.emit_reg1_uint8(BytecodeOpcode::ConstUInt8, dest, value);
     }
 
+    pub fn emit_const_int8(&mut self, dest: Register, value: i8) {
+        self.emit_reg1_uint8(BytecodeOpcode::ConstInt8, dest, value as u8);
+    }
+
+    pub fn emit_const_int16(&mut self, dest: Register, value: i16) {
+        let idx = self.add_const(ConstPoolEntry::Int32(value as i32));
+        self.emit_reg1_idx(BytecodeOpcode::ConstInt16, dest, idx);
+    }
+
+    pub fn emit_const_uint16(&mut self, dest: Register, value: u16) {
+        let idx = self.add_const(ConstPoolEntry::Int32(value as i32));
+        self.emit_reg1_idx(BytecodeOpcode::ConstUInt16, dest, idx);
+    }
+
     pub fn emit_const_int32(&mut self, dest: Register, value: i32) {
         let idx = self.add_const(ConstPoolEntry::Int32(value as i32));
         self.emit_reg1_idx(BytecodeOpcode::ConstInt32, dest, idx);
     }
 
+    pub fn emit_const_uint32(&mut self, dest: Register, value: u32) {
+        let idx = self.add_const(ConstPoolEntry::Int32(value as i32));
+        self.emit_reg1_idx(BytecodeOpcode::ConstUInt32, dest, idx);
+    }
+
     pub fn emit_const_int64(&mut self, dest: Register, value: i64) {
         let idx = self.add_const(ConstPoolEntry::Int64(value as i64));
         self.emit_reg1_idx(BytecodeOpcode::ConstInt64, dest, idx);
     }
 
+    pub fn emit_const_uint64(&mut self, dest: Register, value: u64) {
+        let idx = self.add_const(ConstPoolEntry::Int64(value as i64));
+        self.emit_reg1_idx(BytecodeOpcode::ConstUInt64, dest, idx);
+    }
+
     pub fn emit_const_float32(&mut self, dest: Register, value: f32) {
         let idx = self.add_const(ConstPoolEntry::Float32(value));
         self.emit_reg1_idx(BytecodeOpcode::ConstFloat32, dest, idx);
@@ -258,6 +282,27 @@ impl BytecodeWriter {
         self.emit_reg2_idx(BytecodeOpcode::LoadEnumVariant, dest, src, idx);
     }
 
+    pub fn emit_load_union_variant(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        self.emit_reg2_idx(BytecodeOpcode::LoadUnionVariant, dest, src, idx);
+    }
+
+    pub fn emit_load_union_value(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        self.emit_reg2_idx(BytecodeOpcode::LoadUnionValue, dest, src, idx);
+    }
+
+    pub fn emit_load_trait_object_value(
+        &mut self,
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    ) {
+        self.emit_reg2_idx(BytecodeOpcode::LoadTraitObjectValue, dest, object, idx);
+    }
+
+    pub fn emit_instance_of(&mut self, dest: Register, object: Register, idx: ConstPoolIdx) {
+        self.emit_reg2_idx(BytecodeOpcode::InstanceOf, dest, object, idx);
+    }
+
     pub fn emit_ret(&mut self, src: Register) {
         self.emit_reg1(BytecodeOpcode::Ret, src);
     }
@@ -355,6 +400,14 @@ impl BytecodeWriter {
         ];
         self.emit_values(BytecodeOpcode::NewTraitObject, &values);
     }
+    pub fn emit_new_union_value(&mut self, dest: Register, idx: ConstPoolIdx, src: Register) {
+        let values = [
+            dest.to_usize() as u32,
+            idx.to_usize() as u32,
+            src.to_usize() as u32,
+        ];
+        self.emit_values(BytecodeOpcode::NewUnionValue, &values);
+    }
     pub fn emit_new_lambda(&mut self, dest: Register, idx: ConstPoolIdx) {
         let values = [dest.to_usize() as u32, idx.to_usize() as u32];
         self.emit_values(BytecodeOpcode::NewLambda, &values);
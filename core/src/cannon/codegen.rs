@@ -16,8 +16,10 @@ use crate::cpu::{
 };
 use crate::gc::Address;
 use crate::language::generator::register_bty_from_ty;
+use crate::language::replace_assoc_types;
 use crate::language::sem_analysis::{
-    find_trait_impl, EnumDefinitionId, FctDefinitionId, GlobalDefinitionId, Intrinsic,
+    default_method_type_params, find_trait_impl, impl_trait_ty, vtable_offset, EnumDefinitionId,
+    FctDefinition, FctDefinitionId, GlobalDefinitionId, Intrinsic, TypeParamDefinition,
     ValueDefinitionId,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
@@ -29,10 +31,11 @@ use crate::size::InstanceSize;
 use crate::stdlib;
 use crate::vm::{
     get_concrete_tuple_array, get_concrete_tuple_bytecode_ty, get_concrete_tuple_ty,
-    specialize_class_id_params, specialize_enum_class, specialize_enum_id_params,
-    specialize_lambda, specialize_trait_object, specialize_tuple_array, specialize_tuple_bty,
-    specialize_tuple_ty, specialize_type, specialize_type_list, value_instance, EnumLayout,
-    GcPoint, LazyCompilationSite, Trap, VM,
+    specialize_class_id_params, specialize_default_method_type, specialize_enum_class,
+    specialize_enum_id_params, specialize_lambda, specialize_trait_object, specialize_tuple_array,
+    specialize_tuple_bty, specialize_tuple_ty, specialize_type, specialize_type_list,
+    specialize_union_value, value_instance, ClassInstanceId, EnumLayout, GcPoint,
+    LazyCompilationSite, Trap, VM,
 };
 use crate::vtable::VTable;
 
@@ -258,6 +261,11 @@ impl<'a> CannonCodeGen<'a> {
                 }
 
                 BytecodeType::UInt8
+                | BytecodeType::Int8
+                | BytecodeType::Int16
+                | BytecodeType::UInt16
+                | BytecodeType::UInt32
+                | BytecodeType::UInt64
                 | BytecodeType::Int32
                 | BytecodeType::Bool
                 | BytecodeType::Char
@@ -360,12 +368,18 @@ impl<'a> CannonCodeGen<'a> {
 
                 SourceType::Ptr
                 | SourceType::UInt8
+                | SourceType::Int8
+                | SourceType::Int16
+                | SourceType::UInt16
+                | SourceType::UInt32
+                | SourceType::UInt64
                 | SourceType::Bool
                 | SourceType::Char
                 | SourceType::Int32
                 | SourceType::Int64
                 | SourceType::Class(_, _)
                 | SourceType::Trait(_, _)
+                | SourceType::Union(_, _)
                 | SourceType::Lambda(_, _) => {
                     self.store_params_on_stack_core(
                         &mut reg_idx,
@@ -380,6 +394,7 @@ impl<'a> CannonCodeGen<'a> {
                 | SourceType::Error
                 | SourceType::Any
                 | SourceType::This
+                | SourceType::Assoc(_)
                 | SourceType::Unit => unreachable!(),
             }
         }
@@ -1266,6 +1281,161 @@ impl<'a> CannonCodeGen<'a> {
         }
     }
 
+    fn emit_load_union_variant(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        let (union_id, type_params) = match self.bytecode.const_pool(idx) {
+            ConstPoolEntry::Union(union_id, type_params) => (*union_id, type_params.clone()),
+            _ => unreachable!(),
+        };
+
+        let type_params = specialize_type_list(self.vm, &type_params, self.type_params);
+        debug_assert!(type_params.iter().all(|ty| ty.is_concrete_type(self.vm)));
+
+        let variants = self.vm.unions[union_id].read().variants.clone();
+        assert!(!variants.is_empty());
+
+        // load vtable of object
+        self.emit_load_register_as(src, REG_TMP1.into(), MachineMode::Ptr);
+        self.asm
+            .load_mem(MachineMode::Ptr, REG_TMP1.into(), Mem::Base(REG_TMP1, 0));
+
+        let lbl_end = self.asm.create_label();
+
+        // compare against vtable of each variant
+        for (variant_idx, variant) in variants.into_iter().enumerate() {
+            let variant = specialize_type(self.vm, variant, &type_params);
+            let class_instance_id = match variant {
+                SourceType::Class(cls_id, cls_type_params) => {
+                    specialize_class_id_params(self.vm, cls_id, &cls_type_params)
+                }
+                SourceType::Value(_, _) => specialize_union_value(self.vm, variant),
+                _ => unreachable!(),
+            };
+
+            let class_instance = self.vm.class_instances.idx(class_instance_id);
+            let vtable = class_instance.vtable.read();
+            let vtable: &VTable = vtable.as_ref().unwrap();
+
+            // set result before comparison, loading a constant might clobber flags
+            self.asm
+                .load_int_const(MachineMode::Int32, REG_RESULT, variant_idx as i64);
+
+            let disp = self.asm.add_addr(Address::from_ptr(vtable as *const _));
+            let pos = self.asm.pos() as i32;
+            self.asm.load_constpool(REG_TMP2, disp + pos);
+
+            self.asm.cmp_reg(MachineMode::Ptr, REG_TMP1, REG_TMP2);
+            self.asm.jump_if(CondCode::Equal, lbl_end);
+        }
+
+        // the object isn't an instance of any variant, reading it as one would be unsafe
+        let lbl_bailout = self.asm.create_label();
+        self.asm.jump(lbl_bailout);
+        let pos = self.bytecode.offset_position(self.current_offset.to_u32());
+        self.asm.emit_bailout(lbl_bailout, Trap::CAST, pos);
+
+        self.asm.bind_label(lbl_end);
+        self.emit_store_register_as(REG_RESULT.into(), dest, MachineMode::Int32);
+    }
+
+    fn specialize_trait_object_idx(&self, idx: ConstPoolIdx) -> ClassInstanceId {
+        let (trait_id, type_params, object_ty) = match self.bytecode.const_pool(idx) {
+            ConstPoolEntry::Trait(trait_id, type_params, object_ty) => {
+                (*trait_id, type_params, object_ty.clone())
+            }
+            _ => unreachable!(),
+        };
+
+        let type_params = specialize_type_list(self.vm, type_params, self.type_params);
+        debug_assert!(type_params.iter().all(|ty| ty.is_concrete_type(self.vm)));
+
+        let object_ty = specialize_type(self.vm, object_ty, self.type_params);
+        debug_assert!(object_ty.is_concrete_type(self.vm));
+
+        specialize_trait_object(self.vm, trait_id, &type_params, object_ty)
+    }
+
+    fn emit_instance_of(&mut self, dest: Register, object: Register, idx: ConstPoolIdx) {
+        assert_eq!(self.bytecode.register_type(dest), BytecodeType::Bool);
+        assert!(self.bytecode.register_type(object).is_trait());
+
+        let class_instance_id = self.specialize_trait_object_idx(idx);
+        let cls = self.vm.class_instances.idx(class_instance_id);
+
+        // trait objects share their vtable when they wrap the same type,
+        // so comparing vtables is enough to identify the wrapped type
+        self.emit_load_register_as(object, REG_TMP1.into(), MachineMode::Ptr);
+        self.asm
+            .load_mem(MachineMode::Ptr, REG_TMP1.into(), Mem::Base(REG_TMP1, 0));
+
+        let vtable = cls.vtable.read();
+        let vtable: &VTable = vtable.as_ref().unwrap();
+        let disp = self.asm.add_addr(Address::from_ptr(vtable as *const _));
+        let pos = self.asm.pos() as i32;
+        self.asm.load_constpool(REG_TMP2, disp + pos);
+
+        self.asm.cmp_reg(MachineMode::Ptr, REG_TMP1, REG_TMP2);
+        self.asm.set(REG_RESULT, CondCode::Equal);
+        self.emit_store_register(REG_RESULT.into(), dest);
+    }
+
+    fn emit_load_trait_object_value(
+        &mut self,
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    ) {
+        assert!(self.bytecode.register_type(object).is_trait());
+
+        let class_instance_id = self.specialize_trait_object_idx(idx);
+        let cls = self.vm.class_instances.idx(class_instance_id);
+
+        assert_eq!(cls.fields.len(), 1);
+        let field = &cls.fields[0];
+
+        self.emit_load_register(object, REG_TMP1.into());
+
+        let bytecode_type = self.specialize_register_type(dest);
+        assert_eq!(bytecode_type, register_bty_from_ty(field.ty.clone()));
+        let dest = self.reg(dest);
+        let src = RegOrOffset::RegWithOffset(REG_TMP1, field.offset);
+        self.copy_bytecode_ty(bytecode_type, dest, src);
+    }
+
+    fn emit_load_union_value(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        let value_ty = self.union_value_ty(idx);
+        let class_instance_id = specialize_union_value(self.vm, value_ty);
+        let cls = self.vm.class_instances.idx(class_instance_id);
+
+        assert_eq!(cls.fields.len(), 1);
+        let field = &cls.fields[0];
+
+        self.emit_load_register(src, REG_TMP1.into());
+
+        let bytecode_type = self.specialize_register_type(dest);
+        assert_eq!(bytecode_type, register_bty_from_ty(field.ty.clone()));
+        let dest = self.reg(dest);
+        let src = RegOrOffset::RegWithOffset(REG_TMP1, field.offset);
+        self.copy_bytecode_ty(bytecode_type, dest, src);
+    }
+
+    fn union_value_ty(&self, idx: ConstPoolIdx) -> SourceType {
+        let (union_id, type_params, variant_idx) = match self.bytecode.const_pool(idx) {
+            ConstPoolEntry::UnionVariant(union_id, type_params, variant_idx) => {
+                (*union_id, type_params.clone(), *variant_idx)
+            }
+            _ => unreachable!(),
+        };
+
+        let type_params = specialize_type_list(self.vm, &type_params, self.type_params);
+        debug_assert!(type_params.iter().all(|ty| ty.is_concrete_type(self.vm)));
+
+        let variant = self.vm.unions[union_id].read().variants[variant_idx].clone();
+        let value_ty = specialize_type(self.vm, variant, &type_params);
+        debug_assert!(value_ty.is_value());
+
+        value_ty
+    }
+
     fn copy_tuple(&mut self, subtypes: SourceTypeArray, dest: RegOrOffset, src: RegOrOffset) {
         let tuple = get_concrete_tuple_array(self.vm, subtypes.clone());
 
@@ -1321,6 +1491,11 @@ impl<'a> CannonCodeGen<'a> {
 
             SourceType::Ptr
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Bool
             | SourceType::Char
             | SourceType::Int32
@@ -1329,6 +1504,7 @@ impl<'a> CannonCodeGen<'a> {
             | SourceType::Float64
             | SourceType::Class(_, _)
             | SourceType::Trait(_, _)
+            | SourceType::Union(_, _)
             | SourceType::Lambda(_, _) => {
                 let mode = ty.mode();
                 let tmp = result_reg_mode(mode);
@@ -1337,9 +1513,11 @@ impl<'a> CannonCodeGen<'a> {
                 self.asm.store_mem(mode, dest.mem(), tmp);
             }
 
-            SourceType::TypeParam(_) | SourceType::Error | SourceType::Any | SourceType::This => {
-                unreachable!()
-            }
+            SourceType::TypeParam(_)
+            | SourceType::Error
+            | SourceType::Any
+            | SourceType::This
+            | SourceType::Assoc(_) => unreachable!(),
         }
     }
 
@@ -1382,6 +1560,11 @@ impl<'a> CannonCodeGen<'a> {
             }
 
             BytecodeType::UInt8
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt16
+            | BytecodeType::UInt32
+            | BytecodeType::UInt64
             | BytecodeType::Bool
             | BytecodeType::Char
             | BytecodeType::Int32
@@ -1431,6 +1614,11 @@ impl<'a> CannonCodeGen<'a> {
 
             SourceType::Ptr
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Bool
             | SourceType::Char
             | SourceType::Int32
@@ -1438,7 +1626,8 @@ impl<'a> CannonCodeGen<'a> {
             | SourceType::Float32
             | SourceType::Float64
             | SourceType::Class(_, _)
-            | SourceType::Trait(_, _) => {
+            | SourceType::Trait(_, _)
+            | SourceType::Union(_, _) => {
                 let mode = ty.mode();
                 self.asm.store_zero(mode, dest.mem());
             }
@@ -1447,6 +1636,7 @@ impl<'a> CannonCodeGen<'a> {
             | SourceType::Error
             | SourceType::Any
             | SourceType::This
+            | SourceType::Assoc(_)
             | SourceType::Value(_, _)
             | SourceType::Lambda(_, _) => unreachable!(),
         }
@@ -1482,11 +1672,19 @@ impl<'a> CannonCodeGen<'a> {
                 }
             }
 
-            SourceType::Ptr | SourceType::Class(_, _) | SourceType::Trait(_, _) => {
+            SourceType::Ptr
+            | SourceType::Class(_, _)
+            | SourceType::Trait(_, _)
+            | SourceType::Union(_, _) => {
                 self.asm.store_zero(MachineMode::Ptr, dest.mem());
             }
 
             SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Bool
             | SourceType::Char
             | SourceType::Int32
@@ -1498,6 +1696,7 @@ impl<'a> CannonCodeGen<'a> {
             | SourceType::Error
             | SourceType::Any
             | SourceType::This
+            | SourceType::Assoc(_)
             | SourceType::Value(_, _)
             | SourceType::Lambda(_, _) => unreachable!(),
         }
@@ -1654,6 +1853,11 @@ impl<'a> CannonCodeGen<'a> {
                 unreachable!()
             }
             BytecodeType::UInt8
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt16
+            | BytecodeType::UInt32
+            | BytecodeType::UInt64
             | BytecodeType::Bool
             | BytecodeType::Char
             | BytecodeType::Int32
@@ -1760,12 +1964,18 @@ impl<'a> CannonCodeGen<'a> {
     fn emit_const_int(&mut self, dest: Register, int_const: i64) {
         let bytecode_type = self.specialize_register_type(dest);
 
-        assert!(
-            bytecode_type == BytecodeType::Char
-                || bytecode_type == BytecodeType::UInt8
-                || bytecode_type == BytecodeType::Int32
-                || bytecode_type == BytecodeType::Int64
-        );
+        assert!(match bytecode_type {
+            BytecodeType::Char
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt8
+            | BytecodeType::UInt16
+            | BytecodeType::Int32
+            | BytecodeType::UInt32
+            | BytecodeType::Int64
+            | BytecodeType::UInt64 => true,
+            _ => false,
+        });
 
         self.asm
             .load_int_const(mode(self.vm, bytecode_type), REG_RESULT, int_const);
@@ -1808,8 +2018,13 @@ impl<'a> CannonCodeGen<'a> {
         assert!(
             bytecode_type == BytecodeType::Float32
                 || bytecode_type == BytecodeType::Float64
+                || bytecode_type == BytecodeType::Int8
+                || bytecode_type == BytecodeType::Int16
+                || bytecode_type == BytecodeType::UInt16
                 || bytecode_type == BytecodeType::Int32
+                || bytecode_type == BytecodeType::UInt32
                 || bytecode_type == BytecodeType::Int64
+                || bytecode_type == BytecodeType::UInt64
                 || bytecode_type == BytecodeType::Char
                 || bytecode_type == BytecodeType::UInt8
                 || bytecode_type == BytecodeType::Bool
@@ -1830,11 +2045,32 @@ impl<'a> CannonCodeGen<'a> {
 
             self.emit_store_register(REG_RESULT.into(), dest);
         } else {
+            let mode = mode(self.vm, bytecode_type.clone());
+
             self.emit_load_register(lhs, REG_RESULT.into());
             self.emit_load_register(rhs, REG_TMP1.into());
 
-            self.asm
-                .cmp_reg(mode(self.vm, bytecode_type), REG_RESULT, REG_TMP1);
+            // Narrow registers are loaded zero-extended, signed types need
+            // their sign bit restored before an ordering comparison.
+            if bytecode_type == BytecodeType::Int8 || bytecode_type == BytecodeType::Int16 {
+                self.asm.sign_extend(mode, REG_RESULT, REG_RESULT);
+                self.asm.sign_extend(mode, REG_TMP1, REG_TMP1);
+            }
+
+            let op =
+                if bytecode_type == BytecodeType::UInt32 || bytecode_type == BytecodeType::UInt64 {
+                    match op {
+                        CondCode::Greater => CondCode::UnsignedGreater,
+                        CondCode::GreaterEq => CondCode::UnsignedGreaterEq,
+                        CondCode::Less => CondCode::UnsignedLess,
+                        CondCode::LessEq => CondCode::UnsignedLessEq,
+                        op => op,
+                    }
+                } else {
+                    op
+                };
+
+            self.asm.cmp_reg(mode, REG_RESULT, REG_TMP1);
             self.asm.set(REG_RESULT, op);
 
             self.emit_store_register(REG_RESULT.into(), dest);
@@ -1883,6 +2119,11 @@ impl<'a> CannonCodeGen<'a> {
 
             BytecodeType::Bool
             | BytecodeType::UInt8
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt16
+            | BytecodeType::UInt32
+            | BytecodeType::UInt64
             | BytecodeType::Char
             | BytecodeType::Int32
             | BytecodeType::Int64
@@ -2003,6 +2244,11 @@ impl<'a> CannonCodeGen<'a> {
             }
 
             BytecodeType::UInt8
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt16
+            | BytecodeType::UInt32
+            | BytecodeType::UInt64
             | BytecodeType::Int32
             | BytecodeType::Bool
             | BytecodeType::Char
@@ -2471,6 +2717,25 @@ impl<'a> CannonCodeGen<'a> {
         let class_instance_id =
             specialize_trait_object(self.vm, trait_id, &type_params, object_ty.clone());
 
+        self.emit_new_box_object(dest, class_instance_id, object_ty, src, "NewTraitObject");
+    }
+
+    fn emit_new_union_value(&mut self, dest: Register, idx: ConstPoolIdx, src: Register) {
+        let value_ty = self.union_value_ty(idx);
+        let class_instance_id = specialize_union_value(self.vm, value_ty.clone());
+
+        self.emit_new_box_object(dest, class_instance_id, value_ty, src, "NewUnionValue");
+    }
+
+    // Allocates an object of the given class instance and copies `src` into its only field.
+    fn emit_new_box_object(
+        &mut self,
+        dest: Register,
+        class_instance_id: ClassInstanceId,
+        object_ty: SourceType,
+        src: Register,
+        name: &str,
+    ) {
         let cls = self.vm.class_instances.idx(class_instance_id);
 
         let alloc_size = match cls.size {
@@ -2494,12 +2759,12 @@ impl<'a> CannonCodeGen<'a> {
         // store gc object in register
         comment!(
             self,
-            format!("NewTraitObject: store object address in register {}", dest)
+            format!("{}: store object address in register {}", name, dest)
         );
         self.emit_store_register_as(REG_TMP1.into(), dest, MachineMode::Ptr);
 
         // store classptr in object
-        comment!(self, format!("NewTraitObject: initialize object header"));
+        comment!(self, format!("{}: initialize object header", name));
         let vtable = cls.vtable.read();
         let vtable: &VTable = vtable.as_ref().unwrap();
         let disp = self.asm.add_addr(Address::from_ptr(vtable as *const _));
@@ -2524,10 +2789,7 @@ impl<'a> CannonCodeGen<'a> {
 
         assert_eq!(cls.fields.len(), 1);
         let field = &cls.fields[0];
-        comment!(
-            self,
-            format!("NewTraitObject: store register {} in object", src)
-        );
+        comment!(self, format!("{}: store register {} in object", name, src));
 
         let dest = RegOrOffset::RegWithOffset(REG_TMP1, field.offset);
         let src = self.reg(src);
@@ -2737,6 +2999,11 @@ impl<'a> CannonCodeGen<'a> {
                 unreachable!()
             }
             BytecodeType::UInt8
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt16
+            | BytecodeType::UInt32
+            | BytecodeType::UInt64
             | BytecodeType::Int32
             | BytecodeType::Bool
             | BytecodeType::Char
@@ -2854,6 +3121,11 @@ impl<'a> CannonCodeGen<'a> {
                 unreachable!()
             }
             BytecodeType::UInt8
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt16
+            | BytecodeType::UInt32
+            | BytecodeType::UInt64
             | BytecodeType::Int32
             | BytecodeType::Bool
             | BytecodeType::Char
@@ -3025,16 +3297,31 @@ impl<'a> CannonCodeGen<'a> {
         let fct = self.vm.fcts.idx(fct_id);
         let fct = fct.read();
 
-        let fct_return_type = self.specialize_type(specialize_type(
-            self.vm,
-            fct.return_type.clone(),
-            &type_params,
-        ));
+        // associated types in the return type are bound by the trait object type
+        let fct_return_type = match &bytecode_type_self {
+            BytecodeType::Trait(trait_id, trait_type_params) => {
+                let trait_type_params =
+                    specialize_type_list(self.vm, trait_type_params, self.type_params);
+                let trait_ty = SourceType::Trait(*trait_id, trait_type_params);
+                replace_assoc_types(self.vm, fct.return_type.clone(), &trait_ty)
+            }
+            _ => fct.return_type.clone(),
+        };
+
+        let fct_return_type =
+            self.specialize_type(specialize_type(self.vm, fct_return_type, &type_params));
         assert!(fct_return_type.is_concrete_type(self.vm));
 
         let argsize = self.emit_invoke_arguments(dest, fct_return_type.clone(), arguments);
 
-        let vtable_index = fct.vtable_index.unwrap();
+        let vtable_index = match &bytecode_type_self {
+            // methods of supertraits are stored after the methods of the trait object's trait
+            BytecodeType::Trait(trait_id, _) => {
+                let method_trait_id = fct.parent.trait_id().expect("trait expected");
+                vtable_offset(self.vm, *trait_id, method_trait_id) + fct.vtable_index.unwrap()
+            }
+            _ => fct.vtable_index.unwrap(),
+        };
         let gcpoint = self.create_gcpoint();
 
         let (result_reg, result_mode) = self.call_result_reg_and_mode(bytecode_type);
@@ -3116,11 +3403,7 @@ impl<'a> CannonCodeGen<'a> {
         let fct = self.vm.fcts.idx(fct_id);
         let fct = fct.read();
 
-        let fct_return_type = self.specialize_type(specialize_type(
-            self.vm,
-            fct.return_type.clone(),
-            &type_params,
-        ));
+        let fct_return_type = self.specialize_type(self.callee_return_type(&fct, &type_params));
         assert!(fct_return_type.is_concrete_type(self.vm));
 
         let bytecode_type_self = self.bytecode.register_type(self_register);
@@ -3154,6 +3437,15 @@ impl<'a> CannonCodeGen<'a> {
         self.store_call_result(dest, result_reg, fct_return_type);
     }
 
+    fn callee_return_type(&self, fct: &FctDefinition, type_params: &SourceTypeArray) -> SourceType {
+        if fct.parent.is_trait() {
+            // default methods of traits get Self passed as type param
+            specialize_default_method_type(self.vm, fct, fct.return_type.clone(), type_params)
+        } else {
+            specialize_type(self.vm, fct.return_type.clone(), type_params)
+        }
+    }
+
     fn emit_invoke_static_from_bytecode(&mut self, dest: Register, fct_idx: ConstPoolIdx) {
         let (fct_id, type_params) = match self.bytecode.const_pool(fct_idx) {
             ConstPoolEntry::Fct(fct_id, type_params) => (*fct_id, type_params.clone()),
@@ -3201,11 +3493,7 @@ impl<'a> CannonCodeGen<'a> {
         let fct = self.vm.fcts.idx(fct_id);
         let fct = fct.read();
 
-        let fct_return_type = self.specialize_type(specialize_type(
-            self.vm,
-            fct.return_type.clone(),
-            &type_params,
-        ));
+        let fct_return_type = self.specialize_type(self.callee_return_type(&fct, &type_params));
         assert!(fct_return_type.is_concrete_type(self.vm));
 
         let argsize = self.emit_invoke_arguments(dest, fct_return_type.clone(), arguments);
@@ -3265,7 +3553,18 @@ impl<'a> CannonCodeGen<'a> {
         let trait_ty = SourceType::new_trait(trait_id);
 
         let ty = self.type_params[id.to_usize()].clone();
-        let callee_id = find_trait_impl(self.vm, trait_fct_id, trait_ty, ty);
+        let (callee_id, impl_type_params) =
+            find_trait_impl(self.vm, trait_fct_id, trait_ty, ty.clone());
+
+        let type_params = if callee_id == trait_fct_id {
+            // the impl doesn't override the default method
+            let impl_trait_ty =
+                impl_trait_ty(self.vm, ty.clone(), &TypeParamDefinition::new(), trait_id)
+                    .expect("no impl found");
+            default_method_type_params(self.vm, &impl_trait_ty, ty, &type_params)
+        } else {
+            impl_type_params.connect(&type_params)
+        };
 
         let pos = self.bytecode.offset_position(self.current_offset.to_u32());
         let arguments = self.argument_stack.drain(..).collect::<Vec<_>>();
@@ -3549,6 +3848,25 @@ impl<'a> CannonCodeGen<'a> {
                 self.emit_store_register(REG_RESULT.into(), dest);
             }
 
+            Intrinsic::UInt32Cmp | Intrinsic::UInt64Cmp => {
+                assert_eq!(arguments.len(), 2);
+                let lhs_reg = arguments[0];
+                let rhs_reg = arguments[1];
+
+                self.emit_load_register(lhs_reg, REG_TMP1.into());
+                self.emit_load_register(rhs_reg, REG_TMP2.into());
+
+                let mode = match intrinsic {
+                    Intrinsic::UInt64Cmp => MachineMode::Int64,
+                    Intrinsic::UInt32Cmp => MachineMode::Int32,
+                    _ => unreachable!(),
+                };
+
+                self.asm
+                    .cmp_int_unsigned(mode, REG_RESULT, REG_TMP1, REG_TMP2);
+                self.emit_store_register(REG_RESULT.into(), dest);
+            }
+
             Intrinsic::Int32Cmp
             | Intrinsic::Int64Cmp
             | Intrinsic::UInt8Cmp
@@ -3855,6 +4173,57 @@ impl<'a> CannonCodeGen<'a> {
                 self.asm.store_int64_synchronized(REG_TMP1, REG_RESULT);
             }
 
+            Intrinsic::Int32AddOverflows
+            | Intrinsic::Int32SubOverflows
+            | Intrinsic::Int32MulOverflows
+            | Intrinsic::Int64AddOverflows
+            | Intrinsic::Int64SubOverflows
+            | Intrinsic::Int64MulOverflows => {
+                assert_eq!(arguments.len(), 2);
+
+                let lhs_reg = arguments[0];
+                let rhs_reg = arguments[1];
+
+                let mode = match intrinsic {
+                    Intrinsic::Int32AddOverflows
+                    | Intrinsic::Int32SubOverflows
+                    | Intrinsic::Int32MulOverflows => MachineMode::Int32,
+                    _ => MachineMode::Int64,
+                };
+
+                self.emit_load_register(lhs_reg, REG_TMP1.into());
+                self.emit_load_register(rhs_reg, REG_TMP2.into());
+
+                match intrinsic {
+                    Intrinsic::Int32AddOverflows | Intrinsic::Int64AddOverflows => self
+                        .asm
+                        .int_add_overflow(mode, REG_RESULT, REG_TMP1, REG_TMP2),
+                    Intrinsic::Int32SubOverflows | Intrinsic::Int64SubOverflows => self
+                        .asm
+                        .int_sub_overflow(mode, REG_RESULT, REG_TMP1, REG_TMP2),
+                    _ => self
+                        .asm
+                        .int_mul_overflow(mode, REG_RESULT, REG_TMP1, REG_TMP2),
+                }
+
+                self.emit_store_register(REG_RESULT.into(), dest);
+            }
+
+            Intrinsic::Int32WideningMulUnsigned => {
+                assert_eq!(arguments.len(), 2);
+
+                let lhs_reg = arguments[0];
+                let rhs_reg = arguments[1];
+
+                // Int32 values are zero-extended on load, so a 64-bit
+                // multiplication yields the full unsigned product.
+                self.emit_load_register(lhs_reg, REG_RESULT.into());
+                self.emit_load_register(rhs_reg, REG_TMP1.into());
+                self.asm
+                    .int_mul(MachineMode::Int64, REG_RESULT, REG_RESULT, REG_TMP1);
+                self.emit_store_register(REG_RESULT.into(), dest);
+            }
+
             Intrinsic::Int32MulUnchecked | Intrinsic::Int64MulUnchecked => {
                 assert_eq!(arguments.len(), 2);
 
@@ -3989,6 +4358,93 @@ impl<'a> CannonCodeGen<'a> {
                 self.emit_store_register(REG_RESULT.into(), dest);
             }
 
+            Intrinsic::Int8ToInt32
+            | Intrinsic::Int8ToInt64
+            | Intrinsic::Int16ToInt32
+            | Intrinsic::Int16ToInt64 => {
+                assert_eq!(arguments.len(), 1);
+                let src_reg = arguments[0];
+
+                let mode = match intrinsic {
+                    Intrinsic::Int8ToInt32 | Intrinsic::Int8ToInt64 => MachineMode::Int8,
+                    Intrinsic::Int16ToInt32 | Intrinsic::Int16ToInt64 => MachineMode::Int16,
+                    _ => unreachable!(),
+                };
+
+                self.emit_load_register(src_reg, REG_RESULT.into());
+                self.asm.sign_extend(mode, REG_RESULT, REG_RESULT);
+                self.emit_store_register(REG_RESULT.into(), dest);
+            }
+
+            Intrinsic::Int32ToUInt64 => {
+                assert_eq!(arguments.len(), 1);
+                let src_reg = arguments[0];
+
+                self.emit_load_register(src_reg, REG_RESULT.into());
+                self.asm.int32_to_int64(REG_RESULT, REG_RESULT);
+                self.emit_store_register(REG_RESULT.into(), dest);
+            }
+
+            Intrinsic::UInt16ToInt32
+            | Intrinsic::UInt16ToInt64
+            | Intrinsic::UInt32ToInt32
+            | Intrinsic::UInt32ToInt64
+            | Intrinsic::UInt64ToInt32
+            | Intrinsic::UInt64ToInt64
+            | Intrinsic::Int32ToInt8
+            | Intrinsic::Int32ToInt16
+            | Intrinsic::Int32ToUInt16
+            | Intrinsic::Int32ToUInt32
+            | Intrinsic::Int64ToInt8
+            | Intrinsic::Int64ToInt16
+            | Intrinsic::Int64ToUInt16
+            | Intrinsic::Int64ToUInt32
+            | Intrinsic::Int64ToUInt64 => {
+                // Values are zero-extended on load and truncated on store,
+                // so these conversions only need to copy the bits.
+                assert_eq!(arguments.len(), 1);
+                let src_reg = arguments[0];
+
+                self.emit_load_register(src_reg, REG_RESULT.into());
+                self.emit_store_register(REG_RESULT.into(), dest);
+            }
+
+            Intrinsic::Int8Add
+            | Intrinsic::Int8Sub
+            | Intrinsic::Int8Mul
+            | Intrinsic::Int8Div
+            | Intrinsic::Int8Rem
+            | Intrinsic::Int8Neg
+            | Intrinsic::Int16Add
+            | Intrinsic::Int16Sub
+            | Intrinsic::Int16Mul
+            | Intrinsic::Int16Div
+            | Intrinsic::Int16Rem
+            | Intrinsic::Int16Neg => {
+                self.emit_intrinsic_signed_narrow_int(dest, intrinsic, arguments, pos);
+            }
+
+            Intrinsic::UInt16Add
+            | Intrinsic::UInt16Sub
+            | Intrinsic::UInt16Mul
+            | Intrinsic::UInt16Div
+            | Intrinsic::UInt16Rem
+            | Intrinsic::UInt32Add
+            | Intrinsic::UInt32Sub
+            | Intrinsic::UInt32Mul
+            | Intrinsic::UInt32Div
+            | Intrinsic::UInt32Rem => {
+                self.emit_intrinsic_unsigned_narrow_int(dest, intrinsic, arguments, pos);
+            }
+
+            Intrinsic::UInt64Add
+            | Intrinsic::UInt64Sub
+            | Intrinsic::UInt64Mul
+            | Intrinsic::UInt64Div
+            | Intrinsic::UInt64Rem => {
+                self.emit_intrinsic_uint64(dest, intrinsic, arguments, pos);
+            }
+
             Intrinsic::CharToInt32 => {
                 assert_eq!(arguments.len(), 1);
                 let src_reg = arguments[0];
@@ -4032,6 +4488,193 @@ impl<'a> CannonCodeGen<'a> {
         }
     }
 
+    fn emit_intrinsic_signed_narrow_int(
+        &mut self,
+        dest: Register,
+        intrinsic: Intrinsic,
+        arguments: Vec<Register>,
+        pos: Position,
+    ) {
+        let mode = match intrinsic {
+            Intrinsic::Int8Add
+            | Intrinsic::Int8Sub
+            | Intrinsic::Int8Mul
+            | Intrinsic::Int8Div
+            | Intrinsic::Int8Rem
+            | Intrinsic::Int8Neg => MachineMode::Int8,
+            _ => MachineMode::Int16,
+        };
+
+        // Operate on sign-extended 32-bit values: the result can't overflow
+        // Int32, so only the range of the narrow type needs to be checked.
+        self.emit_load_register(arguments[0], REG_RESULT.into());
+        self.asm.sign_extend(mode, REG_RESULT, REG_RESULT);
+
+        if let Intrinsic::Int8Neg | Intrinsic::Int16Neg = intrinsic {
+            assert_eq!(arguments.len(), 1);
+            self.asm.int_neg(MachineMode::Int32, REG_RESULT, REG_RESULT);
+        } else {
+            assert_eq!(arguments.len(), 2);
+            self.emit_load_register(arguments[1], REG_TMP1.into());
+            self.asm.sign_extend(mode, REG_TMP1, REG_TMP1);
+
+            match intrinsic {
+                Intrinsic::Int8Add | Intrinsic::Int16Add => {
+                    self.asm
+                        .int_add(MachineMode::Int32, REG_RESULT, REG_RESULT, REG_TMP1);
+                }
+                Intrinsic::Int8Sub | Intrinsic::Int16Sub => {
+                    self.asm
+                        .int_sub(MachineMode::Int32, REG_RESULT, REG_RESULT, REG_TMP1);
+                }
+                Intrinsic::Int8Mul | Intrinsic::Int16Mul => {
+                    self.asm
+                        .int_mul(MachineMode::Int32, REG_RESULT, REG_RESULT, REG_TMP1);
+                }
+                Intrinsic::Int8Div | Intrinsic::Int16Div => {
+                    self.asm
+                        .int_div(MachineMode::Int32, REG_RESULT, REG_RESULT, REG_TMP1, pos);
+                }
+                Intrinsic::Int8Rem | Intrinsic::Int16Rem => {
+                    self.asm
+                        .int_mod(MachineMode::Int32, REG_RESULT, REG_RESULT, REG_TMP1, pos);
+                }
+                _ => unreachable!(),
+            }
+        }
+
+        self.asm.sign_extend(mode, REG_TMP1, REG_RESULT);
+        self.asm.cmp_reg(MachineMode::Int32, REG_RESULT, REG_TMP1);
+        self.asm.bailout_if(CondCode::NotEqual, Trap::OVERFLOW, pos);
+        self.emit_store_register(REG_RESULT.into(), dest);
+    }
+
+    fn emit_intrinsic_unsigned_narrow_int(
+        &mut self,
+        dest: Register,
+        intrinsic: Intrinsic,
+        arguments: Vec<Register>,
+        pos: Position,
+    ) {
+        assert_eq!(arguments.len(), 2);
+
+        // UInt16 is computed with 32-bit and UInt32 with 64-bit operations,
+        // in both cases the result can't wrap around before the range check.
+        let (mode, wide_mode) = match intrinsic {
+            Intrinsic::UInt16Add
+            | Intrinsic::UInt16Sub
+            | Intrinsic::UInt16Mul
+            | Intrinsic::UInt16Div
+            | Intrinsic::UInt16Rem => (MachineMode::Int16, MachineMode::Int32),
+            _ => (MachineMode::Int32, MachineMode::Int64),
+        };
+
+        self.emit_load_register(arguments[0], REG_RESULT.into());
+        self.emit_load_register(arguments[1], REG_TMP1.into());
+
+        match intrinsic {
+            Intrinsic::UInt16Add | Intrinsic::UInt32Add => {
+                self.asm
+                    .int_add(wide_mode, REG_RESULT, REG_RESULT, REG_TMP1);
+            }
+            Intrinsic::UInt16Sub | Intrinsic::UInt32Sub => {
+                self.asm
+                    .int_sub(wide_mode, REG_RESULT, REG_RESULT, REG_TMP1);
+            }
+            Intrinsic::UInt16Mul | Intrinsic::UInt32Mul => {
+                self.asm
+                    .int_mul(wide_mode, REG_RESULT, REG_RESULT, REG_TMP1);
+            }
+            Intrinsic::UInt16Div | Intrinsic::UInt32Div => {
+                self.asm
+                    .int_div_unsigned(mode, REG_RESULT, REG_RESULT, REG_TMP1, pos);
+                self.emit_store_register(REG_RESULT.into(), dest);
+                return;
+            }
+            Intrinsic::UInt16Rem | Intrinsic::UInt32Rem => {
+                self.asm
+                    .int_mod_unsigned(mode, REG_RESULT, REG_RESULT, REG_TMP1, pos);
+                self.emit_store_register(REG_RESULT.into(), dest);
+                return;
+            }
+            _ => unreachable!(),
+        }
+
+        if mode == MachineMode::Int16 {
+            self.asm.cmp_reg_imm(wide_mode, REG_RESULT, u16::MAX as i32);
+            self.asm
+                .bailout_if(CondCode::UnsignedGreater, Trap::OVERFLOW, pos);
+        } else {
+            self.asm.copy_reg(mode, REG_TMP1, REG_RESULT);
+            self.asm.cmp_reg(wide_mode, REG_RESULT, REG_TMP1);
+            self.asm.bailout_if(CondCode::NotEqual, Trap::OVERFLOW, pos);
+        }
+
+        self.emit_store_register(REG_RESULT.into(), dest);
+    }
+
+    fn emit_intrinsic_uint64(
+        &mut self,
+        dest: Register,
+        intrinsic: Intrinsic,
+        arguments: Vec<Register>,
+        pos: Position,
+    ) {
+        assert_eq!(arguments.len(), 2);
+        let mode = MachineMode::Int64;
+
+        self.emit_load_register(arguments[0], REG_RESULT.into());
+        self.emit_load_register(arguments[1], REG_TMP1.into());
+
+        match intrinsic {
+            Intrinsic::UInt64Add => {
+                self.asm.int_add(mode, REG_RESULT, REG_RESULT, REG_TMP1);
+                self.asm.cmp_reg(mode, REG_RESULT, REG_TMP1);
+                self.asm
+                    .bailout_if(CondCode::UnsignedLess, Trap::OVERFLOW, pos);
+            }
+
+            Intrinsic::UInt64Sub => {
+                self.asm.cmp_reg(mode, REG_RESULT, REG_TMP1);
+                self.asm
+                    .bailout_if(CondCode::UnsignedLess, Trap::OVERFLOW, pos);
+                self.asm.int_sub(mode, REG_RESULT, REG_RESULT, REG_TMP1);
+            }
+
+            Intrinsic::UInt64Mul => {
+                // The product overflowed iff dividing it by a non-zero lhs
+                // doesn't give back rhs.
+                self.emit_load_register(arguments[0], REG_TMP2.into());
+                self.asm.int_mul(mode, REG_RESULT, REG_RESULT, REG_TMP1);
+                self.emit_store_register(REG_RESULT.into(), dest);
+
+                let lbl_done = self.asm.create_label();
+                self.asm.cmp_zero(mode, REG_TMP2);
+                self.asm.jump_if(CondCode::Zero, lbl_done);
+                self.asm
+                    .int_div_unsigned(mode, REG_RESULT, REG_RESULT, REG_TMP2, pos);
+                self.asm.cmp_reg(mode, REG_RESULT, REG_TMP1);
+                self.asm.bailout_if(CondCode::NotEqual, Trap::OVERFLOW, pos);
+                self.asm.bind_label(lbl_done);
+                return;
+            }
+
+            Intrinsic::UInt64Div => {
+                self.asm
+                    .int_div_unsigned(mode, REG_RESULT, REG_RESULT, REG_TMP1, pos);
+            }
+
+            Intrinsic::UInt64Rem => {
+                self.asm
+                    .int_mod_unsigned(mode, REG_RESULT, REG_RESULT, REG_TMP1, pos);
+            }
+
+            _ => unreachable!(),
+        }
+
+        self.emit_store_register(REG_RESULT.into(), dest);
+    }
+
     fn emit_intrinsic_count_bits(
         &mut self,
         dest: Register,
@@ -4348,6 +4991,11 @@ impl<'a> CannonCodeGen<'a> {
             BytecodeType::Bool
             | BytecodeType::Char
             | BytecodeType::UInt8
+            | BytecodeType::Int8
+            | BytecodeType::Int16
+            | BytecodeType::UInt16
+            | BytecodeType::UInt32
+            | BytecodeType::UInt64
             | BytecodeType::Int32
             | BytecodeType::Int64
             | BytecodeType::Float32
@@ -4513,6 +5161,11 @@ impl<'a> CannonCodeGen<'a> {
 
                 BytecodeType::Bool
                 | BytecodeType::UInt8
+                | BytecodeType::Int8
+                | BytecodeType::Int16
+                | BytecodeType::UInt16
+                | BytecodeType::UInt32
+                | BytecodeType::UInt64
                 | BytecodeType::Char
                 | BytecodeType::Int32
                 | BytecodeType::Int64
@@ -4800,6 +5453,67 @@ impl<'a> BytecodeVisitor for CannonCodeGen<'a> {
         self.emit_load_enum_variant(dest, src, idx);
     }
 
+    fn visit_load_union_variant(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        comment!(self, {
+            let (union_id, type_params) = match self.bytecode.const_pool(idx) {
+                ConstPoolEntry::Union(union_id, type_params) => (*union_id, type_params),
+                _ => unreachable!(),
+            };
+            let union_ = &self.vm.unions[union_id];
+            let union_ = union_.read();
+            let union_name = union_.name_with_params(self.vm, type_params);
+            format!(
+                "LoadUnionVariant {}, {}, ConstPoolIdx({}) # {}",
+                dest,
+                src,
+                idx.to_usize(),
+                union_name,
+            )
+        });
+        self.emit_load_union_variant(dest, src, idx);
+    }
+
+    fn visit_load_union_value(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        comment!(self, {
+            let value_ty = self.union_value_ty(idx);
+            format!(
+                "LoadUnionValue {}, {}, ConstPoolIdx({}) # {}",
+                dest,
+                src,
+                idx.to_usize(),
+                value_ty.name(self.vm),
+            )
+        });
+        self.emit_load_union_value(dest, src, idx);
+    }
+
+    fn visit_load_trait_object_value(
+        &mut self,
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    ) {
+        comment!(self, {
+            let (trait_id, type_params, object_ty) = match self.bytecode.const_pool(idx) {
+                ConstPoolEntry::Trait(trait_id, type_params, object_ty) => {
+                    (*trait_id, type_params, object_ty)
+                }
+                _ => unreachable!(),
+            };
+            let trait_ = self.vm.traits[trait_id].read();
+            let trait_name = trait_.name_with_params(self.vm, type_params);
+            format!(
+                "LoadTraitObjectValue {}, {}, ConstPoolIdx({}) # {} wrapping {}",
+                dest,
+                object,
+                idx.to_usize(),
+                trait_name,
+                object_ty.name(self.vm),
+            )
+        });
+        self.emit_load_trait_object_value(dest, object, idx);
+    }
+
     fn visit_load_value_field(&mut self, dest: Register, obj: Register, field_idx: ConstPoolIdx) {
         comment!(self, {
             let (value_id, type_params, field_id) = match self.bytecode.const_pool(field_idx) {
@@ -4971,6 +5685,44 @@ impl<'a> BytecodeVisitor for CannonCodeGen<'a> {
         comment!(self, format!("ConstUInt8 {}, {}", dest, value));
         self.emit_const_int(dest, value as i64);
     }
+    fn visit_const_int8(&mut self, dest: Register, value: i8) {
+        comment!(self, format!("ConstInt8 {}, {}", dest, value));
+        self.emit_const_int(dest, value as i64);
+    }
+    fn visit_const_int16(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self
+            .bytecode
+            .const_pool(idx)
+            .to_int32()
+            .expect("unexpected const pool entry");
+        comment!(
+            self,
+            format!(
+                "ConstInt16 {}, ConstPoolId({}) # {}",
+                dest,
+                idx.to_usize(),
+                value
+            )
+        );
+        self.emit_const_int(dest, value as i16 as i64);
+    }
+    fn visit_const_uint16(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self
+            .bytecode
+            .const_pool(idx)
+            .to_int32()
+            .expect("unexpected const pool entry");
+        comment!(
+            self,
+            format!(
+                "ConstUInt16 {}, ConstPoolId({}) # {}",
+                dest,
+                idx.to_usize(),
+                value
+            )
+        );
+        self.emit_const_int(dest, value as u16 as i64);
+    }
     fn visit_const_int32(&mut self, dest: Register, idx: ConstPoolIdx) {
         let value = self
             .bytecode
@@ -4988,6 +5740,23 @@ impl<'a> BytecodeVisitor for CannonCodeGen<'a> {
         );
         self.emit_const_int(dest, value as i64);
     }
+    fn visit_const_uint32(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self
+            .bytecode
+            .const_pool(idx)
+            .to_int32()
+            .expect("unexpected const pool entry");
+        comment!(
+            self,
+            format!(
+                "ConstUInt32 {}, ConstPoolId({}) # {}",
+                dest,
+                idx.to_usize(),
+                value
+            )
+        );
+        self.emit_const_int(dest, value as i64);
+    }
     fn visit_const_int64(&mut self, dest: Register, idx: ConstPoolIdx) {
         let value = self
             .bytecode
@@ -5005,6 +5774,23 @@ impl<'a> BytecodeVisitor for CannonCodeGen<'a> {
         );
         self.emit_const_int(dest, value);
     }
+    fn visit_const_uint64(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self
+            .bytecode
+            .const_pool(idx)
+            .to_int64()
+            .expect("unexpected const pool entry");
+        comment!(
+            self,
+            format!(
+                "ConstUInt64 {}, ConstPoolId({}) # {}",
+                dest,
+                idx.to_usize(),
+                value
+            )
+        );
+        self.emit_const_int(dest, value);
+    }
     fn visit_const_float32(&mut self, dest: Register, idx: ConstPoolIdx) {
         let value = self
             .bytecode
@@ -5057,6 +5843,28 @@ impl<'a> BytecodeVisitor for CannonCodeGen<'a> {
         self.emit_const_string(dest, value);
     }
 
+    fn visit_instance_of(&mut self, dest: Register, object: Register, idx: ConstPoolIdx) {
+        comment!(self, {
+            let (trait_id, type_params, object_ty) = match self.bytecode.const_pool(idx) {
+                ConstPoolEntry::Trait(trait_id, type_params, object_ty) => {
+                    (*trait_id, type_params, object_ty)
+                }
+                _ => unreachable!(),
+            };
+            let trait_ = self.vm.traits[trait_id].read();
+            let trait_name = trait_.name_with_params(self.vm, type_params);
+            format!(
+                "InstanceOf {}, {}, ConstPoolIdx({}) # {} wrapping {}",
+                dest,
+                object,
+                idx.to_usize(),
+                trait_name,
+                object_ty.name(self.vm),
+            )
+        });
+        self.emit_instance_of(dest, object, idx);
+    }
+
     fn visit_test_identity(&mut self, dest: Register, lhs: Register, rhs: Register) {
         comment!(self, format!("TestIdentity {}, {}, {}", dest, lhs, rhs));
         self.emit_test_identity(dest, lhs, rhs);
@@ -5372,6 +6180,20 @@ impl<'a> BytecodeVisitor for CannonCodeGen<'a> {
         self.emit_new_trait_object(dest, idx, src);
     }
 
+    fn visit_new_union_value(&mut self, dest: Register, idx: ConstPoolIdx, src: Register) {
+        comment!(self, {
+            let value_ty = self.union_value_ty(idx);
+            format!(
+                "NewUnionValue {}, ConstPoolIdx({}), {} # {}",
+                dest,
+                idx.to_usize(),
+                src,
+                value_ty.name(self.vm),
+            )
+        });
+        self.emit_new_union_value(dest, idx, src);
+    }
+
     fn visit_new_lambda(&mut self, dest: Register, idx: ConstPoolIdx) {
         comment!(self, {
             let (fct_id, _type_params) = match self.bytecode.const_pool(idx) {
@@ -5476,10 +6298,11 @@ fn result_address_offset() -> i32 {
 pub fn mode(vm: &VM, ty: BytecodeType) -> MachineMode {
     match ty {
         BytecodeType::Bool => MachineMode::Int8,
-        BytecodeType::UInt8 => MachineMode::Int8,
+        BytecodeType::Int8 | BytecodeType::UInt8 => MachineMode::Int8,
+        BytecodeType::Int16 | BytecodeType::UInt16 => MachineMode::Int16,
         BytecodeType::Char => MachineMode::Int32,
-        BytecodeType::Int32 => MachineMode::Int32,
-        BytecodeType::Int64 => MachineMode::Int64,
+        BytecodeType::Int32 | BytecodeType::UInt32 => MachineMode::Int32,
+        BytecodeType::Int64 | BytecodeType::UInt64 => MachineMode::Int64,
         BytecodeType::Float32 => MachineMode::Float32,
         BytecodeType::Float64 => MachineMode::Float64,
         BytecodeType::Ptr | BytecodeType::Trait(_, _) => MachineMode::Ptr,
@@ -5507,10 +6330,11 @@ pub fn size(vm: &VM, ty: BytecodeType) -> i32 {
     match ty {
         BytecodeType::Unit => 0,
         BytecodeType::Bool => 1,
-        BytecodeType::UInt8 => 1,
+        BytecodeType::Int8 | BytecodeType::UInt8 => 1,
+        BytecodeType::Int16 | BytecodeType::UInt16 => 2,
         BytecodeType::Char => 4,
-        BytecodeType::Int32 => 4,
-        BytecodeType::Int64 => 8,
+        BytecodeType::Int32 | BytecodeType::UInt32 => 4,
+        BytecodeType::Int64 | BytecodeType::UInt64 => 8,
         BytecodeType::Float32 => 4,
         BytecodeType::Float64 => 8,
         BytecodeType::Ptr | BytecodeType::Trait(_, _) => mem::ptr_width(),
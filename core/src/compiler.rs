@@ -2,6 +2,7 @@ pub use crate::compiler::codegen::generate;
 pub use crate::compiler::core_exit_stubs::*;
 
 pub mod asm;
+pub mod catch_entry_stub;
 pub mod codegen;
 pub mod core_entry_stub;
 pub mod core_exit_stubs;
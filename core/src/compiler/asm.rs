@@ -252,6 +252,10 @@ impl<'a> BaselineAssembler<'a> {
         self.masm.cmp_int(mode, dest, lhs, rhs);
     }
 
+    pub fn cmp_int_unsigned(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.masm.cmp_int_unsigned(mode, dest, lhs, rhs);
+    }
+
     pub fn cmp_reg(&mut self, mode: MachineMode, lhs: Reg, rhs: Reg) {
         self.masm.cmp_reg(mode, lhs, rhs);
     }
@@ -260,6 +264,10 @@ impl<'a> BaselineAssembler<'a> {
         self.masm.cmp_reg_imm(mode, lhs, imm);
     }
 
+    pub fn cmp_zero(&mut self, mode: MachineMode, lhs: Reg) {
+        self.masm.cmp_zero(mode, lhs);
+    }
+
     pub fn cmp_mem_imm(&mut self, mode: MachineMode, mem: Mem, imm: i32) {
         self.masm.cmp_mem_imm(mode, mem, imm);
     }
@@ -325,6 +333,18 @@ impl<'a> BaselineAssembler<'a> {
         self.masm.int_mul_checked(mode, dest, lhs, rhs, pos);
     }
 
+    pub fn int_add_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.masm.int_add_overflow(mode, dest, lhs, rhs);
+    }
+
+    pub fn int_sub_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.masm.int_sub_overflow(mode, dest, lhs, rhs);
+    }
+
+    pub fn int_mul_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.masm.int_mul_overflow(mode, dest, lhs, rhs);
+    }
+
     pub fn int_div(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg, pos: Position) {
         self.masm.int_div(mode, dest, lhs, rhs, pos);
     }
@@ -333,6 +353,28 @@ impl<'a> BaselineAssembler<'a> {
         self.masm.int_mod(mode, dest, lhs, rhs, pos);
     }
 
+    pub fn int_div_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        self.masm.int_div_unsigned(mode, dest, lhs, rhs, pos);
+    }
+
+    pub fn int_mod_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        self.masm.int_mod_unsigned(mode, dest, lhs, rhs, pos);
+    }
+
     pub fn int_neg(&mut self, mode: MachineMode, dest: Reg, src: Reg) {
         self.masm.int_neg(mode, dest, src);
     }
@@ -530,6 +572,10 @@ impl<'a> BaselineAssembler<'a> {
         self.masm.int32_to_int64(dest, src);
     }
 
+    pub fn sign_extend(&mut self, mode: MachineMode, dest: Reg, src: Reg) {
+        self.masm.sign_extend(mode, dest, src);
+    }
+
     pub fn float32_to_float64(&mut self, dest: FReg, src: FReg) {
         self.masm.float32_to_float64(dest, src);
     }
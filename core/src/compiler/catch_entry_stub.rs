@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use crate::cpu::{
+    CALLEE_SAVED_FREGS, CALLEE_SAVED_REGS, CCALL_REG_PARAMS, REG_FP, REG_PARAMS, REG_RESULT,
+    REG_SP, REG_THREAD, REG_TMP1,
+};
+use crate::masm::{MacroAssembler, Mem};
+use crate::mem;
+use crate::mode::MachineMode;
+use crate::threads::CatchFrame;
+use crate::vm::{install_code_stub, Code, CodeKind, VM};
+
+// Stub with signature `(tld, fct, lambda, frame): Bool`, works like the core entry
+// stub but also stores its stack and frame pointer in the given CatchFrame. Returns
+// false when the lambda returned normally and true when a panic resumed the stub.
+pub fn install_catch_entry(vm: &VM) -> Arc<Code> {
+    let mut masm = MacroAssembler::new();
+
+    if vm.args.flag_emit_debug_entry {
+        masm.debug();
+    }
+
+    masm.prolog(framesize());
+    save_registers(&mut masm);
+
+    masm.copy_sp(REG_TMP1);
+    masm.store_mem(
+        MachineMode::Ptr,
+        Mem::Base(CCALL_REG_PARAMS[3], CatchFrame::sp_offset()),
+        REG_TMP1.into(),
+    );
+    masm.store_mem(
+        MachineMode::Ptr,
+        Mem::Base(CCALL_REG_PARAMS[3], CatchFrame::fp_offset()),
+        REG_FP.into(),
+    );
+
+    masm.copy_reg(MachineMode::Ptr, REG_THREAD, CCALL_REG_PARAMS[0]);
+    masm.copy_reg(MachineMode::Ptr, REG_TMP1, CCALL_REG_PARAMS[1]);
+    masm.copy_reg(MachineMode::Ptr, REG_PARAMS[0], CCALL_REG_PARAMS[2]);
+    masm.call_reg(REG_TMP1);
+
+    masm.load_int_const(MachineMode::Int32, REG_RESULT, 0);
+    restore_registers(&mut masm);
+    masm.epilog();
+
+    install_code_stub(vm, masm.code(), CodeKind::CoreStub)
+}
+
+// Stub with signature `(frame)` that never returns: it drops all frames above the
+// catch entry stub that stored `frame` and returns true from that stub instead.
+pub fn install_resume_catch(vm: &VM) -> Arc<Code> {
+    let mut masm = MacroAssembler::new();
+
+    masm.load_mem(
+        MachineMode::Ptr,
+        REG_TMP1.into(),
+        Mem::Base(CCALL_REG_PARAMS[0], CatchFrame::sp_offset()),
+    );
+    masm.load_mem(
+        MachineMode::Ptr,
+        REG_FP.into(),
+        Mem::Base(CCALL_REG_PARAMS[0], CatchFrame::fp_offset()),
+    );
+    masm.set_sp(REG_TMP1);
+
+    masm.load_int_const(MachineMode::Int32, REG_RESULT, 1);
+    restore_registers(&mut masm);
+    masm.epilog();
+
+    install_code_stub(vm, masm.code(), CodeKind::CoreStub)
+}
+
+fn framesize() -> i32 {
+    let slots = CALLEE_SAVED_REGS.len() + CALLEE_SAVED_FREGS.len();
+    mem::align_usize(slots * mem::ptr_width_usize(), 16) as i32
+}
+
+fn save_registers(masm: &mut MacroAssembler) {
+    for (idx, &reg) in CALLEE_SAVED_REGS.iter().enumerate() {
+        masm.store_mem(MachineMode::Ptr, register_slot(idx), reg.into());
+    }
+
+    for (idx, &freg) in CALLEE_SAVED_FREGS.iter().enumerate() {
+        let slot = register_slot(CALLEE_SAVED_REGS.len() + idx);
+        masm.store_mem(MachineMode::Float64, slot, freg.into());
+    }
+}
+
+fn restore_registers(masm: &mut MacroAssembler) {
+    for (idx, &reg) in CALLEE_SAVED_REGS.iter().enumerate() {
+        masm.load_mem(MachineMode::Ptr, reg.into(), register_slot(idx));
+    }
+
+    for (idx, &freg) in CALLEE_SAVED_FREGS.iter().enumerate() {
+        let slot = register_slot(CALLEE_SAVED_REGS.len() + idx);
+        masm.load_mem(MachineMode::Float64, freg.into(), slot);
+    }
+}
+
+fn register_slot(idx: usize) -> Mem {
+    Mem::Base(REG_SP, (idx * mem::ptr_width_usize()) as i32)
+}
@@ -10,7 +10,7 @@ use crate::gc::Address;
 use crate::language::sem_analysis::{FctDefinition, FctDefinitionId};
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::os;
-use crate::vm::{install_code, CodeKind, VM};
+use crate::vm::{install_code, specialize_default_method_type, CodeKind, VM};
 
 pub fn generate(vm: &VM, id: FctDefinitionId, type_params: &SourceTypeArray) -> Address {
     let fct = vm.fcts.idx(id);
@@ -36,9 +36,18 @@ pub fn generate_fct(vm: &VM, fct: &FctDefinition, type_params: &SourceTypeArray)
 
     let code_descriptor = {
         let pos = fct.pos;
-        let params = fct.params_with_self();
-        let params = SourceTypeArray::with(params.to_vec());
-        let return_type = fct.return_type.clone();
+        let mut params = SourceTypeArray::with(fct.params_with_self().to_vec());
+        let mut return_type = fct.return_type.clone();
+
+        if fct.parent.is_trait() {
+            params = SourceTypeArray::with(
+                params
+                    .iter()
+                    .map(|ty| specialize_default_method_type(vm, fct, ty, type_params))
+                    .collect(),
+            );
+            return_type = specialize_default_method_type(vm, fct, return_type, type_params);
+        }
         let has_variadic_parameter = fct.is_variadic;
 
         let compilation_data = CompilationData {
@@ -8,8 +8,10 @@ use crate::cpu::{
 };
 use crate::gc::Address;
 use crate::language::generator::register_bty_from_ty;
+use crate::language::replace_assoc_types;
 use crate::language::sem_analysis::{
-    find_trait_impl, AnalysisData, FctDefinition, FctDefinitionId, FctParent, TypeParamId,
+    extension_matches, impl_trait_ty, parent_class_type, AnalysisData, ClassDefinitionId,
+    FctDefinition, FctDefinitionId, FctParent, TypeParamDefinition, TypeParamId,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::masm::{MacroAssembler, Mem};
@@ -293,6 +295,19 @@ fn patch_virtual_call(
             compiler::generate(vm, thunk_fct_id, &all_type_params)
         }
 
+        ShapeKind::Class(cls_id, cls_type_params) => {
+            let (fct_id, type_params) = virtual_method_target(
+                vm,
+                *cls_id,
+                cls_type_params,
+                trait_fct_id,
+                vtable_index,
+                type_params,
+            );
+
+            compiler::generate(vm, fct_id, &type_params)
+        }
+
         _ => unreachable!(),
     };
 
@@ -302,6 +317,47 @@ fn patch_virtual_call(
     fct_ptr
 }
 
+// Finds the method that is invoked for the given vtable slot on instances of the
+// class and computes its type params from the type params of the called method.
+fn virtual_method_target(
+    vm: &VM,
+    cls_id: ClassDefinitionId,
+    cls_type_params: &SourceTypeArray,
+    called_fct_id: FctDefinitionId,
+    vtable_index: u32,
+    type_params: &SourceTypeArray,
+) -> (FctDefinitionId, SourceTypeArray) {
+    let called_container_type_params = vm.fcts.idx(called_fct_id).read().container_type_params;
+    let fct_type_params =
+        SourceTypeArray::with(type_params.types()[called_container_type_params..].to_vec());
+
+    let fct_id = vm.classes.idx(cls_id).read().virtual_methods[vtable_index as usize];
+
+    let extension_id = match vm.fcts.idx(fct_id).read().parent {
+        FctParent::Extension(extension_id) => extension_id,
+        _ => unreachable!(),
+    };
+
+    let extension_cls_id = vm.extensions[extension_id]
+        .read()
+        .ty
+        .cls_id()
+        .expect("class expected");
+
+    // the method might be defined in one of the parent classes
+    let mut class_ty = SourceType::Class(cls_id, cls_type_params.clone());
+
+    while class_ty.cls_id() != Some(extension_cls_id) {
+        class_ty = parent_class_type(vm, &class_ty).expect("parent class expected");
+    }
+
+    let container_type_params =
+        extension_matches(vm, class_ty, &TypeParamDefinition::new(), extension_id)
+            .expect("extension should match");
+
+    (fct_id, container_type_params.connect(&fct_type_params))
+}
+
 fn patch_direct_call(
     vm: &VM,
     ra: usize,
@@ -341,7 +397,10 @@ fn ensure_thunk(
         return thunk_id;
     }
 
-    let callee_id = find_trait_impl(vm, fct_id, trait_object_ty.clone(), actual_ty.clone());
+    // Associated types in the trait method signature are resolved through
+    // the impl of the receiver's actual type.
+    let impl_trait_ty = impl_trait_ty(vm, actual_ty.clone(), &TypeParamDefinition::new(), trait_id)
+        .expect("no impl found");
 
     let mut thunk_fct = FctDefinition::new(
         fct.package_id,
@@ -363,15 +422,19 @@ fn ensure_thunk(
         &*fct,
         trait_object_ty.clone(),
         &mut thunk_fct,
-        callee_id,
         actual_ty,
+        &impl_trait_ty,
     ));
     thunk_fct.analysis = Some(AnalysisData::new());
 
     let mut param_types: Vec<SourceType> = vec![trait_object_ty];
-    param_types.extend_from_slice(fct.params_without_self());
+    param_types.extend(
+        fct.params_without_self()
+            .iter()
+            .map(|ty| replace_assoc_types(vm, ty.clone(), &impl_trait_ty)),
+    );
     thunk_fct.param_types = param_types;
-    thunk_fct.return_type = fct.return_type.clone();
+    thunk_fct.return_type = replace_assoc_types(vm, fct.return_type.clone(), &impl_trait_ty);
     let thunk_fct_id = vm.add_fct(thunk_fct);
 
     thunk_fct_id
@@ -383,16 +446,17 @@ fn generate_bytecode_for_thunk(
     trait_fct: &FctDefinition,
     trait_object_ty: SourceType,
     thunk_fct: &mut FctDefinition,
-    _callee_id: FctDefinitionId,
     actual_ty: SourceType,
+    impl_trait_ty: &SourceType,
 ) -> BytecodeFunction {
     let mut gen = BytecodeBuilder::new();
     gen.push_scope();
     gen.alloc_var(register_bty_from_ty(trait_object_ty));
 
     for param_ty in trait_fct.params_without_self() {
+        let param_ty = replace_assoc_types(vm, param_ty.clone(), impl_trait_ty);
         if !param_ty.is_unit() {
-            let ty = register_bty_from_ty(param_ty.clone());
+            let ty = register_bty_from_ty(param_ty);
             gen.alloc_var(ty);
         }
     }
@@ -415,7 +479,8 @@ fn generate_bytecode_for_thunk(
     let target_fct_idx =
         gen.add_const_generic(type_param_id, trait_fct.id(), SourceTypeArray::empty());
 
-    let ty = register_bty_from_ty(trait_fct.return_type.clone());
+    let return_type = replace_assoc_types(vm, trait_fct.return_type.clone(), impl_trait_ty);
+    let ty = register_bty_from_ty(return_type);
     let result_reg = gen.alloc_var(ty);
     gen.emit_invoke_generic_direct(result_reg, target_fct_idx, trait_fct.pos);
     gen.emit_ret(result_reg);
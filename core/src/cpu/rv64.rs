@@ -11,6 +11,13 @@ pub static CCALL_FREG_PARAMS: [FReg; 12] = [F0, F1, F2, F3, F4, F5, F6, F7, F28,
 
 pub static SCRATCH: [Reg; 7] = [R5, R6, R7, R28, R29, R30, R31];
 
+// Registers native code expects to be preserved across calls, besides FP and RA.
+pub static CALLEE_SAVED_REGS: [Reg; 12] = [
+    R9, R18, R19, R20, R21, R22, R23, R24, R25, R26, R27, REG_THREAD,
+];
+pub static CALLEE_SAVED_FREGS: [FReg; 12] =
+    [F8, F9, F18, F19, F20, F21, F22, F23, F24, F25, F26, F27];
+
 pub const REG_RESULT: Reg = R10;
 pub const REG_TMP1: Reg = R5;
 pub const REG_TMP2: Reg = R6;
@@ -78,6 +78,19 @@ pub static SCRATCH: [Reg; 4] = [RDI, RSI, RDX, RCX];
 #[cfg(target_family = "windows")]
 pub static SCRATCH: [Reg; 4] = [RCX, RDX, R8, R9];
 
+// Registers native code expects to be preserved across calls, besides the frame pointer.
+#[cfg(target_family = "unix")]
+pub static CALLEE_SAVED_REGS: [Reg; 5] = [RBX, R12, R13, R14, R15];
+#[cfg(target_family = "windows")]
+pub static CALLEE_SAVED_REGS: [Reg; 7] = [RBX, RSI, RDI, R12, R13, R14, R15];
+
+#[cfg(target_family = "unix")]
+pub static CALLEE_SAVED_FREGS: [FReg; 0] = [];
+#[cfg(target_family = "windows")]
+pub static CALLEE_SAVED_FREGS: [FReg; 10] = [
+    XMM6, XMM7, XMM8, XMM9, XMM10, XMM11, XMM12, XMM13, XMM14, XMM15,
+];
+
 pub const FREG_RESULT: FReg = XMM0;
 
 #[cfg(target_family = "unix")]
@@ -42,7 +42,7 @@ Options:
     --gc-dev-verbose        Verbose GC for developers.
     --gc-verify             Verify heap before and after collections.
     --gc-worker=<num>       Number of GC worker threads.
-    --gc=<name>             Switch GC. Possible values: zero, copy, swiper (default).
+    --gc=<name>             Switch GC. Possible values: zero, copy, swiper (default), region.
     --gc-young-size=<SIZE>  Use fixed size for young generation.
     --gc-semi-ratio=<num>   Use fixed ratio of semi space in young generation.
 
@@ -5,6 +5,7 @@ use crate::language::error::msg::ErrorMessage;
 use crate::language::sem_analysis::{
     FctDefinition, FctDefinitionId, ModuleDefinitionId, SemAnalysis,
 };
+use crate::stdlib;
 use crate::timer::Timer;
 use crate::vm::{clear_vm, execute_on_main, set_vm, VM};
 
@@ -77,6 +78,9 @@ pub fn start() -> i32 {
 
     vm.threads.join_all();
 
+    // a panic in a thread that was never joined terminates the program
+    let exit_code = stdlib::report_uncollected_panic(&vm).unwrap_or(exit_code);
+
     if vm.args.flag_gc_stats {
         let duration = timer.stop();
         vm.dump_gc_summary(duration);
@@ -1,23 +1,63 @@
+use std::collections::HashSet;
 use std::fmt;
+use std::ptr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use fixedbitset::FixedBitSet;
 use parking_lot::Mutex;
 
 use crate::driver::cmd::Args;
-use crate::gc::{Address, Collector, GcReason, Region};
+use crate::gc::region::full::FullCollector;
+use crate::gc::region::minor::MinorCollector;
+use crate::gc::region::verify::{Verifier, VerifierPhase};
+use crate::gc::root::{determine_strong_roots, Slot};
+use crate::gc::swiper::{walk_region, CollectionKind, CARD_SIZE_BITS};
+use crate::gc::tlab;
+use crate::gc::{formatted_size, Address, CollectionStats, Collector, GcReason, Region};
+use crate::mem;
+use crate::object::Obj;
 use crate::os::{self, Reservation};
+use crate::safepoint;
+use crate::timer::Timer;
 use crate::utils::enumeration;
 use crate::vm::VM;
 
+mod full;
+mod minor;
+mod verify;
+
 pub const REGION_SIZE_BITS: usize = 20;
 pub const REGION_SIZE: usize = 1 << REGION_SIZE_BITS;
 
+// objects of at least this size get their own contiguous set of regions
+pub const HUMONGOUS_OBJECT_SIZE: usize = REGION_SIZE / 2;
+
+// determines maximum number of young regions when no young size is given:
+// young regions = number of regions / YOUNG_RATIO
+const YOUNG_RATIO: usize = 4;
+
+// regions with more live bytes (in percent) are not evacuated
+// in full collections, their garbage is only filled instead
+const EVACUATION_LIVE_THRESHOLD: usize = 85;
+
 pub struct RegionCollector {
     reservation: Reservation,
+    heap: Region,
     regions: Vec<HeapRegion>,
     number_regions: usize,
-    regular_alloc_region: Mutex<Option<RegionId>>,
+
+    // maximum number of eden and survivor regions
+    young_limit: usize,
+
+    card_table: CardTable,
+    card_table_offset: usize,
+    emit_write_barrier: bool,
+
+    // serializes mutator allocations of new regions
+    eden_alloc_region: Mutex<Option<RegionId>>,
+
+    minor_stats: Mutex<CollectionStats>,
+    full_stats: Mutex<CollectionStats>,
 }
 
 impl RegionCollector {
@@ -25,28 +65,71 @@ impl RegionCollector {
         let max_heap_size = align_region(args.max_heap_size());
 
         let reservation = os::reserve_align(max_heap_size, REGION_SIZE, false);
+        let heap = reservation.start.region_start(max_heap_size);
         let number_regions = max_heap_size / REGION_SIZE;
         let mut regions = Vec::with_capacity(number_regions);
 
-        let mut next_region_start = reservation.start;
+        let mut next_region_start = heap.start;
 
         for _ in 0..number_regions {
             let heap_region = HeapRegion {
                 area_start: next_region_start,
-                area_end: next_region_start.offset(REGION_SIZE - os::page_size()),
+                area_end: next_region_start.offset(REGION_SIZE),
                 top: AtomicUsize::new(next_region_start.to_usize()),
                 state: AtomicRegionState::new(RegionState::Free),
                 live_bytes: AtomicUsize::new(0),
+                remembered_set: Mutex::new(HashSet::new()),
             };
             regions.push(heap_region);
             next_region_start = next_region_start.offset(REGION_SIZE);
         }
 
+        // card table has one byte for each card in the heap
+        let card_size = mem::page_align(max_heap_size >> CARD_SIZE_BITS);
+        let card_start = os::commit(card_size, false);
+
+        if card_start.is_null() {
+            panic!("could not allocate card table of size {} bytes", card_size);
+        }
+
+        let card_table = CardTable::new(card_start.region_start(card_size), heap.start);
+
+        // offset = card_table_start - (heap_start >> CARD_SIZE_BITS)
+        let card_table_offset = card_start.to_usize() - (heap.start.to_usize() >> CARD_SIZE_BITS);
+
+        let young_limit = args
+            .young_size()
+            .map(|young_size| young_size / REGION_SIZE)
+            .unwrap_or(number_regions / YOUNG_RATIO)
+            .max(1);
+
+        if args.flag_gc_verbose {
+            println!(
+                "GC: heap info: {}, {} regions of {}, young limit {} regions, card {} (region {})",
+                formatted_size(max_heap_size),
+                number_regions,
+                formatted_size(REGION_SIZE),
+                young_limit,
+                formatted_size(card_size),
+                heap,
+            );
+        }
+
         RegionCollector {
             reservation,
+            heap,
             regions,
             number_regions,
-            regular_alloc_region: Mutex::new(None),
+            young_limit,
+
+            card_table,
+            card_table_offset,
+            emit_write_barrier: !args.flag_disable_barrier,
+
+            eden_alloc_region: Mutex::new(None),
+
+            minor_stats: Mutex::new(CollectionStats::new()),
+            full_stats: Mutex::new(CollectionStats::new()),
         }
     }
 }
@@ -57,7 +140,7 @@ impl Collector for RegionCollector {
     }
 
     fn alloc_tlab_area(&self, vm: &VM, size: usize) -> Option<Region> {
-        let result = self.regular_alloc(vm, size);
+        let result = self.alloc_regular(vm, size);
 
         if result.is_null() {
             None
@@ -67,114 +150,296 @@ impl Collector for RegionCollector {
     }
 
     fn alloc(&self, vm: &VM, size: usize, _array_ref: bool) -> Address {
-        if size < REGION_SIZE / 2 {
-            self.regular_alloc(vm, size)
+        if size < HUMONGOUS_OBJECT_SIZE {
+            self.alloc_regular(vm, size)
         } else {
-            unimplemented!()
+            self.alloc_humongous(vm, size)
         }
     }
 
-    fn collect(&self, _vm: &VM, _reason: GcReason) {
-        unimplemented!()
+    fn collect(&self, vm: &VM, reason: GcReason) {
+        self.perform_collection(vm, CollectionKind::Full, reason);
     }
 
-    fn minor_collect(&self, _vm: &VM, _reason: GcReason) {
-        unimplemented!()
+    fn minor_collect(&self, vm: &VM, reason: GcReason) {
+        self.perform_collection(vm, CollectionKind::Minor, reason);
     }
 
     fn needs_write_barrier(&self) -> bool {
-        false
+        self.emit_write_barrier
     }
 
     fn card_table_offset(&self) -> usize {
-        unimplemented!()
+        self.card_table_offset
+    }
+
+    fn dump_summary(&self, runtime: f32) {
+        let minor = self.minor_stats.lock();
+        let full = self.full_stats.lock();
+
+        let total_gc = minor.pause() + full.pause();
+        let gc_percentage = ((total_gc / runtime) * 100.0).round();
+        let mutator = runtime - total_gc;
+        let mutator_percentage = 100.0 - gc_percentage;
+
+        println!("GC stats: total={:.1}", runtime);
+        println!("GC stats: mutator={:.1}", mutator);
+        println!("GC stats: collection={:.1}", total_gc);
+        println!("GC stats: collection-minor={:.1}", minor.pause());
+        println!("GC stats: collection-full={:.1}", full.pause());
+
+        println!();
+        println!("GC stats: full-collections={}", full.collections());
+        println!("GC stats: full-pauses={}", full.pauses());
+        println!();
+        println!("GC stats: minor-collections={}", minor.collections());
+        println!("GC stats: minor-pauses={}", minor.pauses());
+        println!();
+
+        println!(
+            "GC summary: {:.1}ms minor ({}), {:.1}ms full ({}), {:.1}ms collection, {:.1}ms mutator, {:.1}ms total ({}% mutator, {}% GC)",
+            minor.pause(),
+            minor.collections(),
+            full.pause(),
+            full.collections(),
+            total_gc,
+            mutator,
+            runtime,
+            mutator_percentage,
+            gc_percentage,
+        );
     }
 
-    fn dump_summary(&self, _runtime: f32) {
-        unimplemented!()
+    fn verify_ref(&self, vm: &VM, reference: Address) {
+        let found = vm.gc.readonly_space.contains(reference)
+            || (self.heap.contains(reference) && self.region_of(reference).contains(reference));
+
+        assert!(found, "write barrier found invalid reference");
     }
+}
 
-    fn verify_ref(&self, _vm: &VM, _reference: Address) {
-        unimplemented!()
+impl Drop for RegionCollector {
+    fn drop(&mut self) {
+        os::free(
+            self.reservation.unaligned_start,
+            self.reservation.unaligned_size,
+        );
+        os::free(self.card_table.start, self.card_table.size());
     }
 }
 
 impl RegionCollector {
-    fn regular_alloc(&self, _vm: &VM, size: usize) -> Address {
-        assert!(size < REGION_SIZE / 2);
+    fn perform_collection(&self, vm: &VM, kind: CollectionKind, reason: GcReason) {
+        let mut timer = Timer::new(vm.args.flag_gc_stats);
 
-        let mut regular_alloc_region = self.regular_alloc_region.lock();
+        let kind = safepoint::stop_the_world(vm, |threads| {
+            tlab::make_iterable_all(vm, threads);
+            let rootset = determine_strong_roots(vm, threads);
 
-        loop {
-            let region_id = match *regular_alloc_region {
-                Some(region_id) => region_id,
-                None => {
-                    let allocated_region_id = self.find_free_region();
+            // the mutator starts with a fresh eden region after the collection
+            *self.eden_alloc_region.lock() = None;
 
-                    if allocated_region_id.is_none() {
-                        return Address::null();
-                    }
+            match kind {
+                CollectionKind::Minor if self.minor_collection_possible() => {
+                    self.minor_collect(vm, reason, &rootset);
+                    CollectionKind::Minor
+                }
 
-                    let allocated_region_id = allocated_region_id.unwrap();
-                    self.init_region(allocated_region_id);
-                    *regular_alloc_region = Some(allocated_region_id);
+                CollectionKind::Minor => {
+                    // not enough free regions to evacuate all young regions
+                    self.full_collect(vm, GcReason::PromotionFailure, &rootset);
+                    CollectionKind::Full
+                }
 
-                    allocated_region_id
+                CollectionKind::Full => {
+                    self.full_collect(vm, reason, &rootset);
+                    CollectionKind::Full
                 }
+            }
+        });
+
+        if vm.args.flag_gc_stats {
+            let duration = timer.stop();
+
+            let mut stats = match kind {
+                CollectionKind::Minor => self.minor_stats.lock(),
+                CollectionKind::Full => self.full_stats.lock(),
             };
 
-            let region = self.region(region_id);
-            let address = self.bump_pointer_alloc(region, size);
+            stats.add(duration);
+        }
+    }
 
-            if address.is_non_null() {
-                return address;
-            } else {
-                *regular_alloc_region = None;
+    fn minor_collect(&self, vm: &VM, reason: GcReason, rootset: &[Slot]) {
+        self.verify(vm, VerifierPhase::PreMinor, rootset);
+
+        let mut collector = MinorCollector::new(vm, self, rootset, reason);
+        collector.collect();
+
+        self.verify(vm, VerifierPhase::PostMinor, rootset);
+    }
+
+    fn full_collect(&self, vm: &VM, reason: GcReason, rootset: &[Slot]) {
+        self.verify(vm, VerifierPhase::PreFull, rootset);
+
+        let mut collector = FullCollector::new(vm, self, rootset, reason);
+        collector.collect();
+
+        self.verify(vm, VerifierPhase::PostFull, rootset);
+    }
+
+    fn verify(&self, vm: &VM, phase: VerifierPhase, rootset: &[Slot]) {
+        if vm.args.flag_gc_verify {
+            if vm.args.flag_gc_dev_verbose {
+                println!("GC: Verify {}", phase);
+            }
+
+            let mut verifier = Verifier::new(self, rootset, &vm.gc.readonly_space, phase);
+            verifier.verify();
+
+            if vm.args.flag_gc_dev_verbose {
+                println!("GC: Verify {} finished", phase);
             }
         }
     }
 
-    fn bump_pointer_alloc(&self, region: &HeapRegion, size: usize) -> Address {
-        let mut top = region.top.load(Ordering::Relaxed);
-        let limit = region.area_end.to_usize();
+    // A minor collection copies every live young object into a fresh region. Each
+    // destination region is at least half full since regular objects are smaller
+    // than half a region, this allows us to bound the number of regions needed.
+    fn minor_collection_possible(&self) -> bool {
+        let young_size: usize = self
+            .regions
+            .iter()
+            .filter(|region| region.state.load().is_young())
+            .map(|region| region.used_size())
+            .sum();
+
+        let needed_regions = 2 * young_size.div_ceil(REGION_SIZE) + 2;
+        needed_regions <= self.count_regions(RegionState::Free)
+    }
+
+    fn alloc_regular(&self, vm: &VM, size: usize) -> Address {
+        let ptr = self.eden_alloc(size);
+
+        if ptr.is_non_null() {
+            return ptr;
+        }
+
+        self.perform_collection(vm, CollectionKind::Minor, GcReason::AllocationFailure);
+
+        let ptr = self.eden_alloc(size);
+
+        if ptr.is_non_null() {
+            return ptr;
+        }
+
+        self.perform_collection(vm, CollectionKind::Full, GcReason::AllocationFailure);
+
+        self.eden_alloc(size)
+    }
+
+    fn alloc_humongous(&self, vm: &VM, size: usize) -> Address {
+        let ptr = self.humongous_alloc(size);
+
+        if ptr.is_non_null() {
+            return ptr;
+        }
+
+        self.perform_collection(vm, CollectionKind::Full, GcReason::AllocationFailure);
+
+        self.humongous_alloc(size)
+    }
+
+    fn eden_alloc(&self, size: usize) -> Address {
+        assert!(size < HUMONGOUS_OBJECT_SIZE);
+
+        let mut eden_alloc_region = self.eden_alloc_region.lock();
 
         loop {
-            if top + size <= limit {
-                let result = region.top.compare_exchange_weak(
-                    top,
-                    top + size,
-                    Ordering::Relaxed,
-                    Ordering::Relaxed,
-                );
+            if let Some(region_id) = *eden_alloc_region {
+                let address = self.region(region_id).bump_alloc(size);
 
-                match result {
-                    Ok(_) => return top.into(),
-                    Err(actual_top) => {
-                        top = actual_top;
-                    }
+                if address.is_non_null() {
+                    return address;
                 }
-            } else {
+            }
+
+            if self.count_young_regions() >= self.young_limit {
                 return Address::null();
             }
+
+            match self.alloc_region(RegionState::Eden) {
+                Some(region_id) => {
+                    *eden_alloc_region = Some(region_id);
+                }
+
+                None => {
+                    return Address::null();
+                }
+            }
         }
     }
 
-    fn init_region(&self, region_id: RegionId) {
-        let region = self.region(region_id);
-        region.state.store(RegionState::Used);
+    fn humongous_alloc(&self, size: usize) -> Address {
+        // allocation of regions is serialized through the eden lock
+        let _eden_alloc_region = self.eden_alloc_region.lock();
 
-        os::commit_at(
-            region.area_start,
-            region.area_size(),
-            os::MemoryPermission::ReadWrite,
-        );
+        let needed = size.div_ceil(REGION_SIZE);
+        let mut run_start = 0;
+        let mut run_length = 0;
+
+        for region_idx in 0..self.number_regions {
+            if self.regions[region_idx].state.load() != RegionState::Free {
+                run_start = region_idx + 1;
+                run_length = 0;
+                continue;
+            }
+
+            run_length += 1;
+
+            if run_length == needed {
+                let first = &self.regions[run_start];
+                os::commit_at(
+                    first.area_start,
+                    needed * REGION_SIZE,
+                    os::MemoryPermission::ReadWrite,
+                );
+
+                for region in &self.regions[run_start + 1..run_start + needed] {
+                    region.state.store(RegionState::HumongousCont);
+                }
+
+                first
+                    .top
+                    .store(first.area_start.offset(size).to_usize(), Ordering::Relaxed);
+                first.state.store(RegionState::Humongous);
+
+                return first.area_start;
+            }
+        }
+
+        Address::null()
     }
 
-    fn find_free_region(&self) -> Option<RegionId> {
+    // takes the first free region and commits its memory.
+    fn alloc_region(&self, state: RegionState) -> Option<RegionId> {
+        debug_assert!(state != RegionState::Free);
+
         for region_idx in 0..self.number_regions {
-            let region = self.region(region_idx.into());
+            let region = &self.regions[region_idx];
 
             if region.state.load() == RegionState::Free {
+                os::commit_at(
+                    region.area_start,
+                    region.area_size(),
+                    os::MemoryPermission::ReadWrite,
+                );
+
+                region
+                    .top
+                    .store(region.area_start.to_usize(), Ordering::Relaxed);
+                region.state.store(state);
+
                 return Some(region_idx.into());
             }
         }
@@ -182,12 +447,113 @@ impl RegionCollector {
         None
     }
 
+    fn free_region(&self, region_id: RegionId) {
+        let region = self.region(region_id);
+
+        region
+            .top
+            .store(region.area_start.to_usize(), Ordering::Relaxed);
+        region.live_bytes.store(0, Ordering::Relaxed);
+        region.remembered_set.lock().clear();
+        region.state.store(RegionState::Free);
+    }
+
+    fn count_regions(&self, state: RegionState) -> usize {
+        self.regions
+            .iter()
+            .filter(|region| region.state.load() == state)
+            .count()
+    }
+
+    fn count_young_regions(&self) -> usize {
+        self.regions
+            .iter()
+            .filter(|region| region.state.load().is_young())
+            .count()
+    }
+
+    fn used_size(&self) -> usize {
+        self.regions.iter().map(|region| region.used_size()).sum()
+    }
+
     fn region(&self, id: RegionId) -> &HeapRegion {
         &self.regions[id.to_usize()]
     }
+
+    fn region_id(&self, addr: Address) -> RegionId {
+        debug_assert!(self.heap.contains(addr));
+        (addr.offset_from(self.heap.start) >> REGION_SIZE_BITS).into()
+    }
+
+    fn region_of(&self, addr: Address) -> &HeapRegion {
+        self.region(self.region_id(addr))
+    }
+
+    fn is_young(&self, addr: Address) -> bool {
+        self.heap.contains(addr) && self.region_of(addr).state.load().is_young()
+    }
+
+    // Slots in old or humongous regions that point into young regions
+    // need to be recorded, they are roots for the next minor collection.
+    fn remember(&self, slot: Slot, target: Address) {
+        if self.is_young(target) && !self.is_young(slot.address()) {
+            let region = self.region_of(target);
+            region.remembered_set.lock().insert(slot.address());
+        }
+    }
+
+    // Scans all dirty cards in old and humongous regions and records the
+    // slots with references into young regions in the remembered sets.
+    // Afterwards all cards are clean again.
+    fn refine_cards(&self) {
+        for region in &self.regions {
+            match region.state.load() {
+                RegionState::Old => {
+                    let used = Region::new(region.area_start, region.top());
+
+                    if !self.card_table.has_dirty_card(used) {
+                        continue;
+                    }
+
+                    walk_region(used, |object, address, size| {
+                        self.refine_object(object, address, size);
+                    });
+                }
+
+                RegionState::Humongous => {
+                    let object = region.area_start.to_mut_obj();
+                    let size = object.size();
+
+                    if self
+                        .card_table
+                        .has_dirty_card(region.area_start.region_start(size))
+                    {
+                        self.refine_object(object, region.area_start, size);
+                    }
+                }
+
+                _ => {}
+            }
+        }
+
+        self.card_table.clean();
+    }
+
+    fn refine_object(&self, object: &mut Obj, address: Address, size: usize) {
+        // The write barrier marks the card of the object start for fields and the card of
+        // the element start for arrays. Elements can span multiple cards, therefore all
+        // fields of objects with a dirty card are visited.
+        if !self.card_table.has_dirty_card(address.region_start(size)) {
+            return;
+        }
+
+        object.visit_reference_fields(|slot| {
+            self.remember(slot, slot.get());
+        });
+    }
 }
 
-struct HeapRegion {
+pub struct HeapRegion {
     // Object area in region.
     area_start: Address,
     area_end: Address,
@@ -200,15 +566,92 @@ struct HeapRegion {
 
     // Number of live bytes after marking.
     live_bytes: AtomicUsize,
+
+    // Addresses of slots outside of the young generation that
+    // reference objects in this region.
+    remembered_set: Mutex<HashSet<Address>>,
 }
 
 impl HeapRegion {
     fn area_size(&self) -> usize {
         self.area_end.to_usize() - self.area_start.to_usize()
     }
+
+    fn top(&self) -> Address {
+        self.top.load(Ordering::Relaxed).into()
+    }
+
+    fn used_size(&self) -> usize {
+        match self.state.load() {
+            RegionState::Free | RegionState::HumongousCont => 0,
+            _ => self.top().offset_from(self.area_start),
+        }
+    }
+
+    // gives true if address is within the used part of the region
+    fn contains(&self, addr: Address) -> bool {
+        match self.state.load() {
+            RegionState::Free | RegionState::HumongousCont => false,
+            _ => self.area_start <= addr && addr < self.top(),
+        }
+    }
+
+    fn bump_alloc(&self, size: usize) -> Address {
+        let mut top = self.top.load(Ordering::Relaxed);
+        let limit = self.area_end.to_usize();
+
+        loop {
+            if top + size <= limit {
+                let result = self.top.compare_exchange_weak(
+                    top,
+                    top + size,
+                    Ordering::Relaxed,
+                    Ordering::Relaxed,
+                );
+
+                match result {
+                    Ok(_) => return top.into(),
+                    Err(actual_top) => {
+                        top = actual_top;
+                    }
+                }
+            } else {
+                return Address::null();
+            }
+        }
+    }
+}
+
+enumeration!(RegionState {
+    Free,
+    Eden,
+    Survivor,
+    Old,
+    Humongous,
+    HumongousCont
+});
+
+impl RegionState {
+    fn is_young(self) -> bool {
+        self == RegionState::Eden || self == RegionState::Survivor
+    }
 }
 
-enumeration!(RegionState { Free, Used });
+impl fmt::Display for RegionState {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match *self {
+            RegionState::Free => "free",
+            RegionState::Eden => "eden",
+            RegionState::Survivor => "survivor",
+            RegionState::Old => "old",
+            RegionState::Humongous => "humongous",
+            RegionState::HumongousCont => "humongous continuation",
+            _ => unreachable!(),
+        };
+
+        write!(f, "{}", name)
+    }
+}
 
 struct AtomicRegionState {
     value: AtomicUsize,
@@ -230,7 +673,60 @@ impl AtomicRegionState {
     }
 }
 
-/// round the given value up to the nearest multiple of a generation
+// One byte per card: 0 means dirty, 1 means clean. The write barrier
+// emitted by the compiler dirties cards directly.
+struct CardTable {
+    start: Address,
+    end: Address,
+    heap_start: Address,
+}
+
+impl CardTable {
+    fn new(table: Region, heap_start: Address) -> CardTable {
+        let card_table = CardTable {
+            start: table.start,
+            end: table.end,
+            heap_start,
+        };
+
+        card_table.clean();
+
+        card_table
+    }
+
+    fn size(&self) -> usize {
+        self.end.offset_from(self.start)
+    }
+
+    fn clean(&self) {
+        unsafe {
+            ptr::write_bytes(self.start.to_mut_ptr::<u8>(), 1, self.size());
+        }
+    }
+
+    fn card_entry(&self, addr: Address) -> Address {
+        let card_idx = addr.offset_from(self.heap_start) >> CARD_SIZE_BITS;
+        let entry = self.start.offset(card_idx);
+        debug_assert!(entry < self.end);
+
+        entry
+    }
+
+    fn has_dirty_card(&self, region: Region) -> bool {
+        if region.empty() {
+            return false;
+        }
+
+        let start = self.card_entry(region.start);
+        let end = self.card_entry(region.end.sub(1)).offset(1);
+        let cards =
+            unsafe { std::slice::from_raw_parts(start.to_ptr::<u8>(), end.offset_from(start)) };
+
+        cards.contains(&0)
+    }
+}
+
+/// round the given value up to the nearest multiple of a region
 pub fn align_region(value: usize) -> usize {
     let align = REGION_SIZE_BITS;
     // we know that region size is power of 2, hence
@@ -249,12 +745,24 @@ impl RegionSet {
         }
     }
 
+    fn insert(&mut self, id: RegionId) {
+        self.bits.insert(id.to_usize());
+    }
+
+    fn contains(&self, id: RegionId) -> bool {
+        self.bits.contains(id.to_usize())
+    }
+
+    fn iter(&self) -> impl Iterator<Item = RegionId> + '_ {
+        self.bits.ones().map(|idx| idx.into())
+    }
+
     fn clear(&mut self) {
         self.bits.clear();
     }
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct RegionId(usize);
 
 impl RegionId {
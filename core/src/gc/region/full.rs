@@ -0,0 +1,354 @@
+use std::sync::atomic::Ordering;
+
+use crate::gc::region::{
+    RegionCollector, RegionId, RegionSet, RegionState, EVACUATION_LIVE_THRESHOLD, REGION_SIZE,
+};
+use crate::gc::root::Slot;
+use crate::gc::swiper::walk_region;
+use crate::gc::{fill_region, formatted_size, iterate_weak_roots, Address, GcReason, Region};
+use crate::object::Obj;
+use crate::timer::Timer;
+use crate::vm::VM;
+
+// Marks the whole heap and evacuates regions with few live objects into
+// fresh old regions. All other regions keep their live objects in place,
+// dead objects in them are overwritten with filler objects. Afterwards
+// there are no young regions left.
+pub struct FullCollector<'a> {
+    vm: &'a VM,
+    collector: &'a RegionCollector,
+    rootset: &'a [Slot],
+    reason: GcReason,
+
+    collection_set: RegionSet,
+    old_region: Option<RegionId>,
+
+    // regions that were filled with evacuated objects
+    evacuation_regions: RegionSet,
+
+    marking_stack: Vec<Address>,
+
+    used_size_before: usize,
+}
+
+impl<'a> FullCollector<'a> {
+    pub fn new(
+        vm: &'a VM,
+        collector: &'a RegionCollector,
+        rootset: &'a [Slot],
+        reason: GcReason,
+    ) -> FullCollector<'a> {
+        FullCollector {
+            vm,
+            collector,
+            rootset,
+            reason,
+
+            collection_set: RegionSet::new(collector.number_regions),
+            old_region: None,
+
+            evacuation_regions: RegionSet::new(collector.number_regions),
+
+            marking_stack: Vec::new(),
+
+            used_size_before: 0,
+        }
+    }
+
+    pub fn collect(&mut self) {
+        let timer = Timer::new(self.vm.args.flag_gc_verbose);
+        self.used_size_before = self.collector.used_size();
+
+        self.mark_live();
+        self.select_collection_set();
+        self.evacuate_collection_set();
+
+        self.update_weak_roots();
+        self.update_roots();
+        self.update_references();
+
+        self.free_collection_set();
+        self.reset_young_regions();
+
+        timer.stop_with(|time_pause| {
+            let used_size_after = self.collector.used_size();
+            let garbage = self.used_size_before - used_size_after;
+
+            println!(
+                "GC: Region Full: {:.1} ms, {}->{} size, {} garbage, {} free regions ({})",
+                time_pause,
+                formatted_size(self.used_size_before),
+                formatted_size(used_size_after),
+                formatted_size(garbage),
+                self.collector.count_regions(RegionState::Free),
+                self.reason,
+            );
+        });
+    }
+
+    fn mark_live(&mut self) {
+        for region in &self.collector.regions {
+            region.live_bytes.store(0, Ordering::Relaxed);
+        }
+
+        for root in self.rootset {
+            self.mark(root.get());
+        }
+
+        while let Some(address) = self.marking_stack.pop() {
+            let object = address.to_mut_obj();
+
+            object.visit_reference_fields(|field| {
+                self.mark(field.get());
+            });
+        }
+    }
+
+    fn mark(&mut self, address: Address) {
+        if !self.collector.heap.contains(address) {
+            debug_assert!(address.is_null() || self.vm.gc.readonly_space.contains(address));
+            return;
+        }
+
+        let object = address.to_mut_obj();
+
+        if object.header().try_mark_non_atomic() {
+            let region = self.collector.region_of(address);
+            region
+                .live_bytes
+                .fetch_add(object.size(), Ordering::Relaxed);
+            self.marking_stack.push(address);
+        }
+    }
+
+    // Prefers regions with the fewest live bytes. The number of evacuated
+    // live bytes is bounded by the free regions: each destination region is
+    // at least half full since regular objects are smaller than half a region.
+    fn select_collection_set(&mut self) {
+        let mut candidates = self
+            .collector
+            .regions
+            .iter()
+            .enumerate()
+            .filter(|(_, region)| {
+                let state = region.state.load();
+                state.is_young() || state == RegionState::Old
+            })
+            .map(|(idx, region)| (idx, region.live_bytes.load(Ordering::Relaxed)))
+            .collect::<Vec<_>>();
+
+        candidates.sort_by_key(|&(_, live_bytes)| live_bytes);
+
+        let free_regions = self.collector.count_regions(RegionState::Free);
+        let mut evacuated_bytes = 0;
+
+        for (idx, live_bytes) in candidates {
+            if live_bytes * 100 >= EVACUATION_LIVE_THRESHOLD * REGION_SIZE {
+                break;
+            }
+
+            let needed_bytes = evacuated_bytes + live_bytes;
+            let needed_regions = 2 * needed_bytes.div_ceil(REGION_SIZE) + 1;
+
+            if live_bytes > 0 && needed_regions > free_regions {
+                break;
+            }
+
+            self.collection_set.insert(idx.into());
+            evacuated_bytes = needed_bytes;
+        }
+    }
+
+    fn evacuate_collection_set(&mut self) {
+        let collection_set = self.collection_set.iter().collect::<Vec<_>>();
+
+        for region_id in collection_set {
+            let region = self.collector.region(region_id);
+            let used = Region::new(region.area_start, region.top());
+
+            walk_region(used, |object, _address, size| {
+                if object.header().is_marked_non_atomic() {
+                    self.evacuate(object, size);
+                }
+            });
+        }
+    }
+
+    fn evacuate(&mut self, object: &mut Obj, size: usize) {
+        let new_address = self.alloc(size);
+
+        object.copy_to(new_address, size);
+        object.header_mut().vtblptr_forward(new_address);
+
+        let new_object = new_address.to_mut_obj();
+        new_object.header_mut().unmark_non_atomic();
+    }
+
+    fn alloc(&mut self, size: usize) -> Address {
+        if let Some(region_id) = self.old_region {
+            let address = self.collector.region(region_id).bump_alloc(size);
+
+            if address.is_non_null() {
+                return address;
+            }
+        }
+
+        // the collection set was chosen such that free regions suffice
+        let region_id = self
+            .collector
+            .alloc_region(RegionState::Old)
+            .expect("no free region for evacuation");
+        self.old_region = Some(region_id);
+        self.evacuation_regions.insert(region_id);
+
+        self.collector.region(region_id).bump_alloc(size)
+    }
+
+    fn update_weak_roots(&self) {
+        iterate_weak_roots(self.vm, |current_address| {
+            if !self.collector.heap.contains(current_address) {
+                return Some(current_address);
+            }
+
+            let object = current_address.to_obj();
+
+            if self.in_collection_set(current_address) {
+                object.header().vtblptr_forwarded()
+            } else if object.header().is_marked_non_atomic() {
+                Some(current_address)
+            } else {
+                None
+            }
+        });
+    }
+
+    fn update_roots(&self) {
+        for root in self.rootset {
+            root.set(self.forward(root.get()));
+        }
+    }
+
+    fn update_references(&self) {
+        for (idx, region) in self.collector.regions.iter().enumerate() {
+            let region_id: RegionId = idx.into();
+
+            if self.collection_set.contains(region_id) {
+                continue;
+            }
+
+            match region.state.load() {
+                RegionState::Free | RegionState::HumongousCont => {}
+
+                RegionState::Humongous => {
+                    let object = region.area_start.to_mut_obj();
+
+                    if object.header().is_marked_non_atomic() {
+                        object.header_mut().unmark_non_atomic();
+                        self.update_fields(object);
+                    } else {
+                        self.free_humongous(region_id);
+                    }
+                }
+
+                _ if self.evacuation_regions.contains(region_id) => {
+                    // only contains live objects
+                    let used = Region::new(region.area_start, region.top());
+
+                    walk_region(used, |object, _address, _size| {
+                        self.update_fields(object);
+                    });
+                }
+
+                _ => {
+                    let used = Region::new(region.area_start, region.top());
+                    self.update_region_in_place(used);
+                }
+            }
+        }
+    }
+
+    // Updates references of live objects and fills dead objects, otherwise
+    // dead objects would still reference freed regions.
+    fn update_region_in_place(&self, used: Region) {
+        let mut garbage_start = Address::null();
+
+        walk_region(used, |object, address, _size| {
+            if object.header().is_marked_non_atomic() {
+                if garbage_start.is_non_null() {
+                    fill_region(self.vm, garbage_start, address);
+                    garbage_start = Address::null();
+                }
+
+                object.header_mut().unmark_non_atomic();
+                self.update_fields(object);
+            } else if garbage_start.is_null() {
+                garbage_start = address;
+            }
+        });
+
+        if garbage_start.is_non_null() {
+            fill_region(self.vm, garbage_start, used.end);
+        }
+    }
+
+    fn update_fields(&self, object: &mut Obj) {
+        object.visit_reference_fields(|field| {
+            field.set(self.forward(field.get()));
+        });
+    }
+
+    fn forward(&self, address: Address) -> Address {
+        if self.in_collection_set(address) {
+            address
+                .to_obj()
+                .header()
+                .vtblptr_forwarded()
+                .expect("live object was not evacuated")
+        } else {
+            address
+        }
+    }
+
+    fn free_humongous(&self, region_id: RegionId) {
+        self.collector.free_region(region_id);
+
+        let next = region_id.to_usize() + 1;
+
+        for idx in next..self.collector.number_regions {
+            if self.collector.regions[idx].state.load() != RegionState::HumongousCont {
+                break;
+            }
+
+            self.collector.free_region(idx.into());
+        }
+    }
+
+    fn free_collection_set(&mut self) {
+        for region_id in self.collection_set.iter() {
+            self.collector.free_region(region_id);
+        }
+
+        self.collection_set.clear();
+    }
+
+    // Young regions that weren't evacuated are now part of the old
+    // generation. No remembered sets are needed without young regions.
+    fn reset_young_regions(&self) {
+        for region in &self.collector.regions {
+            if region.state.load().is_young() {
+                region.state.store(RegionState::Old);
+            }
+
+            region.remembered_set.lock().clear();
+        }
+
+        self.collector.card_table.clean();
+    }
+
+    fn in_collection_set(&self, address: Address) -> bool {
+        self.collector.heap.contains(address)
+            && self
+                .collection_set
+                .contains(self.collector.region_id(address))
+    }
+}
@@ -0,0 +1,214 @@
+use crate::gc::region::{RegionCollector, RegionId, RegionSet, RegionState};
+use crate::gc::root::Slot;
+use crate::gc::{formatted_size, iterate_weak_roots, Address, GcReason};
+use crate::timer::Timer;
+use crate::vm::VM;
+
+// Evacuates all young regions: live objects from eden regions are copied
+// into survivor regions, objects that already survived a collection are
+// promoted into old regions. Roots are the strong roots and the remembered
+// sets of the young regions.
+pub struct MinorCollector<'a> {
+    vm: &'a VM,
+    collector: &'a RegionCollector,
+    rootset: &'a [Slot],
+    reason: GcReason,
+
+    collection_set: RegionSet,
+
+    survivor_region: Option<RegionId>,
+    old_region: Option<RegionId>,
+
+    // copied objects whose fields weren't visited yet
+    worklist: Vec<Address>,
+
+    young_size: usize,
+    survived_size: usize,
+    promoted_size: usize,
+}
+
+impl<'a> MinorCollector<'a> {
+    pub fn new(
+        vm: &'a VM,
+        collector: &'a RegionCollector,
+        rootset: &'a [Slot],
+        reason: GcReason,
+    ) -> MinorCollector<'a> {
+        MinorCollector {
+            vm,
+            collector,
+            rootset,
+            reason,
+
+            collection_set: RegionSet::new(collector.number_regions),
+
+            survivor_region: None,
+            old_region: None,
+
+            worklist: Vec::new(),
+
+            young_size: 0,
+            survived_size: 0,
+            promoted_size: 0,
+        }
+    }
+
+    pub fn collect(&mut self) {
+        let timer = Timer::new(self.vm.args.flag_gc_verbose);
+
+        self.collector.refine_cards();
+        self.select_collection_set();
+
+        self.evacuate_roots();
+        self.evacuate_remembered_sets();
+        self.evacuate_transitive();
+
+        self.update_weak_roots();
+        self.free_collection_set();
+
+        timer.stop_with(|time_pause| {
+            let garbage = self.young_size - self.survived_size - self.promoted_size;
+
+            println!(
+                "GC: Region Minor: {:.1} ms, young {}->{}, {} promoted, {} garbage, {} free regions ({})",
+                time_pause,
+                formatted_size(self.young_size),
+                formatted_size(self.survived_size),
+                formatted_size(self.promoted_size),
+                formatted_size(garbage),
+                self.collector.count_regions(RegionState::Free),
+                self.reason,
+            );
+        });
+    }
+
+    fn select_collection_set(&mut self) {
+        for (idx, region) in self.collector.regions.iter().enumerate() {
+            if region.state.load().is_young() {
+                self.collection_set.insert(idx.into());
+                self.young_size += region.used_size();
+            }
+        }
+    }
+
+    fn evacuate_roots(&mut self) {
+        for &root in self.rootset {
+            let root_ptr = root.get();
+
+            if self.in_collection_set(root_ptr) {
+                root.set(self.evacuate(root_ptr));
+            }
+        }
+    }
+
+    fn evacuate_remembered_sets(&mut self) {
+        let mut slots = Vec::new();
+
+        for region_id in self.collection_set.iter() {
+            let region = self.collector.region(region_id);
+            slots.extend(region.remembered_set.lock().drain());
+        }
+
+        for slot in slots {
+            let slot = Slot::at(slot);
+            let object = slot.get();
+
+            // slot might have been overwritten since it was recorded
+            if self.in_collection_set(object) {
+                let new_address = self.evacuate(object);
+                slot.set(new_address);
+                self.collector.remember(slot, new_address);
+            }
+        }
+    }
+
+    fn evacuate_transitive(&mut self) {
+        while let Some(address) = self.worklist.pop() {
+            let object = address.to_mut_obj();
+
+            object.visit_reference_fields(|field| {
+                let field_ptr = field.get();
+
+                if self.in_collection_set(field_ptr) {
+                    let new_address = self.evacuate(field_ptr);
+                    field.set(new_address);
+                    self.collector.remember(field, new_address);
+                }
+            });
+        }
+    }
+
+    fn update_weak_roots(&self) {
+        iterate_weak_roots(self.vm, |current_address| {
+            if self.in_collection_set(current_address) {
+                current_address.to_obj().header().vtblptr_forwarded()
+            } else {
+                Some(current_address)
+            }
+        });
+    }
+
+    fn free_collection_set(&mut self) {
+        for region_id in self.collection_set.iter() {
+            self.collector.free_region(region_id);
+        }
+
+        self.collection_set.clear();
+    }
+
+    fn evacuate(&mut self, address: Address) -> Address {
+        let object = address.to_mut_obj();
+
+        if let Some(new_address) = object.header().vtblptr_forwarded() {
+            return new_address;
+        }
+
+        let size = object.size();
+
+        let new_address = if self.collector.region_of(address).state.load() == RegionState::Eden {
+            self.survived_size += size;
+            self.alloc(size, RegionState::Survivor)
+        } else {
+            self.promoted_size += size;
+            self.alloc(size, RegionState::Old)
+        };
+
+        object.copy_to(new_address, size);
+        object.header_mut().vtblptr_forward(new_address);
+        self.worklist.push(new_address);
+
+        new_address
+    }
+
+    fn alloc(&mut self, size: usize, state: RegionState) -> Address {
+        let current = if state == RegionState::Survivor {
+            &mut self.survivor_region
+        } else {
+            &mut self.old_region
+        };
+
+        if let Some(region_id) = *current {
+            let address = self.collector.region(region_id).bump_alloc(size);
+
+            if address.is_non_null() {
+                return address;
+            }
+        }
+
+        // enough free regions are guaranteed before starting the minor collection
+        let region_id = self
+            .collector
+            .alloc_region(state)
+            .expect("no free region for evacuation");
+        *current = Some(region_id);
+
+        self.collector.region(region_id).bump_alloc(size)
+    }
+
+    fn in_collection_set(&self, address: Address) -> bool {
+        self.collector.heap.contains(address)
+            && self
+                .collection_set
+                .contains(self.collector.region_id(address))
+    }
+}
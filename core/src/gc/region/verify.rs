@@ -0,0 +1,245 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::gc::region::{HeapRegion, RegionCollector, RegionState};
+use crate::gc::root::Slot;
+use crate::gc::space::Space;
+use crate::gc::swiper::walk_region;
+use crate::gc::{Address, Region};
+
+#[derive(Copy, Clone)]
+pub enum VerifierPhase {
+    PreMinor,
+    PostMinor,
+    PreFull,
+    PostFull,
+}
+
+impl VerifierPhase {
+    fn is_pre(self) -> bool {
+        match self {
+            VerifierPhase::PreMinor | VerifierPhase::PreFull => true,
+            VerifierPhase::PostMinor | VerifierPhase::PostFull => false,
+        }
+    }
+
+    fn is_post_full(self) -> bool {
+        matches!(self, VerifierPhase::PostFull)
+    }
+}
+
+impl fmt::Display for VerifierPhase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = match self {
+            VerifierPhase::PreMinor => "pre minor",
+            VerifierPhase::PostMinor => "post minor",
+            VerifierPhase::PreFull => "pre full",
+            VerifierPhase::PostFull => "post full",
+        };
+
+        write!(f, "{}", text)
+    }
+}
+
+pub struct Verifier<'a> {
+    collector: &'a RegionCollector,
+    rootset: &'a [Slot],
+    readonly_space: &'a Space,
+    phase: VerifierPhase,
+
+    // start addresses of all objects in the heap
+    objects: HashSet<Address>,
+}
+
+impl<'a> Verifier<'a> {
+    pub fn new(
+        collector: &'a RegionCollector,
+        rootset: &'a [Slot],
+        readonly_space: &'a Space,
+        phase: VerifierPhase,
+    ) -> Verifier<'a> {
+        Verifier {
+            collector,
+            rootset,
+            readonly_space,
+            phase,
+
+            objects: HashSet::new(),
+        }
+    }
+
+    pub fn verify(&mut self) {
+        self.verify_region_states();
+        self.collect_objects();
+        self.verify_roots();
+        self.verify_objects();
+    }
+
+    fn verify_region_states(&self) {
+        let mut humongous = false;
+
+        for (idx, region) in self.collector.regions.iter().enumerate() {
+            let state = region.state.load();
+
+            if state == RegionState::HumongousCont {
+                assert!(
+                    humongous,
+                    "region {} continues humongous object without start ({})",
+                    idx, self.phase
+                );
+            } else {
+                humongous = state == RegionState::Humongous;
+            }
+
+            if self.phase.is_post_full() {
+                assert!(
+                    !state.is_young(),
+                    "{} region {} after full collection",
+                    state,
+                    idx
+                );
+            }
+
+            if state == RegionState::Free || state == RegionState::HumongousCont {
+                assert!(region.remembered_set.lock().is_empty());
+            }
+        }
+    }
+
+    fn collect_objects(&mut self) {
+        for region in &self.collector.regions {
+            for_each_object(region, |address| {
+                self.objects.insert(address);
+            });
+        }
+    }
+
+    fn verify_roots(&self) {
+        for root in self.rootset {
+            self.verify_reference(*root, Address::null(), "root set");
+        }
+    }
+
+    fn verify_objects(&self) {
+        for region in &self.collector.regions {
+            let state = region.state.load();
+
+            for_each_object(region, |address| {
+                let object = address.to_mut_obj();
+
+                assert!(
+                    object.header().vtblptr_forwarded().is_none(),
+                    "object {} in {} region is forwarded ({})",
+                    address,
+                    state,
+                    self.phase
+                );
+
+                assert!(
+                    !object.header().is_marked_non_atomic(),
+                    "object {} in {} region is marked ({})",
+                    address,
+                    state,
+                    self.phase
+                );
+
+                let dirty = self
+                    .collector
+                    .card_table
+                    .has_dirty_card(address.region_start(object.size()));
+
+                object.visit_reference_fields(|slot| {
+                    self.verify_reference(slot, address, "object");
+
+                    if !state.is_young() {
+                        self.verify_remembered(slot, address, dirty);
+                    }
+                });
+            });
+        }
+    }
+
+    fn verify_reference(&self, slot: Slot, object: Address, name: &str) {
+        let reference = slot.get();
+
+        if reference.is_null() || self.readonly_space.contains(reference) {
+            return;
+        }
+
+        if !self.collector.heap.contains(reference) {
+            panic!(
+                "reference {} in {} {} at slot {} points outside of heap ({})",
+                reference,
+                name,
+                object,
+                slot.address(),
+                self.phase
+            );
+        }
+
+        if !self.objects.contains(&reference) {
+            let state = self.collector.region_of(reference).state.load();
+
+            panic!(
+                "reference {} in {} {} at slot {} does not point to object in {} region ({})",
+                reference,
+                name,
+                object,
+                slot.address(),
+                state,
+                self.phase
+            );
+        }
+    }
+
+    // Every reference from outside the young generation into a young region
+    // needs to be in the remembered set of that region. Before a collection
+    // a dirty card for the object is also sufficient since cards are only
+    // refined during the collection.
+    fn verify_remembered(&self, slot: Slot, object: Address, dirty: bool) {
+        let reference = slot.get();
+
+        if !self.collector.is_young(reference) {
+            return;
+        }
+
+        let region = self.collector.region_of(reference);
+
+        if region.remembered_set.lock().contains(&slot.address()) {
+            return;
+        }
+
+        if self.phase.is_pre() && dirty {
+            return;
+        }
+
+        panic!(
+            "slot {} in object {} references young object {} but is not remembered ({})",
+            slot.address(),
+            object,
+            reference,
+            self.phase
+        );
+    }
+}
+
+fn for_each_object<F>(region: &HeapRegion, mut f: F)
+where
+    F: FnMut(Address),
+{
+    match region.state.load() {
+        RegionState::Free | RegionState::HumongousCont => {}
+
+        RegionState::Humongous => {
+            f(region.area_start);
+        }
+
+        _ => {
+            let used = Region::new(region.area_start, region.top());
+
+            walk_region(used, |_object, address, _size| {
+                f(address);
+            });
+        }
+    }
+}
@@ -94,6 +94,11 @@ fn iterate_roots_from_globals<F: FnMut(Slot)>(vm: &VM, callback: &mut F) {
 
             SourceType::Unit
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Bool
             | SourceType::Char
             | SourceType::Int32
@@ -101,7 +106,7 @@ fn iterate_roots_from_globals<F: FnMut(Slot)>(vm: &VM, callback: &mut F) {
             | SourceType::Float32
             | SourceType::Float64 => {}
 
-            SourceType::Class(_, _) | SourceType::Trait(_, _) => {
+            SourceType::Class(_, _) | SourceType::Trait(_, _) | SourceType::Union(_, _) => {
                 let slot = Slot::at(global_var.address_value);
                 callback(slot);
             }
@@ -110,6 +115,7 @@ fn iterate_roots_from_globals<F: FnMut(Slot)>(vm: &VM, callback: &mut F) {
             | SourceType::Error
             | SourceType::Any
             | SourceType::This
+            | SourceType::Assoc(_)
             | SourceType::Lambda(_, _)
             | SourceType::Ptr => unreachable!(),
         }
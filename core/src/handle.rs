@@ -37,6 +37,19 @@ impl HandleMemory {
         self.inner.lock().pop_border();
     }
 
+    pub fn borders(&self) -> usize {
+        self.inner.lock().borders.len()
+    }
+
+    // Pops borders until only `count` of them are left.
+    pub fn pop_borders_to(&self, count: usize) {
+        let mut inner = self.inner.lock();
+
+        while inner.borders.len() > count {
+            inner.pop_border();
+        }
+    }
+
     pub fn iter(&self) -> HandleMemoryIter {
         let inner = self.inner.lock();
         let len = inner.blocks.len();
@@ -258,6 +271,29 @@ fn test_handle_iteration() {
     }
 }
 
+#[test]
+fn test_handle_pop_borders_to() {
+    let hm = HandleMemory::new();
+    hm.handle_address(1.into());
+    hm.push_border();
+    assert_eq!(hm.borders(), 1);
+
+    for _ in 0..3 {
+        hm.push_border();
+
+        for _ in 0..HANDLE_BLOCK_SIZE {
+            hm.handle_address(2.into());
+        }
+    }
+
+    hm.pop_borders_to(1);
+    assert_eq!(hm.borders(), 1);
+    hm.pop_border();
+
+    assert_eq!(hm.iter().count(), 1);
+    assert!(hm.iter().all(|x| x.raw_load() == 1.into()));
+}
+
 pub fn handle_scope<F: FnOnce() -> R, R>(f: F) -> R {
     let thread = current_thread();
     thread.handles.push_border();
@@ -7,8 +7,10 @@ use core_parser::interner::Name;
 use core_parser::lexer::position::Position;
 
 pub use readty::{read_type, AllowSelf, TypeParamContext};
+pub use specialize::{replace_assoc_types, resolve_assoc_type};
 
 pub(crate) mod access;
+mod aliasck;
 mod clsdefck;
 mod constdefck;
 mod enumck;
@@ -22,12 +24,14 @@ mod generator_tests;
 mod globaldefck;
 mod implck;
 mod impldefck;
+mod matchck;
 mod program_parser;
 mod readty;
 mod returnck;
 pub mod sem_analysis;
 mod specialize;
 mod stdlib;
+mod superck;
 pub(crate) mod sym;
 #[cfg(test)]
 mod test;
@@ -35,6 +39,7 @@ mod traitdefck;
 pub(crate) mod ty;
 mod type_params;
 mod typeparamck;
+mod unionck;
 mod useck;
 mod valuedefck;
 
@@ -67,21 +72,29 @@ pub fn check(sa: &mut SemAnalysis) -> bool {
     useck::check(sa);
     return_on_error!(sa);
 
+    // expand type aliases
+    aliasck::resolve(sa);
+    return_on_error!(sa);
+
     type_params::check(sa);
     return_on_error!(sa);
 
     // find all trait implementations for classes
     impldefck::check(sa);
 
-    // checks class/struct/trait/enum definitions
+    // checks class/struct/trait/enum/union/alias definitions
     clsdefck::check(sa);
+    superck::check(sa);
     valuedefck::check(sa);
     traitdefck::check(sa);
     enumck::check(sa);
+    unionck::check(sa);
+    aliasck::check(sa);
     return_on_error!(sa);
 
     globaldefck::check(sa);
     constdefck::check(sa);
+    enumck::check_discriminants(sa);
     extensiondefck::check(sa);
     return_on_error!(sa);
 
@@ -89,6 +102,10 @@ pub fn check(sa: &mut SemAnalysis) -> bool {
     fctdefck::check(sa);
     return_on_error!(sa);
 
+    // check overriding methods against the methods of the parent class
+    superck::check_override(sa);
+    return_on_error!(sa);
+
     // check impl methods against trait definition
     implck::check(sa);
     return_on_error!(sa);
@@ -244,6 +261,8 @@ pub fn report_sym_shadow(
         Sym::Value(_) => ErrorMessage::ShadowValue(name),
         Sym::Trait(_) => ErrorMessage::ShadowTrait(name),
         Sym::Enum(_) => ErrorMessage::ShadowEnum(name),
+        Sym::Union(_) => ErrorMessage::ShadowUnion(name),
+        Sym::Alias(_) => ErrorMessage::ShadowAlias(name),
         Sym::Fct(_) => ErrorMessage::ShadowFunction(name),
         Sym::Global(_) => ErrorMessage::ShadowGlobal(name),
         Sym::Const(_) => ErrorMessage::ShadowConst(name),
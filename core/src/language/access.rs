@@ -1,7 +1,7 @@
 use crate::language::sem_analysis::{
-    ClassDefinitionId, ConstDefinitionId, EnumDefinitionId, FctDefinitionId, FctParent, FieldId,
-    GlobalDefinitionId, ModuleDefinitionId, SemAnalysis, TraitDefinitionId, ValueDefinitionFieldId,
-    ValueDefinitionId, Visibility,
+    AliasDefinitionId, ClassDefinitionId, ConstDefinitionId, EnumDefinitionId, FctDefinitionId,
+    FctParent, FieldId, GlobalDefinitionId, ModuleDefinitionId, SemAnalysis, TraitDefinitionId,
+    UnionDefinitionId, ValueDefinitionFieldId, ValueDefinitionId, Visibility,
 };
 use crate::language::sym::Sym;
 
@@ -18,6 +18,8 @@ pub fn sym_accessible_from(sa: &SemAnalysis, sym: Sym, module_id: ModuleDefiniti
         Sym::Module(sym_module_id) => module_accessible_from(sa, sym_module_id, module_id),
         Sym::Value(value_id) => value_accessible_from(sa, value_id, module_id),
         Sym::Trait(trait_id) => trait_accessible_from(sa, trait_id, module_id),
+        Sym::Union(union_id) => union_accessible_from(sa, union_id, module_id),
+        Sym::Alias(alias_id) => alias_accessible_from(sa, alias_id, module_id),
         Sym::TypeParam(_) => unreachable!(),
         Sym::Var(_) => unreachable!(),
     }
@@ -110,6 +112,26 @@ pub fn enum_accessible_from(
     accessible_from(sa, enum_.module_id, enum_.visibility, module_id)
 }
 
+pub fn union_accessible_from(
+    sa: &SemAnalysis,
+    union_id: UnionDefinitionId,
+    module_id: ModuleDefinitionId,
+) -> bool {
+    let union_ = sa.unions[union_id].read();
+
+    accessible_from(sa, union_.module_id, union_.visibility, module_id)
+}
+
+pub fn alias_accessible_from(
+    sa: &SemAnalysis,
+    alias_id: AliasDefinitionId,
+    module_id: ModuleDefinitionId,
+) -> bool {
+    let alias = sa.aliases[alias_id].read();
+
+    accessible_from(sa, alias.module_id, alias.visibility, module_id)
+}
+
 pub fn value_accessible_from(
     sa: &SemAnalysis,
     value_id: ValueDefinitionId,
@@ -0,0 +1,226 @@
+use crate::language::error::msg::ErrorMessage;
+use crate::language::readty::{read_type_unchecked, verify_type};
+use crate::language::sem_analysis::{AliasDefinitionId, SemAnalysis, TypeParamId};
+use crate::language::sym::{ModuleSymTable, Sym};
+use crate::language::ty::SourceType;
+use crate::language::{AllowSelf, TypeParamContext};
+
+pub fn resolve(sa: &SemAnalysis) {
+    for alias in sa.aliases.iter() {
+        let (alias_id, parent) = {
+            let alias = alias.read();
+            (alias.id(), alias.parent)
+        };
+
+        // associated types are resolved together with their trait or impl
+        if parent.is_none() {
+            resolve_alias(sa, alias_id);
+        }
+    }
+}
+
+// Expands the aliased type on first use. Aliases can refer to other aliases
+// declared later in the program, so this also happens on-demand while
+// reading types.
+pub fn resolve_alias(sa: &SemAnalysis, alias_id: AliasDefinitionId) -> SourceType {
+    let alias = sa.aliases.idx(alias_id);
+
+    if let Some(ref ty) = alias.read().ty {
+        return ty.clone();
+    }
+
+    let (module_id, file_id, ast) = {
+        let mut alias = alias.write();
+
+        if alias.resolving {
+            let msg = ErrorMessage::AliasCycle(alias.name(sa));
+            sa.diag.lock().report(alias.file_id, alias.pos, msg);
+            alias.ty = Some(SourceType::Error);
+            return SourceType::Error;
+        }
+
+        alias.resolving = true;
+        (alias.module_id, alias.file_id, alias.ast.clone())
+    };
+
+    let mut symtable = ModuleSymTable::new(sa, module_id);
+    symtable.push_level();
+
+    if let Some(ref type_params) = ast.type_params {
+        for (id, type_param) in type_params.iter().enumerate() {
+            symtable.insert(type_param.name, Sym::TypeParam(TypeParamId(id)));
+        }
+    }
+
+    let ast_ty = ast.ty.as_ref().expect("missing type");
+    let ty = read_type_unchecked(sa, &symtable, file_id, ast_ty);
+    symtable.pop_level();
+
+    let mut alias = alias.write();
+    alias.resolving = false;
+
+    // a cycle was already reported for this alias
+    if alias.ty.is_none() {
+        alias.ty = Some(ty);
+    }
+
+    alias.ty()
+}
+
+pub fn check(sa: &SemAnalysis) {
+    for alias in sa.aliases.iter() {
+        if !alias.read().parent.is_none() {
+            continue;
+        }
+
+        let (alias_id, module_id, file_id, ast, ty) = {
+            let alias = alias.read();
+            (
+                alias.id(),
+                alias.module_id,
+                alias.file_id,
+                alias.ast.clone(),
+                alias.ty(),
+            )
+        };
+
+        let mut symtable = ModuleSymTable::new(sa, module_id);
+        symtable.push_level();
+
+        for (id, name) in alias.read().type_params().names() {
+            symtable.insert(name, Sym::TypeParam(id));
+        }
+
+        verify_type(
+            sa,
+            &symtable,
+            file_id,
+            ast.ty.as_ref().expect("missing type"),
+            ty,
+            TypeParamContext::Alias(alias_id),
+            AllowSelf::No,
+        );
+
+        symtable.pop_level();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::error::msg::ErrorMessage;
+    use crate::language::tests::*;
+
+    #[test]
+    fn alias_definitions() {
+        ok("type Foo = Int32; fun f(x: Foo): Int32 = x;");
+        ok("type Foo = (Int32, Bool); fun f(x: Foo): Int32 = x.0;");
+        ok("type Foo = Bar; type Bar = Int64; fun f(x: Foo): Int64 = x;");
+        ok("class A[T] type Foo[T] = A[T]; fun f(x: Foo[Int32]): A[Int32] = x;");
+        ok("type Foo[T] = (T, T): T; fun f(x: Foo[Int32]): Int32 = x(1i32, 2i32);");
+        ok("alias Foo = Int32; fun f(x: Foo): Int32 = x;");
+
+        err(
+            "type Foo = Unknown;",
+            pos(1, 12),
+            ErrorMessage::UnknownIdentifier("Unknown".into()),
+        );
+        err(
+            "type Foo = Self;",
+            pos(1, 12),
+            ErrorMessage::SelfTypeUnavailable,
+        );
+    }
+
+    #[test]
+    fn alias_generic() {
+        ok("
+            type Map[V] = std::HashMap[String, V];
+            fun f(x: Map[Int32]): std::HashMap[String, Int32] = x;
+        ");
+
+        err(
+            "class A[T] type Foo[T] = A[T]; fun f(x: Foo): Unit {}",
+            pos(1, 41),
+            ErrorMessage::WrongNumberTypeParams(1, 0),
+        );
+
+        err(
+            "trait Foo {} class A[T: Foo] type Bar[T] = A[T];",
+            pos(1, 44),
+            ErrorMessage::TypeNotImplementingTrait("T".into(), "Foo".into()),
+        );
+
+        ok("
+            type Set[T: std::Hash + std::Identity + std::Equals] = std::HashSet[T];
+            fun f(x: Set[Int32]): std::HashSet[Int32] = x;
+        ");
+
+        err(
+            "
+            trait Foo {}
+            class A[T: Foo]
+            type Bar[T: Foo] = A[T];
+            fun f(x: Bar[Int32]): Unit {}
+        ",
+            pos(5, 22),
+            ErrorMessage::TypeNotImplementingTrait("Int32".into(), "Foo".into()),
+        );
+    }
+
+    #[test]
+    fn alias_cycle() {
+        err(
+            "type Foo = Foo;",
+            pos(1, 1),
+            ErrorMessage::AliasCycle("Foo".into()),
+        );
+        err(
+            "type Foo = Bar; type Bar = Array[Foo];",
+            pos(1, 1),
+            ErrorMessage::AliasCycle("Foo".into()),
+        );
+    }
+
+    #[test]
+    fn alias_shows_expanded_type() {
+        err(
+            "type Foo = Int32; fun f(): Foo = \"abc\";",
+            pos(1, 34),
+            ErrorMessage::ReturnType("Int32".into(), "String".into()),
+        );
+        err(
+            "type Map[V] = std::HashMap[String, V]; fun f(x: Map[Int32]): Int64 = x;",
+            pos(1, 70),
+            ErrorMessage::ReturnType("Int64".into(), "HashMap[String, Int32]".into()),
+        );
+    }
+
+    #[test]
+    fn alias_visibility() {
+        ok("
+            mod foo { @pub type Foo = Int32; }
+            fun f(x: foo::Foo): Int32 = x;
+        ");
+
+        err(
+            "
+            mod foo { type Foo = Int32; }
+            fun f(x: foo::Foo): Unit {}
+        ",
+            pos(3, 22),
+            ErrorMessage::NotAccessible("foo::Foo".into()),
+        );
+
+        ok("
+            mod foo { @pub type Foo = Int32; }
+            use foo.Foo;
+            fun f(x: Foo): Int32 = x;
+        ");
+
+        err(
+            "type Foo = Int32; class Foo",
+            pos(1, 19),
+            ErrorMessage::ShadowAlias("Foo".into()),
+        );
+    }
+}
@@ -63,9 +63,44 @@ impl<'x> ClsDefCheck<'x> {
             self.visit_field(field);
         }
 
+        if let Some(ref parent_class) = self.ast.parent_class {
+            self.check_parent_class(parent_class);
+        }
+
         self.sym.pop_level();
     }
 
+    fn check_parent_class(&mut self, parent_class: &ast::Type) {
+        let ty = read_type(
+            self.sa,
+            &self.sym,
+            self.file_id,
+            parent_class,
+            TypeParamContext::Class(self.cls_id),
+            AllowSelf::No,
+        )
+        .unwrap_or(SourceType::Error);
+
+        let is_open = match ty {
+            SourceType::Class(cls_id, _) => self.sa.classes.idx(cls_id).read().is_open,
+            SourceType::Error => return,
+            _ => false,
+        };
+
+        if is_open {
+            let cls = self.sa.classes.idx(self.cls_id);
+            let mut cls = cls.write();
+            cls.parent_class = Some(ty);
+        } else {
+            let name = ty.name(self.sa);
+            let msg = ErrorMessage::UnderivableType(name);
+            self.sa
+                .diag
+                .lock()
+                .report(self.file_id, parent_class.pos(), msg);
+        }
+    }
+
     fn visit_field(&mut self, f: &ast::Field) {
         let ty = read_type(
             self.sa,
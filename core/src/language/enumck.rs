@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::sync::Arc;
 
 use parking_lot::RwLock;
@@ -5,6 +6,7 @@ use parking_lot::RwLock;
 use core_parser::ast;
 
 use crate::language::error::msg::ErrorMessage;
+use crate::language::fctbodyck::constck::ConstCheck;
 use crate::language::sem_analysis::{EnumDefinition, EnumVariant, SemAnalysis, SourceFileId};
 use crate::language::sym::{ModuleSymTable, Sym};
 use crate::language::ty::SourceType;
@@ -113,6 +115,7 @@ impl<'x> EnumCheckVariants<'x> {
                 id: next_variant_id as usize,
                 name: value.name,
                 types: Vec::new(),
+                discriminant: next_variant_id as i32,
             };
 
             self.enum_.variants.push(variant);
@@ -140,6 +143,79 @@ impl<'x> EnumCheckVariants<'x> {
     }
 }
 
+// Discriminants are constant expressions that can refer to consts, so they
+// are evaluated once the types of all consts are known.
+pub fn check_discriminants(sa: &SemAnalysis) {
+    for enum_ in sa.enums.iter() {
+        let (ast, file_id, module_id, is_ordinal_enum) = {
+            let enum_ = enum_.read();
+            (
+                enum_.ast.clone(),
+                enum_.file_id,
+                enum_.module_id,
+                enum_.is_ordinal_enum(),
+            )
+        };
+
+        let first_discriminant = ast
+            .variants
+            .iter()
+            .find_map(|variant| variant.discriminant.as_ref());
+
+        match first_discriminant {
+            None => continue,
+            Some(expr) if !is_ordinal_enum => {
+                let msg = ErrorMessage::EnumDiscriminantNotAllowed;
+                sa.diag.lock().report(file_id, expr.pos(), msg);
+                continue;
+            }
+            Some(_) => {}
+        }
+
+        let mut used = HashSet::new();
+        let mut next_discriminant = Some(0i32);
+
+        for (variant_idx, variant) in ast.variants.iter().enumerate() {
+            let discriminant = if let Some(ref expr) = variant.discriminant {
+                let mut constck = ConstCheck {
+                    sa,
+                    file_id,
+                    symtable: ModuleSymTable::new(sa, module_id),
+                    module_id,
+                    expected_ty: SourceType::Int32,
+                };
+
+                match constck.check_expr(expr) {
+                    (SourceType::Int32, value) => value.to_int() as i32,
+                    (ty, _) => {
+                        if !ty.is_error() {
+                            let msg = ErrorMessage::EnumDiscriminantType(ty.name(sa));
+                            sa.diag.lock().report(file_id, expr.pos(), msg);
+                        }
+
+                        continue;
+                    }
+                }
+            } else if let Some(discriminant) = next_discriminant {
+                discriminant
+            } else {
+                let msg = ErrorMessage::NumberOverflow("Int32".into());
+                sa.diag.lock().report(file_id, variant.pos, msg);
+                continue;
+            };
+
+            if !used.insert(discriminant) {
+                let name = sa.interner.str(variant.name).to_string();
+                let msg = ErrorMessage::DuplicateEnumDiscriminant(name, discriminant);
+                sa.diag.lock().report(file_id, variant.pos, msg);
+            }
+
+            enum_.write().variants[variant_idx].discriminant = discriminant;
+            next_discriminant = discriminant.checked_add(1);
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::language::error::msg::ErrorMessage;
@@ -366,6 +442,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn enum_discriminants() {
+        ok("enum Foo { A = 1, B, C = 10 }");
+        ok("enum Foo { A = X + 1, B = -1 } const X: Int32 = 2;");
+
+        err(
+            "enum Foo { A(Int32) = 1, B }",
+            pos(1, 23),
+            ErrorMessage::EnumDiscriminantNotAllowed,
+        );
+        err(
+            "enum Foo[T] { A = 1, B }",
+            pos(1, 19),
+            ErrorMessage::EnumDiscriminantNotAllowed,
+        );
+        err(
+            "enum Foo { A = true, B }",
+            pos(1, 16),
+            ErrorMessage::EnumDiscriminantType("Bool".into()),
+        );
+        err(
+            "enum Foo { A = 2, B = 1, C }",
+            pos(1, 26),
+            ErrorMessage::DuplicateEnumDiscriminant("C".into(), 2),
+        );
+        err(
+            "enum Foo { A = 2147483647, B }",
+            pos(1, 28),
+            ErrorMessage::NumberOverflow("Int32".into()),
+        );
+    }
+
     #[test]
     fn enum_nested() {
         ok("
@@ -39,9 +39,19 @@ pub enum ErrorMessage {
     ShadowModule(String),
     ShadowEnum(String),
     ShadowEnumVariant(String),
+    ShadowUnion(String),
+    ShadowAlias(String),
     ShadowTypeParam(String),
     InvalidLhsAssignment,
     NoEnumVariant,
+    EnumDiscriminantNotAllowed,
+    EnumDiscriminantType(String),
+    DuplicateEnumDiscriminant(String, i32),
+    NoUnionVariant,
+    AliasCycle(String),
+    UnionVariantClassOrValueExpected(String),
+    UnionVariantDuplicate(String),
+    UnionVariantOpenClass(String),
     EnumArgsIncompatible(String, String, Vec<String>, Vec<String>),
     ValueArgsIncompatible(String, Vec<String>, Vec<String>),
     EnumArgsNoParens(String, String),
@@ -51,22 +61,40 @@ pub enum ErrorMessage {
     IfPatternUnreachable,
     IfBranchTypesIncompatible(String, String),
     IfPatternBindingAlreadyUsed,
+    MatchUncovered(String),
+    MatchUnreachablePattern,
+    MatchBranchTypesIncompatible(String, String),
+    MatchGuardType(String),
+    PatternTypeMismatch(String, String),
+    PatternConstructorExpected,
     EnumExpected,
     EnumVariantExpected,
+    UnionVariantExpected(String, String),
     VarNeedsTypeInfo(String),
     ParamTypesIncompatible(String, Vec<String>, Vec<String>),
     ArgumentNameMismatch(String, Vec<String>, Vec<String>),
+    MissingArgument(String, String),
+    DuplicateArgument(String, String),
+    PositionalArgumentAfterNamed,
+    DefaultValueType(String, String, String),
+    DefaultValueInVariadicFunction,
     LambdaParamTypesIncompatible(Vec<String>, Vec<String>),
+    LambdaParamTypeNotInferred(String),
     WhileCondType(String),
     IfCondType(String),
     ReturnType(String, String),
+    TryOperandExpected(String),
+    TryReturnType(String, String),
     LvalueExpected,
     AssignType(String, String, String),
     AssignField(String, String, String, String),
     UnOpType(String, String),
     BinOpType(String, String, String),
     ConstValueExpected,
+    ConstCycle(String),
+    ConstDivisionByZero,
     OutsideLoop,
+    UnknownLoopLabel(String),
     NoReturnValue,
     MainNotFound,
     WrongMainDefinition,
@@ -75,6 +103,7 @@ pub enum ErrorMessage {
     SuperUnavailable,
     SuperNeedsMethodCall,
     TraitExpected(String),
+    TraitObjectExpected(String),
     NoSuperModule,
     LetMissingInitialization,
     LetReassigned,
@@ -133,8 +162,15 @@ pub enum ErrorMessage {
     MethodNotInTrait(String, String, Vec<String>),
     StaticMethodMissingFromTrait(String, String, Vec<String>),
     MethodMissingFromTrait(String, String, Vec<String>),
+    AssocTypeExists(String),
+    UnknownAssocType(String, String),
+    AssocTypeMissingFromImpl(String, String),
+    MissingAssocTypeBinding(String, String),
+    UnexpectedAssocTypeBinding,
+    AssocTypeNotAllowed,
     WrongNumberTypeParams(usize, usize),
     UnconstrainedTypeParam(String),
+    TypeParamNotInferred(String, String),
     ClassExpected,
     ClassEnumValueExpected,
     ClassExpectedAsTypeParam,
@@ -150,6 +186,7 @@ pub enum ErrorMessage {
     InvalidTestAnnotationUsage,
     GlobalInitializerNotSupported,
     TypeNotUsableInForIn(String),
+    IteratorNotImplemented(String),
     UnknownValueField(String, String),
     UnknownIdentifierInModule(String, String),
     ValueFieldNotInitialized(String, String),
@@ -261,10 +298,37 @@ impl ErrorMessage {
             ErrorMessage::ShadowEnumVariant(ref name) => {
                 format!("can not shadow enum variant `{}`.", name)
             }
+            ErrorMessage::ShadowUnion(ref name) => format!("can not shadow union `{}`.", name),
+            ErrorMessage::ShadowAlias(ref name) => {
+                format!("can not shadow type alias `{}`.", name)
+            }
             ErrorMessage::ShadowTypeParam(ref name) => {
                 format!("can not shadow type param `{}`.", name)
             }
             ErrorMessage::NoEnumVariant => "enum needs at least one variant.".into(),
+            ErrorMessage::EnumDiscriminantNotAllowed => {
+                "discriminants are only allowed in enums without type params and payloads.".into()
+            }
+            ErrorMessage::EnumDiscriminantType(ref ty) => format!(
+                "enum discriminant needs to be of type `Int32` but is `{}`.",
+                ty
+            ),
+            ErrorMessage::DuplicateEnumDiscriminant(ref name, value) => {
+                format!("enum variant `{}` reuses discriminant `{}`.", name, value)
+            }
+            ErrorMessage::NoUnionVariant => "union needs at least one variant.".into(),
+            ErrorMessage::AliasCycle(ref name) => {
+                format!("type alias `{}` is defined in terms of itself.", name)
+            }
+            ErrorMessage::UnionVariantClassOrValueExpected(ref name) => {
+                format!("union variant `{}` needs to be a class or value.", name)
+            }
+            ErrorMessage::UnionVariantDuplicate(ref name) => {
+                format!("type `{}` is already a variant of this union.", name)
+            }
+            ErrorMessage::UnionVariantOpenClass(ref name) => {
+                format!("union variant `{}` can't be an open class.", name)
+            }
             ErrorMessage::EnumArgsIncompatible(ref enum_, ref name, ref def, ref expr) => {
                 let def = def.join(", ");
                 let expr = expr.join(", ");
@@ -294,8 +358,32 @@ impl ErrorMessage {
                 )
             }
             ErrorMessage::IfPatternBindingAlreadyUsed => "var is already used in pattern.".into(),
+            ErrorMessage::MatchUncovered(ref pattern) => {
+                format!("match is not exhaustive, `{}` is not covered.", pattern)
+            }
+            ErrorMessage::MatchUnreachablePattern => "pattern is unreachable.".into(),
+            ErrorMessage::MatchBranchTypesIncompatible(ref type1, ref type2) => format!(
+                "match cases have incompatible types `{}` and `{}`.",
+                type1, type2
+            ),
+            ErrorMessage::MatchGuardType(ref ty) => {
+                format!(
+                    "match guard expects condition of type `bool` but got `{}`.",
+                    ty
+                )
+            }
+            ErrorMessage::PatternTypeMismatch(ref pattern, ref value) => format!(
+                "pattern of type `{}` cannot match value of type `{}`.",
+                pattern, value
+            ),
+            ErrorMessage::PatternConstructorExpected => {
+                "enum variant or value type expected in pattern.".into()
+            }
             ErrorMessage::EnumExpected => format!("enum expected."),
             ErrorMessage::EnumVariantExpected => format!("enum variant expected."),
+            ErrorMessage::UnionVariantExpected(ref union_, ref name) => {
+                format!("`{}` is not a variant of union `{}`.", name, union_)
+            }
             ErrorMessage::IfPatternVariantUncovered => "not all variants are covered.".into(),
             ErrorMessage::IfPatternUnreachable => "variant not reachable.".into(),
             ErrorMessage::VarNeedsTypeInfo(ref name) => format!(
@@ -320,12 +408,34 @@ impl ErrorMessage {
                     name, params, name, args
                 )
             }
+            ErrorMessage::MissingArgument(ref name, ref param) => format!(
+                "call of function `{}` is missing an argument for parameter `{}`.",
+                name, param
+            ),
+            ErrorMessage::DuplicateArgument(ref name, ref param) => format!(
+                "call of function `{}` has more than one argument for parameter `{}`.",
+                name, param
+            ),
+            ErrorMessage::PositionalArgumentAfterNamed => {
+                "positional arguments cannot follow named arguments given out of order.".into()
+            }
+            ErrorMessage::DefaultValueType(ref name, ref def, ref expr) => format!(
+                "cannot use `{}` as default value of parameter `{}` of type `{}`.",
+                expr, name, def
+            ),
+            ErrorMessage::DefaultValueInVariadicFunction => {
+                "parameters of variadic functions cannot have default values.".into()
+            }
             ErrorMessage::LambdaParamTypesIncompatible(ref def, ref expr) => {
                 let def = def.join(", ");
                 let expr = expr.join(", ");
 
                 format!("lambda `({})` cannot be called with `({})`", def, expr)
             }
+            ErrorMessage::LambdaParamTypeNotInferred(ref name) => format!(
+                "cannot infer type of lambda parameter `{}`, add a type annotation.",
+                name
+            ),
             ErrorMessage::WhileCondType(ref ty) => {
                 format!("`while` expects condition of type `bool` but got `{}`.", ty)
             }
@@ -336,6 +446,14 @@ impl ErrorMessage {
                 "`return` expects value of type `{}` but got `{}`.",
                 def, expr
             ),
+            ErrorMessage::TryOperandExpected(ref ty) => format!(
+                "`?` expects value of type `Option` or `Result` but got `{}`.",
+                ty
+            ),
+            ErrorMessage::TryReturnType(ref ty, ref ret) => format!(
+                "`?` cannot return `{}` from function with return type `{}`.",
+                ty, ret
+            ),
             ErrorMessage::LvalueExpected => format!("lvalue expected for assignment"),
             ErrorMessage::ValueExpected => format!("value expected"),
             ErrorMessage::AssignType(ref name, ref def, ref expr) => format!(
@@ -355,7 +473,14 @@ impl ErrorMessage {
                 op, lhs, op, rhs
             ),
             ErrorMessage::ConstValueExpected => "constant value expected".into(),
+            ErrorMessage::ConstCycle(ref name) => {
+                format!("const `{}` depends on its own value.", name)
+            }
+            ErrorMessage::ConstDivisionByZero => "division by zero in constant value.".into(),
             ErrorMessage::OutsideLoop => "statement only allowed inside loops".into(),
+            ErrorMessage::UnknownLoopLabel(ref name) => {
+                format!("no enclosing loop with label `{}`.", name)
+            }
             ErrorMessage::NoReturnValue => {
                 "function does not return a value in all code paths".into()
             }
@@ -372,6 +497,9 @@ impl ErrorMessage {
             ErrorMessage::TraitExpected(ref name) => {
                 format!("`{}` is not a trait.", name)
             }
+            ErrorMessage::TraitObjectExpected(ref name) => {
+                format!("`{}` is not a trait object.", name)
+            }
             ErrorMessage::NoSuperModule => "no super module.".into(),
             ErrorMessage::NotAccessible(ref name) => format!("`{}` is not accessible.", name),
             ErrorMessage::ValueConstructorNotAccessible(ref name) => {
@@ -523,12 +651,37 @@ impl ErrorMessage {
                     trait_name, mtd_name, args
                 )
             }
+            ErrorMessage::AssocTypeExists(ref name) => {
+                format!("associated type `{}` is already defined.", name)
+            }
+            ErrorMessage::UnknownAssocType(ref trait_name, ref name) => format!(
+                "trait `{}` does not define associated type `{}`.",
+                trait_name, name
+            ),
+            ErrorMessage::AssocTypeMissingFromImpl(ref trait_name, ref name) => format!(
+                "trait `{}` defines associated type `{}` but is missing in `impl`.",
+                trait_name, name
+            ),
+            ErrorMessage::MissingAssocTypeBinding(ref trait_name, ref name) => format!(
+                "trait `{}` needs a binding for associated type `{}`.",
+                trait_name, name
+            ),
+            ErrorMessage::UnexpectedAssocTypeBinding => {
+                "associated type bindings are not allowed here.".into()
+            }
+            ErrorMessage::AssocTypeNotAllowed => {
+                "associated types are only allowed in traits and trait impls.".into()
+            }
             ErrorMessage::WrongNumberTypeParams(exp, actual) => {
                 format!("expected {} type parameters but got {}.", exp, actual)
             }
             ErrorMessage::UnconstrainedTypeParam(ref name) => {
                 format!("unconstrained type param `{}`.", name)
             }
+            ErrorMessage::TypeParamNotInferred(ref name, ref callee) => format!(
+                "cannot infer type param `{}` of `{}`, add explicit type arguments.",
+                name, callee
+            ),
             ErrorMessage::ClassExpected => "expected class.".into(),
             ErrorMessage::ClassEnumValueExpected => "expected class, struct or enum.".into(),
             ErrorMessage::ClassExpectedAsTypeParam => "class as type parameter expected.".into(),
@@ -560,6 +713,10 @@ impl ErrorMessage {
                 "type `{}` doesn't implement iterator() or the iterator protocol.",
                 ty
             ),
+            ErrorMessage::IteratorNotImplemented(ref ty) => format!(
+                "type `{}` defines next() but doesn't implement Iterator.",
+                ty
+            ),
             ErrorMessage::UnknownValueField(ref struc, ref field) => {
                 format!("struct `{}` does not have field named `{}`.", struc, field)
             }
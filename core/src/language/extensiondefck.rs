@@ -79,6 +79,11 @@ impl<'x> ExtensionCheck<'x> {
 
                 SourceType::Bool
                 | SourceType::UInt8
+                | SourceType::Int8
+                | SourceType::Int16
+                | SourceType::UInt16
+                | SourceType::UInt32
+                | SourceType::UInt64
                 | SourceType::Char
                 | SourceType::Int32
                 | SourceType::Int64
@@ -155,6 +160,11 @@ impl<'x> ExtensionCheck<'x> {
             SourceType::Enum(enum_id, _) => self.check_in_enum(&fct.ast, enum_id),
             SourceType::Bool
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Char
             | SourceType::Int32
             | SourceType::Int64
@@ -280,9 +290,15 @@ fn discover_type_params(sa: &SemAnalysis, ty: SourceType, used_type_params: &mut
         SourceType::Error
         | SourceType::Unit
         | SourceType::This
+        | SourceType::Assoc(_)
         | SourceType::Any
         | SourceType::Bool
         | SourceType::UInt8
+        | SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt16
+        | SourceType::UInt32
+        | SourceType::UInt64
         | SourceType::Char
         | SourceType::Int32
         | SourceType::Int64
@@ -292,6 +308,7 @@ fn discover_type_params(sa: &SemAnalysis, ty: SourceType, used_type_params: &mut
         | SourceType::Trait(_, _) => {}
         SourceType::Class(_, params)
         | SourceType::Enum(_, params)
+        | SourceType::Union(_, params)
         | SourceType::Value(_, params) => {
             for param in params.iter() {
                 discover_type_params(sa, param, used_type_params);
@@ -1,10 +1,10 @@
 use crate::language::fctbodyck::body::{TypeCheck, VarManager};
-use crate::language::fctbodyck::constck::ConstCheck;
 use crate::language::sem_analysis::{AnalysisData, FctDefinitionId, SemAnalysis};
 use crate::language::sym::ModuleSymTable;
 
 pub mod body;
-mod constck;
+pub mod constck;
+mod infer;
 mod lookup;
 #[cfg(test)]
 mod tests;
@@ -18,18 +18,8 @@ pub fn check(sa: &mut SemAnalysis) {
     }
 
     for const_ in sa.consts.iter() {
-        let mut const_ = const_.write();
-
-        let (_, value) = {
-            let mut constck = ConstCheck {
-                sa,
-                const_: &*const_,
-            };
-
-            constck.check_expr(&const_.expr)
-        };
-
-        const_.value = value;
+        let const_id = const_.read().id();
+        constck::const_value(sa, const_id);
     }
 }
 
@@ -61,12 +51,13 @@ fn check_function(sa: &mut SemAnalysis, id: FctDefinitionId) {
             analysis: &mut analysis,
             ast: &fct.ast,
             symtable: &mut symtable,
-            in_loop: false,
+            loops: Vec::new(),
             self_available: false,
             vars: &mut vars,
             contains_lambda: false,
             outer_context_access_in_function: false,
             outer_context_access_from_lambda: false,
+            inferred_return_types: Vec::new(),
         };
 
         typeck.check();
@@ -18,16 +18,21 @@ use crate::language::access::{
     value_field_accessible_from,
 };
 use crate::language::error::msg::ErrorMessage;
+use crate::language::fctbodyck::infer::{identity_type_params, is_known, CallSignature};
 use crate::language::fctbodyck::lookup::MethodLookup;
+use crate::language::fctdefck;
+use crate::language::matchck;
 use crate::language::sem_analysis::{
-    create_tuple, find_field_in_class, find_methods_in_class, find_methods_in_enum,
-    find_methods_in_value, implements_trait, AnalysisData, CallType, ClassDefinition,
-    ClassDefinitionId, ContextIdx, EnumDefinitionId, EnumVariant, FctDefinition, FctDefinitionId,
-    FctParent, Field, FieldId, ForTypeInfo, IdentType, Intrinsic, ModuleDefinitionId, NestedVarId,
-    PackageDefinitionId, SemAnalysis, SourceFileId, TypeParamDefinition, TypeParamId,
-    ValueDefinition, ValueDefinitionId, Var, VarAccess, VarId, VarLocation, Visibility,
+    all_super_traits, create_tuple, find_field_in_class, find_impl, find_method_with_replace,
+    find_methods_in_class, find_methods_in_enum, find_methods_in_value, impl_matches,
+    implements_trait, parent_class_type, AnalysisData, CallArgument, CallType, Candidate,
+    ClassDefinition, ClassDefinitionId, ContextIdx, EnumDefinitionId, EnumVariant, FctDefinition,
+    FctDefinitionId, FctParent, Field, FieldId, ForTypeInfo, IdentType, Intrinsic,
+    ModuleDefinitionId, NestedVarId, PackageDefinitionId, SemAnalysis, SourceFileId,
+    TypeParamDefinition, TypeParamId, UnionDefinitionId, ValueDefinition, ValueDefinitionFieldId,
+    ValueDefinitionId, Var, VarAccess, VarId, VarLocation, Visibility,
 };
-use crate::language::specialize::replace_type_param;
+use crate::language::specialize::{replace_assoc_types, replace_type_param, specialize_type};
 use crate::language::sym::{ModuleSymTable, Sym};
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::language::typeparamck::{self, ErrorReporting};
@@ -43,12 +48,14 @@ pub struct TypeCheck<'a> {
     pub analysis: &'a mut AnalysisData,
     pub ast: &'a ast::Function,
     pub symtable: &'a mut ModuleSymTable,
-    pub in_loop: bool,
+    pub loops: Vec<Option<Name>>,
     pub self_available: bool,
     pub vars: &'a mut VarManager,
     pub contains_lambda: bool,
     pub outer_context_access_in_function: bool,
     pub outer_context_access_from_lambda: bool,
+    // returned values of a lambda whose return type is inferred from its body
+    pub inferred_return_types: Vec<(Position, SourceType)>,
 }
 
 impl<'a> TypeCheck<'a> {
@@ -193,6 +200,8 @@ impl<'a> TypeCheck<'a> {
         for (id, name) in self.fct.type_params.names() {
             self.symtable.insert(name, Sym::TypeParam(id));
         }
+
+        fctdefck::add_assoc_types(self.sa, self.fct.parent.clone(), self.symtable);
     }
 
     fn add_params(&mut self) {
@@ -415,7 +424,7 @@ impl<'a> TypeCheck<'a> {
         if object_type.is_error() {
             self.symtable.push_level();
             self.check_stmt_let_pattern(&stmt.pattern, SourceType::Error, false);
-            self.visit_stmt(&stmt.block);
+            self.check_loop_body(stmt.label, &stmt.block);
             self.symtable.pop_level();
             return;
         }
@@ -428,7 +437,7 @@ impl<'a> TypeCheck<'a> {
             self.check_stmt_let_pattern(&stmt.pattern, ret_type, false);
             // store fct ids for code generation
             self.analysis.map_fors.insert(stmt.id, for_type_info);
-            self.check_loop_body(&stmt.block);
+            self.check_loop_body(stmt.label, &stmt.block);
             self.symtable.pop_level();
             return;
         }
@@ -448,14 +457,18 @@ impl<'a> TypeCheck<'a> {
                 for_type_info.make_iterator = Some(make_iterator);
                 self.analysis.map_fors.insert(stmt.id, for_type_info);
 
-                self.check_loop_body(&stmt.block);
+                self.check_loop_body(stmt.label, &stmt.block);
                 self.symtable.pop_level();
                 return;
             }
         }
 
         let name = object_type.name_fct(self.sa, self.fct);
-        let msg = ErrorMessage::TypeNotUsableInForIn(name);
+        let msg = if self.type_has_next_method(object_type) {
+            ErrorMessage::IteratorNotImplemented(name)
+        } else {
+            ErrorMessage::TypeNotUsableInForIn(name)
+        };
         self.sa
             .diag
             .lock()
@@ -464,15 +477,42 @@ impl<'a> TypeCheck<'a> {
         // set invalid error type
         self.symtable.push_level();
         self.check_stmt_let_pattern(&stmt.pattern, SourceType::Error, false);
-        self.check_loop_body(&stmt.block);
+        self.check_loop_body(stmt.label, &stmt.block);
         self.symtable.pop_level();
     }
 
-    fn check_loop_body(&mut self, stmt: &ast::Stmt) {
-        let old_in_loop = self.in_loop;
-        self.in_loop = true;
+    fn check_loop_body(&mut self, label: Option<Name>, stmt: &ast::Stmt) {
+        self.loops.push(label);
         self.visit_stmt(&stmt);
-        self.in_loop = old_in_loop;
+        self.loops.pop();
+    }
+
+    fn check_loop_label(&mut self, pos: Position, label: Option<Name>) {
+        if self.loops.is_empty() {
+            self.sa
+                .diag
+                .lock()
+                .report(self.file_id, pos, ErrorMessage::OutsideLoop);
+        } else if let Some(label) = label {
+            if !self.loops.contains(&Some(label)) {
+                let name = self.sa.interner.str(label).to_string();
+                let msg = ErrorMessage::UnknownLoopLabel(name);
+                self.sa.diag.lock().report(self.file_id, pos, msg);
+            }
+        }
+    }
+
+    // Types with a next() method are only iterable when they implement Iterator.
+    fn type_has_next_method(&mut self, object_type: SourceType) -> bool {
+        let next_name = self.sa.interner.intern("next");
+
+        MethodLookup::new(self.sa, self.fct)
+            .no_error_reporting()
+            .method(object_type)
+            .name(next_name)
+            .type_param_defs(&self.fct.type_params)
+            .arg_types(&[])
+            .find()
     }
 
     fn type_supports_make_iterator(
@@ -503,19 +543,73 @@ impl<'a> TypeCheck<'a> {
         object_type: SourceType,
     ) -> Option<(ForTypeInfo, SourceType)> {
         let next_name = self.sa.interner.intern("next");
+        let iterator_trait_id = self.sa.known.traits.iterator();
+
+        let (next_id, next_result_type, next_type_params) =
+            if let SourceType::TypeParam(id) = object_type {
+                // type params can only be iterated when bound by Iterator
+                let trait_ty = self
+                    .fct
+                    .type_params
+                    .bounds_for_type_param(id)
+                    .find(|trait_ty| trait_ty.trait_id() == Some(iterator_trait_id))?;
+
+                let trait_ = self.sa.traits[iterator_trait_id].read();
+                let next_id = trait_.find_method(self.sa, next_name, false)?;
+                let next = self.sa.fcts.idx(next_id);
+                let next = next.read();
+
+                (
+                    next_id,
+                    replace_assoc_types(self.sa, next.return_type.clone(), &trait_ty),
+                    SourceTypeArray::empty(),
+                )
+            } else if object_type.is_trait() {
+                let mut next = MethodLookup::new(self.sa, self.fct)
+                    .no_error_reporting()
+                    .method(object_type.clone())
+                    .name(next_name)
+                    .type_param_defs(&self.fct.type_params)
+                    .arg_types(&[]);
+
+                if !next.find() {
+                    return None;
+                }
 
-        let mut next = MethodLookup::new(self.sa, self.fct)
-            .no_error_reporting()
-            .method(object_type.clone())
-            .name(next_name)
-            .type_param_defs(&self.fct.type_params)
-            .arg_types(&[]);
-
-        if !next.find() {
-            return None;
-        }
-
-        let next_result_type = next.found_ret().unwrap();
+                (
+                    next.found_fct_id().expect("fct_id missing"),
+                    next.found_ret().unwrap(),
+                    SourceTypeArray::empty(),
+                )
+            } else if object_type.is_self() {
+                return None;
+            } else {
+                // concrete types are iterated through their impl of Iterator,
+                // the element type is its binding for Item
+                let impl_id = find_impl(
+                    self.sa,
+                    object_type.clone(),
+                    &self.fct.type_params,
+                    SourceType::new_trait(iterator_trait_id),
+                )?;
+                let impl_type_params =
+                    impl_matches(self.sa, object_type.clone(), &self.fct.type_params, impl_id)?;
+
+                let impl_ = self.sa.impls[impl_id].read();
+                let trait_ty = specialize_type(self.sa, impl_.trait_ty(), &impl_type_params);
+
+                let trait_ = self.sa.traits[iterator_trait_id].read();
+                let trait_next_id = trait_.find_method(self.sa, next_name, false)?;
+                let next_id = impl_.impl_for.get(&trait_next_id).cloned()?;
+                let next = self.sa.fcts.idx(trait_next_id);
+                let next = next.read();
+
+                (
+                    next_id,
+                    replace_assoc_types(self.sa, next.return_type.clone(), &trait_ty),
+                    impl_type_params,
+                )
+            };
 
         let value_type = if let SourceType::Enum(enum_id, type_params) = next_result_type.clone() {
             if enum_id == self.sa.known.enums.option() {
@@ -531,7 +625,8 @@ impl<'a> TypeCheck<'a> {
         Some((
             ForTypeInfo {
                 make_iterator: None,
-                next: next.found_fct_id().expect("fct_id missing"),
+                next: next_id,
+                next_type_params,
                 iterator_type: object_type,
                 next_type: next_result_type,
                 value_type: value_type.clone(),
@@ -549,7 +644,7 @@ impl<'a> TypeCheck<'a> {
             self.sa.diag.lock().report(self.file_id, stmt.pos, msg);
         }
 
-        self.check_loop_body(&stmt.block);
+        self.check_loop_body(stmt.label, &stmt.block);
     }
 
     fn check_stmt_return(&mut self, s: &ast::StmtReturnType) {
@@ -567,6 +662,11 @@ impl<'a> TypeCheck<'a> {
     fn check_fct_return_type(&mut self, pos: Position, expr_type: SourceType) {
         let fct_type = self.fct.return_type.clone();
 
+        if fct_type == SourceType::Any {
+            self.inferred_return_types.push((pos, expr_type));
+            return;
+        }
+
         if !expr_type.is_error() && !fct_type.allows(self.sa, expr_type.clone()) {
             let fct_type = fct_type.name_fct(self.sa, self.fct);
             let expr_type = expr_type.name_fct(self.sa, self.fct);
@@ -580,7 +680,7 @@ impl<'a> TypeCheck<'a> {
     fn check_expr_block(
         &mut self,
         block: &ast::ExprBlockType,
-        _expected_ty: SourceType,
+        expected_ty: SourceType,
     ) -> SourceType {
         self.symtable.push_level();
 
@@ -589,7 +689,7 @@ impl<'a> TypeCheck<'a> {
         }
 
         let ty = if let Some(ref expr) = block.expr {
-            self.check_expr(expr, SourceType::Any)
+            self.check_expr(expr, expected_ty)
         } else {
             SourceType::Unit
         };
@@ -603,7 +703,7 @@ impl<'a> TypeCheck<'a> {
     fn check_expr_tuple(
         &mut self,
         tuple: &ast::ExprTupleType,
-        _expected_ty: SourceType,
+        expected_ty: SourceType,
     ) -> SourceType {
         let mut subtypes = Vec::new();
 
@@ -612,8 +712,15 @@ impl<'a> TypeCheck<'a> {
             return SourceType::Unit;
         }
 
-        for value in &tuple.values {
-            let subtype = self.check_expr(value, SourceType::Any);
+        let expected_types = match expected_ty {
+            SourceType::Tuple(ref subtypes) if subtypes.len() == tuple.values.len() => {
+                subtypes.types().to_vec()
+            }
+            _ => vec![SourceType::Any; tuple.values.len()],
+        };
+
+        for (value, expected_ty) in tuple.values.iter().zip(expected_types) {
+            let subtype = self.check_expr(value, expected_ty);
             subtypes.push(subtype);
         }
 
@@ -626,14 +733,130 @@ impl<'a> TypeCheck<'a> {
     fn check_expr_paren(
         &mut self,
         paren: &ast::ExprParenType,
-        _expected_ty: SourceType,
+        expected_ty: SourceType,
     ) -> SourceType {
-        let ty = self.check_expr(&paren.expr, SourceType::Any);
+        let ty = self.check_expr(&paren.expr, expected_ty);
         self.analysis.set_ty(paren.id, ty.clone());
 
         ty
     }
 
+    // `expr?` unwraps `Some`/`Ok` and returns `None`/`Err` from the function,
+    // so the function needs to return `Option` or a `Result` with the same error type.
+    fn check_expr_try(&mut self, e: &ast::ExprTryType, _expected_ty: SourceType) -> SourceType {
+        let object_type = self.check_expr(&e.expr, SourceType::Any);
+
+        if object_type.is_error() {
+            self.analysis.set_ty(e.id, SourceType::Error);
+            return SourceType::Error;
+        }
+
+        let option_id = self.sa.known.enums.option();
+        let result_id = self.sa.known.enums.result();
+
+        let enum_id = match object_type.enum_id() {
+            Some(enum_id) if enum_id == option_id || enum_id == result_id => enum_id,
+            _ => {
+                let ty = object_type.name_fct(self.sa, self.fct);
+                let msg = ErrorMessage::TryOperandExpected(ty);
+                self.sa.diag.lock().report(self.file_id, e.pos, msg);
+
+                self.analysis.set_ty(e.id, SourceType::Error);
+                return SourceType::Error;
+            }
+        };
+
+        let type_params = object_type.type_params();
+        let return_type = self.fct.return_type.clone();
+
+        let compatible = match return_type {
+            SourceType::Enum(ret_enum_id, ref ret_type_params) if ret_enum_id == enum_id => {
+                enum_id == option_id || ret_type_params[1] == type_params[1]
+            }
+            SourceType::Error => true,
+            _ => false,
+        };
+
+        if !compatible {
+            let ty = object_type.name_fct(self.sa, self.fct);
+            let ret = return_type.name_fct(self.sa, self.fct);
+            let msg = ErrorMessage::TryReturnType(ty, ret);
+            self.sa.diag.lock().report(self.file_id, e.pos, msg);
+        }
+
+        let ty = type_params[0].clone();
+        self.analysis.set_ty(e.id, ty.clone());
+
+        ty
+    }
+
+    fn check_if_union_pattern(
+        &mut self,
+        case: &ast::IfCaseType,
+        pattern: &ast::IfPattern,
+        union_id: UnionDefinitionId,
+        type_params: &SourceTypeArray,
+        used_variants: &mut FixedBitSet,
+    ) {
+        let union_ = self.sa.unions.idx(union_id);
+        let union_ = union_.read();
+
+        let variant_idx = match self.read_path(&pattern.path) {
+            Ok(Sym::Class(cls_id)) => union_.variant_idx_for_class(cls_id),
+            Ok(Sym::Value(value_id)) => union_.variant_idx_for_value(value_id),
+            Ok(_) => None,
+            Err(()) => return,
+        };
+
+        let variant_idx = if let Some(variant_idx) = variant_idx {
+            variant_idx
+        } else {
+            let union_name = union_.name_with_params(self.sa, type_params);
+            let names = pattern
+                .path
+                .names
+                .iter()
+                .map(|&name| self.sa.interner.str(name).to_string())
+                .collect::<Vec<_>>();
+            let msg = ErrorMessage::UnionVariantExpected(union_name, names.join("::"));
+            self.sa.diag.lock().report(self.file_id, pattern.pos, msg);
+            return;
+        };
+
+        if used_variants.contains(variant_idx) {
+            let msg = ErrorMessage::IfPatternUnreachable;
+            self.sa.diag.lock().report(self.file_id, case.pos, msg);
+        }
+
+        used_variants.insert(variant_idx);
+        self.analysis.map_idents.insert(
+            pattern.id,
+            IdentType::UnionVariant(union_id, type_params.clone(), variant_idx),
+        );
+
+        // A union pattern optionally binds the matched object itself: `is Foo(foo)`.
+        if let Some(ref params) = pattern.params {
+            if params.is_empty() {
+                let msg = ErrorMessage::IfPatternNoParens;
+                self.sa.diag.lock().report(self.file_id, case.pos, msg);
+            } else if params.len() != 1 {
+                let msg = ErrorMessage::IfPatternWrongNumberOfParams(params.len(), 1);
+                self.sa.diag.lock().report(self.file_id, case.pos, msg);
+            }
+
+            if let Some(param) = params.first() {
+                if let Some(name) = param.name {
+                    let ty = union_.variant_type(self.sa, type_params, variant_idx);
+                    let var_id = self.vars.add_var(name, ty, false);
+                    self.add_local(var_id, param.pos);
+                    self.analysis
+                        .map_vars
+                        .insert(param.id, self.vars.local_var_id(var_id));
+                }
+            }
+        }
+    }
+
     fn check_expr_if(&mut self, node: &ast::ExprIfType, expected_ty: SourceType) -> SourceType {
         self.check_stmt_let(&node.cond);
         let cond = node.cond.expr.as_ref().unwrap();
@@ -641,16 +864,20 @@ impl<'a> TypeCheck<'a> {
         let mut result_type = SourceType::Error;
 
         let expr_enum_id = expr_type.enum_id();
+        let expr_union_id = expr_type.union_id();
         let expr_type_params = expr_type.type_params();
 
-        let enum_variants = if let Some(expr_enum_id) = expr_enum_id {
+        let variants = if let Some(expr_enum_id) = expr_enum_id {
             let enum_ = self.sa.enums[expr_enum_id].read();
             enum_.variants.len()
+        } else if let Some(expr_union_id) = expr_union_id {
+            let union_ = self.sa.unions[expr_union_id].read();
+            union_.variants.len()
         } else {
             0
         };
 
-        let mut used_variants = FixedBitSet::with_capacity(enum_variants);
+        let mut used_variants = FixedBitSet::with_capacity(variants);
         let mut non_variant_cases = false;
 
         for case in &node.cases {
@@ -667,6 +894,17 @@ impl<'a> TypeCheck<'a> {
                     self.check_if_condition_is_bool(cont_type, continuation);
                     non_variant_cases = true;
                 }
+                ast::IfCaseData::Patterns(patterns) if expr_union_id.is_some() => {
+                    debug_assert_eq!(patterns.len(), 1);
+                    let pattern = patterns.first().expect("no pattern");
+                    self.check_if_union_pattern(
+                        case,
+                        pattern,
+                        expr_union_id.unwrap(),
+                        &expr_type_params,
+                        &mut used_variants,
+                    );
+                }
                 ast::IfCaseData::Patterns(patterns) => {
                     debug_assert_eq!(patterns.len(), 1);
                     if !expr_type.is_enum() {
@@ -837,6 +1075,327 @@ impl<'a> TypeCheck<'a> {
         }
     }
 
+    fn check_expr_match(
+        &mut self,
+        node: &ast::ExprMatchType,
+        expected_ty: SourceType,
+    ) -> SourceType {
+        let expr_type = self.check_expr(&node.expr, SourceType::Any);
+        let mut patterns_valid = !expr_type.is_error();
+        let mut result_type = if node.cases.is_empty() {
+            SourceType::Unit
+        } else {
+            SourceType::Error
+        };
+
+        for case in &node.cases {
+            self.symtable.push_level();
+
+            let mut used_idents = HashSet::new();
+            patterns_valid &=
+                self.check_match_pattern(&case.pattern, expr_type.clone(), &mut used_idents);
+
+            if let Some(ref guard) = case.guard {
+                let guard_type = self.check_expr(guard, SourceType::Bool);
+
+                if !guard_type.is_bool() && !guard_type.is_error() {
+                    let guard_type = guard_type.name_fct(self.sa, self.fct);
+                    let msg = ErrorMessage::MatchGuardType(guard_type);
+                    self.sa.diag.lock().report(self.file_id, guard.pos(), msg);
+                }
+            }
+
+            let case_ty = self.check_expr(&case.value, expected_ty.clone());
+
+            if result_type.is_error() {
+                result_type = case_ty;
+            } else if case_ty.is_error() {
+                // ignore this case
+            } else if !result_type.allows(self.sa, case_ty.clone()) {
+                let result_type_name = result_type.name_fct(self.sa, self.fct);
+                let case_ty_name = case_ty.name_fct(self.sa, self.fct);
+                let msg =
+                    ErrorMessage::MatchBranchTypesIncompatible(result_type_name, case_ty_name);
+                self.sa
+                    .diag
+                    .lock()
+                    .report(self.file_id, case.value.pos(), msg);
+            }
+
+            self.symtable.pop_level();
+        }
+
+        // Patterns with errors can't be analyzed for exhaustiveness.
+        if patterns_valid {
+            matchck::check(self.sa, self.file_id, self.analysis, node, expr_type);
+        }
+
+        self.analysis.set_ty(node.id, result_type.clone());
+
+        result_type
+    }
+
+    // Checks the pattern against the type of the matched value and defines
+    // all bindings. Returns false if the pattern is invalid.
+    fn check_match_pattern(
+        &mut self,
+        pattern: &ast::MatchPattern,
+        ty: SourceType,
+        used_idents: &mut HashSet<Name>,
+    ) -> bool {
+        self.analysis.set_ty(pattern.id(), ty.clone());
+
+        match pattern {
+            ast::MatchPattern::Underscore(_) => true,
+
+            ast::MatchPattern::Ident(ref ident) => {
+                if let Some(Sym::EnumVariant(enum_id, variant_idx)) = self.symtable.get(ident.name)
+                {
+                    return self.check_match_pattern_variant(
+                        pattern,
+                        enum_id,
+                        variant_idx,
+                        None,
+                        ty,
+                        used_idents,
+                    );
+                }
+
+                if !used_idents.insert(ident.name) {
+                    let msg = ErrorMessage::IfPatternBindingAlreadyUsed;
+                    self.sa.diag.lock().report(self.file_id, ident.pos, msg);
+                }
+
+                let var_id = self.vars.add_var(ident.name, ty, false);
+                self.add_local(var_id, ident.pos);
+                self.analysis
+                    .map_vars
+                    .insert(ident.id, self.vars.local_var_id(var_id));
+
+                true
+            }
+
+            ast::MatchPattern::Lit(ref lit) => {
+                let lit_ty = self.check_expr(&lit.expr, ty.clone());
+
+                if ty.is_error() || lit_ty.is_error() {
+                    false
+                } else if lit_ty != ty {
+                    let lit_ty = lit_ty.name_fct(self.sa, self.fct);
+                    let ty = ty.name_fct(self.sa, self.fct);
+                    let msg = ErrorMessage::PatternTypeMismatch(lit_ty, ty);
+                    self.sa.diag.lock().report(self.file_id, lit.pos, msg);
+                    false
+                } else {
+                    true
+                }
+            }
+
+            ast::MatchPattern::Tuple(ref tuple) => {
+                let subtypes = if tuple.parts.is_empty() {
+                    if ty.is_unit() || ty.is_error() {
+                        return !ty.is_error();
+                    }
+
+                    None
+                } else if ty.is_tuple() {
+                    Some(ty.tuple_subtypes())
+                } else {
+                    None
+                };
+
+                let subtypes = match subtypes {
+                    Some(subtypes) => subtypes,
+                    None => {
+                        if !ty.is_error() {
+                            let ty = ty.name_fct(self.sa, self.fct);
+                            let msg = ErrorMessage::LetPatternExpectedTuple(ty);
+                            self.sa.diag.lock().report(self.file_id, tuple.pos, msg);
+                        }
+
+                        self.check_match_pattern_params(Some(&tuple.parts), &[], used_idents);
+                        return false;
+                    }
+                };
+
+                if subtypes.len() != tuple.parts.len() {
+                    let ty = ty.name_fct(self.sa, self.fct);
+                    let msg = ErrorMessage::LetPatternExpectedTupleWithLength(
+                        ty,
+                        subtypes.len(),
+                        tuple.parts.len(),
+                    );
+                    self.sa.diag.lock().report(self.file_id, tuple.pos, msg);
+                    self.check_match_pattern_params(Some(&tuple.parts), &[], used_idents);
+                    return false;
+                }
+
+                self.check_match_pattern_params(Some(&tuple.parts), subtypes.types(), used_idents)
+            }
+
+            ast::MatchPattern::Constructor(ref ctor) => {
+                let params = ctor.params.as_ref();
+
+                match self.read_path(&ctor.path) {
+                    Ok(Sym::EnumVariant(enum_id, variant_idx)) => self.check_match_pattern_variant(
+                        pattern,
+                        enum_id,
+                        variant_idx,
+                        params,
+                        ty,
+                        used_idents,
+                    ),
+
+                    Ok(Sym::Value(value_id)) => {
+                        self.check_match_pattern_value(ctor, value_id, ty, used_idents)
+                    }
+
+                    Ok(_) => {
+                        let msg = ErrorMessage::PatternConstructorExpected;
+                        self.sa.diag.lock().report(self.file_id, ctor.pos, msg);
+                        self.check_match_pattern_params(params, &[], used_idents);
+                        false
+                    }
+
+                    Err(()) => {
+                        self.check_match_pattern_params(params, &[], used_idents);
+                        false
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_match_pattern_variant(
+        &mut self,
+        pattern: &ast::MatchPattern,
+        enum_id: EnumDefinitionId,
+        variant_idx: usize,
+        params: Option<&Vec<ast::MatchPattern>>,
+        ty: SourceType,
+        used_idents: &mut HashSet<Name>,
+    ) -> bool {
+        let pos = pattern.pos();
+        let enum_ = self.sa.enums.idx(enum_id);
+        let enum_ = enum_.read();
+
+        if ty.enum_id() != Some(enum_id) {
+            if !ty.is_error() {
+                let ty = ty.name_fct(self.sa, self.fct);
+                let msg = ErrorMessage::PatternTypeMismatch(enum_.name(self.sa), ty);
+                self.sa.diag.lock().report(self.file_id, pos, msg);
+            }
+
+            self.check_match_pattern_params(params, &[], used_idents);
+            return false;
+        }
+
+        let type_params = ty.type_params();
+        self.analysis.map_idents.insert(
+            pattern.id(),
+            IdentType::EnumValue(enum_id, type_params.clone(), variant_idx),
+        );
+
+        let subtypes = enum_.variants[variant_idx]
+            .types
+            .iter()
+            .map(|ty| replace_type_param(self.sa, ty.clone(), &type_params, None))
+            .collect::<Vec<_>>();
+
+        self.check_match_pattern_arity(pos, params, subtypes.len())
+            & self.check_match_pattern_params(params, &subtypes, used_idents)
+    }
+
+    fn check_match_pattern_value(
+        &mut self,
+        ctor: &ast::MatchPatternConstructorType,
+        value_id: ValueDefinitionId,
+        ty: SourceType,
+        used_idents: &mut HashSet<Name>,
+    ) -> bool {
+        let params = ctor.params.as_ref();
+        let value = self.sa.values.idx(value_id);
+        let value = value.read();
+
+        if ty.value_id() != Some(value_id) || !ty.is_value() {
+            if !ty.is_error() {
+                let ty = ty.name_fct(self.sa, self.fct);
+                let msg = ErrorMessage::PatternTypeMismatch(value.name(self.sa), ty);
+                self.sa.diag.lock().report(self.file_id, ctor.pos, msg);
+            }
+
+            self.check_match_pattern_params(params, &[], used_idents);
+            return false;
+        }
+
+        self.analysis
+            .map_idents
+            .insert(ctor.id, IdentType::Value(value_id));
+
+        let type_params = ty.type_params();
+        let mut subtypes = Vec::with_capacity(value.fields.len());
+
+        for (idx, field) in value.fields.iter().enumerate() {
+            let field_id = ValueDefinitionFieldId(idx);
+
+            if !value_field_accessible_from(self.sa, value_id, field_id, self.module_id) {
+                let name = self.sa.interner.str(field.name).to_string();
+                let msg = ErrorMessage::NotAccessible(name);
+                self.sa.diag.lock().report(self.file_id, ctor.pos, msg);
+            }
+
+            subtypes.push(replace_type_param(
+                self.sa,
+                field.ty.clone(),
+                &type_params,
+                None,
+            ));
+        }
+
+        self.check_match_pattern_arity(ctor.pos, params, subtypes.len())
+            & self.check_match_pattern_params(params, &subtypes, used_idents)
+    }
+
+    fn check_match_pattern_arity(
+        &mut self,
+        pos: Position,
+        params: Option<&Vec<ast::MatchPattern>>,
+        expected_params: usize,
+    ) -> bool {
+        let given_params = params.map_or(0, |params| params.len());
+
+        if given_params == 0 && params.is_some() {
+            let msg = ErrorMessage::IfPatternNoParens;
+            self.sa.diag.lock().report(self.file_id, pos, msg);
+        }
+
+        if given_params != expected_params {
+            let msg = ErrorMessage::IfPatternWrongNumberOfParams(given_params, expected_params);
+            self.sa.diag.lock().report(self.file_id, pos, msg);
+            return false;
+        }
+
+        true
+    }
+
+    // Missing subtypes are treated as errors, this still defines all
+    // bindings in invalid patterns.
+    fn check_match_pattern_params(
+        &mut self,
+        params: Option<&Vec<ast::MatchPattern>>,
+        subtypes: &[SourceType],
+        used_idents: &mut HashSet<Name>,
+    ) -> bool {
+        let mut valid = true;
+
+        for (idx, param) in params.into_iter().flatten().enumerate() {
+            let ty = subtypes.get(idx).cloned().unwrap_or(SourceType::Error);
+            valid &= self.check_match_pattern(param, ty, used_idents);
+        }
+
+        valid
+    }
+
     fn merge_branch_types(
         &mut self,
         expr: &ast::ExprIfType,
@@ -1418,62 +1977,381 @@ impl<'a> TypeCheck<'a> {
             let rhs_type = rhs_type.name_fct(self.sa, self.fct);
             let msg = ErrorMessage::BinOpType(op, lhs_type, rhs_type);
 
-            self.sa.diag.lock().report(self.file_id, e.pos, msg);
+            self.sa.diag.lock().report(self.file_id, e.pos, msg);
+        }
+    }
+
+    fn check_expr_call(&mut self, e: &ast::ExprCallType, expected_ty: SourceType) -> SourceType {
+        let (callee, type_params) = if let Some(expr_type_params) = e.callee.to_type_param() {
+            let type_params: Vec<SourceType> = expr_type_params
+                .args
+                .iter()
+                .map(|p| self.read_type(p))
+                .collect();
+            let type_params: SourceTypeArray = SourceTypeArray::with(type_params);
+            (&expr_type_params.callee, type_params)
+        } else {
+            (&e.callee, SourceTypeArray::empty())
+        };
+
+        if let Some(expr_dot) = callee.to_dot() {
+            // The object is checked first, the method's parameter types are
+            // expected for the arguments then.
+            let object_type = self.check_expr_object(&expr_dot.lhs);
+
+            let method_name = match expr_dot.rhs.to_ident() {
+                Some(ident) => ident.name,
+
+                None => {
+                    let msg = ErrorMessage::NameExpected;
+                    self.sa.diag.lock().report(self.file_id, e.pos, msg);
+
+                    self.analysis.set_ty(e.id, SourceType::Error);
+                    return SourceType::Error;
+                }
+            };
+
+            let signature = self.method_call_signature(&object_type, method_name, &type_params);
+            let arg_types = self.check_expr_call_args(e, signature, &expected_ty);
+
+            return self.check_expr_call_method(
+                e,
+                expected_ty,
+                object_type,
+                method_name,
+                type_params,
+                &arg_types,
+            );
+        }
+
+        let signature = self.call_signature(callee, &type_params);
+        let arg_types = self.check_expr_call_args(e, signature, &expected_ty);
+
+        if let Some(expr_ident) = callee.to_ident() {
+            let sym = self.symtable.get(expr_ident.name);
+
+            self.check_expr_call_sym(e, expected_ty, callee, sym, type_params, &arg_types)
+        } else if let Some(_expr_path) = callee.to_path() {
+            self.check_expr_call_path(e, expected_ty, callee, type_params, &arg_types, false)
+        } else {
+            if !type_params.is_empty() {
+                let msg = ErrorMessage::NoTypeParamsExpected;
+                self.sa
+                    .diag
+                    .lock()
+                    .report(self.file_id, e.callee.pos(), msg);
+            }
+
+            let expr_type = self.check_expr(callee, SourceType::Any);
+            self.check_expr_call_expr(e, expr_type, &arg_types)
+        }
+    }
+
+    fn check_expr_call_args(
+        &mut self,
+        e: &ast::ExprCallType,
+        signature: Option<CallSignature>,
+        expected_ty: &SourceType,
+    ) -> Vec<SourceType> {
+        let expected_arg_types = match signature {
+            Some(signature) => signature.expected_arg_types(expected_ty, &e.arg_names()),
+            None => vec![SourceType::Any; e.args.len()],
+        };
+
+        e.args
+            .iter()
+            .zip(expected_arg_types)
+            .map(|(arg, expected_ty)| self.check_expr(&arg.expr, expected_ty))
+            .collect()
+    }
+
+    // Looks up the callee of a call without reporting any errors, those are
+    // reported once the call itself is checked.
+    fn call_signature(
+        &self,
+        callee: &ast::Expr,
+        type_params: &SourceTypeArray,
+    ) -> Option<CallSignature> {
+        if let Some(expr_ident) = callee.to_ident() {
+            let sym = self.symtable.get(expr_ident.name);
+            return self.sym_call_signature(sym, type_params);
+        }
+
+        let expr_path = callee.to_path()?;
+        let name = expr_path.rhs.to_ident()?.name;
+
+        match self.lookup_path_expr(&expr_path.lhs)? {
+            Sym::Module(module_id) => {
+                let sym = {
+                    let module = &self.sa.modules[module_id].read();
+                    let table = module.table.read();
+
+                    table.get(name)
+                };
+
+                self.sym_call_signature(sym, type_params)
+            }
+
+            Sym::Enum(enum_id) => {
+                let variant_idx = {
+                    let enum_ = self.sa.enums[enum_id].read();
+                    enum_.name_to_value.get(&name).cloned()
+                };
+
+                match variant_idx {
+                    Some(variant_idx) => self.sym_call_signature(
+                        Some(Sym::EnumVariant(enum_id, variant_idx as usize)),
+                        type_params,
+                    ),
+                    None => self.static_method_signature(Sym::Enum(enum_id), name, type_params),
+                }
+            }
+
+            sym => self.static_method_signature(sym, name, type_params),
+        }
+    }
+
+    fn method_call_signature(
+        &self,
+        object_type: &SourceType,
+        name: Name,
+        type_params: &SourceTypeArray,
+    ) -> Option<CallSignature> {
+        let candidate = self.find_method_candidate(object_type, name)?;
+        let fct = self.sa.fcts.idx(candidate.fct_id);
+        let fct = fct.read();
+
+        let mut signature = CallSignature::fct(&fct);
+        signature
+            .type_args()
+            .bind(0, &candidate.container_type_params);
+
+        if !type_params.is_empty() {
+            if fct.type_params.len() != fct.container_type_params + type_params.len() {
+                return None;
+            }
+
+            signature
+                .type_args()
+                .bind(fct.container_type_params, type_params);
+        }
+
+        Some(signature)
+    }
+
+    // Same as the method lookup, but without reporting errors.
+    fn find_method_candidate(&self, object_type: &SourceType, name: Name) -> Option<Candidate> {
+        let object_type = object_type.clone();
+        let type_param_defs = &self.fct.type_params;
+
+        let mut candidates = if object_type.is_enum() {
+            find_methods_in_enum(self.sa, object_type, type_param_defs, name, false, false)
+        } else if object_type.is_value() || object_type.is_primitive() {
+            find_methods_in_value(self.sa, object_type, type_param_defs, name, false, false)
+        } else if object_type.is_cls() {
+            find_methods_in_class(self.sa, object_type, type_param_defs, name, false, false)
+        } else {
+            Vec::new()
+        };
+
+        if candidates.len() == 1 {
+            candidates.pop()
+        } else {
+            None
+        }
+    }
+
+    fn lookup_path_expr(&self, expr: &ast::Expr) -> Option<Sym> {
+        if let Some(expr_ident) = expr.to_ident() {
+            return self.symtable.get(expr_ident.name);
+        }
+
+        let expr_path = expr.to_path()?;
+        let name = expr_path.rhs.to_ident()?.name;
+
+        match self.lookup_path_expr(&expr_path.lhs)? {
+            Sym::Module(module_id) => {
+                let module = &self.sa.modules[module_id].read();
+                let table = module.table.read();
+
+                table.get(name)
+            }
+
+            _ => None,
+        }
+    }
+
+    fn sym_call_signature(
+        &self,
+        sym: Option<Sym>,
+        type_params: &SourceTypeArray,
+    ) -> Option<CallSignature> {
+        let mut signature = match sym? {
+            Sym::Fct(fct_id) => {
+                let fct = self.sa.fcts.idx(fct_id);
+                let fct = fct.read();
+                CallSignature::fct(&fct)
+            }
+
+            Sym::Class(cls_id) => self.class_signature(cls_id),
+            Sym::Value(value_id) => self.value_signature(value_id),
+
+            Sym::EnumVariant(enum_id, variant_idx) => {
+                self.enum_variant_signature(enum_id, variant_idx)
+            }
+
+            _ => return None,
+        };
+
+        if !type_params.is_empty() {
+            if signature.type_args().len() != type_params.len() {
+                return None;
+            }
+
+            signature.type_args().bind(0, type_params);
+        }
+
+        Some(signature)
+    }
+
+    fn class_signature(&self, cls_id: ClassDefinitionId) -> CallSignature {
+        let cls = self.sa.classes.idx(cls_id);
+        let cls = cls.read();
+        let type_params = cls.type_params().len();
+
+        CallSignature::new(
+            cls.fields.iter().map(|field| field.ty.clone()).collect(),
+            SourceType::Class(cls_id, identity_type_params(type_params)),
+            type_params,
+        )
+    }
+
+    fn value_signature(&self, value_id: ValueDefinitionId) -> CallSignature {
+        let value = self.sa.values.idx(value_id);
+        let value = value.read();
+        let type_params = value.type_params().len();
+
+        CallSignature::new(
+            value.fields.iter().map(|field| field.ty.clone()).collect(),
+            SourceType::Value(value_id, identity_type_params(type_params)),
+            type_params,
+        )
+    }
+
+    fn enum_variant_signature(
+        &self,
+        enum_id: EnumDefinitionId,
+        variant_idx: usize,
+    ) -> CallSignature {
+        let enum_ = self.sa.enums[enum_id].read();
+        let type_params = enum_.type_params().len();
+
+        CallSignature::new(
+            enum_.variants[variant_idx].types.clone(),
+            SourceType::Enum(enum_id, identity_type_params(type_params)),
+            type_params,
+        )
+    }
+
+    // Signature of a static method called without type params for the container,
+    // the type params of the impl then still need to be inferred.
+    fn static_method_signature(
+        &self,
+        sym: Sym,
+        name: Name,
+        type_params: &SourceTypeArray,
+    ) -> Option<CallSignature> {
+        let (fct_id, _) = self.find_static_method_candidate(sym, name, false)?;
+        let fct = self.sa.fcts.idx(fct_id);
+        let fct = fct.read();
+
+        let mut signature = CallSignature::fct(&fct);
+
+        if !type_params.is_empty() {
+            if fct.type_params.len() != fct.container_type_params + type_params.len() {
+                return None;
+            }
+
+            signature
+                .type_args()
+                .bind(fct.container_type_params, type_params);
         }
+
+        Some(signature)
     }
 
-    fn check_expr_call(&mut self, e: &ast::ExprCallType, expected_ty: SourceType) -> SourceType {
-        let (callee, type_params) = if let Some(expr_type_params) = e.callee.to_type_param() {
-            let type_params: Vec<SourceType> = expr_type_params
-                .args
-                .iter()
-                .map(|p| self.read_type(p))
-                .collect();
-            let type_params: SourceTypeArray = SourceTypeArray::with(type_params);
-            (&expr_type_params.callee, type_params)
-        } else {
-            (&e.callee, SourceTypeArray::empty())
-        };
+    // Returns the static method with the given name if there is exactly one in
+    // all impls and extensions of the type, together with the type the impl or
+    // extension is defined for.
+    fn find_static_method_candidate(
+        &self,
+        sym: Sym,
+        name: Name,
+        is_nullary: bool,
+    ) -> Option<(FctDefinitionId, SourceType)> {
+        let (impls, extensions) = match sym {
+            Sym::Class(cls_id) => {
+                let cls = self.sa.classes.idx(cls_id);
+                let cls = cls.read();
+                (cls.impls.clone(), cls.extensions.clone())
+            }
 
-        let arg_types: Vec<SourceType> = e
-            .args
-            .iter()
-            .map(|arg| self.check_expr(&arg.expr, SourceType::Any))
-            .collect();
+            Sym::Value(value_id) => {
+                let value = self.sa.values.idx(value_id);
+                let value = value.read();
+                (value.impls.clone(), value.extensions.clone())
+            }
 
-        if let Some(expr_ident) = callee.to_ident() {
-            let sym = self.symtable.get(expr_ident.name);
+            Sym::Enum(enum_id) => {
+                let enum_ = self.sa.enums[enum_id].read();
+                (enum_.impls.clone(), enum_.extensions.clone())
+            }
 
-            self.check_expr_call_sym(e, expected_ty, callee, sym, type_params, &arg_types)
-        } else if let Some(expr_dot) = callee.to_dot() {
-            let object_type = self.check_expr(&expr_dot.lhs, SourceType::Any);
+            _ => return None,
+        };
 
-            let method_name = match expr_dot.rhs.to_ident() {
-                Some(ident) => ident.name,
+        let mut candidates = Vec::new();
 
-                None => {
-                    let msg = ErrorMessage::NameExpected;
-                    self.sa.diag.lock().report(self.file_id, e.pos, msg);
+        for impl_id in impls {
+            let impl_ = self.sa.impls[impl_id].read();
 
-                    self.analysis.set_ty(e.id, SourceType::Error);
-                    return SourceType::Error;
-                }
-            };
-            self.check_expr_call_method(e, object_type, method_name, type_params, &arg_types)
-        } else if let Some(_expr_path) = callee.to_path() {
-            self.check_expr_call_path(e, expected_ty, callee, type_params, &arg_types, false)
-        } else {
-            if !type_params.is_empty() {
-                let msg = ErrorMessage::NoTypeParamsExpected;
-                self.sa
-                    .diag
-                    .lock()
-                    .report(self.file_id, e.callee.pos(), msg);
+            if let Some(&fct_id) = impl_.static_names.get(&name) {
+                candidates.push((fct_id, impl_.extended_ty.clone()));
             }
+        }
 
-            let expr_type = self.check_expr(callee, SourceType::Any);
-            self.check_expr_call_expr(e, expr_type, &arg_types)
+        for extension_id in extensions {
+            let extension = self.sa.extensions[extension_id].read();
+
+            if let Some(&fct_id) = extension.static_names.get(&name) {
+                candidates.push((fct_id, extension.ty.clone()));
+            }
+        }
+
+        if candidates.len() != 1 {
+            return None;
+        }
+
+        let (fct_id, extended_ty) = candidates.pop().unwrap();
+        let fct = self.sa.fcts.idx(fct_id);
+
+        if fct.read().is_nullary != is_nullary {
+            return None;
         }
+
+        Some((fct_id, extended_ty))
+    }
+
+    fn report_type_param_not_inferred(
+        &self,
+        pos: Position,
+        type_params: &TypeParamDefinition,
+        id: TypeParamId,
+        callee: String,
+    ) {
+        let name = self.sa.interner.str(type_params.name(id)).to_string();
+        let msg = ErrorMessage::TypeParamNotInferred(name, callee);
+        self.sa.diag.lock().report(self.file_id, pos, msg);
     }
 
     fn check_expr_call_sym(
@@ -1486,14 +2364,16 @@ impl<'a> TypeCheck<'a> {
         arg_types: &[SourceType],
     ) -> SourceType {
         match sym {
-            Some(Sym::Fct(fct_id)) => self.check_expr_call_fct(e, fct_id, type_params, &arg_types),
+            Some(Sym::Fct(fct_id)) => {
+                self.check_expr_call_fct(e, expected_ty, fct_id, type_params, &arg_types)
+            }
 
             Some(Sym::Class(cls_id)) => {
                 self.check_expr_call_class(e, expected_ty, cls_id, type_params, &arg_types)
             }
 
             Some(Sym::Value(value_id)) => {
-                self.check_expr_call_value(e, value_id, type_params, &arg_types)
+                self.check_expr_call_value(e, expected_ty, value_id, type_params, &arg_types)
             }
 
             Some(Sym::EnumVariant(enum_id, variant_idx)) => self.check_enum_value_with_args(
@@ -1538,8 +2418,27 @@ impl<'a> TypeCheck<'a> {
             self.sa.diag.lock().report(self.file_id, e.pos, msg);
         }
 
-        let type_params = if expected_ty.is_enum_id(enum_id) && type_params.is_empty() {
-            expected_ty.type_params()
+        let type_params = if type_params.is_empty() && !enum_.type_params().is_empty() {
+            if arg_types.contains(&SourceType::Error) {
+                self.analysis.set_ty(e.id, SourceType::Error);
+                return SourceType::Error;
+            }
+
+            let signature = self.enum_variant_signature(enum_id, variant_idx);
+
+            match signature.infer(&expected_ty, arg_types, &e.arg_names()) {
+                Ok(type_params) => type_params,
+                Err(id) => {
+                    let callee = format!(
+                        "{}::{}",
+                        enum_.name(self.sa),
+                        self.sa.interner.str(variant.name)
+                    );
+                    self.report_type_param_not_inferred(e.pos, enum_.type_params(), id, callee);
+                    self.analysis.set_ty(e.id, SourceType::Error);
+                    return SourceType::Error;
+                }
+            }
         } else {
             type_params
         };
@@ -1629,7 +2528,7 @@ impl<'a> TypeCheck<'a> {
             let trait_ = self.sa.traits[trait_id].read();
 
             if let Some(fct_id) = trait_.find_method(self.sa, name, true) {
-                fcts.push((trait_id, fct_id));
+                fcts.push((trait_ty.clone(), fct_id));
             }
         }
 
@@ -1651,7 +2550,8 @@ impl<'a> TypeCheck<'a> {
             return SourceType::Error;
         }
 
-        let (trait_id, fct_id) = fcts[0];
+        let (ref trait_ty, fct_id) = fcts[0];
+        let trait_id = trait_ty.trait_id().expect("trait expected");
         let fct = self.sa.fcts.idx(fct_id);
         let fct = fct.read();
 
@@ -1687,6 +2587,7 @@ impl<'a> TypeCheck<'a> {
             &SourceTypeArray::empty(),
             Some(tp),
         );
+        let return_type = replace_assoc_types(self.sa, return_type, trait_ty);
 
         self.analysis.set_ty(e.id, return_type.clone());
 
@@ -1785,6 +2686,7 @@ impl<'a> TypeCheck<'a> {
     fn check_expr_call_fct(
         &mut self,
         e: &ast::ExprCallType,
+        expected_ty: SourceType,
         fct_id: FctDefinitionId,
         type_params: SourceTypeArray,
         arg_types: &[SourceType],
@@ -1796,6 +2698,30 @@ impl<'a> TypeCheck<'a> {
             self.sa.diag.lock().report(self.file_id, e.pos, msg);
         }
 
+        let type_params = {
+            let fct = self.sa.fcts.idx(fct_id);
+            let fct = fct.read();
+
+            if type_params.is_empty() && !fct.type_params.is_empty() {
+                if arg_types.contains(&SourceType::Error) {
+                    self.analysis.set_ty(e.id, SourceType::Error);
+                    return SourceType::Error;
+                }
+
+                match CallSignature::fct(&fct).infer(&expected_ty, arg_types, &e.arg_names()) {
+                    Ok(type_params) => type_params,
+                    Err(id) => {
+                        let callee = fct.display_name(self.sa);
+                        self.report_type_param_not_inferred(e.pos, &fct.type_params, id, callee);
+                        self.analysis.set_ty(e.id, SourceType::Error);
+                        return SourceType::Error;
+                    }
+                }
+            } else {
+                type_params
+            }
+        };
+
         let mut lookup = MethodLookup::new(self.sa, self.fct)
             .pos(e.pos)
             .callee(fct_id)
@@ -1807,7 +2733,11 @@ impl<'a> TypeCheck<'a> {
             let call_type = CallType::Fct(fct_id, type_params.clone());
             self.analysis.map_calls.insert(e.id, Arc::new(call_type));
 
-            lookup.found_ret().unwrap()
+            let arguments = lookup.found_arguments();
+            let ty = lookup.found_ret().unwrap();
+            self.record_call_arguments(e, arguments);
+
+            ty
         } else {
             SourceType::Error
         };
@@ -1817,6 +2747,18 @@ impl<'a> TypeCheck<'a> {
         ty
     }
 
+    fn record_call_arguments(
+        &mut self,
+        e: &ast::ExprCallType,
+        arguments: Option<Vec<CallArgument>>,
+    ) {
+        if let Some(arguments) = arguments {
+            self.analysis
+                .map_arguments
+                .insert_or_replace(e.id, Arc::new(arguments));
+        }
+    }
+
     fn check_expr_call_static_method(
         &mut self,
         e: &ast::ExprCallType,
@@ -1843,6 +2785,7 @@ impl<'a> TypeCheck<'a> {
             let type_params = container_type_params.connect(&fct_type_params);
             let call_type = Arc::new(CallType::Fct(fct_id, type_params));
             self.analysis.map_calls.insert(e.id, call_type.clone());
+            self.record_call_arguments(e, lookup.found_arguments());
 
             if !method_accessible_from(self.sa, fct_id, self.module_id) {
                 let fct = self.sa.fcts.idx(fct_id);
@@ -1866,6 +2809,7 @@ impl<'a> TypeCheck<'a> {
     fn check_expr_call_method(
         &mut self,
         e: &ast::ExprCallType,
+        expected_ty: SourceType,
         object_type: SourceType,
         method_name: Name,
         fct_type_params: SourceTypeArray,
@@ -1876,12 +2820,35 @@ impl<'a> TypeCheck<'a> {
             return self.check_expr_call_generic(e, id, method_name, arg_types);
         }
 
+        if object_type.is_self() && self.fct.parent.is_trait() {
+            assert_eq!(fct_type_params.len(), 0);
+            return self.check_expr_call_self(e, method_name, arg_types);
+        }
+
         if object_type.is_error() {
             self.analysis.set_ty(e.id, SourceType::Error);
 
             return SourceType::Error;
         }
 
+        let fct_type_params = if fct_type_params.is_empty() && !object_type.is_trait() {
+            match self.infer_method_type_params(
+                e,
+                &expected_ty,
+                &object_type,
+                method_name,
+                arg_types,
+            ) {
+                Some(fct_type_params) => fct_type_params,
+                None => {
+                    self.analysis.set_ty(e.id, SourceType::Error);
+                    return SourceType::Error;
+                }
+            }
+        } else {
+            fct_type_params
+        };
+
         let mut lookup = MethodLookup::new(self.sa, self.fct)
             .no_error_reporting()
             .method(object_type.clone())
@@ -1896,7 +2863,8 @@ impl<'a> TypeCheck<'a> {
             let return_type = lookup.found_ret().unwrap();
 
             let call_type = if object_type.is_trait() {
-                CallType::TraitObjectMethod(object_type, fct_id)
+                let trait_ty = lookup.found_class_type().unwrap();
+                CallType::TraitObjectMethod(trait_ty, fct_id)
             } else {
                 let method_type = lookup.found_class_type().unwrap();
                 let container_type_params = lookup.found_container_type_params().clone().unwrap();
@@ -1907,6 +2875,7 @@ impl<'a> TypeCheck<'a> {
             self.analysis
                 .map_calls
                 .insert_or_replace(e.id, Arc::new(call_type));
+            self.record_call_arguments(e, lookup.found_arguments());
             self.analysis.set_ty(e.id, return_type.clone());
 
             if !method_accessible_from(self.sa, fct_id, self.module_id) {
@@ -1941,6 +2910,50 @@ impl<'a> TypeCheck<'a> {
         }
     }
 
+    // Infers the type params of a generic method called without explicit type
+    // arguments, the type params of its container are known from the object.
+    fn infer_method_type_params(
+        &self,
+        e: &ast::ExprCallType,
+        expected_ty: &SourceType,
+        object_type: &SourceType,
+        method_name: Name,
+        arg_types: &[SourceType],
+    ) -> Option<SourceTypeArray> {
+        let candidate = match self.find_method_candidate(object_type, method_name) {
+            Some(candidate) => candidate,
+            None => return Some(SourceTypeArray::empty()),
+        };
+
+        let fct = self.sa.fcts.idx(candidate.fct_id);
+        let fct = fct.read();
+
+        if fct.type_params.len() == fct.container_type_params {
+            return Some(SourceTypeArray::empty());
+        }
+
+        if arg_types.contains(&SourceType::Error) {
+            return None;
+        }
+
+        let mut signature = CallSignature::fct(&fct);
+        signature
+            .type_args()
+            .bind(0, &candidate.container_type_params);
+
+        match signature.infer(expected_ty, arg_types, &e.arg_names()) {
+            Ok(type_params) => Some(SourceTypeArray::with(
+                type_params.types()[fct.container_type_params..].to_vec(),
+            )),
+
+            Err(id) => {
+                let callee = fct.display_name(self.sa);
+                self.report_type_param_not_inferred(e.pos, &fct.type_params, id, callee);
+                None
+            }
+        }
+    }
+
     fn check_expr_call_field(
         &mut self,
         e: &ast::ExprCallType,
@@ -2014,6 +3027,7 @@ impl<'a> TypeCheck<'a> {
     fn check_expr_call_value(
         &mut self,
         e: &ast::ExprCallType,
+        expected_ty: SourceType,
         value_id: ValueDefinitionId,
         type_params: SourceTypeArray,
         arg_types: &[SourceType],
@@ -2038,6 +3052,28 @@ impl<'a> TypeCheck<'a> {
             self.sa.diag.lock().report(self.file_id, e.pos, msg);
         }
 
+        let type_params = if type_params.is_empty() && !value.type_params().is_empty() {
+            if arg_types.contains(&SourceType::Error) {
+                self.analysis.set_ty(e.id, SourceType::Error);
+                return SourceType::Error;
+            }
+
+            match self
+                .value_signature(value_id)
+                .infer(&expected_ty, arg_types, &e.arg_names())
+            {
+                Ok(type_params) => type_params,
+                Err(id) => {
+                    let callee = value.name(self.sa);
+                    self.report_type_param_not_inferred(e.pos, value.type_params(), id, callee);
+                    self.analysis.set_ty(e.id, SourceType::Error);
+                    return SourceType::Error;
+                }
+            }
+        } else {
+            type_params
+        };
+
         let ty = SourceType::Value(value_id, type_params.clone());
         let type_params_ok = typeparamck::check_value(
             self.sa,
@@ -2135,10 +3171,31 @@ impl<'a> TypeCheck<'a> {
             self.sa.diag.lock().report(self.file_id, e.pos, msg);
         }
 
-        let type_params = if expected_ty.is_cls_id(cls_id) && type_params.is_empty() {
-            expected_ty.type_params()
-        } else {
-            type_params
+        let type_params = {
+            let cls = self.sa.classes.idx(cls_id);
+            let cls = cls.read();
+
+            if type_params.is_empty() && !cls.type_params().is_empty() {
+                if arg_types.contains(&SourceType::Error) {
+                    self.analysis.set_ty(e.id, SourceType::Error);
+                    return SourceType::Error;
+                }
+
+                match self
+                    .class_signature(cls_id)
+                    .infer(&expected_ty, arg_types, &e.arg_names())
+                {
+                    Ok(type_params) => type_params,
+                    Err(id) => {
+                        let callee = cls.name(self.sa);
+                        self.report_type_param_not_inferred(e.pos, cls.type_params(), id, callee);
+                        self.analysis.set_ty(e.id, SourceType::Error);
+                        return SourceType::Error;
+                    }
+                }
+            } else {
+                type_params
+            }
         };
 
         if !typeparamck::check_class(
@@ -2277,6 +3334,59 @@ impl<'a> TypeCheck<'a> {
         )
     }
 
+    // In default methods of traits, Self is only known to implement the trait
+    // itself. The implementing type is passed as an additional type param.
+    fn check_expr_call_self(
+        &mut self,
+        e: &ast::ExprCallType,
+        name: Name,
+        args: &[SourceType],
+    ) -> SourceType {
+        let trait_id = self.fct.trait_id();
+        let trait_ty = SourceType::new_trait(trait_id);
+
+        // Self also implements all supertraits of the trait
+        let number_type_params = self.sa.traits[trait_id].read().type_params().len();
+        let own_type_params = (0..number_type_params)
+            .map(|id| SourceType::TypeParam(TypeParamId(id)))
+            .collect::<Vec<_>>();
+        let own_trait_ty = SourceType::Trait(trait_id, SourceTypeArray::with(own_type_params));
+
+        let found = std::iter::once(trait_ty)
+            .chain(all_super_traits(self.sa, &own_trait_ty))
+            .find_map(|trait_ty| {
+                let trait_id = trait_ty.trait_id().expect("trait expected");
+                find_method_with_replace(self.sa, &trait_ty, false, name, None, args, false)
+                    .map(|fid| (trait_id, fid))
+            });
+
+        if let Some((trait_id, fid)) = found {
+            let fct = self.sa.fcts.idx(fid);
+            let fct = fct.read();
+            let return_type = fct.return_type.clone();
+
+            self.analysis.set_ty(e.id, return_type.clone());
+
+            let self_id = TypeParamId(self.fct.type_params.len());
+            let call_type = CallType::GenericMethod(self_id, trait_id, fid);
+            self.analysis.map_calls.insert(e.id, Arc::new(call_type));
+
+            return_type
+        } else {
+            let name = self.sa.interner.str(name).to_string();
+            let param_names = args
+                .iter()
+                .map(|a| a.name_fct(self.sa, self.fct))
+                .collect::<Vec<String>>();
+            let msg = ErrorMessage::UnknownMethod("Self".into(), name, param_names);
+            self.sa.diag.lock().report(self.file_id, e.pos, msg);
+
+            self.analysis.set_ty(e.id, SourceType::Error);
+
+            SourceType::Error
+        }
+    }
+
     fn check_expr_call_generic_type_param(
         &mut self,
         e: &ast::ExprCallType,
@@ -2289,47 +3399,129 @@ impl<'a> TypeCheck<'a> {
         let mut found_fcts = Vec::new();
 
         for trait_ty in self.fct.type_params.bounds_for_type_param(id) {
-            let trait_id = trait_ty.trait_id().expect("trait expected");
-            let trait_ = self.sa.traits[trait_id].read();
-
             if let Some(fid) =
-                trait_.find_method_with_replace(self.sa, false, name, None, args, is_nullary)
+                find_method_with_replace(self.sa, &trait_ty, false, name, None, args, is_nullary)
             {
-                found_fcts.push(fid);
+                found_fcts.push((fid, trait_ty.clone()));
             }
         }
 
         if found_fcts.len() == 1 {
-            let fid = found_fcts[0];
+            let (fid, ref trait_ty) = found_fcts[0];
+
+            let fct = self.sa.fcts.idx(fid);
+            let fct = fct.read();
+            let return_type = replace_assoc_types(self.sa, fct.return_type.clone(), trait_ty);
+
+            self.analysis.set_ty(e.id, return_type.clone());
+
+            let call_type = CallType::GenericMethod(id, fct.trait_id(), fid);
+            self.analysis.map_calls.insert(e.id, Arc::new(call_type));
+
+            return_type
+        } else {
+            let type_name = object_type.name_fct(self.sa, self.fct);
+            let name = self.sa.interner.str(name).to_string();
+            let param_names = args
+                .iter()
+                .map(|a| a.name_fct(self.sa, self.fct))
+                .collect::<Vec<String>>();
+            let msg = if found_fcts.len() == 0 {
+                ErrorMessage::UnknownMethodForTypeParam(type_name, name, param_names)
+            } else {
+                ErrorMessage::MultipleCandidatesForTypeParam(type_name, name, param_names)
+            };
+
+            self.sa.diag.lock().report(self.file_id, e.pos, msg);
+
+            self.analysis.set_ty(e.id, SourceType::Error);
+
+            SourceType::Error
+        }
+    }
+
+    // `values()` and `fromOrdinal(Int32)` are available for all enums with an
+    // ordinal, unless the enum declares a static method with the same name.
+    fn check_expr_call_enum_builtin(
+        &mut self,
+        e: &ast::ExprCallType,
+        object_ty: &SourceType,
+        name: Name,
+    ) -> Option<SourceType> {
+        let (intrinsic, params, return_type) = if self.is_enum_builtin(object_ty, name, "values") {
+            (
+                Intrinsic::EnumValues,
+                Vec::new(),
+                self.sa.known.array_ty(object_ty.clone()),
+            )
+        } else if self.is_enum_builtin(object_ty, name, "fromOrdinal") {
+            (
+                Intrinsic::EnumFromOrdinal,
+                vec![SourceType::Int32],
+                SourceType::Enum(
+                    self.sa.known.enums.option(),
+                    SourceTypeArray::single(object_ty.clone()),
+                ),
+            )
+        } else {
+            return None;
+        };
+
+        let declared = find_methods_in_enum(
+            self.sa,
+            object_ty.clone(),
+            &self.fct.type_params,
+            name,
+            true,
+            false,
+        );
 
-            let fct = self.sa.fcts.idx(fid);
-            let fct = fct.read();
-            let return_type = fct.return_type.clone();
+        if !declared.is_empty() {
+            return None;
+        }
 
-            self.analysis.set_ty(e.id, return_type.clone());
+        let arg_types = e
+            .args
+            .iter()
+            .map(|arg| self.analysis.ty(arg.expr.id()))
+            .collect::<Vec<_>>();
 
-            let call_type = CallType::GenericMethod(id, fct.trait_id(), fid);
-            self.analysis.map_calls.insert(e.id, Arc::new(call_type));
+        if arg_types.iter().any(|ty| ty.is_error()) {
+            self.analysis.set_ty(e.id, SourceType::Error);
+            return Some(SourceType::Error);
+        }
 
-            return_type
-        } else {
-            let type_name = object_type.name_fct(self.sa, self.fct);
+        if arg_types != params || e.args.iter().any(|arg| arg.name.is_some()) {
             let name = self.sa.interner.str(name).to_string();
-            let param_names = args
+            let params = params.iter().map(|ty| ty.name(self.sa)).collect();
+            let arg_types = arg_types
                 .iter()
-                .map(|a| a.name_fct(self.sa, self.fct))
-                .collect::<Vec<String>>();
-            let msg = if found_fcts.len() == 0 {
-                ErrorMessage::UnknownMethodForTypeParam(type_name, name, param_names)
-            } else {
-                ErrorMessage::MultipleCandidatesForTypeParam(type_name, name, param_names)
-            };
-
+                .map(|ty| ty.name_fct(self.sa, self.fct))
+                .collect();
+            let msg = ErrorMessage::ParamTypesIncompatible(name, params, arg_types);
             self.sa.diag.lock().report(self.file_id, e.pos, msg);
 
             self.analysis.set_ty(e.id, SourceType::Error);
+            return Some(SourceType::Error);
+        }
 
-            SourceType::Error
+        let call_type = Arc::new(CallType::Intrinsic(intrinsic));
+        self.analysis.map_calls.insert_or_replace(e.id, call_type);
+
+        self.analysis.set_ty(e.id, return_type.clone());
+        Some(return_type)
+    }
+
+    fn is_enum_builtin(&self, object_ty: &SourceType, name: Name, builtin: &str) -> bool {
+        match object_ty {
+            &SourceType::Enum(enum_id, _) => {
+                let enum_ = self.sa.enums.idx(enum_id);
+                let enum_ = enum_.read();
+
+                enum_.is_ordinal_enum() && self.sa.interner.str(name).as_str() == builtin
+            }
+
+            _ => false,
         }
     }
 
@@ -2381,6 +3573,39 @@ impl<'a> TypeCheck<'a> {
             return SourceType::Error;
         };
 
+        let method_to_infer = match sym {
+            Some(ref sym) if container_type_params.is_empty() => {
+                self.find_static_method_to_infer(sym.clone(), method_name, &type_params, is_nullary)
+            }
+            _ => None,
+        };
+
+        if let Some(method) = method_to_infer {
+            let inferred = self.infer_static_method_type_params(
+                e,
+                expected_ty,
+                method,
+                &type_params,
+                arg_types,
+            );
+
+            return match inferred {
+                Some((object_ty, type_params)) => self.check_expr_call_static_method(
+                    e,
+                    object_ty,
+                    method_name,
+                    type_params,
+                    arg_types,
+                    is_nullary,
+                ),
+
+                None => {
+                    self.analysis.set_ty(e.id, SourceType::Error);
+                    SourceType::Error
+                }
+            };
+        }
+
         match sym {
             Some(Sym::Class(cls_id)) => {
                 if typeparamck::check_class(
@@ -2471,6 +3696,14 @@ impl<'a> TypeCheck<'a> {
                     ) {
                         let object_ty = SourceType::Enum(enum_id, container_type_params);
 
+                        if type_params.is_empty() {
+                            if let Some(ty) =
+                                self.check_expr_call_enum_builtin(e, &object_ty, method_name)
+                            {
+                                return ty;
+                            }
+                        }
+
                         self.check_expr_call_static_method(
                             e,
                             object_ty,
@@ -2527,6 +3760,100 @@ impl<'a> TypeCheck<'a> {
         }
     }
 
+    // Static methods of generic types can be called without type params for the
+    // type, e.g. `Array::new()`. Returns the method if its type params still need
+    // to be inferred, otherwise the regular lookup checks the call.
+    fn find_static_method_to_infer(
+        &self,
+        sym: Sym,
+        method_name: Name,
+        type_params: &SourceTypeArray,
+        is_nullary: bool,
+    ) -> Option<(FctDefinitionId, SourceType)> {
+        let is_generic = match sym {
+            Sym::Class(cls_id) => {
+                let cls = self.sa.classes.idx(cls_id);
+                let cls = cls.read();
+                !cls.type_params().is_empty()
+            }
+
+            Sym::Value(value_id) => {
+                let value = self.sa.values.idx(value_id);
+                let value = value.read();
+                !value.type_params().is_empty()
+            }
+
+            Sym::Enum(enum_id) => {
+                let enum_ = self.sa.enums[enum_id].read();
+
+                if enum_.name_to_value.contains_key(&method_name) {
+                    return None;
+                }
+
+                !enum_.type_params().is_empty()
+            }
+
+            _ => return None,
+        };
+
+        let (fct_id, extended_ty) =
+            self.find_static_method_candidate(sym, method_name, is_nullary)?;
+        let fct = self.sa.fcts.idx(fct_id);
+        let fct = fct.read();
+
+        let fct_type_params = fct.type_params.len() - fct.container_type_params;
+
+        if !type_params.is_empty() && type_params.len() != fct_type_params {
+            return None;
+        }
+
+        if !is_generic && (fct_type_params == 0 || !type_params.is_empty()) {
+            return None;
+        }
+
+        Some((fct_id, extended_ty))
+    }
+
+    // Infers the type params of the type a static method is called on together
+    // with omitted type params of the method itself.
+    fn infer_static_method_type_params(
+        &mut self,
+        e: &ast::ExprCallType,
+        expected_ty: SourceType,
+        (fct_id, extended_ty): (FctDefinitionId, SourceType),
+        type_params: &SourceTypeArray,
+        arg_types: &[SourceType],
+    ) -> Option<(SourceType, SourceTypeArray)> {
+        let fct = self.sa.fcts.idx(fct_id);
+        let fct = fct.read();
+
+        let container_type_params = fct.container_type_params;
+        let mut signature = CallSignature::fct(&fct);
+        signature
+            .type_args()
+            .bind(container_type_params, type_params);
+
+        if arg_types.contains(&SourceType::Error) {
+            return None;
+        }
+
+        match signature.infer(&expected_ty, arg_types, &e.arg_names()) {
+            Ok(inferred) => {
+                let (container, fct_type_params) = inferred.types().split_at(container_type_params);
+                let container = SourceTypeArray::with(container.to_vec());
+                let object_ty = replace_type_param(self.sa, extended_ty, &container, None);
+
+                Some((object_ty, SourceTypeArray::with(fct_type_params.to_vec())))
+            }
+
+            Err(id) => {
+                let callee = fct.display_name(self.sa);
+                self.report_type_param_not_inferred(e.pos, &fct.type_params, id, callee);
+                None
+            }
+        }
+    }
+
     fn check_expr_path(&mut self, e: &ast::ExprPathType, expected_ty: SourceType) -> SourceType {
         let (container_expr, type_params) = if let Some(expr_type_params) = e.lhs.to_type_param() {
             let type_params: Vec<SourceType> = expr_type_params
@@ -2823,7 +4150,7 @@ impl<'a> TypeCheck<'a> {
         &mut self,
         expr_id: ast::NodeId,
         expr_pos: Position,
-        _expected_ty: SourceType,
+        expected_ty: SourceType,
         enum_id: EnumDefinitionId,
         type_params: SourceTypeArray,
         name: Name,
@@ -2835,6 +4162,30 @@ impl<'a> TypeCheck<'a> {
             self.sa.diag.lock().report(self.file_id, expr_pos, msg);
         }
 
+        let type_params = match enum_.name_to_value.get(&name) {
+            Some(&variant_idx) if type_params.is_empty() && !enum_.type_params().is_empty() => {
+                let signature = self.enum_variant_signature(enum_id, variant_idx as usize);
+
+                match signature.infer(&expected_ty, &[], &[]) {
+                    Ok(type_params) => type_params,
+                    Err(id) => {
+                        let callee =
+                            format!("{}::{}", enum_.name(self.sa), self.sa.interner.str(name));
+                        self.report_type_param_not_inferred(
+                            expr_pos,
+                            enum_.type_params(),
+                            id,
+                            callee,
+                        );
+                        self.analysis.set_ty(expr_id, SourceType::Error);
+                        return SourceType::Error;
+                    }
+                }
+            }
+
+            _ => type_params,
+        };
+
         let type_params_ok = typeparamck::check_enum(
             self.sa,
             self.fct,
@@ -2996,8 +4347,22 @@ impl<'a> TypeCheck<'a> {
             self.sa.diag.lock().report(self.file_id, expr_pos, msg);
         }
 
-        let type_params = if expected_ty.is_enum_id(enum_id) && type_params.is_empty() {
-            expected_ty.type_params()
+        let type_params = if type_params.is_empty() && !enum_.type_params().is_empty() {
+            let signature = self.enum_variant_signature(enum_id, variant_idx);
+
+            match signature.infer(&expected_ty, &[], &[]) {
+                Ok(type_params) => type_params,
+                Err(id) => {
+                    let callee = format!(
+                        "{}::{}",
+                        enum_.name(self.sa),
+                        self.sa.interner.str(enum_.variants[variant_idx].name)
+                    );
+                    self.report_type_param_not_inferred(expr_pos, enum_.type_params(), id, callee);
+                    self.analysis.set_ty(expr_id, SourceType::Error);
+                    return SourceType::Error;
+                }
+            }
         } else {
             type_params
         };
@@ -3047,7 +4412,7 @@ impl<'a> TypeCheck<'a> {
     }
 
     fn check_expr_dot(&mut self, e: &ast::ExprDotType, _expected_ty: SourceType) -> SourceType {
-        let object_type = self.check_expr(&e.lhs, SourceType::Any);
+        let object_type = self.check_expr_object(&e.lhs);
 
         if object_type.is_tuple() {
             return self.check_expr_dot_tuple(e, object_type);
@@ -3120,26 +4485,26 @@ impl<'a> TypeCheck<'a> {
             true,
             name,
             &[],
-            &object_type.type_params(),
+            &SourceTypeArray::empty(),
             false,
         ) {
             let call_type = Arc::new(CallType::Method(
-                object_type.clone(),
+                function.object_type,
                 function.fct_id,
-                object_type.type_params(),
+                function.type_params,
             ));
             self.analysis.map_calls.insert_or_replace(e.id, call_type);
 
-            let class_type_params = object_type.type_params();
-            let fty = replace_type_param(
-                self.sa,
-                function.return_type.clone(),
-                &class_type_params,
-                None,
-            );
+            self.analysis.set_ty(e.id, function.return_type.clone());
+            return function.return_type;
+        }
 
-            self.analysis.set_ty(e.id, fty.clone());
-            return fty;
+        if self.is_enum_builtin(&object_type, name, "ordinal") {
+            let call_type = Arc::new(CallType::Intrinsic(Intrinsic::EnumOrdinal));
+            self.analysis.map_calls.insert_or_replace(e.id, call_type);
+
+            self.analysis.set_ty(e.id, SourceType::Int32);
+            return SourceType::Int32;
         }
 
         if object_type.is_type_param() {
@@ -3237,30 +4602,94 @@ impl<'a> TypeCheck<'a> {
         var.ty.clone()
     }
 
+    // `super` is only allowed as object of a method call or field access.
+    fn check_expr_object(&mut self, e: &ast::Expr) -> SourceType {
+        if let ast::Expr::Super(ref expr) = *e {
+            self.check_expr_super_object(expr)
+        } else {
+            self.check_expr(e, SourceType::Any)
+        }
+    }
+
+    fn check_expr_super_object(&mut self, e: &ast::ExprSuperType) -> SourceType {
+        let parent_type = if self.self_available {
+            let self_ty = self.vars.get_var(NestedVarId(0)).ty.clone();
+            parent_class_type(self.sa, &self_ty)
+        } else {
+            None
+        };
+
+        let parent_type = match parent_type {
+            Some(parent_type) => parent_type,
+            None => {
+                let msg = ErrorMessage::SuperUnavailable;
+                self.sa.diag.lock().report(self.file_id, e.pos, msg);
+                self.analysis.set_ty(e.id, SourceType::Error);
+                return SourceType::Error;
+            }
+        };
+
+        let ident = self
+            .vars
+            .check_context_allocated(NestedVarId(0), &mut self.outer_context_access_in_function);
+        self.analysis.map_idents.insert(e.id, ident);
+
+        self.analysis.set_ty(e.id, parent_type.clone());
+        parent_type
+    }
+
+    fn check_expr_super(&mut self, e: &ast::ExprSuperType, _expected_ty: SourceType) -> SourceType {
+        let msg = ErrorMessage::SuperNeedsMethodCall;
+        self.sa.diag.lock().report(self.file_id, e.pos, msg);
+        self.analysis.set_ty(e.id, SourceType::Error);
+        SourceType::Error
+    }
+
+    // Omitted parameter and return types are taken from the expected lambda
+    // type. A return type that isn't known is inferred from the lambda's body.
     fn check_expr_lambda(
         &mut self,
         node: &Arc<ast::Function>,
-        _expected_ty: SourceType,
+        expected_ty: SourceType,
     ) -> SourceType {
+        let (expected_params, expected_ret) = match expected_ty {
+            SourceType::Lambda(params, ret) if params.len() == node.params.len() => {
+                (params.types().to_vec(), *ret)
+            }
+            _ => (vec![SourceType::Any; node.params.len()], SourceType::Any),
+        };
+
         let ret = if let Some(ref ret_type) = node.return_type {
             self.read_type(ret_type)
+        } else if is_known(&expected_ret) {
+            expected_ret
         } else {
-            SourceType::Unit
+            SourceType::Any
         };
 
         self.contains_lambda = true;
 
         let mut params = Vec::new();
 
-        for param in &node.params {
-            params.push(self.read_type(&param.data_type));
+        for (param, expected_param) in node.params.iter().zip(expected_params) {
+            let ty = if let Some(ref data_type) = param.data_type {
+                self.read_type(data_type)
+            } else if is_known(&expected_param) {
+                expected_param
+            } else {
+                let name = self.sa.interner.str(param.name).to_string();
+                let msg = ErrorMessage::LambdaParamTypeNotInferred(name);
+                self.sa.diag.lock().report(self.file_id, param.pos, msg);
+                SourceType::Error
+            };
+
+            params.push(ty);
         }
 
-        let ty = SourceType::Lambda(SourceTypeArray::with(params.clone()), Box::new(ret.clone()));
         let parent_fct_id = self.fct.id();
 
         let mut params_with_ctxt = vec![SourceType::Ptr];
-        params_with_ctxt.append(&mut params);
+        params_with_ctxt.extend_from_slice(&params);
 
         let mut lambda = FctDefinition::new(
             self.package_id,
@@ -3275,12 +4704,12 @@ impl<'a> TypeCheck<'a> {
         let lambda_fct_id = self.sa.add_fct(lambda);
         self.analysis.map_lambdas.insert(node.id, lambda_fct_id);
 
-        {
+        let ret = {
             let lambda = self.sa.fcts.idx(lambda_fct_id);
 
             let mut analysis = AnalysisData::new();
 
-            {
+            let return_types = {
                 let lambda = lambda.read();
 
                 let mut typeck = TypeCheck {
@@ -3292,29 +4721,61 @@ impl<'a> TypeCheck<'a> {
                     analysis: &mut analysis,
                     ast: &node,
                     symtable: &mut self.symtable,
-                    in_loop: false,
+                    loops: Vec::new(),
                     self_available: self.self_available.clone(),
                     vars: self.vars,
                     contains_lambda: false,
                     outer_context_access_in_function: false,
                     outer_context_access_from_lambda: false,
+                    inferred_return_types: Vec::new(),
                 };
 
                 typeck.check();
-            }
+                typeck.inferred_return_types
+            };
 
             if analysis.outer_context_access() {
                 self.outer_context_access_from_lambda = true
             }
 
-            lambda.write().analysis = Some(analysis);
-        }
+            let mut lambda = lambda.write();
+            lambda.analysis = Some(analysis);
+
+            if lambda.return_type == SourceType::Any {
+                lambda.return_type = self.infer_lambda_return_type(return_types);
+            }
+
+            lambda.return_type.clone()
+        };
 
+        let ty = SourceType::Lambda(SourceTypeArray::with(params), Box::new(ret));
         self.analysis.set_ty(node.id, ty.clone());
 
         ty
     }
 
+    // The first returned value determines the return type, all other
+    // returned values need to be compatible with it.
+    fn infer_lambda_return_type(&self, return_types: Vec<(Position, SourceType)>) -> SourceType {
+        let mut return_types = return_types.into_iter();
+
+        let ret = match return_types.next() {
+            Some((_, ty)) => ty,
+            None => SourceType::Unit,
+        };
+
+        for (pos, ty) in return_types {
+            if !ty.is_error() && !ret.allows(self.sa, ty.clone()) {
+                let ret = ret.name_fct(self.sa, self.fct);
+                let ty = ty.name_fct(self.sa, self.fct);
+                let msg = ErrorMessage::ReturnType(ret, ty);
+                self.sa.diag.lock().report(self.file_id, pos, msg);
+            }
+        }
+
+        ret
+    }
+
     fn check_expr_conv(&mut self, e: &ast::ExprConvType, _expected_ty: SourceType) -> SourceType {
         let object_type = self.check_expr(&e.object, SourceType::Any);
         self.analysis.set_ty(e.object.id(), object_type.clone());
@@ -3322,6 +4783,31 @@ impl<'a> TypeCheck<'a> {
         let check_type = self.read_type(&e.data_type);
         self.analysis.set_ty(e.data_type.id(), check_type.clone());
 
+        if e.is || (!check_type.is_trait() && object_type.is_trait()) {
+            return self.check_expr_downcast(e, object_type, check_type);
+        }
+
+        if let Some(union_id) = check_type.union_id() {
+            let union_ = self.sa.unions[union_id].read();
+            let type_params = check_type.type_params();
+            let is_variant = (0..union_.variants.len())
+                .any(|idx| union_.variant_type(self.sa, &type_params, idx) == object_type);
+
+            if !is_variant && !object_type.is_error() {
+                let object_type = object_type.name_fct(self.sa, self.fct);
+                let check_type = check_type.name_fct(self.sa, self.fct);
+
+                self.sa.diag.lock().report(
+                    self.file_id,
+                    e.pos,
+                    ErrorMessage::UnionVariantExpected(check_type, object_type),
+                );
+            }
+
+            self.analysis.set_ty(e.id, check_type.clone());
+            return check_type;
+        }
+
         if check_type.is_trait() {
             let implements = implements_trait(
                 self.sa,
@@ -3357,6 +4843,61 @@ impl<'a> TypeCheck<'a> {
         }
     }
 
+    // `obj is T` and `obj as T` test whether the trait object `obj` was created
+    // from a value of type `T`. The cast returns `Option[T]`.
+    fn check_expr_downcast(
+        &mut self,
+        e: &ast::ExprConvType,
+        object_type: SourceType,
+        check_type: SourceType,
+    ) -> SourceType {
+        if object_type.is_error() || check_type.is_error() {
+            self.analysis.set_ty(e.id, SourceType::Error);
+            return SourceType::Error;
+        }
+
+        if !object_type.is_trait() {
+            let name = object_type.name_fct(self.sa, self.fct);
+            self.sa.diag.lock().report(
+                self.file_id,
+                e.pos,
+                ErrorMessage::TraitObjectExpected(name),
+            );
+            self.analysis.set_ty(e.id, SourceType::Error);
+            return SourceType::Error;
+        }
+
+        let implements = implements_trait(
+            self.sa,
+            check_type.clone(),
+            &self.fct.type_params,
+            object_type.clone(),
+        );
+
+        if !implements {
+            let check_type = check_type.name_fct(self.sa, self.fct);
+            let object_type = object_type.name_fct(self.sa, self.fct);
+
+            self.sa.diag.lock().report(
+                self.file_id,
+                e.pos,
+                ErrorMessage::TypeNotImplementingTrait(check_type, object_type),
+            );
+        }
+
+        let ty = if e.is {
+            SourceType::Bool
+        } else {
+            SourceType::Enum(
+                self.sa.known.enums.option(),
+                SourceTypeArray::single(check_type),
+            )
+        };
+
+        self.analysis.set_ty(e.id, ty.clone());
+        ty
+    }
+
     fn check_expr_lit_int(
         &mut self,
         e: &ast::ExprLitIntType,
@@ -3480,12 +5021,15 @@ impl<'a> TypeCheck<'a> {
             ast::Expr::Path(ref expr) => self.check_expr_path(expr, expected_ty),
             ast::Expr::Dot(ref expr) => self.check_expr_dot(expr, expected_ty),
             ast::Expr::This(ref expr) => self.check_expr_this(expr, expected_ty),
+            ast::Expr::Super(ref expr) => self.check_expr_super(expr, expected_ty),
             ast::Expr::Conv(ref expr) => self.check_expr_conv(expr, expected_ty),
             ast::Expr::Lambda(ref expr) => self.check_expr_lambda(expr, expected_ty),
             ast::Expr::Block(ref expr) => self.check_expr_block(expr, expected_ty),
             ast::Expr::If(ref expr) => self.check_expr_if(expr, expected_ty),
+            ast::Expr::Match(ref expr) => self.check_expr_match(expr, expected_ty),
             ast::Expr::Tuple(ref expr) => self.check_expr_tuple(expr, expected_ty),
             ast::Expr::Paren(ref expr) => self.check_expr_paren(expr, expected_ty),
+            ast::Expr::Try(ref expr) => self.check_expr_try(expr, expected_ty),
         }
     }
 }
@@ -3501,6 +5045,8 @@ impl<'a> Visitor for TypeCheck<'a> {
             ast::Stmt::While(ref stmt) => self.check_stmt_while(stmt),
             ast::Stmt::For(ref stmt) => self.check_stmt_for(stmt),
             ast::Stmt::Return(ref stmt) => self.check_stmt_return(stmt),
+            ast::Stmt::Break(ref stmt) => self.check_loop_label(stmt.pos, stmt.label),
+            ast::Stmt::Continue(ref stmt) => self.check_loop_label(stmt.pos, stmt.label),
             ast::Stmt::Expr(ref stmt) => {
                 self.check_expr(&stmt.expr, SourceType::Any);
             }
@@ -3569,7 +5115,7 @@ fn args_compatible(
     for (ind, def_arg) in def.iter().enumerate() {
         let def_arg = replace_type_param(sa, def_arg.clone(), &type_params, self_ty.clone());
 
-        if !arg_allows(sa, def_arg, args[ind].clone(), self_ty.clone()) {
+        if !arg_allows_subclass(sa, def_arg, args[ind].clone(), self_ty.clone()) {
             return false;
         }
     }
@@ -3579,7 +5125,7 @@ fn args_compatible(
         let rest_ty = replace_type_param(sa, rest_ty, &type_params, self_ty.clone());
 
         for expr_ty in &args[ind..] {
-            if !arg_allows(sa, rest_ty.clone(), expr_ty.clone(), self_ty.clone()) {
+            if !arg_allows_subclass(sa, rest_ty.clone(), expr_ty.clone(), self_ty.clone()) {
                 return false;
             }
         }
@@ -3588,6 +5134,24 @@ fn args_compatible(
     true
 }
 
+// Instances of subclasses can be passed where the parent class is expected,
+// type params of classes still need to match exactly.
+fn arg_allows_subclass(
+    sa: &SemAnalysis,
+    def: SourceType,
+    arg: SourceType,
+    self_ty: Option<SourceType>,
+) -> bool {
+    if arg_allows(sa, def.clone(), arg.clone(), self_ty.clone()) {
+        return true;
+    }
+
+    match parent_class_type(sa, &arg) {
+        Some(parent_class) => arg_allows_subclass(sa, def, parent_class, self_ty),
+        None => false,
+    }
+}
+
 fn arg_allows(
     sa: &SemAnalysis,
     def: SourceType,
@@ -3599,6 +5163,11 @@ fn arg_allows(
         SourceType::Unit
         | SourceType::Bool
         | SourceType::UInt8
+        | SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt16
+        | SourceType::UInt32
+        | SourceType::UInt64
         | SourceType::Char
         | SourceType::Value(_, _)
         | SourceType::Int32
@@ -3613,7 +5182,9 @@ fn arg_allows(
             arg_allows(sa, real, arg, self_ty)
         }
 
-        SourceType::TypeParam(_) => def == arg,
+        SourceType::TypeParam(_) | SourceType::Assoc(_) => def == arg,
+
+        SourceType::Union(_, _) => def.allows(sa, arg),
 
         SourceType::Class(cls_id, ref params) => {
             if def == arg {
@@ -3692,14 +5263,30 @@ pub fn check_lit_int(
     let value = e.value;
 
     if e.base == IntBase::Dec {
-        let max = match ty {
-            SourceType::UInt8 => 256,
-            SourceType::Int32 => 1u64 << 31,
-            SourceType::Int64 => 1u64 << 63,
+        let max: u128 = match ty {
+            SourceType::Int8 => 1 << 7,
+            SourceType::Int16 => 1 << 15,
+            SourceType::UInt8 => 1 << 8,
+            SourceType::UInt16 => 1 << 16,
+            SourceType::Int32 => 1 << 31,
+            SourceType::UInt32 => 1 << 32,
+            SourceType::Int64 => 1 << 63,
+            SourceType::UInt64 => 1 << 64,
             _ => unreachable!(),
         };
 
-        if (negate && value > max) || (!negate && value >= max) {
+        let value = value as u128;
+
+        // Unsigned types only allow negating zero.
+        let overflow = if negate && ty.is_unsigned_int() {
+            value != 0
+        } else if negate {
+            value > max
+        } else {
+            value >= max
+        };
+
+        if overflow {
             sa.diag
                 .lock()
                 .report(file, e.pos, ErrorMessage::NumberOverflow(ty_name.into()));
@@ -3716,9 +5303,10 @@ pub fn check_lit_int(
         assert!(!negate);
 
         let max = match ty {
-            SourceType::UInt8 => 256 as u64,
-            SourceType::Int32 => u32::max_value() as u64,
-            SourceType::Int64 => u64::max_value() as u64,
+            SourceType::Int8 | SourceType::UInt8 => u8::MAX as u64,
+            SourceType::Int16 | SourceType::UInt16 => u16::MAX as u64,
+            SourceType::Int32 | SourceType::UInt32 => u32::MAX as u64,
+            SourceType::Int64 | SourceType::UInt64 => u64::MAX,
             _ => unreachable!(),
         };
 
@@ -3734,9 +5322,14 @@ pub fn check_lit_int(
 
 fn determine_suffix_type_int_literal(e: &ast::ExprLitIntType) -> Option<SourceType> {
     match e.suffix {
+        IntSuffix::Int8 => Some(SourceType::Int8),
+        IntSuffix::Int16 => Some(SourceType::Int16),
         IntSuffix::UInt8 => Some(SourceType::UInt8),
+        IntSuffix::UInt16 => Some(SourceType::UInt16),
         IntSuffix::Int32 => Some(SourceType::Int32),
+        IntSuffix::UInt32 => Some(SourceType::UInt32),
         IntSuffix::Int64 => Some(SourceType::Int64),
+        IntSuffix::UInt64 => Some(SourceType::UInt64),
         IntSuffix::None => None,
     }
 }
@@ -3748,9 +5341,14 @@ pub fn determine_type_literal_int(
     let suffix_type = determine_suffix_type_int_literal(e);
 
     let default_type = match expected_type {
-        SourceType::UInt8 => SourceType::UInt8,
-        SourceType::Int32 => SourceType::Int32,
-        SourceType::Int64 => SourceType::Int64,
+        SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt8
+        | SourceType::UInt16
+        | SourceType::Int32
+        | SourceType::UInt32
+        | SourceType::Int64
+        | SourceType::UInt64 => expected_type,
         _ => SourceType::Int64,
     };
 
@@ -3790,6 +5388,7 @@ pub fn check_lit_float(
 }
 
 struct MethodDescriptor {
+    object_type: SourceType,
     fct_id: FctDefinitionId,
     type_params: SourceTypeArray,
     return_type: SourceType,
@@ -3848,6 +5447,7 @@ fn lookup_method(
             let cmp_type = replace_type_param(sa, method.return_type.clone(), &type_params, None);
 
             return Some(MethodDescriptor {
+                object_type: candidates[0].object_type.clone(),
                 fct_id: method_id,
                 type_params: type_params,
                 return_type: cmp_type,
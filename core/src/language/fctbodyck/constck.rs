@@ -1,51 +1,139 @@
+use std::cmp::Ordering;
+
+use crate::language::access::const_accessible_from;
 use crate::language::error::msg::ErrorMessage;
 use crate::language::fctbodyck::body::{
     check_lit_float, check_lit_int, determine_type_literal_int,
 };
-use crate::language::sem_analysis::{ConstDefinition, ConstValue, SemAnalysis};
+use crate::language::sem_analysis::{
+    ConstDefinition, ConstDefinitionId, ConstValue, ModuleDefinitionId, SemAnalysis, SourceFileId,
+};
+use crate::language::sym::{ModuleSymTable, Sym};
 use crate::language::ty::SourceType;
 
 use core_parser::ast::*;
+use core_parser::interner::Name;
+use core_parser::lexer::position::Position;
+
+// Evaluates the value of a const definition on first use. Consts can refer to
+// other consts declared later in the program, so this also happens on-demand
+// while evaluating constant expressions.
+pub fn const_value(sa: &SemAnalysis, const_id: ConstDefinitionId) -> (SourceType, ConstValue) {
+    let const_ = sa.consts.idx(const_id);
+
+    let (file_id, module_id, ty, expr) = {
+        let mut const_ = const_.write();
+
+        if const_.evaluated {
+            return evaluated_value(&const_);
+        }
+
+        if const_.evaluating {
+            let msg = ErrorMessage::ConstCycle(const_.name(sa));
+            sa.diag.lock().report(const_.file_id, const_.pos, msg);
+            const_.evaluated = true;
+            return (SourceType::Error, ConstValue::None);
+        }
+
+        const_.evaluating = true;
+        (
+            const_.file_id,
+            const_.module_id,
+            const_.ty.clone(),
+            const_.expr.clone(),
+        )
+    };
+
+    let (value_ty, value) = {
+        let mut constck = ConstCheck {
+            sa,
+            file_id,
+            symtable: ModuleSymTable::new(sa, module_id),
+            module_id,
+            expected_ty: ty.clone(),
+        };
+
+        constck.check_expr(&expr)
+    };
 
+    let mut const_ = const_.write();
+    const_.evaluating = false;
+
+    // a cycle was already reported for this const
+    if const_.evaluated {
+        return (SourceType::Error, ConstValue::None);
+    }
+
+    if !value_ty.is_error() && !ty.allows(sa, value_ty.clone()) {
+        let name = sa.interner.str(const_.name).to_string();
+        let const_ty = ty.name(sa);
+        let value_ty = value_ty.name(sa);
+        let msg = ErrorMessage::AssignType(name, const_ty, value_ty);
+        sa.diag.lock().report(file_id, expr.pos(), msg);
+    }
+
+    const_.evaluated = true;
+
+    if value_ty.is_error() {
+        return (SourceType::Error, ConstValue::None);
+    }
+
+    const_.value = value;
+    evaluated_value(&const_)
+}
+
+fn evaluated_value(const_: &ConstDefinition) -> (SourceType, ConstValue) {
+    match const_.value {
+        ConstValue::None => (SourceType::Error, ConstValue::None),
+        ref value => (const_.ty.clone(), value.clone()),
+    }
+}
+
+// Evaluates the value of a constant expression, used for the values of
+// `const` definitions and default values of parameters.
 pub struct ConstCheck<'a> {
     pub sa: &'a SemAnalysis,
-    pub const_: &'a ConstDefinition,
+    pub file_id: SourceFileId,
+    pub symtable: ModuleSymTable,
+    pub module_id: ModuleDefinitionId,
+    pub expected_ty: SourceType,
 }
 
 impl<'a> ConstCheck<'a> {
     pub fn check_expr(&mut self, expr: &Expr) -> (SourceType, ConstValue) {
-        let expected_type = self.const_.ty.clone();
+        let expected_ty = self.expected_ty.clone();
+        self.eval(expr, expected_ty)
+    }
 
-        let (ty, lit) = match expr {
+    fn eval(&mut self, expr: &Expr, expected_type: SourceType) -> (SourceType, ConstValue) {
+        match expr {
             &Expr::LitChar(ref expr) => (SourceType::Char, ConstValue::Char(expr.value)),
             &Expr::LitInt(ref expr) => {
-                let (ty, value) =
-                    check_lit_int(self.sa, self.const_.file_id, expr, false, expected_type);
+                let (ty, value) = check_lit_int(self.sa, self.file_id, expr, false, expected_type);
 
                 (ty, ConstValue::Int(value))
             }
             &Expr::LitFloat(ref expr) => {
-                let (ty, val) = check_lit_float(self.sa, self.const_.file_id, expr, false);
+                let (ty, val) = check_lit_float(self.sa, self.file_id, expr, false);
                 (ty, ConstValue::Float(val))
             }
             &Expr::LitBool(ref expr) => (SourceType::Bool, ConstValue::Bool(expr.value)),
+            &Expr::LitStr(ref expr) => (self.string_ty(), ConstValue::String(expr.value.clone())),
 
             &Expr::Un(ref expr) if expr.op == UnOp::Neg && expr.opnd.is_lit_int() => {
                 let lit_int = expr.opnd.to_lit_int().unwrap();
                 let ty = determine_type_literal_int(lit_int, expected_type.clone());
 
-                if ty == SourceType::UInt8 {
-                    let ty = SourceType::UInt8.name(self.sa);
+                if ty.is_unsigned_int() {
+                    let ty = ty.name(self.sa);
                     let msg = ErrorMessage::UnOpType(expr.op.as_str().into(), ty);
-                    self.sa
-                        .diag
-                        .lock()
-                        .report(self.const_.file_id, expr.pos, msg);
+                    self.sa.diag.lock().report(self.file_id, expr.pos, msg);
+                    return (SourceType::Error, ConstValue::None);
                 }
 
                 let (ty, value) = check_lit_int(
                     self.sa,
-                    self.const_.file_id,
+                    self.file_id,
                     expr.opnd.to_lit_int().unwrap(),
                     true,
                     expected_type,
@@ -57,34 +145,544 @@ impl<'a> ConstCheck<'a> {
             &Expr::Un(ref expr) if expr.op == UnOp::Neg && expr.opnd.is_lit_float() => {
                 let (ty, val) = check_lit_float(
                     self.sa,
-                    self.const_.file_id,
+                    self.file_id,
                     expr.opnd.to_lit_float().unwrap(),
                     true,
                 );
                 (ty, ConstValue::Float(val))
             }
 
-            _ => {
-                let msg = ErrorMessage::ConstValueExpected;
-                self.sa
-                    .diag
-                    .lock()
-                    .report(self.const_.file_id, expr.pos(), msg);
-                return (SourceType::Error, ConstValue::None);
+            &Expr::Un(ref expr) => self.eval_un(expr, expected_type),
+            &Expr::Bin(ref expr) => self.eval_bin(expr, expected_type),
+            &Expr::Paren(ref expr) => self.eval(&expr.expr, expected_type),
+
+            &Expr::Ident(ref expr) => {
+                let sym = self.symtable.get(expr.name);
+                self.eval_sym(sym, expr.name, expr.pos)
+            }
+
+            &Expr::Path(ref expr) => self.eval_path(expr),
+
+            &Expr::Dot(ref expr) if expr.rhs.is_ident() => {
+                let name = expr.rhs.to_ident().unwrap().name;
+                self.eval_method(&expr.lhs, name, &[], expr.pos, expected_type)
+            }
+
+            &Expr::Call(ref expr) if expr.callee.is_dot() => {
+                let callee = expr.callee.to_dot().unwrap();
+
+                if let Some(ident) = callee.rhs.to_ident() {
+                    self.eval_method(&callee.lhs, ident.name, &expr.args, expr.pos, expected_type)
+                } else {
+                    self.const_value_expected(expr.pos)
+                }
+            }
+
+            _ => self.const_value_expected(expr.pos()),
+        }
+    }
+
+    fn eval_un(
+        &mut self,
+        expr: &ExprUnType,
+        expected_type: SourceType,
+    ) -> (SourceType, ConstValue) {
+        let (ty, value) = self.eval(&expr.opnd, expected_type);
+
+        if ty.is_error() {
+            return (ty, value);
+        }
+
+        let result = match (expr.op, &value) {
+            (UnOp::Plus, &ConstValue::Int(_)) | (UnOp::Plus, &ConstValue::Float(_)) => Some(value),
+            (UnOp::Neg, &ConstValue::Int(value)) if !ty.is_unsigned_int() => {
+                return self.int_result(ty, value.checked_neg(), expr.pos)
             }
+            (UnOp::Neg, &ConstValue::Float(value)) => Some(ConstValue::Float(-value)),
+            _ => None,
         };
 
-        if !self.const_.ty.allows(self.sa, ty.clone()) {
-            let name = self.sa.interner.str(self.const_.name).to_string();
-            let const_ty = self.const_.ty.name(self.sa);
-            let ty = ty.name(self.sa);
-            let msg = ErrorMessage::AssignType(name, const_ty, ty);
-            self.sa
-                .diag
-                .lock()
-                .report(self.const_.file_id, expr.pos(), msg);
+        match result {
+            Some(value) => (ty, value),
+            None => {
+                let ty = ty.name(self.sa);
+                let msg = ErrorMessage::UnOpType(expr.op.as_str().into(), ty);
+                self.sa.diag.lock().report(self.file_id, expr.pos, msg);
+                (SourceType::Error, ConstValue::None)
+            }
         }
+    }
+
+    fn eval_bin(
+        &mut self,
+        expr: &ExprBinType,
+        expected_type: SourceType,
+    ) -> (SourceType, ConstValue) {
+        // Operands of comparisons are unrelated to the expected Bool, while
+        // literals in arithmetic get the type of the other operand.
+        let expected_type = if expr.op.is_compare() {
+            SourceType::Any
+        } else {
+            expected_type
+        };
+
+        let (lhs_ty, lhs) = self.eval(&expr.lhs, expected_type);
+        let (rhs_ty, rhs) = self.eval(&expr.rhs, lhs_ty.clone());
+
+        if lhs_ty.is_error() || rhs_ty.is_error() {
+            return (SourceType::Error, ConstValue::None);
+        }
+
+        if lhs_ty != rhs_ty {
+            return self.bin_op_type_error(expr, lhs_ty, rhs_ty);
+        }
+
+        let ty = lhs_ty;
+
+        let result = match (expr.op, lhs, rhs) {
+            (BinOp::Cmp(op), ConstValue::Int(lhs), ConstValue::Int(rhs))
+                if ty == SourceType::UInt64 && expr.op.is_compare() =>
+            {
+                let value = compare_ordering(op, (lhs as u64).cmp(&(rhs as u64)));
+                return (SourceType::Bool, ConstValue::Bool(value.unwrap()));
+            }
+
+            (BinOp::Cmp(op), lhs, rhs) if expr.op.is_compare() => match compare(op, &lhs, &rhs) {
+                Some(value) => return (SourceType::Bool, ConstValue::Bool(value)),
+                None => None,
+            },
+
+            (BinOp::Or, ConstValue::Bool(lhs), ConstValue::Bool(rhs)) => {
+                Some(ConstValue::Bool(lhs || rhs))
+            }
+            (BinOp::And, ConstValue::Bool(lhs), ConstValue::Bool(rhs)) => {
+                Some(ConstValue::Bool(lhs && rhs))
+            }
+
+            (BinOp::Add, ConstValue::String(lhs), ConstValue::String(rhs)) => {
+                Some(ConstValue::String(lhs + &rhs))
+            }
+
+            (op, ConstValue::Int(lhs), ConstValue::Int(rhs)) if ty == SourceType::UInt64 => {
+                let (lhs, rhs) = (lhs as u64, rhs as u64);
+
+                let value = match op {
+                    BinOp::Add => lhs.checked_add(rhs),
+                    BinOp::Sub => lhs.checked_sub(rhs),
+                    BinOp::Mul => lhs.checked_mul(rhs),
+                    BinOp::Div if rhs == 0 => return self.division_by_zero(expr.pos),
+                    BinOp::Div => lhs.checked_div(rhs),
+                    BinOp::BitOr => Some(lhs | rhs),
+                    BinOp::BitAnd => Some(lhs & rhs),
+                    BinOp::BitXor => Some(lhs ^ rhs),
+                    _ => return self.bin_op_type_error(expr, ty.clone(), ty),
+                };
+
+                return self.int_result(ty, value.map(|value| value as i64), expr.pos);
+            }
+
+            (op, ConstValue::Int(lhs), ConstValue::Int(rhs)) => {
+                let value = match op {
+                    BinOp::Add => lhs.checked_add(rhs),
+                    BinOp::Sub => lhs.checked_sub(rhs),
+                    BinOp::Mul => lhs.checked_mul(rhs),
+                    BinOp::Div if rhs == 0 => return self.division_by_zero(expr.pos),
+                    BinOp::Div => lhs.checked_div(rhs),
+                    BinOp::BitOr => Some(lhs | rhs),
+                    BinOp::BitAnd => Some(lhs & rhs),
+                    BinOp::BitXor => Some(lhs ^ rhs),
+                    _ => return self.bin_op_type_error(expr, ty.clone(), ty),
+                };
+
+                return self.int_result(ty, value, expr.pos);
+            }
+
+            (op, ConstValue::Float(lhs), ConstValue::Float(rhs)) => {
+                let value = match op {
+                    BinOp::Add => lhs + rhs,
+                    BinOp::Sub => lhs - rhs,
+                    BinOp::Mul => lhs * rhs,
+                    BinOp::Div => lhs / rhs,
+                    _ => return self.bin_op_type_error(expr, ty.clone(), ty),
+                };
+
+                Some(float_result(&ty, value))
+            }
+
+            _ => None,
+        };
+
+        match result {
+            Some(value) => (ty, value),
+            None => self.bin_op_type_error(expr, ty.clone(), ty),
+        }
+    }
+
+    fn eval_path(&mut self, expr: &ExprPathType) -> (SourceType, ConstValue) {
+        let module_id = match expr.lhs.to_ident() {
+            Some(ident) => match self.symtable.get(ident.name) {
+                Some(Sym::Module(module_id)) => Some(module_id),
+                _ => None,
+            },
+            None => match self.eval_path_module(&expr.lhs) {
+                Some(module_id) => Some(module_id),
+                None => return self.const_value_expected(expr.pos),
+            },
+        };
+
+        let (module_id, name) = match (module_id, expr.rhs.to_ident()) {
+            (Some(module_id), Some(ident)) => (module_id, ident.name),
+            _ => return self.const_value_expected(expr.pos),
+        };
+
+        let sym = {
+            let module = &self.sa.modules[module_id].read();
+            let symtable = module.table.read();
+            symtable.get(name)
+        };
+
+        self.eval_sym(sym, name, expr.pos)
+    }
+
+    fn eval_path_module(&mut self, expr: &Expr) -> Option<ModuleDefinitionId> {
+        let sym = if let Some(ident) = expr.to_ident() {
+            self.symtable.get(ident.name)
+        } else if let Some(path) = expr.to_path() {
+            let module_id = self.eval_path_module(&path.lhs)?;
+            let name = path.rhs.to_ident()?.name;
+            let module = &self.sa.modules[module_id].read();
+            let symtable = module.table.read();
+            symtable.get(name)
+        } else {
+            None
+        };
+
+        match sym {
+            Some(Sym::Module(module_id)) => Some(module_id),
+            _ => None,
+        }
+    }
+
+    fn eval_sym(
+        &mut self,
+        sym: Option<Sym>,
+        name: Name,
+        pos: Position,
+    ) -> (SourceType, ConstValue) {
+        match sym {
+            Some(Sym::Const(const_id)) => {
+                if !const_accessible_from(self.sa, const_id, self.module_id) {
+                    let const_ = self.sa.consts.idx(const_id);
+                    let const_ = const_.read();
+                    let msg = ErrorMessage::NotAccessible(const_.name(self.sa));
+                    self.sa.diag.lock().report(self.file_id, pos, msg);
+                }
+
+                const_value(self.sa, const_id)
+            }
+
+            None => {
+                let name = self.sa.interner.str(name).to_string();
+                let msg = ErrorMessage::UnknownIdentifier(name);
+                self.sa.diag.lock().report(self.file_id, pos, msg);
+                (SourceType::Error, ConstValue::None)
+            }
+
+            Some(_) => self.const_value_expected(pos),
+        }
+    }
+
+    // Conversions and operations of primitive types without an operator.
+    fn eval_method(
+        &mut self,
+        object: &Expr,
+        name: Name,
+        args: &[Box<Arg>],
+        pos: Position,
+        expected_type: SourceType,
+    ) -> (SourceType, ConstValue) {
+        let method = self.sa.interner.str(name).to_string();
+
+        let object_expected_type = match method.as_str() {
+            "remainder" | "shiftLeft" | "shiftRight" | "shiftRightSigned" | "not" => expected_type,
+            _ => SourceType::Any,
+        };
+
+        let (ty, value) = self.eval(object, object_expected_type);
+
+        if ty.is_error() {
+            return (ty, value);
+        }
+
+        let arg = match args {
+            [] => None,
+            [arg] if arg.name.is_none() => {
+                let arg_expected_type = match method.as_str() {
+                    "remainder" => ty.clone(),
+                    _ => SourceType::Int32,
+                };
+
+                let (arg_ty, arg) = self.eval(&arg.expr, arg_expected_type);
+
+                if arg_ty.is_error() {
+                    return (arg_ty, arg);
+                }
+
+                Some((arg_ty, arg))
+            }
+            _ => return self.const_value_expected(pos),
+        };
+
+        let result = match (method.as_str(), &value, arg) {
+            ("not", &ConstValue::Bool(value), None) => Some((ty, ConstValue::Bool(!value))),
+            ("not", &ConstValue::Int(value), None) if ty != SourceType::UInt8 => {
+                let value = match ty {
+                    SourceType::UInt16 => value ^ u16::MAX as i64,
+                    SourceType::UInt32 => value ^ u32::MAX as i64,
+                    _ => !value,
+                };
+
+                return self.int_result(ty, Some(value), pos);
+            }
+
+            ("remainder", &ConstValue::Int(value), Some((arg_ty, ConstValue::Int(rhs))))
+                if arg_ty == ty =>
+            {
+                if rhs == 0 {
+                    return self.division_by_zero(pos);
+                }
+
+                let min = match ty {
+                    SourceType::Int8 => Some(i8::MIN as i64),
+                    SourceType::Int16 => Some(i16::MIN as i64),
+                    SourceType::Int32 => Some(i32::MIN as i64),
+                    _ => None,
+                };
+
+                let value = if ty == SourceType::UInt64 {
+                    (value as u64)
+                        .checked_rem(rhs as u64)
+                        .map(|value| value as i64)
+                } else if Some(value) == min && rhs == -1 {
+                    // The runtime traps on MIN % -1 just like on MIN / -1.
+                    None
+                } else {
+                    value.checked_rem(rhs)
+                };
+
+                return self.int_result(ty, value, pos);
+            }
+
+            (
+                "shiftLeft" | "shiftRight" | "shiftRightSigned",
+                &ConstValue::Int(value),
+                Some((SourceType::Int32, ConstValue::Int(by))),
+            ) if ty == SourceType::Int32 || ty == SourceType::Int64 => {
+                let by = by as u32;
+
+                let value = match (method.as_str(), ty.clone()) {
+                    ("shiftLeft", SourceType::Int32) => (value as i32).wrapping_shl(by) as i64,
+                    ("shiftLeft", _) => value.wrapping_shl(by),
+                    ("shiftRight", SourceType::Int32) => {
+                        (value as u32).wrapping_shr(by) as i32 as i64
+                    }
+                    ("shiftRight", _) => (value as u64).wrapping_shr(by) as i64,
+                    ("shiftRightSigned", SourceType::Int32) => {
+                        (value as i32).wrapping_shr(by) as i64
+                    }
+                    _ => value.wrapping_shr(by),
+                };
+
+                Some((ty, ConstValue::Int(value)))
+            }
+
+            (_, _, Some(_)) => None,
+
+            ("toInt32", &ConstValue::Bool(value), None) => {
+                Some((SourceType::Int32, ConstValue::Int(value as i64)))
+            }
+            ("toInt64", &ConstValue::Bool(value), None) => {
+                Some((SourceType::Int64, ConstValue::Int(value as i64)))
+            }
+
+            ("toInt32", &ConstValue::Char(value), None) => {
+                Some((SourceType::Int32, ConstValue::Int(value as i64)))
+            }
+            ("toInt64", &ConstValue::Char(value), None) => {
+                Some((SourceType::Int64, ConstValue::Int(value as i64)))
+            }
+
+            ("toChar", &ConstValue::Int(value), None) if ty == SourceType::UInt8 => {
+                Some((SourceType::Char, ConstValue::Char(value as u8 as char)))
+            }
+            ("toCharUnchecked", &ConstValue::Int(value), None) if ty != SourceType::UInt8 => {
+                match u32::try_from(value).ok().and_then(char::from_u32) {
+                    Some(value) => Some((SourceType::Char, ConstValue::Char(value))),
+                    None => {
+                        let msg = ErrorMessage::NumberOverflow("Char".into());
+                        self.sa.diag.lock().report(self.file_id, pos, msg);
+                        return (SourceType::Error, ConstValue::None);
+                    }
+                }
+            }
+
+            ("toInt8", &ConstValue::Int(value), None) if ty != SourceType::Int8 => {
+                Some((SourceType::Int8, ConstValue::Int(value as i8 as i64)))
+            }
+            ("toInt16", &ConstValue::Int(value), None) if ty != SourceType::Int16 => {
+                Some((SourceType::Int16, ConstValue::Int(value as i16 as i64)))
+            }
+            ("toUInt8", &ConstValue::Int(value), None) if ty != SourceType::UInt8 => {
+                Some((SourceType::UInt8, ConstValue::Int(value as u8 as i64)))
+            }
+            ("toUInt16", &ConstValue::Int(value), None) if ty != SourceType::UInt16 => {
+                Some((SourceType::UInt16, ConstValue::Int(value as u16 as i64)))
+            }
+            ("toUInt32", &ConstValue::Int(value), None) if ty != SourceType::UInt32 => {
+                Some((SourceType::UInt32, ConstValue::Int(value as u32 as i64)))
+            }
+            ("toUInt64", &ConstValue::Int(value), None) if ty != SourceType::UInt64 => {
+                Some((SourceType::UInt64, ConstValue::Int(value)))
+            }
+            ("toInt32", &ConstValue::Int(value), None) if ty != SourceType::Int32 => {
+                Some((SourceType::Int32, ConstValue::Int(value as i32 as i64)))
+            }
+            ("toInt64", &ConstValue::Int(value), None) if ty != SourceType::Int64 => {
+                Some((SourceType::Int64, ConstValue::Int(value)))
+            }
+            ("toFloat32", &ConstValue::Int(value), None) if ty == SourceType::UInt64 => Some((
+                SourceType::Float32,
+                ConstValue::Float(value as u64 as f32 as f64),
+            )),
+            ("toFloat64", &ConstValue::Int(value), None) if ty == SourceType::UInt64 => {
+                Some((SourceType::Float64, ConstValue::Float(value as u64 as f64)))
+            }
+            ("toFloat32", &ConstValue::Int(value), None) => {
+                Some((SourceType::Float32, ConstValue::Float(value as f32 as f64)))
+            }
+            ("toFloat64", &ConstValue::Int(value), None) => {
+                Some((SourceType::Float64, ConstValue::Float(value as f64)))
+            }
+
+            ("toInt32", &ConstValue::Float(value), None) => {
+                Some((SourceType::Int32, ConstValue::Int(value as i32 as i64)))
+            }
+            ("toInt64", &ConstValue::Float(value), None) => {
+                Some((SourceType::Int64, ConstValue::Int(value as i64)))
+            }
+            ("toFloat32", &ConstValue::Float(value), None) if ty == SourceType::Float64 => {
+                Some((SourceType::Float32, ConstValue::Float(value as f32 as f64)))
+            }
+            ("toFloat64", &ConstValue::Float(value), None) if ty == SourceType::Float32 => {
+                Some((SourceType::Float64, ConstValue::Float(value)))
+            }
+
+            _ => None,
+        };
+
+        match result {
+            Some(result) => result,
+            None => self.const_value_expected(pos),
+        }
+    }
+
+    // Integer results that do not fit into their type are reported as an
+    // overflow, just like the checked arithmetic at runtime.
+    fn int_result(
+        &mut self,
+        ty: SourceType,
+        value: Option<i64>,
+        pos: Position,
+    ) -> (SourceType, ConstValue) {
+        let fits = match (&ty, value) {
+            (_, None) => false,
+            (SourceType::Int8, Some(value)) => i8::try_from(value).is_ok(),
+            (SourceType::Int16, Some(value)) => i16::try_from(value).is_ok(),
+            (SourceType::UInt8, Some(value)) => u8::try_from(value).is_ok(),
+            (SourceType::UInt16, Some(value)) => u16::try_from(value).is_ok(),
+            (SourceType::Int32, Some(value)) => i32::try_from(value).is_ok(),
+            (SourceType::UInt32, Some(value)) => u32::try_from(value).is_ok(),
+            (_, Some(_)) => true,
+        };
+
+        if fits {
+            (ty, ConstValue::Int(value.unwrap()))
+        } else {
+            let msg = ErrorMessage::NumberOverflow(ty.name(self.sa));
+            self.sa.diag.lock().report(self.file_id, pos, msg);
+            (SourceType::Error, ConstValue::None)
+        }
+    }
+
+    fn bin_op_type_error(
+        &mut self,
+        expr: &ExprBinType,
+        lhs_ty: SourceType,
+        rhs_ty: SourceType,
+    ) -> (SourceType, ConstValue) {
+        let lhs_ty = lhs_ty.name(self.sa);
+        let rhs_ty = rhs_ty.name(self.sa);
+        let msg = ErrorMessage::BinOpType(expr.op.as_str().into(), lhs_ty, rhs_ty);
+        self.sa.diag.lock().report(self.file_id, expr.pos, msg);
+        (SourceType::Error, ConstValue::None)
+    }
+
+    fn division_by_zero(&mut self, pos: Position) -> (SourceType, ConstValue) {
+        let msg = ErrorMessage::ConstDivisionByZero;
+        self.sa.diag.lock().report(self.file_id, pos, msg);
+        (SourceType::Error, ConstValue::None)
+    }
+
+    fn const_value_expected(&mut self, pos: Position) -> (SourceType, ConstValue) {
+        let msg = ErrorMessage::ConstValueExpected;
+        self.sa.diag.lock().report(self.file_id, pos, msg);
+        (SourceType::Error, ConstValue::None)
+    }
+
+    fn string_ty(&self) -> SourceType {
+        self.sa.cls(self.sa.known.classes.string())
+    }
+}
+
+fn compare(op: CmpOp, lhs: &ConstValue, rhs: &ConstValue) -> Option<bool> {
+    let ordering = match (lhs, rhs) {
+        (ConstValue::Bool(lhs), ConstValue::Bool(rhs)) => lhs.cmp(rhs),
+        (ConstValue::Char(lhs), ConstValue::Char(rhs)) => lhs.cmp(rhs),
+        (ConstValue::Int(lhs), ConstValue::Int(rhs)) => lhs.cmp(rhs),
+        (ConstValue::String(lhs), ConstValue::String(rhs)) => lhs.cmp(rhs),
+        (ConstValue::Float(lhs), ConstValue::Float(rhs)) => {
+            return match op {
+                CmpOp::Eq => Some(lhs == rhs),
+                CmpOp::Ne => Some(lhs != rhs),
+                CmpOp::Lt => Some(lhs < rhs),
+                CmpOp::Le => Some(lhs <= rhs),
+                CmpOp::Gt => Some(lhs > rhs),
+                CmpOp::Ge => Some(lhs >= rhs),
+                CmpOp::Is | CmpOp::IsNot => None,
+            };
+        }
+        _ => return None,
+    };
+
+    compare_ordering(op, ordering)
+}
+
+fn compare_ordering(op: CmpOp, ordering: Ordering) -> Option<bool> {
+    match op {
+        CmpOp::Eq => Some(ordering.is_eq()),
+        CmpOp::Ne => Some(ordering.is_ne()),
+        CmpOp::Lt => Some(ordering.is_lt()),
+        CmpOp::Le => Some(ordering.is_le()),
+        CmpOp::Gt => Some(ordering.is_gt()),
+        CmpOp::Ge => Some(ordering.is_ge()),
+        CmpOp::Is | CmpOp::IsNot => None,
+    }
+}
 
-        (ty, lit)
+fn float_result(ty: &SourceType, value: f64) -> ConstValue {
+    if *ty == SourceType::Float32 {
+        ConstValue::Float(value as f32 as f64)
+    } else {
+        ConstValue::Float(value)
     }
 }
@@ -0,0 +1,266 @@
+use crate::language::sem_analysis::{FctDefinition, TypeParamId};
+use crate::language::ty::{SourceType, SourceTypeArray};
+
+use core_parser::interner::Name;
+
+// Declared parameter and return types of the callee of a call, expressed in
+// terms of the callee's own type params.
+pub struct CallSignature {
+    params: Vec<SourceType>,
+    param_names: Vec<Name>,
+    is_variadic: bool,
+    return_type: SourceType,
+    type_args: TypeArgs,
+}
+
+impl CallSignature {
+    pub fn new(
+        params: Vec<SourceType>,
+        return_type: SourceType,
+        type_params: usize,
+    ) -> CallSignature {
+        CallSignature {
+            params,
+            param_names: Vec::new(),
+            is_variadic: false,
+            return_type,
+            type_args: TypeArgs::new(type_params),
+        }
+    }
+
+    pub fn fct(fct: &FctDefinition) -> CallSignature {
+        CallSignature {
+            params: fct.params_without_self().to_vec(),
+            param_names: fct.param_names.clone(),
+            is_variadic: fct.is_variadic,
+            return_type: fct.return_type.clone(),
+            type_args: TypeArgs::new(fct.type_params.len()),
+        }
+    }
+
+    pub fn type_args(&mut self) -> &mut TypeArgs {
+        &mut self.type_args
+    }
+
+    // Named arguments belong to the parameter with the same name, all others
+    // are positional.
+    fn param(&self, idx: usize, name: Option<&Name>) -> Option<&SourceType> {
+        if let Some(name) = name {
+            if let Some(param_idx) = self.param_names.iter().position(|param| param == name) {
+                return self.params.get(param_idx);
+            }
+        }
+
+        if self.is_variadic && idx + 1 >= self.params.len() {
+            self.params.last()
+        } else {
+            self.params.get(idx)
+        }
+    }
+
+    // Types the arguments are checked against: parameter types with all type
+    // arguments known from explicit type params or the expected type.
+    pub fn expected_arg_types(
+        mut self,
+        expected_ty: &SourceType,
+        arg_names: &[&Option<Name>],
+    ) -> Vec<SourceType> {
+        self.type_args.unify(&self.return_type, expected_ty);
+
+        arg_names
+            .iter()
+            .enumerate()
+            .map(|(idx, name)| match self.param(idx, name.as_ref()) {
+                Some(param) => self.type_args.specialize(param),
+                None => SourceType::Any,
+            })
+            .collect()
+    }
+
+    // The expected type is considered first, so that it takes precedence
+    // over the argument types.
+    pub fn infer(
+        mut self,
+        expected_ty: &SourceType,
+        arg_types: &[SourceType],
+        arg_names: &[&Option<Name>],
+    ) -> Result<SourceTypeArray, TypeParamId> {
+        self.type_args.unify(&self.return_type, expected_ty);
+
+        for (idx, arg_ty) in arg_types.iter().enumerate() {
+            let name = arg_names.get(idx).and_then(|name| name.as_ref());
+
+            if let Some(param) = self.param(idx, name).cloned() {
+                self.type_args.unify(&param, arg_ty);
+            }
+        }
+
+        self.type_args.finish()
+    }
+}
+
+// Type arguments of a generic callee that were not given explicitly. They get
+// bound by matching the declared types of the callee against the type expected
+// at the call site and against the types of the arguments.
+pub struct TypeArgs {
+    args: Vec<Option<SourceType>>,
+}
+
+impl TypeArgs {
+    pub fn new(count: usize) -> TypeArgs {
+        TypeArgs {
+            args: vec![None; count],
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.args.len()
+    }
+
+    pub fn bind(&mut self, offset: usize, types: &SourceTypeArray) {
+        for (idx, ty) in types.iter().enumerate() {
+            self.args[offset + idx] = Some(ty);
+        }
+    }
+
+    // Binds type params in `declared` to the corresponding parts of `actual`.
+    // Type params that are already bound are never rebound, conflicts are left
+    // to the regular type check of the call.
+    pub fn unify(&mut self, declared: &SourceType, actual: &SourceType) {
+        match (declared, actual) {
+            (_, SourceType::Any) | (_, SourceType::Error) => {}
+
+            (SourceType::TypeParam(id), _) => {
+                if let Some(arg) = self.args.get_mut(id.to_usize()) {
+                    if arg.is_none() {
+                        *arg = Some(actual.clone());
+                    }
+                }
+            }
+
+            (SourceType::Class(lhs_id, lhs), SourceType::Class(rhs_id, rhs))
+                if lhs_id == rhs_id =>
+            {
+                self.unify_all(lhs, rhs)
+            }
+
+            (SourceType::Value(lhs_id, lhs), SourceType::Value(rhs_id, rhs))
+                if lhs_id == rhs_id =>
+            {
+                self.unify_all(lhs, rhs)
+            }
+
+            (SourceType::Enum(lhs_id, lhs), SourceType::Enum(rhs_id, rhs)) if lhs_id == rhs_id => {
+                self.unify_all(lhs, rhs)
+            }
+
+            (SourceType::Trait(lhs_id, lhs), SourceType::Trait(rhs_id, rhs))
+                if lhs_id == rhs_id =>
+            {
+                self.unify_all(lhs, rhs)
+            }
+
+            (SourceType::Union(lhs_id, lhs), SourceType::Union(rhs_id, rhs))
+                if lhs_id == rhs_id =>
+            {
+                self.unify_all(lhs, rhs)
+            }
+
+            (SourceType::Tuple(lhs), SourceType::Tuple(rhs)) => self.unify_all(lhs, rhs),
+
+            (SourceType::Lambda(lhs_params, lhs_ret), SourceType::Lambda(rhs_params, rhs_ret)) => {
+                self.unify_all(lhs_params, rhs_params);
+                self.unify(lhs_ret, rhs_ret);
+            }
+
+            _ => {}
+        }
+    }
+
+    fn unify_all(&mut self, declared: &SourceTypeArray, actual: &SourceTypeArray) {
+        if declared.len() != actual.len() {
+            return;
+        }
+
+        for (declared, actual) in declared.iter().zip(actual.iter()) {
+            self.unify(&declared, &actual);
+        }
+    }
+
+    // Specializes `declared` with the type arguments bound so far. Parts that
+    // still depend on unbound type params are unknown and become `Any`.
+    pub fn specialize(&self, declared: &SourceType) -> SourceType {
+        match declared {
+            SourceType::TypeParam(id) => self
+                .args
+                .get(id.to_usize())
+                .cloned()
+                .flatten()
+                .unwrap_or(SourceType::Any),
+
+            SourceType::This | SourceType::Assoc(_) => SourceType::Any,
+
+            SourceType::Class(id, params) => SourceType::Class(*id, self.specialize_all(params)),
+            SourceType::Value(id, params) => SourceType::Value(*id, self.specialize_all(params)),
+            SourceType::Enum(id, params) => SourceType::Enum(*id, self.specialize_all(params)),
+            SourceType::Trait(id, params) => SourceType::Trait(*id, self.specialize_all(params)),
+            SourceType::Union(id, params) => SourceType::Union(*id, self.specialize_all(params)),
+            SourceType::Tuple(params) => SourceType::Tuple(self.specialize_all(params)),
+
+            SourceType::Lambda(params, ret) => {
+                SourceType::Lambda(self.specialize_all(params), Box::new(self.specialize(ret)))
+            }
+
+            _ => declared.clone(),
+        }
+    }
+
+    fn specialize_all(&self, declared: &SourceTypeArray) -> SourceTypeArray {
+        SourceTypeArray::with(declared.iter().map(|ty| self.specialize(&ty)).collect())
+    }
+
+    // Returns all type arguments or the first type param that could not be inferred.
+    pub fn finish(&self) -> Result<SourceTypeArray, TypeParamId> {
+        let mut args = Vec::with_capacity(self.args.len());
+
+        for (idx, arg) in self.args.iter().enumerate() {
+            match arg {
+                Some(arg) => args.push(arg.clone()),
+                None => return Err(TypeParamId(idx)),
+            }
+        }
+
+        Ok(SourceTypeArray::with(args))
+    }
+}
+
+// Type params of a generic definition referring to themselves, used as the
+// declared type of the value a constructor or enum variant creates.
+pub fn identity_type_params(count: usize) -> SourceTypeArray {
+    SourceTypeArray::with(
+        (0..count)
+            .map(|idx| SourceType::TypeParam(TypeParamId(idx)))
+            .collect(),
+    )
+}
+
+// Whether the type is completely known, i.e. contains no unknown parts
+// left by specializing with unbound type params.
+pub fn is_known(ty: &SourceType) -> bool {
+    match ty {
+        SourceType::Any => false,
+
+        SourceType::Class(_, params)
+        | SourceType::Value(_, params)
+        | SourceType::Enum(_, params)
+        | SourceType::Trait(_, params)
+        | SourceType::Union(_, params)
+        | SourceType::Tuple(params) => params.iter().all(|param| is_known(&param)),
+
+        SourceType::Lambda(params, ret) => {
+            params.iter().all(|param| is_known(&param)) && is_known(ret)
+        }
+
+        _ => true,
+    }
+}
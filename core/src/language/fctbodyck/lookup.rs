@@ -1,8 +1,9 @@
 use crate::language::error::msg::ErrorMessage;
-use crate::language::fctbodyck::body::{arg_names_valid, args_compatible_fct};
+use crate::language::fctbodyck::body::args_compatible_fct;
 use crate::language::sem_analysis::{
-    find_methods_in_class, find_methods_in_enum, find_methods_in_value, FctDefinition,
-    FctDefinitionId, SemAnalysis, SourceFileId, TraitDefinitionId, TypeParamDefinition,
+    all_super_traits, find_methods_in_class, find_methods_in_enum, find_methods_in_value,
+    ArgumentError, CallArgument, FctDefinition, FctDefinitionId, SemAnalysis, SourceFileId,
+    TraitDefinitionId, TypeParamDefinition,
 };
 use crate::language::specialize::replace_type_param;
 use crate::language::ty::{SourceType, SourceTypeArray};
@@ -15,7 +16,7 @@ use core_parser::lexer::position::Position;
 enum LookupKind {
     Method(SourceType),
     Static(SourceType),
-    Trait(TraitDefinitionId),
+    Trait(TraitDefinitionId, SourceType),
     Callee(FctDefinitionId),
 }
 
@@ -38,6 +39,7 @@ pub struct MethodLookup<'a> {
     found_class_type: Option<SourceType>,
     found_ret: Option<SourceType>,
     found_container_type_params: Option<SourceTypeArray>,
+    found_arguments: Option<Vec<CallArgument>>,
 
     found_multiple_functions: bool,
 }
@@ -63,6 +65,7 @@ impl<'a> MethodLookup<'a> {
             found_class_type: None,
             found_ret: None,
             found_container_type_params: None,
+            found_arguments: None,
 
             found_multiple_functions: false,
         }
@@ -75,7 +78,7 @@ impl<'a> MethodLookup<'a> {
 
     pub fn method(mut self, obj: SourceType) -> MethodLookup<'a> {
         self.kind = if let SourceType::Trait(trait_id, _) = obj {
-            Some(LookupKind::Trait(trait_id))
+            Some(LookupKind::Trait(trait_id, obj))
         } else {
             Some(LookupKind::Method(obj))
         };
@@ -145,9 +148,9 @@ impl<'a> MethodLookup<'a> {
                 self.find_method(obj.clone(), name, false, self.is_nullary)
             }
 
-            LookupKind::Trait(trait_id) => {
+            LookupKind::Trait(_, ref trait_ty) => {
                 let name = self.name.expect("name not set");
-                self.find_method_in_trait(trait_id, name, false)
+                self.find_method_in_trait(trait_ty.clone(), name, false)
             }
 
             LookupKind::Static(ref obj) => {
@@ -181,7 +184,7 @@ impl<'a> MethodLookup<'a> {
                     }
                 }
 
-                LookupKind::Trait(trait_id) => {
+                LookupKind::Trait(trait_id, _) => {
                     let trait_ = &self.sa.traits[trait_id];
                     let trait_ = trait_.read();
                     let type_name = self.sa.interner.str(trait_.name).to_string();
@@ -221,6 +224,16 @@ impl<'a> MethodLookup<'a> {
 
         let type_params = container_tps.connect(&fct_tps);
 
+        // associated types of trait objects are resolved with the bindings of the object type
+        let self_ty = match kind {
+            LookupKind::Trait(_, _) => self.found_class_type.clone(),
+            // default methods of traits use the implementing type as Self
+            LookupKind::Method(_) | LookupKind::Static(_) if fct.parent.is_trait() => {
+                self.found_class_type.clone()
+            }
+            _ => None,
+        };
+
         if !self.check_tps(&fct.type_params, &type_params) {
             return false;
         }
@@ -229,7 +242,52 @@ impl<'a> MethodLookup<'a> {
             return false;
         }
 
-        if !args_compatible_fct(self.sa, &*fct, arg_types, &type_params, None) {
+        if self.arg_names.is_empty() {
+            self.arg_names = vec![&None; arg_types.len()];
+        }
+
+        let arguments = match fct.bind_arguments(&self.arg_names) {
+            Ok(arguments) => arguments,
+
+            Err(ArgumentError::TooMany) | Err(ArgumentError::Missing(_))
+                if self.arg_names.iter().all(|name| name.is_none()) =>
+            {
+                // Report the wrong number of arguments as incompatible types.
+                (0..arg_types.len()).map(CallArgument::Arg).collect()
+            }
+
+            Err(err) => {
+                if !self.report_errors {
+                    return false;
+                }
+
+                let msg = self.argument_error_message(&*fct, err);
+                self.sa
+                    .diag
+                    .lock()
+                    .report(self.file, self.pos.expect("pos not set"), msg);
+                return false;
+            }
+        };
+
+        // Parameters without argument use the declared type of their default value.
+        let params = fct.params_without_self();
+        let bound_arg_types = arguments
+            .iter()
+            .enumerate()
+            .map(|(param_idx, argument)| match argument {
+                CallArgument::Arg(idx) => arg_types[*idx].clone(),
+                CallArgument::Default(_) => params[param_idx].clone(),
+            })
+            .collect::<Vec<_>>();
+
+        if !args_compatible_fct(
+            self.sa,
+            &*fct,
+            &bound_arg_types,
+            &type_params,
+            self_ty.clone(),
+        ) {
             if !self.report_errors {
                 return false;
             }
@@ -253,22 +311,19 @@ impl<'a> MethodLookup<'a> {
                 .report(self.file, self.pos.expect("pos not set"), msg);
             return false;
         }
-        if !arg_names_valid(&fct.param_names, &self.arg_names) {
-            if !self.report_errors {
-                return false;
-            }
 
-            let msg = self.argument_name_mismatch_message(&*fct);
-            self.sa
-                .diag
-                .lock()
-                .report(self.file, self.pos.expect("pos not set"), msg);
-            return false;
+        let in_order = arguments
+            .iter()
+            .enumerate()
+            .all(|(param_idx, argument)| *argument == CallArgument::Arg(param_idx));
+
+        if !in_order {
+            self.found_arguments = Some(arguments);
         }
 
         let cmp_type = {
             let type_list = container_tps.connect(&fct_tps);
-            replace_type_param(self.sa, fct.return_type.clone(), &type_list, None)
+            replace_type_param(self.sa, fct.return_type.clone(), &type_list, self_ty)
         };
 
         if self.ret.is_none() || self.ret.clone().unwrap() == cmp_type {
@@ -279,6 +334,25 @@ impl<'a> MethodLookup<'a> {
         }
     }
 
+    fn argument_error_message(&mut self, fct: &FctDefinition, err: ArgumentError) -> ErrorMessage {
+        let fct_name = self.sa.interner.str(fct.name).to_string();
+
+        match err {
+            ArgumentError::UnknownName | ArgumentError::TooMany => {
+                self.argument_name_mismatch_message(fct)
+            }
+            ArgumentError::PositionalAfterNamed => ErrorMessage::PositionalArgumentAfterNamed,
+            ArgumentError::Duplicate(name) => {
+                let name = self.sa.interner.str(name).to_string();
+                ErrorMessage::DuplicateArgument(fct_name, name)
+            }
+            ArgumentError::Missing(name) => {
+                let name = self.sa.interner.str(name).to_string();
+                ErrorMessage::MissingArgument(fct_name, name)
+            }
+        }
+    }
+
     fn argument_name_mismatch_message(&mut self, fct: &FctDefinition) -> ErrorMessage {
         let fct_name = self.sa.interner.str(fct.name).to_string();
         let param_names = fct
@@ -365,16 +439,28 @@ impl<'a> MethodLookup<'a> {
         }
     }
 
+    // Methods of supertraits can be called on trait objects as well, the trait
+    // that defines the method is remembered as found class type.
     fn find_method_in_trait(
         &mut self,
-        trait_id: TraitDefinitionId,
+        trait_ty: SourceType,
         name: Name,
         is_static: bool,
     ) -> Option<FctDefinitionId> {
-        let trait_ = &self.sa.traits[trait_id];
-        let trait_ = trait_.read();
+        let candidates =
+            std::iter::once(trait_ty.clone()).chain(all_super_traits(self.sa, &trait_ty));
+
+        for trait_ty in candidates {
+            let trait_id = trait_ty.trait_id().expect("trait expected");
+            let trait_ = self.sa.traits[trait_id].read();
 
-        trait_.find_method(self.sa, name, is_static)
+            if let Some(fct_id) = trait_.find_method(self.sa, name, is_static) {
+                self.found_class_type = Some(trait_ty);
+                return Some(fct_id);
+            }
+        }
+
+        None
     }
 
     fn check_tps(&self, specified_tps: &TypeParamDefinition, tps: &SourceTypeArray) -> bool {
@@ -402,4 +488,9 @@ impl<'a> MethodLookup<'a> {
     pub fn found_ret(&self) -> Option<SourceType> {
         self.found_ret.clone()
     }
+
+    // Only set when arguments need to be reordered or default values passed.
+    pub fn found_arguments(&self) -> Option<Vec<CallArgument>> {
+        self.found_arguments.clone()
+    }
 }
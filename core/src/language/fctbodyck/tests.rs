@@ -250,6 +250,41 @@ fn type_while() {
     );
 }
 
+#[test]
+fn type_break_and_continue() {
+    ok("fun x(): Unit { while true { break; } }");
+    ok("fun x(): Unit { while true { continue; } }");
+    ok("fun x(): Unit { for i in std::range(0i32, 3i32) { if i == 1i32 { break; } } }");
+    ok("fun x(): Unit { outer: while true { while true { break outer; } } }");
+    ok("fun x(): Unit { outer: for i in std::range(0i32, 3i32) { while true { continue outer; } } }");
+
+    err(
+        "fun x(): Unit { break; }",
+        pos(1, 17),
+        ErrorMessage::OutsideLoop,
+    );
+    err(
+        "fun x(): Unit { continue; }",
+        pos(1, 17),
+        ErrorMessage::OutsideLoop,
+    );
+    err(
+        "fun x(): Unit { while true { ||: Unit { break; }; } }",
+        pos(1, 41),
+        ErrorMessage::OutsideLoop,
+    );
+    err(
+        "fun x(): Unit { while true { break outer; } }",
+        pos(1, 30),
+        ErrorMessage::UnknownLoopLabel("outer".into()),
+    );
+    err(
+        "fun x(): Unit { inner: while true { } while true { continue inner; } }",
+        pos(1, 52),
+        ErrorMessage::UnknownLoopLabel("inner".into()),
+    );
+}
+
 #[test]
 fn type_if() {
     ok("fun x(): Unit { if true { } }");
@@ -720,6 +755,57 @@ fn test_literal_int64_overflow() {
     ok("fun f(): Unit { let x = -9223372036854775808i64 }");
 }
 
+#[test]
+fn test_literal_fixed_width_int_overflow() {
+    ok("fun f(): Unit { let x = 127i8; let y = -128i8 }");
+    err(
+        "fun f(): Unit { let x = 128i8 }",
+        pos(1, 25),
+        ErrorMessage::NumberOverflow("Int8".into()),
+    );
+    err(
+        "fun f(): Unit { let x = -129i8 }",
+        pos(1, 26),
+        ErrorMessage::NumberOverflow("Int8".into()),
+    );
+
+    ok("fun f(): Unit { let x = 32767i16; let y = -32768i16 }");
+    err(
+        "fun f(): Unit { let x = 32768i16 }",
+        pos(1, 25),
+        ErrorMessage::NumberOverflow("Int16".into()),
+    );
+
+    ok("fun f(): Unit { let x = 65535u16 }");
+    err(
+        "fun f(): Unit { let x = 65536u16 }",
+        pos(1, 25),
+        ErrorMessage::NumberOverflow("UInt16".into()),
+    );
+
+    ok("fun f(): Unit { let x = 4294967295u32 }");
+    err(
+        "fun f(): Unit { let x = 4294967296u32 }",
+        pos(1, 25),
+        ErrorMessage::NumberOverflow("UInt32".into()),
+    );
+
+    ok("fun f(): Unit { let x = 18446744073709551615u64 }");
+    ok("fun f(): Unit { let x = 0xFFFFFFFFFFFFFFFFu64 }");
+}
+
+#[test]
+fn test_literal_fixed_width_int_expected_type() {
+    ok("fun f(): Int8 = 1");
+    ok("fun f(): UInt64 = 18446744073709551615");
+    ok("fun f(): Unit { let x: UInt16 = 65535 }");
+    err(
+        "fun f(): UInt32 = 4294967296",
+        pos(1, 19),
+        ErrorMessage::NumberOverflow("UInt32".into()),
+    );
+}
+
 #[test]
 fn test_literal_float_overflow() {
     err(
@@ -769,7 +855,7 @@ fn test_generic_arguments_mismatch() {
                 let a = A()
             }",
         pos(3, 26),
-        ErrorMessage::WrongNumberTypeParams(1, 0),
+        ErrorMessage::TypeParamNotInferred("T".into(), "A".into()),
     );
 
     err(
@@ -818,7 +904,7 @@ fn test_fct_with_type_params() {
     err(
         "fun f[T](): Unit {} fun g(): Unit = f()",
         pos(1, 38),
-        ErrorMessage::WrongNumberTypeParams(1, 0),
+        ErrorMessage::TypeParamNotInferred("T".into(), "f".into()),
     );
     ok("fun f[T](): Unit {} fun g(): Unit = f[Int32]()");
     ok("fun f[T1, T2](): Unit {} fun g(): Unit = f[Int32, String]()");
@@ -942,6 +1028,108 @@ fn test_const_values() {
     );
 }
 
+#[test]
+fn test_const_expressions() {
+    ok_with_test(
+        "  const mask: Int64 = 1i64.shiftLeft(48i32) - 1
+                        const half: Int32 = (later + 1i32) / 2i32
+                        const later: Int32 = 41i32
+                        const name: String = \"core\" + \"-\" + \"lang\"
+                        const next: Char = ('a'.toInt32 + 1i32).toCharUnchecked
+                        const big: Bool = mask > 1000 && half == 21i32
+                        const rem: Int64 = -(7 - 2).remainder(3)
+                        const scaled: Float64 = 1.5 * 2.0",
+        |sa| {
+            let value = |name: &'static str| {
+                let id = sa.const_by_name(name);
+                let const_ = sa.consts.idx(id);
+                let value = const_.read().value.clone();
+                value
+            };
+
+            assert_eq!(ConstValue::Int((1 << 48) - 1), value("mask"));
+            assert_eq!(ConstValue::Int(21), value("half"));
+            assert_eq!(ConstValue::String("core-lang".into()), value("name"));
+            assert_eq!(ConstValue::Char('b'), value("next"));
+            assert_eq!(ConstValue::Bool(true), value("big"));
+            assert_eq!(ConstValue::Int(-2), value("rem"));
+            assert_eq!(ConstValue::Float(3.0), value("scaled"));
+        },
+    );
+
+    ok("const x: Int64 = foo::y + 1 mod foo { @pub const y: Int64 = 1 }");
+    ok("const x: Int32 = 3i32 fun f(y: Int32 = x * 2i32): Int32 = y");
+}
+
+#[test]
+fn test_const_expressions_fail() {
+    err(
+        "const x: Int64 = y + 1 const y: Int64 = x",
+        pos(1, 1),
+        ErrorMessage::ConstCycle("x".into()),
+    );
+
+    err(
+        "const x: Int32 = 2147483647i32 + 1i32",
+        pos(1, 32),
+        ErrorMessage::NumberOverflow("Int32".into()),
+    );
+
+    err(
+        "const x: UInt8 = 200u8 * 2u8",
+        pos(1, 24),
+        ErrorMessage::NumberOverflow("UInt8".into()),
+    );
+
+    ok("const x: UInt64 = 9223372036854775808u64 + 1u64");
+    ok("const x: Int8 = 127i32.toInt8");
+    err(
+        "const x: UInt64 = 0u64 - 1u64",
+        pos(1, 24),
+        ErrorMessage::NumberOverflow("UInt64".into()),
+    );
+    err(
+        "const x: Int16 = 32767i16 + 1i16",
+        pos(1, 27),
+        ErrorMessage::NumberOverflow("Int16".into()),
+    );
+
+    ok("const x: Int32 = (-2147483647i32).remainder(-1i32)");
+    err(
+        "const x: Int32 = (-2147483648i32).remainder(-1i32)",
+        pos(1, 44),
+        ErrorMessage::NumberOverflow("Int32".into()),
+    );
+    err(
+        "const x: Int16 = (-32768i16).remainder(-1i16)",
+        pos(1, 39),
+        ErrorMessage::NumberOverflow("Int16".into()),
+    );
+    err(
+        "const x: Int8 = (-128i8).remainder(-1i8)",
+        pos(1, 35),
+        ErrorMessage::NumberOverflow("Int8".into()),
+    );
+
+    err(
+        "const x: Int64 = 1 / (2 - 2)",
+        pos(1, 20),
+        ErrorMessage::ConstDivisionByZero,
+    );
+
+    err(
+        "const x: Int64 = 1 + 1i32",
+        pos(1, 20),
+        ErrorMessage::BinOpType("+".into(), "Int64".into(), "Int32".into()),
+    );
+
+    err(
+        "const x: Int64 = f() fun f(): Int64 = 1",
+        pos(1, 19),
+        ErrorMessage::ConstValueExpected,
+    );
+}
+
 #[test]
 fn test_assignment_to_const() {
     err(
@@ -963,6 +1151,22 @@ fn test_unary_minus_byte() {
     ok("const m1: Int64 = -1i64");
 }
 
+#[test]
+fn test_unary_minus_unsigned() {
+    err(
+        "const m1: UInt32 = -1u32",
+        pos(1, 20),
+        ErrorMessage::UnOpType("-".into(), "UInt32".into()),
+    );
+    err(
+        "fun f(x: UInt64): UInt64 = -x",
+        pos(1, 28),
+        ErrorMessage::UnOpType("-".into(), "UInt64".into()),
+    );
+    ok("const m1: Int8 = -1i8");
+    ok("fun f(x: Int16): Int16 = -x");
+}
+
 #[test]
 fn test_generic_trait_bounds() {
     ok("trait Foo {}
@@ -1097,13 +1301,61 @@ fn generic_trait_method_call() {
             }");
 }
 
+#[test]
+fn test_infer_type_params_from_expected_type() {
+    ok("fun f(): Option[Int32] = None");
+    ok("fun f(): Unit { let x: Option[Int32] = Option::None; }");
+    ok("fun f(): Option[Int32] = Some(1)");
+    ok("fun f(x: Option[String]): Unit {} fun g(): Unit { f(None); }");
+    ok("fun f(): (Option[Int32], Bool) = (None, true)");
+    ok("fun f(): Result[Int32, String] { if true { Ok(1) } else { Err(\"error\") } }");
+    ok("class Foo[T](x: Option[T]) fun f(): Foo[Bool] = Foo(None)");
+    ok("fun f(): Array[Int32] = Array::new()");
+    ok("fun f(): Array[UInt8] = Array::new(1, 2, 3)");
+    ok("fun f(): Unit { let x: Array[Int32] = Array::new(); }");
+
+    err(
+        "fun f(): Unit { let x = None; }",
+        pos(1, 25),
+        ErrorMessage::TypeParamNotInferred("T".into(), "std::primitives::Option::None".into()),
+    );
+    err(
+        "fun f(): Unit { let x = Array::new(); }",
+        pos(1, 35),
+        ErrorMessage::TypeParamNotInferred("T".into(), "std::collections::Array::new".into()),
+    );
+    err(
+        "class Foo[A, B](a: A) fun f(): Unit { let x = Foo(1); }",
+        pos(1, 50),
+        ErrorMessage::TypeParamNotInferred("B".into(), "Foo".into()),
+    );
+}
+
+#[test]
+fn test_infer_type_params_from_arguments() {
+    ok("fun id[T](x: T): T = x fun f(): Int32 = id(1i32)");
+    ok("fun id[T](x: T): T = x fun f(): Int64 { let x = id(1); x }");
+    ok("fun id[T](x: T): T = x fun f(): Int32 = id(1)");
+    ok("fun f(): Option[String] = Some(\"abc\")");
+    ok("fun f(): Unit { let x = Some(1i32); let y: Option[Int32] = x; }");
+    ok("class Foo[T](x: T) fun f(): Foo[String] { let x = Foo(\"abc\"); x }");
+    ok("value Foo[T](x: T) fun f(): Foo[Bool] { let x = Foo(true); x }");
+    ok("fun f(): Array[Int32] { let x = Array::new(1i32, 2i32); x }");
+
+    err(
+        "fun id[T](x: T): T = x fun f(): Unit { let x: Int32 = id(\"abc\"); }",
+        pos(1, 57),
+        ErrorMessage::ParamTypesIncompatible("id".into(), vec!["T".into()], vec!["String".into()]),
+    );
+}
+
 #[test]
 fn test_generic_ctor_without_type_params() {
     err(
         "class Foo[A, B]
             fun test(): Unit = Foo()",
         pos(2, 35),
-        ErrorMessage::WrongNumberTypeParams(2, 0),
+        ErrorMessage::TypeParamNotInferred("A".into(), "Foo".into()),
     );
 }
 
@@ -1139,11 +1391,61 @@ fn test_for_supports_make_iterator() {
             impl Foo { fun iterator(): FooIter = FooIter() }
             class FooIter
             impl std::Iterator for FooIter {
+                type Item = Int32;
                 fun next(): Option[Int32] = Some[Int32](0i32)
             }
             fun f(): Int32 { for i in Foo() { return i; } return 0i32; }");
 }
 
+#[test]
+fn test_for_iterator_bound() {
+    ok("
+            fun f[T: std::Iterator[Item = Int32]](it: T): Int32 {
+                for i in it { return i; }
+                0i32
+            }");
+
+    ok("
+            fun f(it: std::Iterator[Item = Int32]): Int32 {
+                for i in it { return i; }
+                0i32
+            }");
+
+    err(
+        "fun f[T](it: T): Unit { for i in it {} }",
+        pos(1, 34),
+        ErrorMessage::TypeNotUsableInForIn("T".into()),
+    );
+}
+
+#[test]
+fn test_for_concrete_iterator_impl() {
+    ok("
+            class Foo
+            impl std::Iterator for Foo {
+                type Item = Int64;
+                fun next(): Option[Int64] = None[Int64]
+            }
+            fun f(it: Foo): Int64 { for i in it { let x: Int64 = i; return x; } 0 }");
+
+    ok("
+            class Foo[T]
+            impl[T] std::Iterator for Foo[T] {
+                type Item = T;
+                fun next(): Option[T] = None[T]
+            }
+            fun f(it: Foo[Int32]): Int32 { for i in it { let x: Int32 = i; return x; } 0i32 }");
+
+    err(
+        "
+            class Foo
+            impl Foo { fun next(): Option[Int32] = None[Int32] }
+            fun f(): Unit { for i in Foo() {} }",
+        pos(4, 41),
+        ErrorMessage::IteratorNotImplemented("Foo".into()),
+    );
+}
+
 #[test]
 fn test_ctor_with_type_param() {
     err(
@@ -1438,6 +1740,92 @@ fn test_named_arguments_fail_value() {
     );
 }
 
+#[test]
+fn test_named_arguments_reorder() {
+    ok("
+        fun foo(x: Int64, y: Bool): Unit {}
+        fun x(): Unit = foo(y = true, x = 23)");
+    ok("
+        class Foo()
+        impl Foo { fun foo(x: Int64, y: Bool): Unit {} }
+        fun x(): Unit = Foo().foo(1, y = true)");
+    ok("
+        class Foo()
+        impl Foo { @static fun foo(x: Int64, y: Bool): Unit {} }
+        fun x(): Unit = Foo::foo(y = true, x = 1)");
+}
+
+#[test]
+fn test_default_values() {
+    ok("
+        fun foo(x: Int64, verbose: Bool = false): Unit {}
+        fun x(): Unit { foo(1); foo(1, true); foo(x = 1, true); foo(verbose = true, x = 1); }");
+    ok("
+        fun foo(x: Int32 = 1i32, y: Float64 = 2.0, z: Char = 'a'): Unit {}
+        fun x(): Unit { foo(); foo(z = 'b'); }");
+    ok("
+        class Foo()
+        impl Foo { fun foo(x: Int64 = -1): Unit {} }
+        fun x(): Unit = Foo().foo()");
+
+    err(
+        "fun foo(x: Int64, y: Bool = 1): Unit {}",
+        pos(1, 29),
+        ErrorMessage::DefaultValueType("y".into(), "Bool".into(), "Int64".into()),
+    );
+
+    err(
+        "fun foo(x: Int64 = bar()): Unit {} fun bar(): Int64 = 1",
+        pos(1, 23),
+        ErrorMessage::ConstValueExpected,
+    );
+
+    err(
+        "fun foo(x: Int64 = 1, y: Int64...): Unit {}",
+        pos(1, 20),
+        ErrorMessage::DefaultValueInVariadicFunction,
+    );
+}
+
+#[test]
+fn test_default_values_fail_call() {
+    err(
+        "
+            fun foo(x: Int64, verbose: Bool = false): Unit {}
+            fun x(): Unit = foo(verbose = true)",
+        pos(3, 32),
+        ErrorMessage::MissingArgument("foo".into(), "x".into()),
+    );
+
+    err(
+        "
+            fun foo(x: Int64, verbose: Bool = false): Unit {}
+            fun x(): Unit = foo(1, x = 2)",
+        pos(3, 32),
+        ErrorMessage::DuplicateArgument("foo".into(), "x".into()),
+    );
+
+    err(
+        "
+            fun foo(x: Int64, verbose: Bool = false): Unit {}
+            fun x(): Unit = foo(verbose = true, 1)",
+        pos(3, 32),
+        ErrorMessage::PositionalArgumentAfterNamed,
+    );
+
+    err(
+        "
+            fun foo(x: Int64, verbose: Bool = false): Unit {}
+            fun x(): Unit = foo(1, 2)",
+        pos(3, 32),
+        ErrorMessage::ParamTypesIncompatible(
+            "foo".into(),
+            vec!["Int64".into(), "Bool".into()],
+            vec!["Int64".into(), "Int64".into()],
+        ),
+    );
+}
+
 #[test]
 fn test_array_syntax_get() {
     ok("fun f(t: Array[Int32]): Int32 = t(0)");
@@ -1500,6 +1888,31 @@ fn test_trait_object_as_argument() {
     );
 }
 
+#[test]
+fn test_trait_default_method() {
+    ok(
+        "trait Foo { fun foo(): Int32; fun bar(): Int32 { self.foo() } }
+        class A
+        impl Foo for A { fun foo(): Int32 = 1i32 }
+        fun f(a: A): Int32 = a.bar()
+        fun g[T: Foo](x: T): Int32 = x.bar()
+        fun h(x: Foo): Int32 = x.bar()",
+    );
+    ok("trait Foo { fun me(): Self { self } }
+        class A
+        impl Foo for A {}
+        fun f(a: A): A = a.me()");
+    err(
+        "trait Foo { fun me(): Self { self } }
+        class A
+        class B
+        impl Foo for A {}
+        fun f(a: A): B = a.me()",
+        pos(5, 30),
+        ErrorMessage::ReturnType("B".into(), "A".into()),
+    );
+}
+
 #[test]
 fn test_type_param_used_as_value() {
     err(
@@ -1704,13 +2117,17 @@ fn test_value_with_type_params() {
         value Foo[T](f1: Int32)
         fun f(): Foo[Int32] { Foo[Int32](1i32) }
     ");
+    ok("
+        value Foo[T](f1: Int32)
+        fun f(): Foo[Int32] { Foo(1i32) }
+    ");
     err(
         "
         value Foo[T](f1: Int32)
-        fun f(): Foo[Int32] { Foo(1i32) }
+        fun f(): Unit { Foo(1i32); }
     ",
-        pos(3, 34),
-        ErrorMessage::WrongNumberTypeParams(1, 0),
+        pos(3, 28),
+        ErrorMessage::TypeParamNotInferred("T".into(), "Foo".into()),
     );
     err(
         "
@@ -1744,13 +2161,9 @@ fn test_value_with_type_params() {
         pos(3, 29),
         ErrorMessage::ReturnType("Foo[Int32]".into(), "Foo[Bool]".into()),
     );
-    err(
-        "
+    ok("
         value Foo[T](f1: T, f2: Bool)
-        fun f[T](val: T): Foo[T] { Foo(val, false) }",
-        pos(3, 39),
-        ErrorMessage::WrongNumberTypeParams(1, 0),
-    );
+        fun f[T](val: T): Foo[T] { Foo(val, false) }");
 }
 
 #[test]
@@ -1840,10 +2253,12 @@ fn test_enum() {
         ErrorMessage::UnknownEnumVariant("V3".into()),
     );
 
+    ok("enum A[T] { V1, V2 } fun f(): A[Int32] = A::V1");
+
     err(
-        "enum A[T] { V1, V2 } fun f(): A[Int32] = A::V1",
-        pos(1, 43),
-        ErrorMessage::WrongNumberTypeParams(1, 0),
+        "enum A[T] { V1, V2 } fun f(): Unit { A::V1; }",
+        pos(1, 39),
+        ErrorMessage::TypeParamNotInferred("T".into(), "A::V1".into()),
     );
 
     err(
@@ -2010,6 +2425,159 @@ fn test_enum_if_else() {
     ");
 }
 
+#[test]
+fn test_union_if_pattern() {
+    ok("
+        class A(a: Int32) class B(b: Int32)
+        union U of A, B;
+        fun f(x: U): Int32 = if x
+          ... is A(a) { a.a }
+          ... is B(b) { b.b }
+    ");
+
+    ok("
+        class A[T](a: T) class B
+        union U[T] of A[T], B;
+        fun f(x: U[Int32]): Int32 = if x
+          ... is A(a) { a.a }
+          ... is B    { 0i32 }
+    ");
+
+    ok("
+        class A class B
+        union U of A, B;
+        fun f(x: U): Bool = if x
+          ... is A { true  }
+          else     { false }
+    ");
+
+    err(
+        "
+        class A class B class C
+        union U of A, B, C;
+        fun f(x: U): Int32 = if x
+          ... is A { 1i32 }
+          ... is B { 2i32 }
+    ",
+        pos(4, 30),
+        ErrorMessage::IfPatternVariantUncovered,
+    );
+
+    err(
+        "
+        class A class B
+        union U of A, B;
+        fun f(x: U): Int32 = if x
+          ... is A { 1i32 }
+          ... is B { 2i32 }
+          ... is A { 3i32 }
+    ",
+        pos(7, 18),
+        ErrorMessage::IfPatternUnreachable,
+    );
+
+    err(
+        "
+        class A class B class C
+        union U of A, B;
+        fun f(x: U): Int32 = if x
+          ... is A { 1i32 }
+          ... is C { 2i32 }
+          ... is B { 3i32 }
+    ",
+        pos(6, 18),
+        ErrorMessage::UnionVariantExpected("U".into(), "C".into()),
+    );
+
+    err(
+        "
+        class A class B
+        union U of A, B;
+        fun f(x: U): Int32 = if x
+          ... is A(a, b) { 1i32 }
+          ... is B       { 2i32 }
+    ",
+        pos(5, 18),
+        ErrorMessage::IfPatternWrongNumberOfParams(2, 1),
+    );
+}
+
+#[test]
+fn test_union_allows_variant() {
+    ok("
+        class A class B
+        union U of A, B;
+        fun f(): U = A();
+        fun g(): Unit { h(B()); let x: U = A(); }
+        fun h(x: U): Unit {}
+    ");
+
+    ok("
+        class A[T] class B
+        union U[T] of A[T], B;
+        fun f(): U[Int32] = A[Int32]();
+    ");
+
+    err(
+        "
+        class A class B class C
+        union U of A, B;
+        fun f(): U = C();
+    ",
+        pos(4, 23),
+        ErrorMessage::ReturnType("U".into(), "C".into()),
+    );
+
+    err(
+        "
+        class A[T] class B
+        union U[T] of A[T], B;
+        fun f(): U[Int32] = A[Int64]();
+    ",
+        pos(4, 37),
+        ErrorMessage::ReturnType("U[Int32]".into(), "A[Int64]".into()),
+    );
+}
+
+#[test]
+fn test_union_value_variant() {
+    ok("
+        class A value V(x: Int32)
+        union U of A, V;
+        fun f(): U = V(1i32) as U;
+        fun g(): U = A() as U;
+        fun h(x: U): Int32 = if x
+          ... is A { 0i32 }
+          ... is V(v) { v.x }
+    ");
+
+    ok("
+        value V[T](x: T) class B
+        union U[T] of V[T], B;
+        fun f[T](x: T): U[T] = V[T](x) as U[T];
+    ");
+
+    err(
+        "
+        class A value V(x: Int32)
+        union U of A, V;
+        fun f(): U = V(1i32);
+    ",
+        pos(4, 23),
+        ErrorMessage::ReturnType("U".into(), "V".into()),
+    );
+
+    err(
+        "
+        class A value V(x: Int32) value W(x: Int32)
+        union U of A, V;
+        fun f(): U = W(1i32) as U;
+    ",
+        pos(4, 30),
+        ErrorMessage::UnionVariantExpected("U".into(), "W".into()),
+    );
+}
+
 #[test]
 fn test_enum_equals() {
     ok("
@@ -2027,6 +2595,44 @@ fn test_enum_equals() {
     );
 }
 
+#[test]
+fn test_enum_ordinal() {
+    ok("
+        enum A { V1 = 1, V2 }
+        fun f(x: A): Int32 = x.ordinal
+        fun g(): Array[A] = A::values()
+        fun h(x: Int32): Option[A] = A::fromOrdinal(x)
+    ");
+
+    ok("
+        enum A { V1, V2 }
+        impl A { fun ordinal: Int64 = 1 }
+        fun f(x: A): Int64 = x.ordinal
+    ");
+
+    err(
+        "
+        enum A { V1(Int32), V2 }
+        fun f(x: A): Int32 = x.ordinal
+    ",
+        pos(3, 31),
+        ErrorMessage::UnknownField("ordinal".into(), "A".into()),
+    );
+
+    err(
+        "
+        enum A { V1, V2 }
+        fun f(): Option[A] = A::fromOrdinal(1)
+    ",
+        pos(3, 44),
+        ErrorMessage::ParamTypesIncompatible(
+            "fromOrdinal".into(),
+            vec!["Int32".into()],
+            vec!["Int64".into()],
+        ),
+    );
+}
+
 #[test]
 fn test_use_enum_value() {
     ok("enum A { V1(Int32), V2 } use A.V1; fun f(): A = V1(1i32)");
@@ -3534,6 +4140,67 @@ fn trait_object_cast() {
     );
 }
 
+#[test]
+fn trait_object_downcast() {
+    ok("
+        trait Foo { fun bar(): Int32; }
+        class Bar
+        impl Foo for Bar {
+            fun bar(): Int32 = 1i32
+        }
+        fun f(x: Foo): Bool = x is Bar
+        fun g(x: Foo): Option[Bar] = x as Bar
+    ");
+
+    ok("
+        trait Foo { fun bar(): Int32; }
+        fun f[T: Foo](x: Foo): Bool = x is T
+        fun g[T: Foo](x: Foo): Option[T] = x as T
+    ");
+
+    err(
+        "
+        trait Foo { fun bar(): Int32; }
+        class Bar
+        fun f(x: Foo): Bool = x is Bar
+    ",
+        pos(4, 33),
+        ErrorMessage::TypeNotImplementingTrait("Bar".into(), "Foo".into()),
+    );
+
+    err(
+        "
+        trait Foo { fun bar(): Int32; }
+        class Bar
+        fun f(x: Bar): Bool = x is Bar
+    ",
+        pos(4, 33),
+        ErrorMessage::TraitObjectExpected("Bar".into()),
+    );
+
+    err(
+        "
+        trait Foo { fun bar(): Int32; }
+        class Bar
+        impl Foo for Bar {
+            fun bar(): Int32 = 1i32
+        }
+        fun f(x: Foo): Bar = x as Bar
+    ",
+        pos(7, 32),
+        ErrorMessage::ReturnType("Bar".into(), "Option[Bar]".into()),
+    );
+
+    err(
+        "
+        class Bar
+        fun f(x: Bar): Bar = x as Bar
+    ",
+        pos(3, 32),
+        ErrorMessage::TraitExpected("Bar".into()),
+    );
+}
+
 #[test]
 fn infer_enum_type() {
     ok("fun f(): Option[Int32] = None");
@@ -3630,6 +4297,37 @@ fn lambda_closure() {
     );
 }
 
+#[test]
+fn lambda_infer_types() {
+    ok("fun f(): (Int32): Int32 = |x| { x }");
+    ok("fun f(): (Int32, Int32): Bool = |a, b| { a == b }");
+    ok("fun f(): Int32 { let g = |x: Int32| { x + 1i32 }; g(1i32) }");
+    ok("fun f(): Int64 { let g = || { return 1; 2 }; g() }");
+    ok("fun f(g: (Int64): Int64): Unit {} fun h(): Unit { f(|x| { x * 2 }); }");
+    ok("fun f(x: Array[Int32]): Bool = x.all(|e| { e > 0i32 })");
+    ok("fun f(x: Array[Int32]): Array[String] = x.map(|e| { e.toString() })");
+    ok("fun f(x: List[Int32]): List[Int64] = x.map(|e| { e.toInt64 })");
+    ok("fun f(x: std::Mutex): Int32 = x.lock(|| { 1i32 })");
+
+    err(
+        "fun f(): Unit { let g = |x| { x }; }",
+        pos(1, 26),
+        ErrorMessage::LambdaParamTypeNotInferred("x".into()),
+    );
+
+    err(
+        "fun f(): Unit { let g = || { if true { return 1i32; } false }; }",
+        pos(1, 28),
+        ErrorMessage::ReturnType("Int32".into(), "Bool".into()),
+    );
+
+    err(
+        "fun f(): (Int32): Int32 = |x| { false }",
+        pos(1, 31),
+        ErrorMessage::ReturnType("Int32".into(), "Bool".into()),
+    );
+}
+
 #[test]
 fn internal_class_ctor() {
     err(
@@ -3665,3 +4363,64 @@ fn self_unavailable_in_lambda() {
         ErrorMessage::ThisUnavailable,
     );
 }
+
+#[test]
+fn super_trait_methods() {
+    ok("
+        trait Foo { fun foo(): Int32; }
+        trait Bar: Foo { fun bar(): Int32 = self.foo() + 1i32; }
+        fun f[T: Bar](x: T): Int32 = x.foo() + x.bar()
+        fun g(x: Bar): Int32 = x.foo() + x.bar()
+    ");
+
+    ok("
+        trait Foo { fun foo(): Int32; }
+        trait Bar: Foo {}
+        fun f[T: Foo](x: T): Int32 = x.foo()
+        fun g[T: Bar](x: T): Int32 = f[T](x)
+    ");
+
+    err(
+        "
+        trait Foo { fun foo(): Int32; }
+        trait Bar { fun bar(): Int32; }
+        fun f(x: Bar): Int32 = x.foo()
+    ",
+        pos(4, 37),
+        ErrorMessage::UnknownMethod("Bar".into(), "foo".into(), Vec::new()),
+    );
+}
+
+#[test]
+fn try_operator() {
+    ok("fun f(x: Option[Int32]): Option[Int64] { let y: Int32 = x?; None }");
+    ok("fun f(x: Result[Int32, String]): Result[Bool, String] { x?; Ok(true) }");
+    ok("fun f[T](x: Option[T]): Option[T] { Some[T](x?) }");
+
+    err(
+        "fun f(x: Int32): Option[Int32] { x? }",
+        pos(1, 35),
+        ErrorMessage::TryOperandExpected("Int32".into()),
+    );
+
+    err(
+        "fun f(x: Option[Int32]): Int32 { x? }",
+        pos(1, 35),
+        ErrorMessage::TryReturnType("Option[Int32]".into(), "Int32".into()),
+    );
+
+    err(
+        "fun f(x: Option[Int32]): Result[Int32, String] { Ok(x?) }",
+        pos(1, 54),
+        ErrorMessage::TryReturnType("Option[Int32]".into(), "Result[Int32, String]".into()),
+    );
+
+    err(
+        "fun f(x: Result[Int32, String]): Result[Int32, Int64] { x?; Ok(1i32) }",
+        pos(1, 58),
+        ErrorMessage::TryReturnType(
+            "Result[Int32, String]".into(),
+            "Result[Int32, Int64]".into(),
+        ),
+    );
+}
@@ -1,12 +1,16 @@
 use std::collections::HashSet;
 
 use crate::language::error::msg::ErrorMessage;
+use crate::language::fctbodyck::constck::ConstCheck;
 use crate::language::sem_analysis::{
-    FctDefinition, FctDefinitionId, FctParent, SemAnalysis, TypeParamId,
+    ConstValue, FctDefinition, FctDefinitionId, FctParent, SemAnalysis, TypeParamId,
 };
 use crate::language::sym::{ModuleSymTable, Sym};
 use crate::language::ty::SourceType;
-use crate::language::{self, AllowSelf, TypeParamContext};
+use crate::language::{self, type_params, AllowSelf, TypeParamContext};
+
+use core_parser::ast;
+use core_parser::interner::Name;
 
 pub fn check(sa: &SemAnalysis) {
     for fct in sa.fcts.iter() {
@@ -53,6 +57,8 @@ pub fn check(sa: &SemAnalysis) {
             sym_table.insert(name, Sym::TypeParam(id));
         }
 
+        add_assoc_types(sa, fct.parent.clone(), &mut sym_table);
+
         let container_type_params = fct.type_params.len();
         fct.container_type_params = container_type_params;
 
@@ -97,6 +103,12 @@ pub fn check(sa: &SemAnalysis) {
                         }
                     }
 
+                    type_params::add_implied_bounds(
+                        sa,
+                        &mut fct.type_params,
+                        TypeParamId(container_type_params + type_param_id),
+                    );
+
                     let sym = Sym::TypeParam(TypeParamId(container_type_params + type_param_id));
                     sym_table.insert(type_param.name, sym);
                 }
@@ -119,7 +131,7 @@ pub fn check(sa: &SemAnalysis) {
                 sa,
                 &sym_table,
                 fct.file_id,
-                &p.data_type,
+                p.data_type.as_ref().expect("missing type"),
                 TypeParamContext::Fct(&*fct),
                 if fct.in_trait() {
                     AllowSelf::Yes
@@ -129,13 +141,28 @@ pub fn check(sa: &SemAnalysis) {
             )
             .unwrap_or(SourceType::Error);
 
+            let default_value = p
+                .default_value
+                .as_ref()
+                .map(|expr| check_default_value(sa, &*fct, p.name, &ty, expr));
+
             fct.param_types.push(ty);
+            fct.param_defaults.push(default_value);
 
             if p.variadic {
                 fct.is_variadic = true;
             }
         }
 
+        if fct.is_variadic {
+            for p in &ast.params {
+                if let Some(ref default_value) = p.default_value {
+                    let msg = ErrorMessage::DefaultValueInVariadicFunction;
+                    sa.diag.lock().report(fct.file_id, default_value.pos(), msg);
+                }
+            }
+        }
+
         if let Some(ret) = ast.return_type.as_ref() {
             let ty = language::read_type(
                 sa,
@@ -176,6 +203,51 @@ pub fn check(sa: &SemAnalysis) {
     }
 }
 
+// Associated types of the surrounding trait or impl can be used in
+// signatures and bodies of methods, either as `Self::Item` or as `Item`.
+pub fn add_assoc_types(sa: &SemAnalysis, parent: FctParent, sym_table: &mut ModuleSymTable) {
+    let aliases = match parent {
+        FctParent::Trait(trait_id) => sa.traits[trait_id].read().aliases.clone(),
+        FctParent::Impl(impl_id) => sa.impls[impl_id].read().aliases.clone(),
+        _ => return,
+    };
+
+    for alias_id in aliases {
+        let name = sa.aliases[alias_id].read().name;
+        sym_table.insert(name, Sym::Alias(alias_id));
+    }
+}
+
+// Default values are evaluated at compile time and passed by the caller
+// whenever the argument is left out.
+fn check_default_value(
+    sa: &SemAnalysis,
+    fct: &FctDefinition,
+    name: Name,
+    ty: &SourceType,
+    expr: &ast::Expr,
+) -> ConstValue {
+    let mut constck = ConstCheck {
+        sa,
+        file_id: fct.file_id,
+        symtable: ModuleSymTable::new(sa, fct.module_id),
+        module_id: fct.module_id,
+        expected_ty: ty.clone(),
+    };
+
+    let (value_ty, value) = constck.check_expr(expr);
+
+    if !value_ty.is_error() && !ty.allows(sa, value_ty.clone()) {
+        let name = sa.interner.str(name).to_string();
+        let ty = ty.name_fct(sa, fct);
+        let value_ty = value_ty.name_fct(sa, fct);
+        let msg = ErrorMessage::DefaultValueType(name, ty, value_ty);
+        sa.diag.lock().report(fct.file_id, expr.pos(), msg);
+    }
+
+    value
+}
+
 fn check_test(sa: &SemAnalysis, fct: &FctDefinition) {
     debug_assert!(fct.initialized);
 
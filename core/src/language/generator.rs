@@ -2,33 +2,48 @@ use std::collections::HashMap;
 use std::convert::TryInto;
 
 use core_parser::ast;
+use core_parser::interner::Name;
 use core_parser::lexer::position::Position;
 
 use crate::bytecode::{
     BytecodeBuilder, BytecodeFunction, BytecodeType, ConstPoolIdx, Label, Register,
 };
+use crate::language::matchck::{self, Ctor, Pat};
+use crate::language::sem_analysis::ValueDefinitionFieldId;
 use crate::language::sem_analysis::{
-    find_impl, AnalysisData, CallType, ClassDefinitionId, ConstDefinitionId, ContextIdx,
-    EnumDefinitionId, FctDefinition, FctDefinitionId, FieldId, GlobalDefinitionId, IdentType,
-    Intrinsic, SemAnalysis, TypeParamId, ValueDefinitionId, VarId,
+    default_method_type_params, find_impl, impl_trait_ty, AnalysisData, CallArgument, CallType,
+    ClassDefinitionId, ConstDefinitionId, ConstValue, ContextIdx, EnumDefinitionId, FctDefinition,
+    FctDefinitionId, FieldId, GlobalDefinitionId, IdentType, Intrinsic, SemAnalysis, TypeParamId,
+    ValueDefinitionId, VarId,
 };
-use crate::language::specialize::specialize_type;
+use crate::language::specialize::{replace_assoc_types, replace_type_param, specialize_type};
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::language::{expr_always_returns, expr_block_always_returns};
 
 use super::sem_analysis::VarLocation;
 
 pub struct LoopLabels {
+    name: Option<Name>,
     cond: Label,
     end: Label,
 }
 
 impl LoopLabels {
-    fn new(cond: Label, end: Label) -> LoopLabels {
-        LoopLabels { cond, end }
+    fn new(name: Option<Name>, cond: Label, end: Label) -> LoopLabels {
+        LoopLabels { name, cond, end }
     }
 }
 
+// Row of the pattern matrix used while building the decision tree for a
+// match expression. Bindings are collected while columns are removed.
+struct MatchRow {
+    pats: Vec<Pat>,
+    case_idx: usize,
+    bindings: Vec<(ast::NodeId, Register)>,
+}
+
+type MatchOccurrence = (Register, SourceType);
+
 pub fn generate_fct(sa: &SemAnalysis, id: FctDefinitionId) -> BytecodeFunction {
     let fct = sa.fcts.idx(id);
     let fct = fct.read();
@@ -74,7 +89,7 @@ impl<'a> AstBytecodeGen<'a> {
 
         if self.fct.has_self() {
             let var_self = self.analysis.vars.get_self();
-            let var_ty = var_self.ty.clone();
+            let var_ty = self.specialize_self_ty(var_self.ty.clone());
 
             let bty = bty_from_ty(var_ty.clone());
             params.push(bty);
@@ -154,7 +169,9 @@ impl<'a> AstBytecodeGen<'a> {
         let return_type = if self.fct.return_type.is_unit() {
             None
         } else {
-            Some(bty_from_ty(self.fct.return_type.clone()))
+            Some(bty_from_ty(
+                self.specialize_self_ty(self.fct.return_type.clone()),
+            ))
         };
         self.builder.set_return_type(return_type);
 
@@ -218,6 +235,30 @@ impl<'a> AstBytecodeGen<'a> {
             }
             ast::Stmt::While(ref stmt) => self.visit_stmt_while(stmt),
             ast::Stmt::For(ref stmt) => self.visit_stmt_for(stmt),
+            ast::Stmt::Break(ref stmt) => self.visit_stmt_break(stmt),
+            ast::Stmt::Continue(ref stmt) => self.visit_stmt_continue(stmt),
+        }
+    }
+
+    fn visit_stmt_break(&mut self, stmt: &ast::StmtBreakType) {
+        let end = self.find_loop(stmt.label).end;
+        self.builder.emit_jump(end);
+    }
+
+    fn visit_stmt_continue(&mut self, stmt: &ast::StmtContinueType) {
+        let cond = self.find_loop(stmt.label).cond;
+        self.builder.emit_jump_loop(cond);
+    }
+
+    fn find_loop(&self, label: Option<Name>) -> &LoopLabels {
+        match label {
+            Some(label) => self
+                .loops
+                .iter()
+                .rev()
+                .find(|lbl| lbl.name == Some(label))
+                .expect("loop label not found"),
+            None => self.loops.last().expect("not inside loop"),
         }
     }
 
@@ -404,7 +445,6 @@ impl<'a> AstBytecodeGen<'a> {
         self.builder.emit_loop_start();
 
         let iterator_type = for_type_info.iterator_type.clone();
-        let iterator_type_params = iterator_type.type_params();
 
         self.builder.emit_push_register(iterator_reg);
 
@@ -417,17 +457,47 @@ impl<'a> AstBytecodeGen<'a> {
         let next_result_ty = register_bty_from_ty(for_type_info.next_type.clone());
         let next_result_reg = self.alloc_temp(next_result_ty);
 
-        let fct_idx = self
-            .builder
-            .add_const_fct_types(for_type_info.next, iterator_type_params);
-
         self.builder.emit_push_register(iterator_reg);
-        self.emit_invoke_direct(
-            for_type_info.next_type.clone(),
-            next_result_reg,
-            fct_idx,
-            stmt.expr.pos(),
-        );
+
+        match iterator_type {
+            SourceType::TypeParam(id) => {
+                let fct_idx = self.builder.add_const_generic(
+                    id,
+                    for_type_info.next,
+                    SourceTypeArray::empty(),
+                );
+                self.emit_invoke_generic_direct(
+                    for_type_info.next_type.clone(),
+                    next_result_reg,
+                    fct_idx,
+                    stmt.expr.pos(),
+                );
+            }
+
+            SourceType::Trait(_, _) => {
+                let fct_idx = self
+                    .builder
+                    .add_const_fct_types(for_type_info.next, SourceTypeArray::empty());
+                self.emit_invoke_virtual(
+                    for_type_info.next_type.clone(),
+                    next_result_reg,
+                    fct_idx,
+                    stmt.expr.pos(),
+                );
+            }
+
+            _ => {
+                let fct_idx = self
+                    .builder
+                    .add_const_fct_types(for_type_info.next, for_type_info.next_type_params);
+                self.emit_invoke_direct(
+                    for_type_info.next_type.clone(),
+                    next_result_reg,
+                    fct_idx,
+                    stmt.expr.pos(),
+                );
+            }
+        }
 
         // Emit: if <next-result>.isNone() then goto lbl_end
         let cond_reg = self.alloc_temp(BytecodeType::Bool);
@@ -463,7 +533,8 @@ impl<'a> AstBytecodeGen<'a> {
             );
         }
 
-        self.loops.push(LoopLabels::new(lbl_cond, lbl_end));
+        self.loops
+            .push(LoopLabels::new(stmt.label, lbl_cond, lbl_end));
         self.visit_stmt(&stmt.block);
         self.loops.pop().unwrap();
 
@@ -582,7 +653,8 @@ impl<'a> AstBytecodeGen<'a> {
         let cond_reg = self.visit_expr(&stmt.cond, DataDest::Alloc);
         self.builder.emit_jump_if_false(cond_reg, end_lbl);
         self.free_if_temp(cond_reg);
-        self.loops.push(LoopLabels::new(cond_lbl, end_lbl));
+        self.loops
+            .push(LoopLabels::new(stmt.label, cond_lbl, end_lbl));
         self.visit_stmt(&stmt.block);
         self.loops.pop().unwrap();
         self.builder.emit_jump_loop(cond_lbl);
@@ -624,6 +696,7 @@ impl<'a> AstBytecodeGen<'a> {
             ast::Expr::Dot(ref field) => self.visit_expr_dot(field, dest),
             ast::Expr::Block(ref block) => self.visit_expr_block(block, dest),
             ast::Expr::If(ref expr) => self.visit_expr_if(expr, dest),
+            ast::Expr::Match(ref expr) => self.visit_expr_match(expr, dest),
             ast::Expr::Template(ref template) => self.visit_expr_template(template, dest),
             ast::Expr::TypeParam(ref expr) => self.visit_expr_type_param(expr, dest),
             ast::Expr::Path(ref path) => self.visit_expr_path(path, dest),
@@ -635,10 +708,12 @@ impl<'a> AstBytecodeGen<'a> {
             ast::Expr::Ident(ref ident) => self.visit_expr_ident(ident, dest),
             ast::Expr::Call(ref call) => self.visit_expr_call(call, dest),
             ast::Expr::This(ref expr) => self.visit_expr_self(expr, dest),
+            ast::Expr::Super(ref expr) => self.visit_expr_super(expr, dest),
             ast::Expr::Conv(ref conv) => self.visit_expr_conv(conv, dest),
             ast::Expr::Tuple(ref tuple) => self.visit_expr_tuple(tuple, dest),
             ast::Expr::Paren(ref paren) => self.visit_expr(&paren.expr, dest),
             ast::Expr::Lambda(ref node) => self.visit_expr_lambda(node, dest),
+            ast::Expr::Try(ref expr) => self.visit_expr_try(expr, dest),
         }
     }
 
@@ -811,6 +886,14 @@ impl<'a> AstBytecodeGen<'a> {
         let object_type = self.ty(expr.object.id());
         let check_type = self.ty(expr.data_type.id());
 
+        if check_type.is_union() {
+            return self.visit_expr_conv_union(expr, object_type, check_type, dest);
+        }
+
+        if expr.is || !check_type.is_trait() {
+            return self.visit_expr_downcast(expr, object_type, check_type, dest);
+        }
+
         let (trait_id, type_params) = match check_type {
             SourceType::Trait(trait_id, ref type_params) => (trait_id, type_params.clone()),
             _ => unreachable!(),
@@ -828,6 +911,179 @@ impl<'a> AstBytecodeGen<'a> {
         dest
     }
 
+    // Class variants already are union references, value variants
+    // need to be boxed first.
+    fn visit_expr_conv_union(
+        &mut self,
+        expr: &ast::ExprConvType,
+        object_type: SourceType,
+        union_type: SourceType,
+        dest: DataDest,
+    ) -> Register {
+        if !object_type.is_value() {
+            return self.visit_expr(&expr.object, dest);
+        }
+
+        let union_id = union_type.union_id().expect("union expected");
+        let type_params = union_type.type_params();
+        let variant_idx = {
+            let union_ = self.sa.unions[union_id].read();
+            (0..union_.variants.len())
+                .find(|&idx| union_.variant_type(self.sa, &type_params, idx) == object_type)
+                .expect("variant missing")
+        };
+
+        let object = self.visit_expr(&expr.object, DataDest::Alloc);
+        let idx = self
+            .builder
+            .add_const_union_variant(union_id, type_params, variant_idx);
+        let dest = self.ensure_register(dest, BytecodeType::Ptr);
+        self.builder
+            .emit_new_union_value(dest, idx, object, expr.pos);
+        self.free_if_temp(object);
+        dest
+    }
+
+    fn visit_expr_downcast(
+        &mut self,
+        expr: &ast::ExprConvType,
+        object_type: SourceType,
+        check_type: SourceType,
+        dest: DataDest,
+    ) -> Register {
+        let (trait_id, trait_type_params) = match object_type {
+            SourceType::Trait(trait_id, ref type_params) => (trait_id, type_params.clone()),
+            _ => unreachable!(),
+        };
+
+        let object = self.visit_expr(&expr.object, DataDest::Alloc);
+        let idx = self
+            .builder
+            .add_const_trait(trait_id, trait_type_params, check_type.clone());
+
+        if expr.is {
+            let dest = self.ensure_register(dest, BytecodeType::Bool);
+            self.builder.emit_instance_of(dest, object, idx);
+            self.free_if_temp(object);
+            return dest;
+        }
+
+        let option_id = self.sa.known.enums.option();
+        let option_type_params = SourceTypeArray::single(check_type.clone());
+        let dest = self.ensure_register(
+            dest,
+            BytecodeType::Enum(option_id, option_type_params.clone()),
+        );
+
+        let lbl_none = self.builder.create_label();
+        let lbl_end = self.builder.create_label();
+
+        let is_reg = self.alloc_temp(BytecodeType::Bool);
+        self.builder.emit_instance_of(is_reg, object, idx);
+        self.builder.emit_jump_if_false(is_reg, lbl_none);
+        self.free_temp(is_reg);
+
+        let value_reg = self.alloc_temp(register_bty_from_ty(check_type));
+        self.builder
+            .emit_load_trait_object_value(value_reg, object, idx);
+        self.builder.emit_push_register(value_reg);
+        let some_idx =
+            self.builder
+                .add_const_enum_variant(option_id, option_type_params.clone(), 0);
+        self.builder.emit_new_enum(dest, some_idx, expr.pos);
+        self.free_temp(value_reg);
+        self.builder.emit_jump(lbl_end);
+
+        self.builder.bind_label(lbl_none);
+        let none_idx = self
+            .builder
+            .add_const_enum_variant(option_id, option_type_params, 1);
+        self.builder.emit_new_enum(dest, none_idx, expr.pos);
+        self.builder.bind_label(lbl_end);
+
+        self.free_if_temp(object);
+        dest
+    }
+
+    // Variant 0 of both `Option` and `Result` holds the value, otherwise
+    // `None` or the `Err` value is returned from the function.
+    fn visit_expr_try(&mut self, expr: &ast::ExprTryType, dest: DataDest) -> Register {
+        let object_ty = self.ty(expr.expr.id());
+        let enum_id = object_ty.enum_id().expect("enum expected");
+        let type_params = object_ty.type_params();
+
+        let object = self.visit_expr(&expr.expr, DataDest::Alloc);
+
+        let variant_reg = self.alloc_temp(BytecodeType::Int32);
+        let idx = self.builder.add_const_enum(enum_id, type_params.clone());
+        self.builder
+            .emit_load_enum_variant(variant_reg, object, idx, expr.pos);
+
+        let lbl_value = self.builder.create_label();
+        let zero_reg = self.alloc_temp(BytecodeType::Int32);
+        let cmp_reg = self.alloc_temp(BytecodeType::Bool);
+        self.builder.emit_const_int32(zero_reg, 0);
+        self.builder.emit_test_eq(cmp_reg, variant_reg, zero_reg);
+        self.builder.emit_jump_if_true(cmp_reg, lbl_value);
+        self.free_temp(cmp_reg);
+        self.free_temp(zero_reg);
+        self.free_temp(variant_reg);
+
+        let return_type = self.fct.return_type.clone();
+        let return_type_params = return_type.type_params();
+        let ret_reg = self.alloc_temp(register_bty_from_ty(return_type.clone()));
+
+        if enum_id == self.sa.known.enums.result() {
+            let error_ty = type_params[1].clone();
+            let error_reg = if error_ty.is_unit() {
+                self.ensure_unit_register()
+            } else {
+                let error_reg = self.alloc_temp(register_bty_from_ty(error_ty));
+                let idx = self
+                    .builder
+                    .add_const_enum_element(enum_id, type_params.clone(), 1, 0);
+                self.builder
+                    .emit_load_enum_element(error_reg, object, idx, expr.pos);
+                error_reg
+            };
+
+            self.builder.emit_push_register(error_reg);
+            let idx = self
+                .builder
+                .add_const_enum_variant(enum_id, return_type_params, 1);
+            self.builder.emit_new_enum(ret_reg, idx, expr.pos);
+            self.free_if_temp(error_reg);
+        } else {
+            let idx = self
+                .builder
+                .add_const_enum_variant(enum_id, return_type_params, 1);
+            self.builder.emit_new_enum(ret_reg, idx, expr.pos);
+        }
+
+        self.emit_ret_value(ret_reg);
+        self.free_temp(ret_reg);
+
+        self.builder.bind_label(lbl_value);
+
+        let value_ty = type_params[0].clone();
+
+        if value_ty.is_unit() || dest.is_effect() {
+            self.free_if_temp(object);
+            assert!(dest.is_unit());
+            return Register::invalid();
+        }
+
+        let dest = self.ensure_register(dest, register_bty_from_ty(value_ty));
+        let idx = self
+            .builder
+            .add_const_enum_element(enum_id, type_params, 0, 0);
+        self.builder
+            .emit_load_enum_element(dest, object, idx, expr.pos);
+        self.free_if_temp(object);
+
+        dest
+    }
+
     fn visit_expr_if(&mut self, node: &ast::ExprIfType, dest: DataDest) -> Register {
         let cond_reg = self.visit_stmt_let(&node.cond);
         let result_ty = self.ty(node.id);
@@ -882,6 +1138,87 @@ impl<'a> AstBytecodeGen<'a> {
                     self.builder.bind_label(next_lbl);
                     next_lbl = self.builder.create_label();
                 }
+                ast::IfCaseData::Patterns(patterns) if cond_ty.is_union() => {
+                    let union_id = cond_ty.union_id().expect("union expected");
+
+                    self.builder.bind_label(next_lbl);
+                    next_lbl = self.builder.create_label();
+
+                    let expr_reg = match cond_reg {
+                        Some(cond_reg) => cond_reg,
+                        None => self.visit_expr(cond, DataDest::Alloc),
+                    };
+
+                    let variant_reg = self.alloc_temp(BytecodeType::Int32);
+                    let constidx = self
+                        .builder
+                        .add_const_union(union_id, cond_ty.type_params());
+                    self.builder
+                        .emit_load_union_variant(variant_reg, expr_reg, constidx, node.pos);
+                    debug_assert_eq!(patterns.len(), 1);
+                    let pattern = patterns.first().expect("pattern missing");
+                    let variant_idx = {
+                        let ident_type = self.analysis.map_idents.get(pattern.id).unwrap();
+
+                        match ident_type {
+                            IdentType::UnionVariant(_, _, variant_idx) => *variant_idx,
+                            _ => unreachable!(),
+                        }
+                    };
+
+                    if idx != node.cases.len() - 1 || node.else_block.is_some() {
+                        let tmp_reg = self.alloc_temp(BytecodeType::Int32);
+                        let cmp_reg = self.alloc_temp(BytecodeType::Bool);
+                        self.builder
+                            .emit_const_int32(tmp_reg, variant_idx.try_into().unwrap());
+                        self.builder.emit_test_eq(cmp_reg, variant_reg, tmp_reg);
+                        self.builder.emit_jump_if_false(cmp_reg, next_lbl);
+                        self.free_temp(tmp_reg);
+                        self.free_temp(cmp_reg);
+                    }
+
+                    self.push_scope();
+
+                    if let Some(param) = pattern.params.as_ref().and_then(|p| p.first()) {
+                        if param.name.is_some() {
+                            let var_id = *self.analysis.map_vars.get(param.id).unwrap();
+                            let variant_ty = self.sa.unions[union_id].read().variant_type(
+                                self.sa,
+                                &cond_ty.type_params(),
+                                variant_idx,
+                            );
+                            let var_reg = self.alloc_var(register_bty_from_ty(variant_ty.clone()));
+                            self.var_registers.insert(var_id, var_reg);
+
+                            if variant_ty.is_value() {
+                                // value variants are boxed, copy the value out of the box
+                                let idx = self.builder.add_const_union_variant(
+                                    union_id,
+                                    cond_ty.type_params(),
+                                    variant_idx,
+                                );
+                                self.builder.emit_load_union_value(var_reg, expr_reg, idx);
+                            } else {
+                                self.builder.emit_mov(var_reg, expr_reg);
+                            }
+                        }
+                    }
+
+                    if let Some(dest) = dest {
+                        self.visit_expr(&case.value, DataDest::Reg(dest));
+                    } else {
+                        self.visit_expr(&case.value, DataDest::Effect);
+                    }
+
+                    self.pop_scope();
+
+                    self.builder.emit_jump(end_lbl);
+                    self.free_temp(variant_reg);
+
+                    if cond_reg.is_none() {
+                        self.free_if_temp(expr_reg);
+                    }
+                }
                 ast::IfCaseData::Patterns(patterns) => {
                     let expr_reg = self.visit_expr(&cond, DataDest::Alloc);
                     let enum_id = cond_ty.enum_id().expect("enum expected");
@@ -976,6 +1313,350 @@ impl<'a> AstBytecodeGen<'a> {
         dest.unwrap_or(Register::invalid())
     }
 
+    fn visit_expr_match(&mut self, node: &ast::ExprMatchType, dest: DataDest) -> Register {
+        let result_ty = self.ty(node.id);
+        let expr_ty = self.ty(node.expr.id());
+
+        let dest = if result_ty.is_unit() {
+            None
+        } else {
+            let result_bc_ty = register_bty_from_ty(result_ty);
+            Some(self.ensure_register(dest, result_bc_ty))
+        };
+
+        let expr_reg = self.visit_expr(&node.expr, DataDest::Alloc);
+        let end_lbl = self.builder.create_label();
+
+        self.push_scope();
+
+        let mut rows = Vec::with_capacity(node.cases.len());
+        let mut case_lbls = Vec::with_capacity(node.cases.len());
+
+        for (case_idx, case) in node.cases.iter().enumerate() {
+            let pat = matchck::lower_pattern(self.analysis, &case.pattern);
+            self.setup_match_bindings(&pat);
+
+            rows.push(MatchRow {
+                pats: vec![pat],
+                case_idx,
+                bindings: Vec::new(),
+            });
+            case_lbls.push(self.builder.create_label());
+        }
+
+        self.emit_decision_tree(node, rows, vec![(expr_reg, expr_ty)], &case_lbls);
+
+        for (case, case_lbl) in node.cases.iter().zip(case_lbls) {
+            self.builder.bind_label(case_lbl);
+
+            if let Some(dest) = dest {
+                self.visit_expr(&case.value, DataDest::Reg(dest));
+            } else {
+                self.visit_expr(&case.value, DataDest::Effect);
+            }
+
+            if !expr_always_returns(&case.value) {
+                self.builder.emit_jump(end_lbl);
+            }
+        }
+
+        self.pop_scope();
+        self.builder.bind_label(end_lbl);
+        self.free_if_temp(expr_reg);
+
+        dest.unwrap_or(Register::invalid())
+    }
+
+    fn setup_match_bindings(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Any(Some(pattern_id)) => {
+                let var_id = *self.analysis.map_vars.get(*pattern_id).unwrap();
+                let var = self.analysis.vars.get_var(var_id);
+
+                if !var.ty.is_unit() && var.location == VarLocation::Stack {
+                    let bty: BytecodeType = register_bty_from_ty(var.ty.clone());
+                    let var_reg = self.alloc_var(bty);
+                    self.var_registers.insert(var_id, var_reg);
+                }
+            }
+            Pat::Any(None) => {}
+            Pat::Ctor(_, params) => {
+                for param in params {
+                    self.setup_match_bindings(param);
+                }
+            }
+        }
+    }
+
+    // Emits the decision tree for the given pattern matrix. Every path through
+    // the tree ends in a jump to the body of the selected case.
+    fn emit_decision_tree(
+        &mut self,
+        node: &ast::ExprMatchType,
+        mut rows: Vec<MatchRow>,
+        mut occurrences: Vec<MatchOccurrence>,
+        case_lbls: &[Label],
+    ) {
+        assert!(!rows.is_empty(), "match not exhaustive");
+
+        let column = rows[0].pats.iter().position(|pat| pat.ctor().is_some());
+
+        if let Some(column) = column {
+            for row in &mut rows {
+                row.pats.swap(0, column);
+            }
+
+            occurrences.swap(0, column);
+            self.emit_decision_tree_switch(node, rows, occurrences, case_lbls);
+            return;
+        }
+
+        // The first row only consists of wildcards and always matches.
+        let mut row = rows.remove(0);
+
+        for (pat, &(reg, _)) in row.pats.iter().zip(&occurrences) {
+            if let Pat::Any(Some(pattern_id)) = pat {
+                row.bindings.push((*pattern_id, reg));
+            }
+        }
+
+        for &(pattern_id, reg) in &row.bindings {
+            self.emit_match_binding(pattern_id, reg, node.pos);
+        }
+
+        let case = &node.cases[row.case_idx];
+
+        if let Some(ref guard) = case.guard {
+            let next_lbl = self.builder.create_label();
+            let guard_reg = self.visit_expr(guard, DataDest::Alloc);
+            self.builder.emit_jump_if_false(guard_reg, next_lbl);
+            self.free_if_temp(guard_reg);
+            self.builder.emit_jump(case_lbls[row.case_idx]);
+
+            self.builder.bind_label(next_lbl);
+            self.emit_decision_tree(node, rows, occurrences, case_lbls);
+        } else {
+            self.builder.emit_jump(case_lbls[row.case_idx]);
+        }
+    }
+
+    fn emit_decision_tree_switch(
+        &mut self,
+        node: &ast::ExprMatchType,
+        rows: Vec<MatchRow>,
+        occurrences: Vec<MatchOccurrence>,
+        case_lbls: &[Label],
+    ) {
+        let (reg, ty) = occurrences[0].clone();
+        let ctors = matchck::head_ctors(rows.iter().map(|row| &row.pats[0]));
+        let complete = matchck::complete_ctors(self.sa, &ty, &ctors).is_some();
+
+        let variant_reg = if let Some(enum_id) = ty.enum_id() {
+            let variant_reg = self.alloc_temp(BytecodeType::Int32);
+            let idx = self.builder.add_const_enum(enum_id, ty.type_params());
+            self.builder
+                .emit_load_enum_variant(variant_reg, reg, idx, node.pos);
+            Some(variant_reg)
+        } else {
+            None
+        };
+
+        for (idx, ctor) in ctors.iter().enumerate() {
+            // The last constructor doesn't need to be tested if all
+            // constructors are covered.
+            let needs_test = !complete || idx != ctors.len() - 1;
+            let next_lbl = self.builder.create_label();
+
+            if needs_test {
+                self.emit_match_ctor_test(node, ctor, reg, &ty, variant_reg, next_lbl);
+            }
+
+            let subtypes = matchck::ctor_subtypes(self.sa, &ty, ctor);
+            let sub_occurrences = self.load_match_ctor_params(node, ctor, reg, &ty, subtypes);
+            let sub_rows = specialize_match_rows(&rows, ctor, sub_occurrences.len(), reg);
+
+            let mut new_occurrences = sub_occurrences.clone();
+            new_occurrences.extend_from_slice(&occurrences[1..]);
+            self.emit_decision_tree(node, sub_rows, new_occurrences, case_lbls);
+
+            for (sub_reg, _) in sub_occurrences {
+                self.free_if_temp(sub_reg);
+            }
+
+            if needs_test {
+                self.builder.bind_label(next_lbl);
+            }
+        }
+
+        if !complete {
+            let mut default_rows = Vec::new();
+
+            for row in &rows {
+                if let Pat::Any(binding) = row.pats[0] {
+                    let mut bindings = row.bindings.clone();
+                    bindings.extend(binding.map(|pattern_id| (pattern_id, reg)));
+
+                    default_rows.push(MatchRow {
+                        pats: row.pats[1..].to_vec(),
+                        case_idx: row.case_idx,
+                        bindings,
+                    });
+                }
+            }
+
+            self.emit_decision_tree(node, default_rows, occurrences[1..].to_vec(), case_lbls);
+        }
+
+        if let Some(variant_reg) = variant_reg {
+            self.free_temp(variant_reg);
+        }
+    }
+
+    // Jumps to `lbl` if the value in `reg` wasn't built with the given constructor.
+    fn emit_match_ctor_test(
+        &mut self,
+        node: &ast::ExprMatchType,
+        ctor: &Ctor,
+        reg: Register,
+        ty: &SourceType,
+        variant_reg: Option<Register>,
+        lbl: Label,
+    ) {
+        if let Ctor::Bool(value) = *ctor {
+            if value {
+                self.builder.emit_jump_if_false(reg, lbl);
+            } else {
+                self.builder.emit_jump_if_true(reg, lbl);
+            }
+            return;
+        }
+
+        let (lhs, const_reg) = match ctor {
+            Ctor::Variant(variant_idx) => {
+                let const_reg = self.alloc_temp(BytecodeType::Int32);
+                self.builder
+                    .emit_const_int32(const_reg, (*variant_idx).try_into().unwrap());
+                (variant_reg.expect("missing variant"), const_reg)
+            }
+            Ctor::Int(value) => {
+                let const_reg = self.alloc_temp(register_bty_from_ty(ty.clone()));
+                self.emit_const_int(const_reg, register_bty_from_ty(ty.clone()), *value);
+                (reg, const_reg)
+            }
+            Ctor::Float(bits) => {
+                let const_reg = self.alloc_temp(register_bty_from_ty(ty.clone()));
+                let value = f64::from_bits(*bits);
+                match ty {
+                    SourceType::Float32 => self.builder.emit_const_float32(const_reg, value as f32),
+                    SourceType::Float64 => self.builder.emit_const_float64(const_reg, value),
+                    _ => unreachable!(),
+                }
+                (reg, const_reg)
+            }
+            Ctor::Char(value) => {
+                let const_reg = self.alloc_temp(BytecodeType::Char);
+                self.builder.emit_const_char(const_reg, *value);
+                (reg, const_reg)
+            }
+            Ctor::Str(ref value) => {
+                let const_reg = self.alloc_temp(BytecodeType::Ptr);
+                self.builder.emit_const_string(const_reg, value.clone());
+                let cmp_reg = self.alloc_temp(BytecodeType::Bool);
+                let fct_idx = self
+                    .builder
+                    .add_const_fct(self.sa.known.functions.string_equals());
+                self.builder.emit_push_register(reg);
+                self.builder.emit_push_register(const_reg);
+                self.builder.emit_invoke_direct(cmp_reg, fct_idx, node.pos);
+                self.builder.emit_jump_if_false(cmp_reg, lbl);
+                self.free_temp(cmp_reg);
+                self.free_temp(const_reg);
+                return;
+            }
+            Ctor::Bool(_) | Ctor::Tuple | Ctor::Value => unreachable!(),
+        };
+
+        let cmp_reg = self.alloc_temp(BytecodeType::Bool);
+        self.builder.emit_test_eq(cmp_reg, lhs, const_reg);
+        self.builder.emit_jump_if_false(cmp_reg, lbl);
+        self.free_temp(cmp_reg);
+        self.free_temp(const_reg);
+    }
+
+    // Loads the components of the value in `reg` into temporary registers.
+    fn load_match_ctor_params(
+        &mut self,
+        node: &ast::ExprMatchType,
+        ctor: &Ctor,
+        reg: Register,
+        ty: &SourceType,
+        subtypes: Vec<SourceType>,
+    ) -> Vec<MatchOccurrence> {
+        let mut result = Vec::with_capacity(subtypes.len());
+
+        for (idx, subtype) in subtypes.into_iter().enumerate() {
+            if subtype.is_unit() {
+                result.push((Register::invalid(), subtype));
+                continue;
+            }
+
+            let sub_reg = self.alloc_temp(register_bty_from_ty(subtype.clone()));
+
+            match ctor {
+                Ctor::Variant(variant_idx) => {
+                    let enum_id = ty.enum_id().expect("enum expected");
+                    let const_idx = self.builder.add_const_enum_element(
+                        enum_id,
+                        ty.type_params(),
+                        *variant_idx,
+                        idx,
+                    );
+                    self.builder
+                        .emit_load_enum_element(sub_reg, reg, const_idx, node.pos);
+                }
+                Ctor::Tuple => {
+                    let const_idx = self.builder.add_const_tuple_element(ty.clone(), idx);
+                    self.builder
+                        .emit_load_tuple_element(sub_reg, reg, const_idx);
+                }
+                Ctor::Value => {
+                    let value_id = ty.value_id().expect("value expected");
+                    let const_idx = self.builder.add_const_value_field(
+                        value_id,
+                        ty.type_params(),
+                        ValueDefinitionFieldId(idx),
+                    );
+                    self.builder.emit_load_value_field(sub_reg, reg, const_idx);
+                }
+                _ => unreachable!(),
+            }
+
+            result.push((sub_reg, subtype));
+        }
+
+        result
+    }
+
+    fn emit_match_binding(&mut self, pattern_id: ast::NodeId, reg: Register, pos: Position) {
+        let var_id = *self.analysis.map_vars.get(pattern_id).unwrap();
+        let var = self.analysis.vars.get_var(var_id);
+
+        if var.ty.is_unit() {
+            return;
+        }
+
+        match var.location {
+            VarLocation::Context(context_idx) => {
+                self.store_in_context(reg, context_idx, pos);
+            }
+
+            VarLocation::Stack => {
+                let var_reg = self.var_reg(var_id);
+                self.emit_mov(var_reg, reg);
+            }
+        }
+    }
+
     fn visit_expr_lambda(&mut self, node: &ast::Function, dest: DataDest) -> Register {
         let dest = self.ensure_register(dest, BytecodeType::Ptr);
 
@@ -1033,6 +1714,11 @@ impl<'a> AstBytecodeGen<'a> {
         }
 
         let call_type = self.analysis.map_calls.get(expr.id);
+
+        if call_type.and_then(|c| c.to_intrinsic()) == Some(Intrinsic::EnumOrdinal) {
+            return self.emit_enum_ordinal(expr, object_ty, dest);
+        }
+
         if call_type.is_some_and(|c| c.is_method() || c.is_generic_method()) {
             let dot = ast::ExprDotType {
                 id: expr.id,
@@ -1240,8 +1926,18 @@ impl<'a> AstBytecodeGen<'a> {
             self.builder.emit_push_register(arg_reg);
         }
 
+        // Calls through `super` always invoke the method of the parent class
+        let is_virtual = callee.is_virtual() && !expr.object().is_some_and(|obj| obj.is_super());
+
         // Emit the actual Invoke(Direct|Static|Virtual)XXX instruction
-        self.emit_call_inst(&call_type, return_type, expr.pos, callee_idx, return_reg);
+        self.emit_call_inst(
+            &call_type,
+            is_virtual,
+            return_type,
+            expr.pos,
+            callee_idx,
+            return_reg,
+        );
 
         // Store result
         let result_reg = self.emit_call_result(&call_type, dest, return_reg, object_argument);
@@ -1478,6 +2174,14 @@ impl<'a> AstBytecodeGen<'a> {
             _ => 0,
         };
 
+        if let Some(arguments) = self.analysis.map_arguments.get(expr.id).cloned() {
+            return self.emit_call_bound_arguments(
+                expr,
+                &arguments,
+                &arg_types[arg_start_offset..],
+            );
+        }
+
         // Calculate number of non-variadic arguments
         let non_variadic_arguments = if callee.is_variadic {
             arg_types.len() - arg_start_offset - 1
@@ -1504,6 +2208,32 @@ impl<'a> AstBytecodeGen<'a> {
         registers
     }
 
+    // Arguments are evaluated in the order of the call, but passed in the
+    // order of the parameters. Left out arguments pass the default value.
+    fn emit_call_bound_arguments(
+        &mut self,
+        expr: &ast::ExprCallType,
+        arguments: &[CallArgument],
+        param_types: &[SourceType],
+    ) -> Vec<Register> {
+        let registers = expr
+            .args
+            .iter()
+            .map(|arg| self.visit_expr(&arg.expr, DataDest::Alloc))
+            .collect::<Vec<_>>();
+
+        arguments
+            .iter()
+            .zip(param_types)
+            .map(|(argument, ty)| match argument {
+                CallArgument::Arg(idx) => registers[*idx],
+                CallArgument::Default(value) => {
+                    self.emit_const_value(ty.clone(), value, DataDest::Alloc)
+                }
+            })
+            .collect()
+    }
+
     fn emit_array_with_variadic_arguments(
         &mut self,
         expr: &ast::ExprCallType,
@@ -1580,6 +2310,7 @@ impl<'a> AstBytecodeGen<'a> {
     fn emit_call_inst(
         &mut self,
         call_type: &CallType,
+        is_virtual: bool,
         return_type: SourceType,
         pos: Position,
         callee_idx: ConstPoolIdx,
@@ -1592,7 +2323,11 @@ impl<'a> AstBytecodeGen<'a> {
             }
 
             CallType::Method(_, _, _) => {
-                self.emit_invoke_direct(return_type, return_reg, callee_idx, pos);
+                if is_virtual {
+                    self.emit_invoke_virtual(return_type, return_reg, callee_idx, pos);
+                } else {
+                    self.emit_invoke_direct(return_type, return_reg, callee_idx, pos);
+                }
             }
             CallType::ModuleMethod(_, _, _) | CallType::Fct(_, _) => {
                 self.emit_invoke_static(return_type, return_reg, callee_idx, pos);
@@ -1728,6 +2463,17 @@ impl<'a> AstBytecodeGen<'a> {
         }
     }
 
+    fn visit_expr_super(&mut self, expr: &ast::ExprSuperType, dest: DataDest) -> Register {
+        // `super` refers to the same object as `self`
+        let expr = ast::ExprSelfType {
+            id: expr.id,
+            pos: expr.pos,
+            span: expr.span,
+        };
+
+        self.visit_expr_self(&expr, dest)
+    }
+
     fn visit_expr_self(&mut self, expr: &ast::ExprSelfType, dest: DataDest) -> Register {
         if dest.is_effect() {
             return Register::invalid();
@@ -1784,9 +2530,14 @@ impl<'a> AstBytecodeGen<'a> {
         let ty = self.analysis.ty(lit.id);
 
         let ty = match ty {
-            SourceType::UInt8 => BytecodeType::UInt8,
-            SourceType::Int32 => BytecodeType::Int32,
-            SourceType::Int64 => BytecodeType::Int64,
+            SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt8
+            | SourceType::UInt16
+            | SourceType::Int32
+            | SourceType::UInt32
+            | SourceType::Int64
+            | SourceType::UInt64 => register_bty_from_ty(ty),
             SourceType::Float32 => {
                 let dest = self.ensure_register(dest, BytecodeType::Float32);
                 let value = lit.value as f32;
@@ -1812,14 +2563,23 @@ impl<'a> AstBytecodeGen<'a> {
             lit.value as i64
         };
 
+        self.emit_const_int(dest, ty, value);
+
+        dest
+    }
+
+    fn emit_const_int(&mut self, dest: Register, ty: BytecodeType, value: i64) {
         match ty {
+            BytecodeType::Int8 => self.builder.emit_const_int8(dest, value as i8),
+            BytecodeType::Int16 => self.builder.emit_const_int16(dest, value as i16),
             BytecodeType::UInt8 => self.builder.emit_const_uint8(dest, value as u8),
+            BytecodeType::UInt16 => self.builder.emit_const_uint16(dest, value as u16),
             BytecodeType::Int32 => self.builder.emit_const_int32(dest, value as i32),
+            BytecodeType::UInt32 => self.builder.emit_const_uint32(dest, value as u32),
             BytecodeType::Int64 => self.builder.emit_const_int64(dest, value),
+            BytecodeType::UInt64 => self.builder.emit_const_uint64(dest, value as u64),
             _ => unreachable!(),
         }
-
-        dest
     }
 
     fn visit_expr_lit_float(&mut self, lit: &ast::ExprLitFloatType, dest: DataDest) -> Register {
@@ -1914,7 +2674,11 @@ impl<'a> AstBytecodeGen<'a> {
         if expr.op == ast::UnOp::Neg && expr.opnd.is_lit_int() {
             self.visit_expr_lit_int(expr.opnd.to_lit_int().unwrap(), dest, true)
         } else if let Some(intrinsic) = self.get_intrinsic(expr.id) {
-            self.emit_intrinsic_un(&expr.opnd, intrinsic, expr.pos, dest)
+            if intrinsic.intrinsic.emit_as_function_in_bytecode() {
+                self.visit_expr_un_method(expr, dest)
+            } else {
+                self.emit_intrinsic_un(&expr.opnd, intrinsic, expr.pos, dest)
+            }
         } else {
             self.visit_expr_un_method(expr, dest)
         }
@@ -1959,7 +2723,11 @@ impl<'a> AstBytecodeGen<'a> {
         } else if expr.op == ast::BinOp::And {
             self.emit_bin_and(expr, dest)
         } else if let Some(info) = self.get_intrinsic(expr.id) {
-            self.emit_intrinsic_bin(&expr.lhs, &expr.rhs, info, Some(expr.op), expr.pos, dest)
+            if info.intrinsic.emit_as_function_in_bytecode() {
+                self.visit_expr_bin_method(expr, dest)
+            } else {
+                self.emit_intrinsic_bin(&expr.lhs, &expr.rhs, info, Some(expr.op), expr.pos, dest)
+            }
         } else {
             self.visit_expr_bin_method(expr, dest)
         }
@@ -2049,18 +2817,17 @@ impl<'a> AstBytecodeGen<'a> {
 
         if call_type.is_method() {
             let object = expr.object().unwrap();
+            let args = self.intrinsic_arguments(expr);
 
-            match expr.args.len() {
+            match args.len() {
                 0 => self.emit_intrinsic_un(object, info, expr.pos, dest),
-                1 => {
-                    self.emit_intrinsic_bin(object, &expr.args[0].expr, info, None, expr.pos, dest)
-                }
+                1 => self.emit_intrinsic_bin(object, args[0], info, None, expr.pos, dest),
                 2 => {
                     assert_eq!(intrinsic, Intrinsic::ArraySet);
                     self.emit_intrinsic_array_set(
                         expr.object().unwrap(),
-                        &expr.args[0].expr,
-                        &expr.args[1].expr,
+                        args[0],
+                        args[1],
                         expr.pos,
                         dest,
                     )
@@ -2097,11 +2864,190 @@ impl<'a> AstBytecodeGen<'a> {
                     self.emit_array_with_variadic_arguments(expr, &[element_ty], 0, dest)
                 }
 
+                Intrinsic::EnumValues => self.emit_enum_values(expr, dest),
+
+                Intrinsic::EnumFromOrdinal => self.emit_enum_from_ordinal(expr, dest),
+
                 _ => panic!("unimplemented intrinsic {:?}", intrinsic),
             }
         }
     }
 
+    fn enum_discriminants(&self, enum_id: EnumDefinitionId) -> Vec<i32> {
+        let enum_ = self.sa.enums.idx(enum_id);
+        let enum_ = enum_.read();
+
+        enum_
+            .variants
+            .iter()
+            .map(|variant| variant.discriminant)
+            .collect()
+    }
+
+    // Enums with the Int layout store the variant index, which needs to be
+    // mapped to the discriminant when the enum declares custom values.
+    fn emit_enum_ordinal(
+        &mut self,
+        expr: &ast::ExprDotType,
+        object_ty: SourceType,
+        dest: DataDest,
+    ) -> Register {
+        let enum_id = object_ty.enum_id().expect("enum expected");
+        let discriminants = self.enum_discriminants(enum_id);
+        let has_custom_discriminants = self.sa.enums.idx(enum_id).read().has_custom_discriminants();
+
+        let object = self.visit_expr(&expr.lhs, DataDest::Alloc);
+        let dest = self.ensure_register(dest, BytecodeType::Int32);
+        let idx = self
+            .builder
+            .add_const_enum(enum_id, object_ty.type_params());
+
+        if !has_custom_discriminants {
+            self.builder
+                .emit_load_enum_variant(dest, object, idx, expr.pos);
+            self.free_if_temp(object);
+            return dest;
+        }
+
+        let variant_reg = self.alloc_temp(BytecodeType::Int32);
+        self.builder
+            .emit_load_enum_variant(variant_reg, object, idx, expr.pos);
+        self.free_if_temp(object);
+
+        let lbl_end = self.builder.create_label();
+        let idx_reg = self.alloc_temp(BytecodeType::Int32);
+        let cmp_reg = self.alloc_temp(BytecodeType::Bool);
+
+        for (variant_idx, &discriminant) in discriminants.iter().enumerate() {
+            let lbl_next = self.builder.create_label();
+            self.builder.emit_const_int32(idx_reg, variant_idx as i32);
+            self.builder.emit_test_eq(cmp_reg, variant_reg, idx_reg);
+            self.builder.emit_jump_if_false(cmp_reg, lbl_next);
+            self.builder.emit_const_int32(dest, discriminant);
+            self.builder.emit_jump(lbl_end);
+            self.builder.bind_label(lbl_next);
+        }
+
+        // Unreachable, every variant index is covered above.
+        self.builder.emit_const_int32(dest, 0);
+        self.builder.bind_label(lbl_end);
+
+        self.free_temp(cmp_reg);
+        self.free_temp(idx_reg);
+        self.free_temp(variant_reg);
+
+        dest
+    }
+
+    fn emit_enum_values(&mut self, expr: &ast::ExprCallType, dest: DataDest) -> Register {
+        let array_ty = self.ty(expr.id);
+        let cls_id = array_ty.cls_id().expect("class expected");
+        let type_params = array_ty.type_params();
+        let enum_ty = type_params[0].clone();
+        let enum_id = enum_ty.enum_id().expect("enum expected");
+        let variants = self.enum_discriminants(enum_id).len();
+
+        let cls_idx = self.builder.add_const_cls_types(cls_id, type_params);
+        let array_reg = self.ensure_register(dest, BytecodeType::Ptr);
+        let length_reg = self.alloc_temp(BytecodeType::Int64);
+        self.builder.emit_const_int64(length_reg, variants as i64);
+        self.builder
+            .emit_new_array(array_reg, cls_idx, length_reg, expr.pos);
+        self.free_temp(length_reg);
+
+        let index_reg = self.alloc_temp(BytecodeType::Int64);
+        let value_reg = self.alloc_temp(register_bty_from_ty(enum_ty.clone()));
+
+        for variant_idx in 0..variants {
+            self.emit_new_enum(
+                enum_id,
+                enum_ty.type_params(),
+                variant_idx,
+                expr.pos,
+                DataDest::Reg(value_reg),
+            );
+            self.builder.emit_const_int64(index_reg, variant_idx as i64);
+            self.builder
+                .emit_store_array(value_reg, array_reg, index_reg, expr.pos);
+        }
+
+        self.free_temp(value_reg);
+        self.free_temp(index_reg);
+
+        array_reg
+    }
+
+    fn emit_enum_from_ordinal(&mut self, expr: &ast::ExprCallType, dest: DataDest) -> Register {
+        let option_ty = self.ty(expr.id);
+        let option_id = option_ty.enum_id().expect("enum expected");
+        let option_type_params = option_ty.type_params();
+        let enum_ty = option_type_params[0].clone();
+        let enum_id = enum_ty.enum_id().expect("enum expected");
+        let discriminants = self.enum_discriminants(enum_id);
+
+        let ordinal = self.visit_expr(&expr.args[0].expr, DataDest::Alloc);
+        let dest = self.ensure_register(
+            dest,
+            BytecodeType::Enum(option_id, option_type_params.clone()),
+        );
+
+        let lbl_end = self.builder.create_label();
+        let discriminant_reg = self.alloc_temp(BytecodeType::Int32);
+        let cmp_reg = self.alloc_temp(BytecodeType::Bool);
+        let value_reg = self.alloc_temp(register_bty_from_ty(enum_ty.clone()));
+        let some_idx =
+            self.builder
+                .add_const_enum_variant(option_id, option_type_params.clone(), 0);
+
+        for (variant_idx, &discriminant) in discriminants.iter().enumerate() {
+            let lbl_next = self.builder.create_label();
+            self.builder
+                .emit_const_int32(discriminant_reg, discriminant);
+            self.builder
+                .emit_test_eq(cmp_reg, ordinal, discriminant_reg);
+            self.builder.emit_jump_if_false(cmp_reg, lbl_next);
+            self.emit_new_enum(
+                enum_id,
+                enum_ty.type_params(),
+                variant_idx,
+                expr.pos,
+                DataDest::Reg(value_reg),
+            );
+            self.builder.emit_push_register(value_reg);
+            self.builder.emit_new_enum(dest, some_idx, expr.pos);
+            self.builder.emit_jump(lbl_end);
+            self.builder.bind_label(lbl_next);
+        }
+
+        let none_idx = self
+            .builder
+            .add_const_enum_variant(option_id, option_type_params, 1);
+        self.builder.emit_new_enum(dest, none_idx, expr.pos);
+        self.builder.bind_label(lbl_end);
+
+        self.free_temp(value_reg);
+        self.free_temp(cmp_reg);
+        self.free_temp(discriminant_reg);
+        self.free_if_temp(ordinal);
+
+        dest
+    }
+
+    // Arguments of an intrinsic in the order of its parameters.
+    fn intrinsic_arguments<'e>(&self, expr: &'e ast::ExprCallType) -> Vec<&'e ast::Expr> {
+        match self.analysis.map_arguments.get(expr.id) {
+            Some(arguments) => arguments
+                .iter()
+                .map(|argument| match argument {
+                    CallArgument::Arg(idx) => &*expr.args[*idx].expr,
+                    CallArgument::Default(_) => unreachable!("intrinsic with default value"),
+                })
+                .collect(),
+
+            None => expr.args.iter().map(|arg| &*arg.expr).collect(),
+        }
+    }
+
     fn emit_intrinsic_new_array(&mut self, expr: &ast::ExprCallType, dest: DataDest) -> Register {
         // We need array of elements
         let element_ty = self.ty(expr.id);
@@ -2809,6 +3755,7 @@ impl<'a> AstBytecodeGen<'a> {
 
             &IdentType::Fct(_, _) => unreachable!(),
             &IdentType::Class(_, _) => unreachable!(),
+            &IdentType::UnionVariant(_, _, _) => unreachable!(),
         }
     }
 
@@ -2890,14 +3837,17 @@ impl<'a> AstBytecodeGen<'a> {
 
         let const_ = self.sa.consts.idx(const_id);
         let const_ = const_.read();
-        let ty = const_.ty.clone();
 
+        self.emit_const_value(const_.ty.clone(), &const_.value, dest)
+    }
+
+    fn emit_const_value(&mut self, ty: SourceType, value: &ConstValue, dest: DataDest) -> Register {
         let bytecode_ty = register_bty_from_ty(ty.clone());
         let dest = self.ensure_register(dest, bytecode_ty);
 
         match ty {
             SourceType::Bool => {
-                if const_.value.to_bool() {
+                if value.to_bool() {
                     self.builder.emit_const_true(dest);
                 } else {
                     self.builder.emit_const_false(dest);
@@ -2905,31 +3855,32 @@ impl<'a> AstBytecodeGen<'a> {
             }
 
             SourceType::Char => {
-                self.builder.emit_const_char(dest, const_.value.to_char());
+                self.builder.emit_const_char(dest, value.to_char());
             }
 
-            SourceType::UInt8 => {
-                self.builder
-                    .emit_const_uint8(dest, const_.value.to_int() as u8);
-            }
-
-            SourceType::Int32 => {
-                self.builder
-                    .emit_const_int32(dest, const_.value.to_int() as i32);
-            }
-
-            SourceType::Int64 => {
-                self.builder.emit_const_int64(dest, const_.value.to_int());
+            SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt8
+            | SourceType::UInt16
+            | SourceType::Int32
+            | SourceType::UInt32
+            | SourceType::Int64
+            | SourceType::UInt64 => {
+                self.emit_const_int(dest, register_bty_from_ty(ty.clone()), value.to_int());
             }
 
             SourceType::Float32 => {
                 self.builder
-                    .emit_const_float32(dest, const_.value.to_float() as f32);
+                    .emit_const_float32(dest, value.to_float() as f32);
             }
 
             SourceType::Float64 => {
+                self.builder.emit_const_float64(dest, value.to_float());
+            }
+
+            SourceType::Class(cls_id, _) if cls_id == self.sa.known.classes.string() => {
                 self.builder
-                    .emit_const_float64(dest, const_.value.to_float());
+                    .emit_const_string(dest, value.to_str().to_string());
             }
 
             _ => unimplemented!(),
@@ -3074,6 +4025,32 @@ impl<'a> AstBytecodeGen<'a> {
         }
         assert_eq!(fct.type_params.len(), type_params.len());
 
+        let type_params = match *call_type {
+            CallType::Method(ref object_ty, _, _) if fct.parent.is_trait() => {
+                // default method of a trait, called on the implementing type
+                let trait_id = fct.trait_id();
+                let impl_trait_ty =
+                    impl_trait_ty(self.sa, object_ty.clone(), &self.fct.type_params, trait_id)
+                        .expect("no impl found");
+                let number_type_params = self.sa.traits[trait_id].read().number_type_params();
+                let fct_type_params =
+                    SourceTypeArray::with(type_params.types()[number_type_params..].to_vec());
+                default_method_type_params(
+                    self.sa,
+                    &impl_trait_ty,
+                    object_ty.clone(),
+                    &fct_type_params,
+                )
+            }
+            _ => type_params,
+        };
+        let type_params = SourceTypeArray::with(
+            type_params
+                .iter()
+                .map(|ty| self.specialize_self_ty(ty))
+                .collect::<Vec<_>>(),
+        );
+
         match *call_type {
             CallType::GenericStaticMethod(id, _, _) | CallType::GenericMethod(id, _, _) => {
                 self.builder.add_const_generic(id, fct.id(), type_params)
@@ -3083,10 +4060,17 @@ impl<'a> AstBytecodeGen<'a> {
     }
 
     fn specialize_type_for_call(&self, call_type: &CallType, ty: SourceType) -> SourceType {
+        let ty = self.specialize_type_for_callee(call_type, ty);
+        self.specialize_self_ty(ty)
+    }
+
+    fn specialize_type_for_callee(&self, call_type: &CallType, ty: SourceType) -> SourceType {
         match call_type {
             CallType::Fct(_, ref type_params) => specialize_type(self.sa, ty, type_params),
 
-            CallType::Method(_, _, ref type_params) => specialize_type(self.sa, ty, type_params),
+            CallType::Method(ref object_ty, _, ref type_params) => {
+                replace_type_param(self.sa, ty, type_params, Some(object_ty.clone()))
+            }
 
             CallType::ModuleMethod(cls_ty, _, ref fct_type_params) => {
                 let cls_type_params = cls_ty.type_params();
@@ -3103,15 +4087,31 @@ impl<'a> AstBytecodeGen<'a> {
 
             CallType::TraitObjectMethod(trait_ty, _) => {
                 let container_type_params = trait_ty.type_params();
-                specialize_type(self.sa, ty, &container_type_params)
+                let ty = specialize_type(self.sa, ty, &container_type_params);
+                replace_assoc_types(self.sa, ty, trait_ty)
             }
-            CallType::GenericMethod(id, _, _) | CallType::GenericStaticMethod(id, _, _) => {
-                debug_assert!(ty.is_concrete_type(self.sa) || ty.is_self());
+            CallType::GenericMethod(id, trait_id, _)
+            | CallType::GenericStaticMethod(id, trait_id, _) => {
                 if ty.is_self() {
-                    SourceType::TypeParam(*id)
-                } else {
-                    ty
+                    return SourceType::TypeParam(*id);
                 }
+
+                let trait_ty = match self
+                    .fct
+                    .type_params
+                    .bounds_for_type_param(*id)
+                    .find(|trait_ty| trait_ty.trait_id() == Some(*trait_id))
+                {
+                    Some(trait_ty) => trait_ty,
+                    None => {
+                        // Self in default methods of traits has no explicit bound
+                        assert!(self.fct.parent.is_trait());
+                        return ty;
+                    }
+                };
+
+                // associated types are only known through the bound
+                replace_assoc_types(self.sa, ty, &trait_ty)
             }
 
             CallType::Enum(_, _) => unreachable!(),
@@ -3123,11 +4123,43 @@ impl<'a> AstBytecodeGen<'a> {
     }
 
     fn ty(&self, id: ast::NodeId) -> SourceType {
-        self.analysis.ty(id)
+        self.specialize_self_ty(self.analysis.ty(id))
     }
 
     fn var_ty(&self, id: VarId) -> SourceType {
-        self.analysis.vars.get_var(id).ty.clone()
+        self.specialize_self_ty(self.analysis.vars.get_var(id).ty.clone())
+    }
+
+    // Default methods of traits are generated once for all implementing types:
+    // Self and the associated types of the trait are passed as additional type
+    // params after the declared ones.
+    fn specialize_self_ty(&self, ty: SourceType) -> SourceType {
+        if !self.fct.parent.is_trait() {
+            return ty;
+        }
+
+        let self_id = self.fct.type_params.len();
+        let trait_id = self.fct.trait_id();
+        let trait_ = self.sa.traits[trait_id].read();
+
+        let trait_type_params = (0..trait_.number_type_params())
+            .map(|idx| SourceType::TypeParam(TypeParamId(idx)))
+            .chain(
+                (0..trait_.aliases.len())
+                    .map(|idx| SourceType::TypeParam(TypeParamId(self_id + 1 + idx))),
+            )
+            .collect::<Vec<_>>();
+        let trait_ty = SourceType::Trait(trait_id, SourceTypeArray::with(trait_type_params));
+
+        // keep all type params, including the ones added for Self and the associated types
+        let type_params = (0..self_id + 1 + trait_.aliases.len())
+            .map(|idx| SourceType::TypeParam(TypeParamId(idx)))
+            .collect::<Vec<_>>();
+        let type_params = SourceTypeArray::with(type_params);
+
+        let self_ty = SourceType::TypeParam(TypeParamId(self_id));
+        let ty = replace_type_param(self.sa, ty, &type_params, Some(self_ty));
+        replace_assoc_types(self.sa, ty, &trait_ty)
     }
 
     fn get_intrinsic(&self, id: ast::NodeId) -> Option<IntrinsicInfo> {
@@ -3210,6 +4242,38 @@ impl<'a> AstBytecodeGen<'a> {
     }
 }
 
+fn specialize_match_rows(
+    rows: &[MatchRow],
+    ctor: &Ctor,
+    arity: usize,
+    reg: Register,
+) -> Vec<MatchRow> {
+    let mut result = Vec::new();
+
+    for row in rows {
+        let mut bindings = row.bindings.clone();
+
+        let mut pats = match &row.pats[0] {
+            Pat::Ctor(row_ctor, params) if row_ctor == ctor => params.clone(),
+            Pat::Ctor(_, _) => continue,
+            Pat::Any(binding) => {
+                bindings.extend(binding.map(|pattern_id| (pattern_id, reg)));
+                vec![Pat::Any(None); arity]
+            }
+        };
+
+        pats.extend_from_slice(&row.pats[1..]);
+
+        result.push(MatchRow {
+            pats,
+            case_idx: row.case_idx,
+            bindings,
+        });
+    }
+
+    result
+}
+
 struct IntrinsicInfo {
     intrinsic: Intrinsic,
     fct_id: Option<FctDefinitionId>,
@@ -3282,14 +4346,20 @@ pub fn bty_from_ty(ty: SourceType) -> BytecodeType {
     match ty {
         SourceType::Unit => BytecodeType::Unit,
         SourceType::Bool => BytecodeType::Bool,
+        SourceType::Int8 => BytecodeType::Int8,
+        SourceType::Int16 => BytecodeType::Int16,
         SourceType::UInt8 => BytecodeType::UInt8,
+        SourceType::UInt16 => BytecodeType::UInt16,
         SourceType::Char => BytecodeType::Char,
         SourceType::Int32 => BytecodeType::Int32,
+        SourceType::UInt32 => BytecodeType::UInt32,
         SourceType::Int64 => BytecodeType::Int64,
+        SourceType::UInt64 => BytecodeType::UInt64,
         SourceType::Float32 => BytecodeType::Float32,
         SourceType::Float64 => BytecodeType::Float64,
         SourceType::Class(class_id, type_params) => BytecodeType::Class(class_id, type_params),
         SourceType::Trait(trait_id, type_params) => BytecodeType::Trait(trait_id, type_params),
+        SourceType::Union(_, _) => BytecodeType::Ptr,
         SourceType::Enum(enum_id, type_params) => BytecodeType::Enum(enum_id, type_params),
         SourceType::Value(value_id, type_params) => BytecodeType::Value(value_id, type_params),
         SourceType::Tuple(subtypes) => BytecodeType::Tuple(subtypes),
@@ -3304,14 +4374,20 @@ pub fn register_bty_from_ty(ty: SourceType) -> BytecodeType {
     match ty {
         SourceType::Unit => BytecodeType::Unit,
         SourceType::Bool => BytecodeType::Bool,
+        SourceType::Int8 => BytecodeType::Int8,
+        SourceType::Int16 => BytecodeType::Int16,
         SourceType::UInt8 => BytecodeType::UInt8,
+        SourceType::UInt16 => BytecodeType::UInt16,
         SourceType::Char => BytecodeType::Char,
         SourceType::Int32 => BytecodeType::Int32,
+        SourceType::UInt32 => BytecodeType::UInt32,
         SourceType::Int64 => BytecodeType::Int64,
+        SourceType::UInt64 => BytecodeType::UInt64,
         SourceType::Float32 => BytecodeType::Float32,
         SourceType::Float64 => BytecodeType::Float64,
         SourceType::Class(_, _) => BytecodeType::Ptr,
         SourceType::Trait(trait_id, type_params) => BytecodeType::Trait(trait_id, type_params),
+        SourceType::Union(_, _) => BytecodeType::Ptr,
         SourceType::Enum(enum_id, type_params) => BytecodeType::Enum(enum_id, type_params),
         SourceType::Value(value_id, type_params) => BytecodeType::Value(value_id, type_params),
         SourceType::Tuple(subtypes) => BytecodeType::Tuple(subtypes),
@@ -563,6 +563,41 @@ fn gen_expr_lit_int64() {
     assert_eq!(expected, result);
 }
 
+#[test]
+fn gen_expr_lit_int8() {
+    let result = code("fun f(): Int8 { return -128i8; }");
+    let expected = vec![ConstInt8(r(0), -128), Ret(r(0))];
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn gen_expr_lit_int16() {
+    let result = code("fun f(): Int16 { return -1i16; }");
+    let expected = vec![ConstInt16(r(0), -1), Ret(r(0))];
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn gen_expr_lit_uint16() {
+    let result = code("fun f(): UInt16 { return 65535u16; }");
+    let expected = vec![ConstUInt16(r(0), 65535), Ret(r(0))];
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn gen_expr_lit_uint32() {
+    let result = code("fun f(): UInt32 { return 4294967295u32; }");
+    let expected = vec![ConstUInt32(r(0), 4294967295), Ret(r(0))];
+    assert_eq!(expected, result);
+}
+
+#[test]
+fn gen_expr_lit_uint64() {
+    let result = code("fun f(): UInt64 { return 18446744073709551615u64; }");
+    let expected = vec![ConstUInt64(r(0), 18446744073709551615), Ret(r(0))];
+    assert_eq!(expected, result);
+}
+
 #[test]
 fn gen_expr_lit_float32() {
     let result = code("fun f(): Float32 { return 1f32; }");
@@ -3896,6 +3931,26 @@ fn gen_tuple_element() {
     );
 }
 
+#[test]
+fn gen_union_value() {
+    gen_fct(
+        "
+        class A value V(x: Int32)
+        union U of A, V;
+        fun f(x: V): U { x as U }
+    ",
+        |_sa, code, fct| {
+            let expected = vec![NewUnionValue(r(1), ConstPoolIdx(0), r(0)), Ret(r(1))];
+            assert_eq!(expected, code);
+
+            assert!(matches!(
+                fct.const_pool(ConstPoolIdx(0)),
+                &ConstPoolEntry::UnionVariant(_, _, 1)
+            ));
+        },
+    );
+}
+
 #[test]
 fn gen_trait_object() {
     gen_fct(
@@ -3922,6 +3977,132 @@ fn gen_trait_object() {
     );
 }
 
+#[test]
+fn gen_trait_object_is() {
+    gen_fct(
+        "
+        trait Foo { fun bar(): Int32; }
+        class Bar
+        impl Foo for Bar {
+            fun bar(): Int32 { 1i32 }
+        }
+        fun f(x: Foo): Bool { x is Bar }
+    ",
+        |sa, code, fct| {
+            let trait_id = sa.trait_by_name("Foo");
+            let cls_id = sa.cls_by_name("Bar");
+            let object_ty = SourceType::Class(cls_id, SourceTypeArray::empty());
+            let expected = vec![InstanceOf(r(1), r(0), ConstPoolIdx(0)), Ret(r(1))];
+            assert_eq!(expected, code);
+
+            assert_eq!(
+                fct.const_pool(ConstPoolIdx(0)),
+                &ConstPoolEntry::Trait(trait_id, SourceTypeArray::empty(), object_ty)
+            );
+        },
+    );
+}
+
+#[test]
+fn gen_try_option() {
+    gen_fct(
+        "fun f(x: Option[Int32]): Option[Int32] { Some[Int32](x?) }",
+        |sa, code, fct| {
+            let option_id = sa.known.enums.option();
+            let type_params = SourceTypeArray::single(SourceType::Int32);
+            let expected = vec![
+                LoadEnumVariant(r(1), r(0), ConstPoolIdx(0)),
+                ConstInt32(r(2), 0),
+                TestEq(r(3), r(1), r(2)),
+                JumpIfTrue(r(3), 6),
+                NewEnum(r(4), ConstPoolIdx(3)),
+                Ret(r(4)),
+                LoadEnumElement(r(1), r(0), ConstPoolIdx(4)),
+                PushRegister(r(1)),
+                NewEnum(r(4), ConstPoolIdx(5)),
+                Ret(r(4)),
+            ];
+            assert_eq!(expected, code);
+
+            assert_eq!(
+                fct.const_pool(ConstPoolIdx(3)),
+                &ConstPoolEntry::EnumVariant(option_id, type_params.clone(), 1)
+            );
+            assert_eq!(
+                fct.const_pool(ConstPoolIdx(4)),
+                &ConstPoolEntry::EnumElement(option_id, type_params, 0, 0)
+            );
+        },
+    );
+}
+
+#[test]
+fn gen_try_result() {
+    gen_fct(
+        "fun f(x: Result[Int32, String]): Result[Int64, String] { x?; Ok[Int64, String](1) }",
+        |sa, code, fct| {
+            let result_id = sa.known.enums.result();
+            let string_ty = SourceType::Class(sa.known.classes.string(), SourceTypeArray::empty());
+            let type_params = SourceTypeArray::with(vec![SourceType::Int32, string_ty.clone()]);
+            let expected = vec![
+                LoadEnumVariant(r(1), r(0), ConstPoolIdx(0)),
+                ConstInt32(r(2), 0),
+                TestEq(r(3), r(1), r(2)),
+                JumpIfTrue(r(3), 8),
+                LoadEnumElement(r(5), r(0), ConstPoolIdx(3)),
+                PushRegister(r(5)),
+                NewEnum(r(4), ConstPoolIdx(4)),
+                Ret(r(4)),
+                ConstInt64(r(6), 1),
+                PushRegister(r(6)),
+                NewEnum(r(4), ConstPoolIdx(6)),
+                Ret(r(4)),
+            ];
+            assert_eq!(expected, code);
+
+            assert_eq!(
+                fct.const_pool(ConstPoolIdx(3)),
+                &ConstPoolEntry::EnumElement(result_id, type_params, 1, 0)
+            );
+            assert_eq!(
+                fct.const_pool(ConstPoolIdx(4)),
+                &ConstPoolEntry::EnumVariant(
+                    result_id,
+                    SourceTypeArray::with(vec![SourceType::Int64, string_ty]),
+                    1
+                )
+            );
+        },
+    );
+}
+
+#[test]
+fn gen_trait_object_downcast() {
+    gen_fct(
+        "
+        trait Foo { fun bar(): Int32; }
+        class Bar
+        impl Foo for Bar {
+            fun bar(): Int32 { 1i32 }
+        }
+        fun f(x: Foo): Option[Bar] { x as Bar }
+    ",
+        |_sa, code, _fct| {
+            let expected = vec![
+                InstanceOf(r(2), r(0), ConstPoolIdx(0)),
+                JumpIfFalse(r(2), 6),
+                LoadTraitObjectValue(r(3), r(0), ConstPoolIdx(0)),
+                PushRegister(r(3)),
+                NewEnum(r(1), ConstPoolIdx(2)),
+                Jump(7),
+                NewEnum(r(1), ConstPoolIdx(4)),
+                Ret(r(1)),
+            ];
+            assert_eq!(expected, code);
+        },
+    );
+}
+
 #[test]
 fn gen_trait_object_copy() {
     gen(
@@ -4091,7 +4272,11 @@ pub enum Bytecode {
     Mov(Register, Register),
 
     LoadTupleElement(Register, Register, ConstPoolIdx),
+    LoadEnumElement(Register, Register, ConstPoolIdx),
+    LoadEnumVariant(Register, Register, ConstPoolIdx),
+    LoadUnionValue(Register, Register, ConstPoolIdx),
     LoadValueField(Register, Register, ConstPoolIdx),
+    LoadTraitObjectValue(Register, Register, ConstPoolIdx),
 
     LoadField(Register, Register, ConstPoolIdx),
     StoreField(Register, Register, ConstPoolIdx),
@@ -4105,12 +4290,19 @@ pub enum Bytecode {
     ConstFalse(Register),
     ConstUInt8(Register, u8),
     ConstChar(Register, char),
+    ConstInt8(Register, i8),
+    ConstInt16(Register, i16),
+    ConstUInt16(Register, u16),
     ConstInt32(Register, i32),
+    ConstUInt32(Register, u32),
     ConstInt64(Register, i64),
+    ConstUInt64(Register, u64),
     ConstFloat32(Register, f32),
     ConstFloat64(Register, f64),
     ConstString(Register, String),
 
+    InstanceOf(Register, Register, ConstPoolIdx),
+
     TestIdentity(Register, Register, Register),
     TestEq(Register, Register, Register),
     TestNe(Register, Register, Register),
@@ -4139,6 +4331,7 @@ pub enum Bytecode {
     NewEnum(Register, ConstPoolIdx),
     NewValue(Register, ConstPoolIdx),
     NewTraitObject(Register, ConstPoolIdx, Register),
+    NewUnionValue(Register, ConstPoolIdx, Register),
     NewLambda(Register, ConstPoolIdx),
 
     ArrayLength(Register, Register),
@@ -4271,10 +4464,31 @@ impl<'a> BytecodeVisitor for BytecodeArrayBuilder<'a> {
         self.emit(Bytecode::LoadTupleElement(src, dest, idx));
     }
 
+    fn visit_load_enum_element(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        self.emit(Bytecode::LoadEnumElement(dest, src, idx));
+    }
+
+    fn visit_load_enum_variant(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        self.emit(Bytecode::LoadEnumVariant(dest, src, idx));
+    }
+
     fn visit_load_value_field(&mut self, dest: Register, obj: Register, idx: ConstPoolIdx) {
         self.emit(Bytecode::LoadValueField(dest, obj, idx));
     }
 
+    fn visit_load_union_value(&mut self, dest: Register, src: Register, idx: ConstPoolIdx) {
+        self.emit(Bytecode::LoadUnionValue(dest, src, idx));
+    }
+
+    fn visit_load_trait_object_value(
+        &mut self,
+        dest: Register,
+        object: Register,
+        idx: ConstPoolIdx,
+    ) {
+        self.emit(Bytecode::LoadTraitObjectValue(dest, object, idx));
+    }
+
     fn visit_load_field(&mut self, dest: Register, obj: Register, idx: ConstPoolIdx) {
         self.emit(Bytecode::LoadField(dest, obj, idx));
     }
@@ -4308,14 +4522,33 @@ impl<'a> BytecodeVisitor for BytecodeArrayBuilder<'a> {
     fn visit_const_uint8(&mut self, dest: Register, value: u8) {
         self.emit(Bytecode::ConstUInt8(dest, value));
     }
+    fn visit_const_int8(&mut self, dest: Register, value: i8) {
+        self.emit(Bytecode::ConstInt8(dest, value));
+    }
+    fn visit_const_int16(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self.bc.const_pool(idx).to_int32().expect("int expected");
+        self.emit(Bytecode::ConstInt16(dest, value as i16));
+    }
+    fn visit_const_uint16(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self.bc.const_pool(idx).to_int32().expect("int expected");
+        self.emit(Bytecode::ConstUInt16(dest, value as u16));
+    }
     fn visit_const_int32(&mut self, dest: Register, idx: ConstPoolIdx) {
         let value = self.bc.const_pool(idx).to_int32().expect("int expected");
         self.emit(Bytecode::ConstInt32(dest, value));
     }
+    fn visit_const_uint32(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self.bc.const_pool(idx).to_int32().expect("int expected");
+        self.emit(Bytecode::ConstUInt32(dest, value as u32));
+    }
     fn visit_const_int64(&mut self, dest: Register, idx: ConstPoolIdx) {
         let value = self.bc.const_pool(idx).to_int64().expect("int64 expected");
         self.emit(Bytecode::ConstInt64(dest, value));
     }
+    fn visit_const_uint64(&mut self, dest: Register, idx: ConstPoolIdx) {
+        let value = self.bc.const_pool(idx).to_int64().expect("int64 expected");
+        self.emit(Bytecode::ConstUInt64(dest, value as u64));
+    }
     fn visit_const_float32(&mut self, dest: Register, idx: ConstPoolIdx) {
         let value = self
             .bc
@@ -4342,6 +4575,10 @@ impl<'a> BytecodeVisitor for BytecodeArrayBuilder<'a> {
         self.emit(Bytecode::ConstString(dest, value));
     }
 
+    fn visit_instance_of(&mut self, dest: Register, object: Register, idx: ConstPoolIdx) {
+        self.emit(Bytecode::InstanceOf(dest, object, idx));
+    }
+
     fn visit_test_identity(&mut self, dest: Register, lhs: Register, rhs: Register) {
         self.emit(Bytecode::TestIdentity(dest, lhs, rhs));
     }
@@ -4445,6 +4682,9 @@ impl<'a> BytecodeVisitor for BytecodeArrayBuilder<'a> {
     fn visit_new_trait_object(&mut self, dest: Register, idx: ConstPoolIdx, src: Register) {
         self.emit(Bytecode::NewTraitObject(dest, idx, src));
     }
+    fn visit_new_union_value(&mut self, dest: Register, idx: ConstPoolIdx, src: Register) {
+        self.emit(Bytecode::NewUnionValue(dest, idx, src));
+    }
     fn visit_new_lambda(&mut self, dest: Register, idx: ConstPoolIdx) {
         self.emit(Bytecode::NewLambda(dest, idx));
     }
@@ -1,7 +1,10 @@
 use std::collections::{HashMap, HashSet};
 
 use crate::language::error::msg::ErrorMessage;
-use crate::language::sem_analysis::{SemAnalysis, SourceFileId};
+use crate::language::sem_analysis::{
+    find_method_with_replace, implements_trait, SemAnalysis, SourceFileId,
+};
+use crate::language::specialize::replace_assoc_types;
 
 use core_parser::lexer::position::Position;
 
@@ -19,8 +22,9 @@ pub fn check(sa: &mut SemAnalysis) {
                 let method = sa.fcts.idx(method_id);
                 let method = method.read();
 
-                if let Some(fid) = trait_.find_method_with_replace(
+                if let Some(fid) = find_method_with_replace(
                     sa,
+                    &impl_.trait_ty,
                     method.is_static,
                     method.name,
                     Some(impl_.extended_ty.clone()),
@@ -39,7 +43,11 @@ pub fn check(sa: &mut SemAnalysis) {
                         == if trait_method.return_type.is_self() {
                             impl_.extended_ty.clone()
                         } else {
-                            trait_method.return_type.clone()
+                            replace_assoc_types(
+                                sa,
+                                trait_method.return_type.clone(),
+                                &impl_.trait_ty,
+                            )
                         };
 
                     if !return_type_valid {
@@ -98,6 +106,23 @@ pub fn check(sa: &mut SemAnalysis) {
                 report(sa, impl_.file_id, impl_.pos, msg);
             }
 
+            if !impl_.extended_ty.is_error() {
+                for super_trait in trait_.super_trait_types(sa, &impl_.trait_ty) {
+                    if !implements_trait(
+                        sa,
+                        impl_.extended_ty.clone(),
+                        impl_.type_params(),
+                        super_trait.clone(),
+                    ) {
+                        let msg = ErrorMessage::TypeNotImplementingTrait(
+                            impl_.extended_ty.name(sa),
+                            super_trait.name(sa),
+                        );
+                        report(sa, impl_.file_id, impl_.pos, msg);
+                    }
+                }
+            }
+
             impl_for
         };
 
@@ -211,4 +236,62 @@ mod tests {
             class Bar {}
             impl Foo for Bar {}");
     }
+
+    #[test]
+    fn impl_method_with_default_body_overridden() {
+        ok("
+            trait Foo {
+                fun foo(): Int32 { 1i32 }
+            }
+            class Bar {}
+            impl Foo for Bar {
+                fun foo(): Int32 { 2i32 }
+            }");
+
+        err(
+            "
+            trait Foo {
+                fun foo(): Int32 { 1i32 }
+            }
+            class Bar {}
+            impl Foo for Bar {
+                fun foo(): Bool { false }
+            }",
+            pos(7, 17),
+            ErrorMessage::ReturnTypeMismatch("Bool".into(), "Int32".into()),
+        );
+    }
+
+    #[test]
+    fn method_return_type_check_assoc_type() {
+        err(
+            "
+            trait Foo { type Item; fun get(): Self::Item; }
+            class Bar
+            impl Foo for Bar {
+                type Item = Int32;
+                fun get(): Int64 { 1 }
+            }",
+            pos(6, 17),
+            ErrorMessage::ReturnTypeMismatch("Int64".into(), "Self::Item".into()),
+        );
+    }
+
+    #[test]
+    fn super_trait_impl_missing() {
+        ok("trait Foo {} trait Bar: Foo {}
+            class A
+            impl Foo for A {}
+            impl Bar for A {}");
+
+        err(
+            "
+            trait Foo {}
+            trait Bar: Foo {}
+            class A
+            impl Bar for A {}",
+            pos(5, 13),
+            ErrorMessage::TypeNotImplementingTrait("A".into(), "Foo".into()),
+        );
+    }
 }
@@ -1,10 +1,12 @@
 use crate::language::error::msg::ErrorMessage;
 use crate::language::extensiondefck::check_for_unconstrained_type_params;
+use crate::language::readty::read_impl_trait_type;
 use crate::language::sem_analysis::{
     FctDefinitionId, ImplDefinitionId, ModuleDefinitionId, SemAnalysis, SourceFileId,
+    TraitDefinitionId,
 };
 use crate::language::sym::{ModuleSymTable, Sym};
-use crate::language::ty::SourceType;
+use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::language::{self, AllowSelf, TypeParamContext};
 
 use core_parser::ast;
@@ -65,16 +67,17 @@ impl<'x> ImplCheck<'x> {
 
         let ast_trait_type = self.ast.trait_type.as_ref().unwrap();
 
-        if let Some(trait_ty) = language::read_type(
+        if let Some(trait_ty) = read_impl_trait_type(
             self.sa,
             &self.sym,
             self.file_id.into(),
             ast_trait_type,
             TypeParamContext::Impl(&*impl_),
-            AllowSelf::No,
         ) {
             match trait_ty {
                 SourceType::Trait(trait_id, type_params) => {
+                    let bindings = self.check_aliases(&*impl_, trait_id);
+                    let type_params = type_params.connect(&bindings);
                     impl_.trait_ty = SourceType::Trait(trait_id, type_params);
                 }
 
@@ -129,6 +132,11 @@ impl<'x> ImplCheck<'x> {
 
                 SourceType::Bool
                 | SourceType::UInt8
+                | SourceType::Int8
+                | SourceType::Int16
+                | SourceType::UInt16
+                | SourceType::UInt32
+                | SourceType::UInt64
                 | SourceType::Char
                 | SourceType::Int32
                 | SourceType::Int64
@@ -168,6 +176,74 @@ impl<'x> ImplCheck<'x> {
         }
     }
 
+    // Reads the associated types defined in the impl and returns them in the
+    // order they are declared in the trait.
+    fn check_aliases(
+        &mut self,
+        impl_: &ImplDefinition,
+        trait_id: TraitDefinitionId,
+    ) -> SourceTypeArray {
+        let trait_ = self.sa.traits[trait_id].read();
+        let mut bindings = vec![None; trait_.aliases.len()];
+
+        for &alias_id in &impl_.aliases {
+            let (name, pos, ast) = {
+                let alias = self.sa.aliases[alias_id].read();
+                (alias.name, alias.pos, alias.ast.clone())
+            };
+
+            let ty = language::read_type(
+                self.sa,
+                &self.sym,
+                self.file_id,
+                ast.ty.as_ref().expect("missing type"),
+                TypeParamContext::Impl(impl_),
+                AllowSelf::No,
+            )
+            .unwrap_or(SourceType::Error);
+
+            self.sa.aliases[alias_id].write().ty = Some(ty.clone());
+
+            let idx = trait_.alias_names.get(&name).and_then(|trait_alias_id| {
+                trait_.aliases.iter().position(|id| id == trait_alias_id)
+            });
+
+            match idx {
+                Some(idx) if bindings[idx].is_none() => {
+                    bindings[idx] = Some(ty);
+                }
+
+                Some(_) => {
+                    let name = self.sa.interner.str(name).to_string();
+                    let msg = ErrorMessage::AssocTypeExists(name);
+                    self.sa.diag.lock().report(self.file_id, pos, msg);
+                }
+
+                None => {
+                    let name = self.sa.interner.str(name).to_string();
+                    let msg = ErrorMessage::UnknownAssocType(trait_.name(self.sa), name);
+                    self.sa.diag.lock().report(self.file_id, pos, msg);
+                }
+            }
+        }
+
+        let bindings = bindings
+            .into_iter()
+            .zip(trait_.aliases.iter())
+            .map(|(binding, &trait_alias_id)| {
+                binding.unwrap_or_else(|| {
+                    let name = self.sa.aliases[trait_alias_id].read().name;
+                    let name = self.sa.interner.str(name).to_string();
+                    let msg = ErrorMessage::AssocTypeMissingFromImpl(trait_.name(self.sa), name);
+                    self.sa.diag.lock().report(self.file_id, self.ast.pos, msg);
+                    SourceType::Error
+                })
+            })
+            .collect::<Vec<_>>();
+
+        SourceTypeArray::with(bindings)
+    }
+
     fn visit_method(&mut self, impl_: &mut ImplDefinition, fct_id: FctDefinitionId) {
         let method = self.sa.fcts.idx(fct_id);
         let method = method.read();
@@ -352,4 +428,73 @@ mod tests {
             }
         ")
     }
+
+    #[test]
+    fn impl_assoc_type() {
+        ok("
+            trait Foo { type Item; fun get(): Self::Item; }
+            class Bar
+            impl Foo for Bar {
+                type Item = Int32;
+                fun get(): Int32 { 1i32 }
+            }
+        ");
+
+        ok("
+            trait Foo { type Item; fun get(): Self::Item; }
+            class Bar[T](value: T)
+            impl[T] Foo for Bar[T] {
+                type Item = T;
+                fun get(): Self::Item { self.value }
+            }
+        ");
+    }
+
+    #[test]
+    fn impl_assoc_type_missing() {
+        err(
+            "
+            trait Foo { type Item; }
+            class Bar
+            impl Foo for Bar {}",
+            pos(4, 13),
+            ErrorMessage::AssocTypeMissingFromImpl("Foo".into(), "Item".into()),
+        );
+    }
+
+    #[test]
+    fn impl_assoc_type_unknown() {
+        err(
+            "
+            trait Foo {}
+            class Bar
+            impl Foo for Bar { type Item = Int32; }",
+            pos(4, 32),
+            ErrorMessage::UnknownAssocType("Foo".into(), "Item".into()),
+        );
+    }
+
+    #[test]
+    fn impl_assoc_type_defined_twice() {
+        err(
+            "
+            trait Foo { type Item; }
+            class Bar
+            impl Foo for Bar { type Item = Int32; type Item = Int64; }",
+            pos(4, 51),
+            ErrorMessage::AssocTypeExists("Item".into()),
+        );
+    }
+
+    #[test]
+    fn impl_assoc_type_with_binding() {
+        err(
+            "
+            trait Foo { type Item; }
+            class Bar
+            impl Foo[Item = Int32] for Bar { type Item = Int32; }",
+            pos(4, 18),
+            ErrorMessage::UnexpectedAssocTypeBinding,
+        );
+    }
 }
@@ -0,0 +1,564 @@
+use core_parser::ast;
+
+use crate::language::error::msg::ErrorMessage;
+use crate::language::sem_analysis::{AnalysisData, IdentType, SemAnalysis, SourceFileId};
+use crate::language::specialize::replace_type_param;
+use crate::language::ty::SourceType;
+
+// Constructor tested by a pattern. Tuples and values only have a single
+// constructor, literals of numbers, chars and strings form an infinite set.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Ctor {
+    Variant(usize),
+    Bool(bool),
+    Tuple,
+    Value,
+    Int(i64),
+    Float(u64),
+    Char(char),
+    Str(String),
+}
+
+#[derive(Clone, Debug)]
+pub enum Pat {
+    // Matches everything, optionally binds the value to the variable of the
+    // given pattern node.
+    Any(Option<ast::NodeId>),
+    Ctor(Ctor, Vec<Pat>),
+}
+
+impl Pat {
+    pub fn ctor(&self) -> Option<&Ctor> {
+        match self {
+            Pat::Ctor(ctor, _) => Some(ctor),
+            Pat::Any(_) => None,
+        }
+    }
+}
+
+pub fn lower_pattern(analysis: &AnalysisData, pattern: &ast::MatchPattern) -> Pat {
+    match pattern {
+        ast::MatchPattern::Underscore(_) => Pat::Any(None),
+
+        ast::MatchPattern::Ident(ref ident) => match analysis.map_idents.get(ident.id) {
+            Some(&IdentType::EnumValue(_, _, variant_idx)) => {
+                Pat::Ctor(Ctor::Variant(variant_idx), Vec::new())
+            }
+            _ => Pat::Any(Some(ident.id)),
+        },
+
+        ast::MatchPattern::Lit(ref lit) => {
+            Pat::Ctor(lower_literal(analysis, &lit.expr), Vec::new())
+        }
+
+        ast::MatchPattern::Tuple(ref tuple) => {
+            if tuple.parts.is_empty() {
+                Pat::Any(None)
+            } else {
+                let parts = tuple
+                    .parts
+                    .iter()
+                    .map(|part| lower_pattern(analysis, part))
+                    .collect();
+                Pat::Ctor(Ctor::Tuple, parts)
+            }
+        }
+
+        ast::MatchPattern::Constructor(ref ctor) => {
+            let params = ctor
+                .params
+                .iter()
+                .flatten()
+                .map(|param| lower_pattern(analysis, param))
+                .collect();
+
+            match analysis.map_idents.get(ctor.id) {
+                Some(&IdentType::EnumValue(_, _, variant_idx)) => {
+                    Pat::Ctor(Ctor::Variant(variant_idx), params)
+                }
+                Some(&IdentType::Value(_)) => Pat::Ctor(Ctor::Value, params),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+fn lower_literal(analysis: &AnalysisData, expr: &ast::Expr) -> Ctor {
+    let (expr, negate) = match expr.to_un() {
+        Some(un) => (&*un.opnd, true),
+        None => (expr, false),
+    };
+
+    match expr {
+        ast::Expr::LitInt(ref lit) => match analysis.ty(lit.id) {
+            SourceType::Float32 | SourceType::Float64 => {
+                let value = lit.value as f64;
+                let value = if negate { -value } else { value };
+                Ctor::Float(value.to_bits())
+            }
+            _ => {
+                let value = lit.value as i64;
+                Ctor::Int(if negate { value.wrapping_neg() } else { value })
+            }
+        },
+        ast::Expr::LitFloat(ref lit) => {
+            let value = if negate { -lit.value } else { lit.value };
+            Ctor::Float(value.to_bits())
+        }
+        ast::Expr::LitChar(ref lit) => Ctor::Char(lit.value),
+        ast::Expr::LitStr(ref lit) => Ctor::Str(lit.value.clone()),
+        ast::Expr::LitBool(ref lit) => Ctor::Bool(lit.value),
+        _ => unreachable!(),
+    }
+}
+
+// Returns all constructors of the type, or None if there are infinitely
+// many of them.
+fn all_ctors(sa: &SemAnalysis, ty: &SourceType) -> Option<Vec<Ctor>> {
+    match ty {
+        SourceType::Bool => Some(vec![Ctor::Bool(true), Ctor::Bool(false)]),
+        SourceType::Enum(enum_id, _) => {
+            let enum_ = sa.enums[*enum_id].read();
+            Some((0..enum_.variants.len()).map(Ctor::Variant).collect())
+        }
+        SourceType::Tuple(_) => Some(vec![Ctor::Tuple]),
+        SourceType::Value(_, _) => Some(vec![Ctor::Value]),
+        _ => None,
+    }
+}
+
+pub fn ctor_subtypes(sa: &SemAnalysis, ty: &SourceType, ctor: &Ctor) -> Vec<SourceType> {
+    let type_params = ty.type_params();
+
+    match ctor {
+        Ctor::Variant(variant_idx) => {
+            let enum_id = ty.enum_id().expect("enum expected");
+            let enum_ = sa.enums[enum_id].read();
+
+            enum_.variants[*variant_idx]
+                .types
+                .iter()
+                .map(|ty| replace_type_param(sa, ty.clone(), &type_params, None))
+                .collect()
+        }
+        Ctor::Tuple => ty.tuple_subtypes().types().to_vec(),
+        Ctor::Value => {
+            let value_id = ty.value_id().expect("value expected");
+            let value = sa.values.idx(value_id);
+            let value = value.read();
+
+            value
+                .fields
+                .iter()
+                .map(|field| replace_type_param(sa, field.ty.clone(), &type_params, None))
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+// Distinct constructors in the first column of the matrix in order of
+// their first occurrence.
+pub fn head_ctors<'a, I>(heads: I) -> Vec<Ctor>
+where
+    I: Iterator<Item = &'a Pat>,
+{
+    let mut ctors: Vec<Ctor> = Vec::new();
+
+    for head in heads {
+        if let Some(ctor) = head.ctor() {
+            if !ctors.contains(ctor) {
+                ctors.push(ctor.clone());
+            }
+        }
+    }
+
+    ctors
+}
+
+// Returns all constructors of the type if the used constructors cover them.
+pub fn complete_ctors(sa: &SemAnalysis, ty: &SourceType, used: &[Ctor]) -> Option<Vec<Ctor>> {
+    let all = all_ctors(sa, ty)?;
+
+    if all.iter().all(|ctor| used.contains(ctor)) {
+        Some(all)
+    } else {
+        None
+    }
+}
+
+fn specialize(rows: &[Vec<Pat>], ctor: &Ctor, arity: usize) -> Vec<Vec<Pat>> {
+    let mut result = Vec::new();
+
+    for row in rows {
+        let mut new_row = match &row[0] {
+            Pat::Ctor(row_ctor, params) if row_ctor == ctor => params.clone(),
+            Pat::Ctor(_, _) => continue,
+            Pat::Any(_) => vec![Pat::Any(None); arity],
+        };
+
+        new_row.extend_from_slice(&row[1..]);
+        result.push(new_row);
+    }
+
+    result
+}
+
+fn default(rows: &[Vec<Pat>]) -> Vec<Vec<Pat>> {
+    rows.iter()
+        .filter(|row| row[0].ctor().is_none())
+        .map(|row| row[1..].to_vec())
+        .collect()
+}
+
+fn with_subtypes(sa: &SemAnalysis, tys: &[SourceType], ctor: &Ctor) -> Vec<SourceType> {
+    let mut result = ctor_subtypes(sa, &tys[0], ctor);
+    result.extend_from_slice(&tys[1..]);
+    result
+}
+
+// A pattern vector is useful if it matches some value that isn't
+// matched by any of the rows before it.
+fn is_useful(sa: &SemAnalysis, rows: &[Vec<Pat>], tys: &[SourceType], pats: &[Pat]) -> bool {
+    if pats.is_empty() {
+        return rows.is_empty();
+    }
+
+    match &pats[0] {
+        Pat::Ctor(ctor, params) => {
+            let rows = specialize(rows, ctor, params.len());
+            let tys = with_subtypes(sa, tys, ctor);
+            let mut pats_rest = params.clone();
+            pats_rest.extend_from_slice(&pats[1..]);
+            is_useful(sa, &rows, &tys, &pats_rest)
+        }
+
+        Pat::Any(_) => {
+            let used = head_ctors(rows.iter().map(|row| &row[0]));
+
+            if let Some(all) = complete_ctors(sa, &tys[0], &used) {
+                all.iter().any(|ctor| {
+                    let tys = with_subtypes(sa, tys, ctor);
+                    let arity = tys.len() + 1 - pats.len();
+                    let rows = specialize(rows, ctor, arity);
+                    let mut pats_rest = vec![Pat::Any(None); arity];
+                    pats_rest.extend_from_slice(&pats[1..]);
+                    is_useful(sa, &rows, &tys, &pats_rest)
+                })
+            } else {
+                is_useful(sa, &default(rows), &tys[1..], &pats[1..])
+            }
+        }
+    }
+}
+
+// Computes values not matched by any of the rows.
+fn missing_values(sa: &SemAnalysis, rows: &[Vec<Pat>], tys: &[SourceType]) -> Option<Vec<Pat>> {
+    if tys.is_empty() {
+        return if rows.is_empty() {
+            Some(Vec::new())
+        } else {
+            None
+        };
+    }
+
+    let used = head_ctors(rows.iter().map(|row| &row[0]));
+
+    if let Some(all) = complete_ctors(sa, &tys[0], &used) {
+        for ctor in all {
+            let subtys = with_subtypes(sa, tys, &ctor);
+            let arity = subtys.len() + 1 - tys.len();
+            let rows = specialize(rows, &ctor, arity);
+
+            if let Some(mut missing) = missing_values(sa, &rows, &subtys) {
+                let params = missing.drain(..arity).collect();
+                missing.insert(0, Pat::Ctor(ctor, params));
+                return Some(missing);
+            }
+        }
+
+        None
+    } else {
+        let mut missing = missing_values(sa, &default(rows), &tys[1..])?;
+
+        // Name one of the missing constructors when the type has finitely
+        // many of them, otherwise there is nothing better than `_`.
+        let head = all_ctors(sa, &tys[0])
+            .filter(|_| !used.is_empty())
+            .and_then(|all| all.into_iter().find(|ctor| !used.contains(ctor)))
+            .map(|ctor| {
+                let arity = ctor_subtypes(sa, &tys[0], &ctor).len();
+                Pat::Ctor(ctor, vec![Pat::Any(None); arity])
+            })
+            .unwrap_or(Pat::Any(None));
+
+        missing.insert(0, head);
+        Some(missing)
+    }
+}
+
+fn pattern_name(sa: &SemAnalysis, pat: &Pat, ty: &SourceType) -> String {
+    let (ctor, params) = match pat {
+        Pat::Any(_) => return "_".into(),
+        Pat::Ctor(ctor, params) => (ctor, params),
+    };
+
+    let subtypes = ctor_subtypes(sa, ty, ctor);
+    let params = params
+        .iter()
+        .zip(subtypes.iter())
+        .map(|(param, ty)| pattern_name(sa, param, ty))
+        .collect::<Vec<_>>();
+
+    let name = match ctor {
+        Ctor::Variant(variant_idx) => {
+            let enum_ = sa.enums[ty.enum_id().expect("enum expected")].read();
+            let variant = &enum_.variants[*variant_idx];
+            format!("{}::{}", enum_.name(sa), sa.interner.str(variant.name))
+        }
+        Ctor::Value => {
+            let value = sa.values.idx(ty.value_id().expect("value expected"));
+            let value = value.read();
+            value.name(sa)
+        }
+        Ctor::Bool(value) => return value.to_string(),
+        Ctor::Tuple => return format!("({})", params.join(", ")),
+        _ => return "_".into(),
+    };
+
+    if params.is_empty() {
+        name
+    } else {
+        format!("{}({})", name, params.join(", "))
+    }
+}
+
+pub fn check(
+    sa: &SemAnalysis,
+    file_id: SourceFileId,
+    analysis: &AnalysisData,
+    node: &ast::ExprMatchType,
+    ty: SourceType,
+) {
+    let tys = vec![ty.clone()];
+    let mut rows = Vec::new();
+
+    for case in &node.cases {
+        let pats = vec![lower_pattern(analysis, &case.pattern)];
+
+        if !is_useful(sa, &rows, &tys, &pats) {
+            let msg = ErrorMessage::MatchUnreachablePattern;
+            sa.diag.lock().report(file_id, case.pos, msg);
+        }
+
+        // Guarded cases might not match, they can't make other cases
+        // unreachable and don't help with exhaustiveness.
+        if case.guard.is_none() {
+            rows.push(pats);
+        }
+    }
+
+    if let Some(missing) = missing_values(sa, &rows, &tys) {
+        let msg = ErrorMessage::MatchUncovered(pattern_name(sa, &missing[0], &ty));
+        sa.diag.lock().report(file_id, node.pos, msg);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::error::msg::ErrorMessage;
+    use crate::language::tests::*;
+
+    #[test]
+    fn match_enum_exhaustive() {
+        ok("
+            enum Foo { A(Int32), B, C(Bool) }
+            fun f(x: Foo): Int32 {
+                match x {
+                    Foo::A(a) => a,
+                    Foo::B => 1i32,
+                    Foo::C(_) => 2i32,
+                }
+            }
+        ");
+
+        err(
+            "
+            enum Foo { A(Int32), B, C(Bool) }
+            fun f(x: Foo): Int32 {
+                match x {
+                    Foo::A(a) => a,
+                    Foo::C(_) => 2i32,
+                }
+            }
+        ",
+            pos(4, 17),
+            ErrorMessage::MatchUncovered("Foo::B".into()),
+        );
+
+        err(
+            "
+            enum Foo { A(Int32), B, C(Bool) }
+            fun f(x: Foo): Int32 {
+                match x {
+                    Foo::A(a) => a,
+                    Foo::B => 1i32,
+                    Foo::C(true) => 2i32,
+                }
+            }
+        ",
+            pos(4, 17),
+            ErrorMessage::MatchUncovered("Foo::C(false)".into()),
+        );
+    }
+
+    #[test]
+    fn match_nested_patterns() {
+        ok("
+            fun f(x: Option[(Int32, Bool)]): Int32 {
+                match x {
+                    Some((a, true)) => a,
+                    Some((_, false)) => 0i32,
+                    None => -1i32,
+                }
+            }
+        ");
+
+        err(
+            "
+            fun f(x: Option[(Int32, Bool)]): Int32 {
+                match x {
+                    Some((a, true)) => a,
+                    None => -1i32,
+                }
+            }
+        ",
+            pos(3, 17),
+            ErrorMessage::MatchUncovered("std::primitives::Option::Some((_, false))".into()),
+        );
+
+        ok("
+            value Point(x: Int32, y: Int32)
+            fun f(p: Point): Int32 {
+                match p {
+                    Point(0i32, y) => y,
+                    Point(x, _) => x,
+                }
+            }
+        ");
+    }
+
+    #[test]
+    fn match_literals() {
+        ok("
+            fun f(x: Int32): String {
+                match x {
+                    1i32 => \"one\",
+                    -1i32 => \"minus one\",
+                    _ => \"other\",
+                }
+            }
+        ");
+
+        err(
+            "
+            fun f(x: Int32): String {
+                match x {
+                    1i32 => \"one\",
+                    2i32 => \"two\",
+                }
+            }
+        ",
+            pos(3, 17),
+            ErrorMessage::MatchUncovered("_".into()),
+        );
+
+        ok("fun f(x: Bool): Int32 = match x { true => 1i32, false => 0i32 };");
+        ok("fun f(x: String): Int32 = match x { \"a\" => 1i32, _ => 0i32 };");
+        ok("fun f(x: Char): Int32 = match x { 'a' => 1i32, _ => 0i32 };");
+    }
+
+    #[test]
+    fn match_unreachable() {
+        err(
+            "fun f(x: Int32): Int32 = match x { _ => 1i32, 2i32 => 2i32 };",
+            pos(1, 47),
+            ErrorMessage::MatchUnreachablePattern,
+        );
+
+        err(
+            "fun f(x: (Bool, Bool)): Int32 = match x { (true, _) => 1i32, (false, _) => 2i32, (_, true) => 3i32 };",
+            pos(1, 82),
+            ErrorMessage::MatchUnreachablePattern,
+        );
+
+        err(
+            "fun f(x: Int32): Int32 = match x { 1i32 => 1i32, 1i32 => 2i32, _ => 3i32 };",
+            pos(1, 50),
+            ErrorMessage::MatchUnreachablePattern,
+        );
+    }
+
+    #[test]
+    fn match_guards() {
+        ok("fun f(x: Int32): Int32 = match x { a if a > 0i32 => a, _ => 0i32 };");
+        ok("fun f(x: Int32): Int32 = match x { a if a > 0i32 => a, a if a < 0i32 => 0i32 - a, a => a };");
+
+        err(
+            "fun f(x: Bool): Int32 = match x { true => 1i32, false if x => 0i32 };",
+            pos(1, 25),
+            ErrorMessage::MatchUncovered("false".into()),
+        );
+
+        err(
+            "fun f(x: Int32): Int32 = match x { a if a => 1i32, _ => 0i32 };",
+            pos(1, 41),
+            ErrorMessage::MatchGuardType("Int32".into()),
+        );
+    }
+
+    #[test]
+    fn match_pattern_errors() {
+        err(
+            "fun f(x: Int32): Int32 = match x { true => 1i32, _ => 0i32 };",
+            pos(1, 36),
+            ErrorMessage::PatternTypeMismatch("Bool".into(), "Int32".into()),
+        );
+
+        err(
+            "fun f(x: Int32): Int32 = match x { (a, b) => a, _ => 0i32 };",
+            pos(1, 36),
+            ErrorMessage::LetPatternExpectedTuple("Int32".into()),
+        );
+
+        err(
+            "fun f(x: (Int32, Int32)): Int32 = match x { (a, b, c) => a };",
+            pos(1, 45),
+            ErrorMessage::LetPatternExpectedTupleWithLength("(Int32, Int32)".into(), 2, 3),
+        );
+
+        err(
+            "fun f(x: Option[Int32]): Int32 = match x { Some(a, b) => a, None => 0i32 };",
+            pos(1, 44),
+            ErrorMessage::IfPatternWrongNumberOfParams(2, 1),
+        );
+
+        err(
+            "fun f(x: Option[(Int32, Int32)]): Int32 = match x { Some((a, a)) => a, _ => 0i32 };",
+            pos(1, 62),
+            ErrorMessage::IfPatternBindingAlreadyUsed,
+        );
+
+        err(
+            "fun f(x: Int32): Int32 = match x { 1i32 => 1i32, _ => \"a\" };",
+            pos(1, 55),
+            ErrorMessage::MatchBranchTypesIncompatible("Int32".into(), "String".into()),
+        );
+
+        err(
+            "fun g(): Int32 = 1i32; fun f(x: Int32): Int32 = match x { g(a) => a };",
+            pos(1, 59),
+            ErrorMessage::PatternConstructorExpected,
+        );
+    }
+}
@@ -1,3 +1,4 @@
+use std::collections::hash_map::Entry;
 use std::collections::{HashMap, VecDeque};
 use std::fs;
 use std::io::{Error, Read};
@@ -7,11 +8,11 @@ use std::sync::Arc;
 use crate::language::error::msg::ErrorMessage;
 use crate::language::report_sym_shadow;
 use crate::language::sem_analysis::{
-    AnnotationDefinition, ClassDefinition, ConstDefinition, EnumDefinition, ExtensionDefinition,
-    ExtensionDefinitionId, FctDefinition, FctParent, GlobalDefinition, GlobalDefinitionId,
-    ImplDefinition, ImplDefinitionId, ModuleDefinition, ModuleDefinitionId, PackageDefinitionId,
-    PackageName, SemAnalysis, SourceFileId, TraitDefinition, TraitDefinitionId, UseDefinition,
-    ValueDefinition,
+    AliasDefinition, AliasParent, AnnotationDefinition, ClassDefinition, ConstDefinition,
+    EnumDefinition, ExtensionDefinition, ExtensionDefinitionId, FctDefinition, FctParent,
+    GlobalDefinition, GlobalDefinitionId, ImplDefinition, ImplDefinitionId, ModuleDefinition,
+    ModuleDefinitionId, PackageDefinitionId, PackageName, SemAnalysis, SourceFileId,
+    TraitDefinition, TraitDefinitionId, UnionDefinition, UseDefinition, ValueDefinition,
 };
 use crate::language::sym::Sym;
 use core_parser::ast::visit::Visitor;
@@ -441,6 +442,11 @@ impl<'x> visit::Visitor for GlobalDef<'x> {
 
             find_methods_in_impl(self.sa, impl_id, node);
         } else {
+            for alias in &node.aliases {
+                let msg = ErrorMessage::AssocTypeNotAllowed;
+                self.sa.diag.lock().report(self.file_id, alias.pos, msg);
+            }
+
             let extension =
                 ExtensionDefinition::new(self.package_id, self.module_id, self.file_id, node);
             let extension_id = self.sa.extensions.push(extension);
@@ -480,6 +486,32 @@ impl<'x> visit::Visitor for GlobalDef<'x> {
         }
     }
 
+    fn visit_union(&mut self, node: &Arc<ast::Union>) {
+        let union_ = UnionDefinition::new(self.package_id, self.module_id, self.file_id, node);
+        let id = self.sa.unions.push(union_);
+
+        let sym = Sym::Union(id);
+        if let Some(sym) = self.insert(node.name, sym) {
+            report_sym_shadow(self.sa, node.name, self.file_id, node.pos, sym);
+        }
+    }
+
+    fn visit_alias(&mut self, node: &Arc<ast::Alias>) {
+        let alias = AliasDefinition::new(
+            self.package_id,
+            self.module_id,
+            self.file_id,
+            AliasParent::None,
+            node,
+        );
+        let id = self.sa.aliases.push(alias);
+
+        let sym = Sym::Alias(id);
+        if let Some(sym) = self.insert(node.name, sym) {
+            report_sym_shadow(self.sa, node.name, self.file_id, node.pos, sym);
+        }
+    }
+
     fn visit_annotation(&mut self, node: &Arc<ast::Annotation>) {
         let annotation = AnnotationDefinition::new(
             self.package_id,
@@ -544,6 +576,30 @@ fn find_methods_in_trait(
         let fct_id = sa.add_fct(fct);
         trait_.methods.push(fct_id);
     }
+
+    for alias_node in &node.aliases {
+        let alias = AliasDefinition::new(
+            trait_.package_id,
+            trait_.module_id,
+            trait_.file_id,
+            AliasParent::Trait(trait_id),
+            alias_node,
+        );
+
+        let alias_id = sa.aliases.push(alias);
+
+        match trait_.alias_names.entry(alias_node.name) {
+            Entry::Occupied(_) => {
+                let name = sa.interner.str(alias_node.name).to_string();
+                let msg = ErrorMessage::AssocTypeExists(name);
+                sa.diag.lock().report(trait_.file_id, alias_node.pos, msg);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(alias_id);
+                trait_.aliases.push(alias_id);
+            }
+        }
+    }
 }
 
 fn find_methods_in_impl(sa: &mut SemAnalysis, impl_id: ImplDefinitionId, node: &Arc<ast::Impl>) {
@@ -562,6 +618,19 @@ fn find_methods_in_impl(sa: &mut SemAnalysis, impl_id: ImplDefinitionId, node: &
         let fct_id = sa.add_fct(fct);
         impl_.methods.push(fct_id);
     }
+
+    for alias_node in &node.aliases {
+        let alias = AliasDefinition::new(
+            impl_.package_id,
+            impl_.module_id,
+            impl_.file_id,
+            AliasParent::Impl(impl_id),
+            alias_node,
+        );
+
+        let alias_id = sa.aliases.push(alias);
+        impl_.aliases.push(alias_id);
+    }
 }
 
 fn find_methods_in_extension(
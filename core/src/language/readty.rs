@@ -2,25 +2,29 @@ use parking_lot::RwLock;
 use std::sync::Arc;
 
 use crate::language::access::{
-    class_accessible_from, enum_accessible_from, trait_accessible_from, value_accessible_from,
+    alias_accessible_from, class_accessible_from, enum_accessible_from, trait_accessible_from,
+    union_accessible_from, value_accessible_from,
 };
+use crate::language::aliasck;
 use crate::language::error::msg::ErrorMessage;
 use crate::language::sem_analysis::{
-    implements_trait, ClassDefinitionId, EnumDefinitionId, ExtensionDefinitionId, FctDefinition,
-    ImplDefinition, ModuleDefinitionId, SemAnalysis, SourceFileId, TraitDefinitionId,
-    TypeParamDefinition, ValueDefinitionId,
+    implements_trait, AliasDefinitionId, AliasParent, ClassDefinitionId, EnumDefinitionId,
+    ExtensionDefinitionId, FctDefinition, ImplDefinition, SemAnalysis, SourceFileId,
+    TraitDefinitionId, TypeParamDefinition, UnionDefinitionId, ValueDefinitionId,
 };
 use crate::language::specialize::specialize_type;
 use crate::language::sym::{ModuleSymTable, Sym, SymTable};
 use crate::language::ty::{SourceType, SourceTypeArray};
 
-use core_parser::ast::{self, TypeBasicType, TypeLambdaType, TypeTupleType};
+use core_parser::ast::{self, TypeAssocType, TypeBasicType, TypeLambdaType, TypeTupleType};
 use core_parser::lexer::position::Position;
 
 #[derive(Copy, Clone)]
 pub enum TypeParamContext<'a> {
     Class(ClassDefinitionId),
     Enum(EnumDefinitionId),
+    Union(UnionDefinitionId),
+    Alias(AliasDefinitionId),
     Value(ValueDefinitionId),
     Fct(&'a FctDefinition),
     Trait(TraitDefinitionId),
@@ -43,6 +47,7 @@ pub fn read_type_unchecked(
 ) -> SourceType {
     match *t {
         ast::Type::This(_) => SourceType::This,
+        ast::Type::Assoc(ref node) => read_type_assoc_unchecked(sa, table, file_id, node),
         ast::Type::Basic(ref node) => read_type_basic_unchecked(sa, table, file_id, node),
         ast::Type::Tuple(ref node) => read_type_tuple_unchecked(sa, table, file_id, node),
         ast::Type::Lambda(ref node) => read_type_lambda_unchecked(sa, table, file_id, node),
@@ -72,6 +77,20 @@ fn read_type_basic_unchecked(
         type_params.push(ty);
     }
 
+    if !node.bindings.is_empty() && sym.is_some() {
+        return match sym {
+            Some(Sym::Trait(trait_id)) => {
+                read_type_trait_bindings(sa, table, file_id, node, trait_id, type_params)
+            }
+
+            _ => {
+                let msg = ErrorMessage::UnexpectedAssocTypeBinding;
+                sa.diag.lock().report(file_id, node.pos, msg);
+                SourceType::Error
+            }
+        };
+    }
+
     let type_params = SourceTypeArray::with(type_params);
 
     match sym {
@@ -94,6 +113,31 @@ fn read_type_basic_unchecked(
             }
         }
         Some(Sym::Enum(enum_id)) => SourceType::Enum(enum_id, type_params),
+        Some(Sym::Union(union_id)) => SourceType::Union(union_id, type_params),
+        Some(Sym::Alias(alias_id)) if !sa.aliases[alias_id].read().parent.is_none() => {
+            if !type_params.is_empty() {
+                let msg = ErrorMessage::WrongNumberTypeParams(0, type_params.len());
+                sa.diag.lock().report(file_id, node.pos, msg);
+                return SourceType::Error;
+            }
+
+            read_assoc_alias(sa, alias_id)
+        }
+        Some(Sym::Alias(alias_id)) => {
+            let number_type_params = sa.aliases[alias_id].read().number_type_params();
+
+            if number_type_params != type_params.len() {
+                let msg =
+                    ErrorMessage::WrongNumberTypeParams(number_type_params, type_params.len());
+                sa.diag.lock().report(file_id, node.pos, msg);
+                SourceType::Error
+            } else if type_params.iter().any(|ty| contains_error(&ty)) {
+                SourceType::Error
+            } else {
+                let ty = aliasck::resolve_alias(sa, alias_id);
+                specialize_type(sa, ty, &type_params)
+            }
+        }
         Some(Sym::TypeParam(type_param_id)) => {
             if node.params.len() > 0 {
                 let msg = ErrorMessage::NoTypeParamsExpected;
@@ -125,6 +169,117 @@ fn read_type_basic_unchecked(
     }
 }
 
+// Bindings for associated types are stored after the regular type params
+// of the trait, in the order the associated types are declared in the trait.
+fn read_type_trait_bindings(
+    sa: &SemAnalysis,
+    table: &ModuleSymTable,
+    file_id: SourceFileId,
+    node: &TypeBasicType,
+    trait_id: TraitDefinitionId,
+    mut type_params: Vec<SourceType>,
+) -> SourceType {
+    let trait_ = sa.traits[trait_id].read();
+    let mut bindings: Vec<Option<SourceType>> = vec![None; trait_.aliases.len()];
+    let mut success = true;
+
+    for binding in &node.bindings {
+        let ty = read_type_unchecked(sa, table, file_id, &binding.ty);
+
+        let idx = trait_
+            .alias_names
+            .get(&binding.name)
+            .and_then(|alias_id| trait_.aliases.iter().position(|id| id == alias_id));
+
+        match idx {
+            Some(idx) if bindings[idx].is_none() => {
+                bindings[idx] = Some(ty);
+            }
+
+            Some(_) => {
+                let name = sa.interner.str(binding.name).to_string();
+                let msg = ErrorMessage::AssocTypeExists(name);
+                sa.diag.lock().report(file_id, binding.pos, msg);
+                success = false;
+            }
+
+            None => {
+                let trait_name = trait_.name(sa);
+                let name = sa.interner.str(binding.name).to_string();
+                let msg = ErrorMessage::UnknownAssocType(trait_name, name);
+                sa.diag.lock().report(file_id, binding.pos, msg);
+                success = false;
+            }
+        }
+    }
+
+    for (idx, binding) in bindings.into_iter().enumerate() {
+        if let Some(ty) = binding {
+            type_params.push(ty);
+        } else if success {
+            let alias_name = sa.aliases[trait_.aliases[idx]].read().name;
+            let name = sa.interner.str(alias_name).to_string();
+            let msg = ErrorMessage::MissingAssocTypeBinding(trait_.name(sa), name);
+            sa.diag.lock().report(file_id, node.pos, msg);
+            success = false;
+        }
+    }
+
+    if success {
+        SourceType::Trait(trait_id, SourceTypeArray::with(type_params))
+    } else {
+        SourceType::Error
+    }
+}
+
+fn read_type_assoc_unchecked(
+    sa: &SemAnalysis,
+    table: &ModuleSymTable,
+    file_id: SourceFileId,
+    node: &TypeAssocType,
+) -> SourceType {
+    match table.get(node.name) {
+        Some(Sym::Alias(alias_id)) if !sa.aliases[alias_id].read().parent.is_none() => {
+            read_assoc_alias(sa, alias_id)
+        }
+
+        _ => {
+            let name = format!("Self::{}", sa.interner.str(node.name));
+            let msg = ErrorMessage::UnknownType(name);
+            sa.diag.lock().report(file_id, node.pos, msg);
+            SourceType::Error
+        }
+    }
+}
+
+// Inside a trait an associated type stays abstract, inside an impl it
+// is replaced by its definition.
+fn read_assoc_alias(sa: &SemAnalysis, alias_id: AliasDefinitionId) -> SourceType {
+    let alias = sa.aliases[alias_id].read();
+
+    match alias.parent {
+        AliasParent::Trait(_) => SourceType::Assoc(alias_id),
+        AliasParent::Impl(_) => alias.ty.clone().unwrap_or(SourceType::Error),
+        AliasParent::None => unreachable!(),
+    }
+}
+
+fn contains_error(ty: &SourceType) -> bool {
+    match ty {
+        SourceType::Error => true,
+        SourceType::Class(_, params)
+        | SourceType::Trait(_, params)
+        | SourceType::Value(_, params)
+        | SourceType::Enum(_, params)
+        | SourceType::Union(_, params)
+        | SourceType::Tuple(params) => params.iter().any(|ty| contains_error(&ty)),
+        SourceType::Lambda(params, return_type) => {
+            params.iter().any(|ty| contains_error(&ty)) || contains_error(return_type)
+        }
+        _ => false,
+    }
+}
+
 fn read_type_lambda_unchecked(
     sa: &SemAnalysis,
     table: &ModuleSymTable,
@@ -167,7 +322,7 @@ fn read_type_tuple_unchecked(
 
 pub fn verify_type(
     sa: &SemAnalysis,
-    module_id: ModuleDefinitionId,
+    table: &ModuleSymTable,
     file_id: SourceFileId,
     t: &ast::Type,
     ty: SourceType,
@@ -186,8 +341,11 @@ pub fn verify_type(
             }
         }
 
+        // only associated types in scope are read, nothing left to verify
+        &ast::Type::Assoc(_) => {}
+
         &ast::Type::Basic(ref node) => {
-            if !verify_type_basic(sa, module_id, file_id, node, ty, ctxt, allow_self) {
+            if !verify_type_basic(sa, table, file_id, node, ty, ctxt, allow_self) {
                 return false;
             }
         }
@@ -204,7 +362,7 @@ pub fn verify_type(
             assert_eq!(subtypes.len(), node.subtypes.len());
 
             for (subtype, ast_param) in subtypes.iter().zip(node.subtypes.iter()) {
-                if !verify_type(sa, module_id, file_id, ast_param, subtype, ctxt, allow_self) {
+                if !verify_type(sa, table, file_id, ast_param, subtype, ctxt, allow_self) {
                     return false;
                 }
             }
@@ -218,20 +376,12 @@ pub fn verify_type(
             assert_eq!(params.len(), node.params.len());
 
             for (param, ast_param) in params.iter().zip(node.params.iter()) {
-                if !verify_type(sa, module_id, file_id, ast_param, param, ctxt, allow_self) {
+                if !verify_type(sa, table, file_id, ast_param, param, ctxt, allow_self) {
                     return false;
                 }
             }
 
-            if !verify_type(
-                sa,
-                module_id,
-                file_id,
-                &node.ret,
-                return_type,
-                ctxt,
-                allow_self,
-            ) {
+            if !verify_type(sa, table, file_id, &node.ret, return_type, ctxt, allow_self) {
                 return false;
             }
         }
@@ -242,14 +392,24 @@ pub fn verify_type(
 
 fn verify_type_basic(
     sa: &SemAnalysis,
-    module_id: ModuleDefinitionId,
+    table: &ModuleSymTable,
     file_id: SourceFileId,
     node: &ast::TypeBasicType,
     ty: SourceType,
     ctxt: TypeParamContext,
     allow_self: AllowSelf,
 ) -> bool {
-    match ty {
+    let module_id = table.module_id();
+
+    if ty.is_error() {
+        return false;
+    }
+
+    if let Ok(Some(Sym::Alias(alias_id))) = read_type_path(sa, table, file_id, node) {
+        return verify_type_alias(sa, table, file_id, node, alias_id, ctxt, allow_self);
+    }
+
+    match ty.clone() {
         SourceType::TypeParam(_) => {}
 
         SourceType::Class(cls_id, type_params) => {
@@ -265,7 +425,7 @@ fn verify_type_basic(
             for (type_param, ast_type_param) in type_params.iter().zip(node.params.iter()) {
                 if !verify_type(
                     sa,
-                    module_id,
+                    table,
                     file_id,
                     ast_type_param,
                     type_param,
@@ -301,7 +461,7 @@ fn verify_type_basic(
             for (type_param, ast_type_param) in type_params.iter().zip(node.params.iter()) {
                 if !verify_type(
                     sa,
-                    module_id,
+                    table,
                     file_id,
                     ast_type_param,
                     type_param,
@@ -324,9 +484,50 @@ fn verify_type_basic(
             }
         }
 
+        SourceType::Union(union_id, type_params) => {
+            let union_ = sa.unions.idx(union_id);
+            let union_ = union_.read();
+
+            if !union_accessible_from(sa, union_id, module_id) {
+                let msg = ErrorMessage::NotAccessible(union_.name(sa));
+                sa.diag.lock().report(file_id, node.pos, msg);
+                return false;
+            }
+
+            for (type_param, ast_type_param) in type_params.iter().zip(node.params.iter()) {
+                if !verify_type(
+                    sa,
+                    table,
+                    file_id,
+                    ast_type_param,
+                    type_param,
+                    ctxt,
+                    allow_self,
+                ) {
+                    return false;
+                }
+            }
+
+            if !check_type_params(
+                sa,
+                union_.type_params(),
+                type_params.types(),
+                file_id,
+                node.pos,
+                ctxt,
+            ) {
+                return false;
+            }
+        }
+
         SourceType::Unit
         | SourceType::Bool
         | SourceType::UInt8
+        | SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt16
+        | SourceType::UInt32
+        | SourceType::UInt64
         | SourceType::Char
         | SourceType::Int32
         | SourceType::Int64
@@ -358,7 +559,7 @@ fn verify_type_basic(
             for (type_param, ast_type_param) in type_params.iter().zip(node.params.iter()) {
                 if !verify_type(
                     sa,
-                    module_id,
+                    table,
                     file_id,
                     ast_type_param,
                     type_param,
@@ -381,38 +582,21 @@ fn verify_type_basic(
             }
         }
 
-        SourceType::Trait(trait_id, type_params) => {
-            let trait_ = sa.traits.idx(trait_id);
-            let trait_ = trait_.read();
-
-            if !trait_accessible_from(sa, trait_id, module_id) {
-                let msg = ErrorMessage::NotAccessible(trait_.name(sa));
-                sa.diag.lock().report(file_id, node.pos, msg);
+        SourceType::Trait(trait_id, _) => {
+            if !verify_type_trait(sa, table, file_id, node, ty.clone(), ctxt, allow_self) {
                 return false;
             }
 
-            for (type_param, ast_type_param) in type_params.iter().zip(node.params.iter()) {
-                if !verify_type(
-                    sa,
-                    module_id,
-                    file_id,
-                    ast_type_param,
-                    type_param,
-                    ctxt,
-                    allow_self,
-                ) {
-                    return false;
-                }
-            }
+            // Type param bounds need bindings as well: there is no projection
+            // like `T::Item` that could name an unbound associated type.
+            let trait_ = sa.traits.idx(trait_id);
+            let trait_ = trait_.read();
 
-            if !check_type_params(
-                sa,
-                trait_.type_params(),
-                type_params.types(),
-                file_id,
-                node.pos,
-                ctxt,
-            ) {
+            if node.bindings.is_empty() && !trait_.aliases.is_empty() {
+                let alias_name = sa.aliases[trait_.aliases[0]].read().name;
+                let name = sa.interner.str(alias_name).to_string();
+                let msg = ErrorMessage::MissingAssocTypeBinding(trait_.name(sa), name);
+                sa.diag.lock().report(file_id, node.pos, msg);
                 return false;
             }
         }
@@ -430,25 +614,167 @@ fn verify_type_basic(
     true
 }
 
-pub fn read_type(
+fn verify_type_trait(
     sa: &SemAnalysis,
     table: &ModuleSymTable,
     file_id: SourceFileId,
-    t: &ast::Type,
+    node: &ast::TypeBasicType,
+    trait_ty: SourceType,
     ctxt: TypeParamContext,
     allow_self: AllowSelf,
+) -> bool {
+    let trait_id = trait_ty.trait_id().expect("trait expected");
+    let type_params = trait_ty.type_params();
+    let trait_ = sa.traits.idx(trait_id);
+    let trait_ = trait_.read();
+
+    if !trait_accessible_from(sa, trait_id, table.module_id()) {
+        let msg = ErrorMessage::NotAccessible(trait_.name(sa));
+        sa.diag.lock().report(file_id, node.pos, msg);
+        return false;
+    }
+
+    let (params, bindings) = type_params.types().split_at(node.params.len());
+
+    for (type_param, ast_type_param) in params.iter().zip(node.params.iter()) {
+        if !verify_type(
+            sa,
+            table,
+            file_id,
+            ast_type_param,
+            type_param.clone(),
+            ctxt,
+            allow_self,
+        ) {
+            return false;
+        }
+    }
+
+    for (&alias_id, binding_ty) in trait_.aliases.iter().zip(bindings.iter()) {
+        let alias_name = sa.aliases[alias_id].read().name;
+        let binding = node
+            .bindings
+            .iter()
+            .find(|binding| binding.name == alias_name)
+            .expect("missing binding");
+
+        if !verify_type(
+            sa,
+            table,
+            file_id,
+            &binding.ty,
+            binding_ty.clone(),
+            ctxt,
+            allow_self,
+        ) {
+            return false;
+        }
+    }
+
+    check_type_params(sa, trait_.type_params(), params, file_id, node.pos, ctxt)
+}
+
+// Impls name their trait without bindings for its associated types,
+// the impl defines them itself (e.g. `type Item = Int32`).
+pub fn read_impl_trait_type(
+    sa: &SemAnalysis,
+    table: &ModuleSymTable,
+    file_id: SourceFileId,
+    t: &ast::Type,
+    ctxt: TypeParamContext,
 ) -> Option<SourceType> {
     let ty = read_type_unchecked(sa, table, file_id, t);
 
-    if verify_type(
+    match (t, ty.clone()) {
+        (ast::Type::Basic(node), SourceType::Trait(_, _)) => {
+            if !node.bindings.is_empty() {
+                let msg = ErrorMessage::UnexpectedAssocTypeBinding;
+                sa.diag.lock().report(file_id, node.pos, msg);
+                return None;
+            }
+
+            if verify_type_trait(sa, table, file_id, node, ty.clone(), ctxt, AllowSelf::No) {
+                Some(ty)
+            } else {
+                None
+            }
+        }
+
+        _ => {
+            if verify_type(sa, table, file_id, t, ty.clone(), ctxt, AllowSelf::No) {
+                Some(ty)
+            } else {
+                None
+            }
+        }
+    }
+}
+
+fn verify_type_alias(
+    sa: &SemAnalysis,
+    table: &ModuleSymTable,
+    file_id: SourceFileId,
+    node: &ast::TypeBasicType,
+    alias_id: AliasDefinitionId,
+    ctxt: TypeParamContext,
+    allow_self: AllowSelf,
+) -> bool {
+    // associated types were already verified with their trait or impl
+    if !sa.aliases[alias_id].read().parent.is_none() {
+        return true;
+    }
+
+    if !alias_accessible_from(sa, alias_id, table.module_id()) {
+        let alias = sa.aliases.idx(alias_id);
+        let msg = ErrorMessage::NotAccessible(alias.read().name(sa));
+        sa.diag.lock().report(file_id, node.pos, msg);
+        return false;
+    }
+
+    let mut type_params = Vec::with_capacity(node.params.len());
+
+    for ast_type_param in &node.params {
+        let type_param = read_type_unchecked(sa, table, file_id, ast_type_param);
+
+        if !verify_type(
+            sa,
+            table,
+            file_id,
+            ast_type_param,
+            type_param.clone(),
+            ctxt,
+            allow_self,
+        ) {
+            return false;
+        }
+
+        type_params.push(type_param);
+    }
+
+    let alias = sa.aliases.idx(alias_id);
+    let alias = alias.read();
+
+    check_type_params(
         sa,
-        table.module_id(),
+        alias.type_params(),
+        &type_params,
         file_id,
-        t,
-        ty.clone(),
+        node.pos,
         ctxt,
-        allow_self,
-    ) {
+    )
+}
+
+pub fn read_type(
+    sa: &SemAnalysis,
+    table: &ModuleSymTable,
+    file_id: SourceFileId,
+    t: &ast::Type,
+    ctxt: TypeParamContext,
+    allow_self: AllowSelf,
+) -> Option<SourceType> {
+    let ty = read_type_unchecked(sa, table, file_id, t);
+
+    if verify_type(sa, table, file_id, t, ty.clone(), ctxt, allow_self) {
         Some(ty)
     } else {
         None
@@ -519,7 +845,7 @@ fn check_type_params(
     use_type_params(sa, ctxt, |check_type_param_defs| {
         for bound in tp_definitions.bounds() {
             let tp_ty = bound.ty();
-            let trait_ty = bound.trait_ty();
+            let trait_ty = specialize_type(sa, bound.trait_ty(), &type_params_sta);
             let tp_ty = specialize_type(sa, tp_ty, &type_params_sta);
 
             if !implements_trait(sa, tp_ty.clone(), check_type_param_defs, trait_ty.clone()) {
@@ -554,6 +880,20 @@ where
             callback(enum_.type_params())
         }
 
+        TypeParamContext::Union(union_id) => {
+            let union_ = &sa.unions[union_id];
+            let union_ = union_.read();
+
+            callback(union_.type_params())
+        }
+
+        TypeParamContext::Alias(alias_id) => {
+            let alias = &sa.aliases[alias_id];
+            let alias = alias.read();
+
+            callback(alias.type_params())
+        }
+
         TypeParamContext::Value(value_id) => {
             let value = &sa.values.idx(value_id);
             let value = value.read();
@@ -621,6 +961,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn mod_union() {
+        ok("
+            fun f(x: foo::Foo): Unit {}
+            mod foo { @pub class A @pub union Foo of A; }
+        ");
+
+        err(
+            "
+            fun f(x: foo::Foo): Unit {}
+            mod foo { @pub class A union Foo of A; }
+        ",
+            pos(2, 22),
+            ErrorMessage::NotAccessible("foo::Foo".into()),
+        );
+    }
+
     #[test]
     fn mod_trait() {
         ok("
@@ -637,4 +994,67 @@ mod tests {
             ErrorMessage::NotAccessible("foo::Foo".into()),
         );
     }
+
+    #[test]
+    fn trait_bindings() {
+        ok("
+            trait Foo { type Item; }
+            fun f(x: Foo[Item = Int32]): Unit {}
+            fun g[T: Foo[Item = String]](x: T): Unit {}
+        ");
+
+        err(
+            "
+            trait Foo { type Item; }
+            fun f(x: Foo): Unit {}
+        ",
+            pos(3, 22),
+            ErrorMessage::MissingAssocTypeBinding("Foo".into(), "Item".into()),
+        );
+
+        err(
+            "
+            trait Foo { type Item; }
+            fun g[T: Foo](x: T): Unit {}
+        ",
+            pos(3, 22),
+            ErrorMessage::MissingAssocTypeBinding("Foo".into(), "Item".into()),
+        );
+
+        err(
+            "
+            trait Foo { type Item; }
+            fun g[T: Foo[Item = Int32]](x: T): T::Item { 1i32 }
+        ",
+            pos(3, 48),
+            ErrorMessage::ExpectedModule,
+        );
+
+        err(
+            "
+            trait Foo { type Item; }
+            fun f(x: Foo[Item = Int32, Item = Int64]): Unit {}
+        ",
+            pos(3, 40),
+            ErrorMessage::AssocTypeExists("Item".into()),
+        );
+
+        err(
+            "
+            trait Foo { type Item; }
+            fun f(x: Foo[Value = Int32]): Unit {}
+        ",
+            pos(3, 26),
+            ErrorMessage::UnknownAssocType("Foo".into(), "Value".into()),
+        );
+
+        err(
+            "
+            class Foo
+            fun f(x: Foo[Item = Int32]): Unit {}
+        ",
+            pos(3, 22),
+            ErrorMessage::UnexpectedAssocTypeBinding,
+        );
+    }
 }
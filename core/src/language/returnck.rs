@@ -7,6 +7,8 @@ pub fn returns_value(s: &Stmt) -> Result<(), Position> {
         Stmt::For(ref stmt) => Err(stmt.pos),
         Stmt::While(ref stmt) => Err(stmt.pos),
         Stmt::Let(ref stmt) => Err(stmt.pos),
+        Stmt::Break(ref stmt) => Err(stmt.pos),
+        Stmt::Continue(ref stmt) => Err(stmt.pos),
         Stmt::Expr(ref stmt) => expr_returns_value(&stmt.expr),
     }
 }
@@ -15,6 +17,7 @@ pub fn expr_returns_value(e: &Expr) -> Result<(), Position> {
     match *e {
         Expr::Block(ref block) => expr_block_returns_value(block),
         Expr::If(ref expr) => expr_if_returns_value(expr),
+        Expr::Match(ref expr) => expr_match_returns_value(expr),
         _ => Err(e.pos()),
     }
 }
@@ -47,6 +50,18 @@ fn expr_if_returns_value(e: &ExprIfType) -> Result<(), Position> {
     }
 }
 
+fn expr_match_returns_value(e: &ExprMatchType) -> Result<(), Position> {
+    if e.cases.is_empty() {
+        return Err(e.pos);
+    }
+
+    for case in &e.cases {
+        expr_returns_value(&case.value)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::language::error::msg::ErrorMessage;
@@ -11,20 +11,24 @@ use crate::language::sym::SymTable;
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::vm::VM;
 
+pub use self::aliases::{AliasDefinition, AliasDefinitionId, AliasParent};
 pub use self::annotations::{AnnotationDefinition, AnnotationDefinitionId};
 pub use self::classes::{
-    find_field_in_class, find_methods_in_class, Candidate, ClassDefinition, ClassDefinitionId,
-    Field, FieldId, TypeParamDefinition, TypeParamId, Visibility,
+    find_field_in_class, find_methods_in_class, parent_class_type, Candidate, ClassDefinition,
+    ClassDefinitionId, Field, FieldId, TypeParamDefinition, TypeParamId, Visibility,
 };
 pub use self::consts::{ConstDefinition, ConstDefinitionId, ConstValue};
 pub use self::enums::{find_methods_in_enum, EnumDefinition, EnumDefinitionId, EnumVariant};
 pub use self::extensions::{
     extension_matches, extension_matches_ty, ExtensionDefinition, ExtensionDefinitionId,
 };
-pub use self::functions::{FctDefinition, FctDefinitionId, FctParent, Intrinsic};
+pub use self::functions::{
+    ArgumentError, CallArgument, FctDefinition, FctDefinitionId, FctParent, Intrinsic,
+};
 pub use self::globals::{GlobalDefinition, GlobalDefinitionId};
 pub use self::impls::{
-    find_impl, find_trait_impl, impl_matches, implements_trait, ImplDefinition, ImplDefinitionId,
+    default_method_type_params, find_default_method, find_impl, find_trait_impl, impl_matches,
+    impl_trait_ty, implements_trait, ImplDefinition, ImplDefinitionId,
 };
 pub use self::modules::{module_package, module_path, ModuleDefinition, ModuleDefinitionId};
 pub use self::packages::{PackageDefinition, PackageDefinitionId, PackageName};
@@ -33,14 +37,19 @@ pub use self::src::{
     AnalysisData, CallType, ContextIdx, ForTypeInfo, IdentType, NestedVarId, Var, VarAccess, VarId,
     VarLocation,
 };
-pub use self::traits::{TraitDefinition, TraitDefinitionId};
+pub use self::traits::{
+    all_super_traits, find_method_with_replace, vtable_offset, vtable_traits, TraitDefinition,
+    TraitDefinitionId,
+};
 pub use self::tuples::create_tuple;
+pub use self::unions::{UnionDefinition, UnionDefinitionId};
 pub use self::uses::UseDefinition;
 pub use self::values::{
     find_methods_in_value, ValueDefinition, ValueDefinitionField, ValueDefinitionFieldId,
     ValueDefinitionId,
 };
 
+mod aliases;
 mod annotations;
 mod classes;
 mod consts;
@@ -55,6 +64,7 @@ mod source_files;
 mod src;
 mod traits;
 mod tuples;
+mod unions;
 mod uses;
 mod values;
 
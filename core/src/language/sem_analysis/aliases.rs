@@ -0,0 +1,117 @@
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use core_parser::ast;
+use core_parser::interner::Name;
+use core_parser::lexer::position::Position;
+
+use crate::language::sem_analysis::{
+    module_path, ImplDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis,
+    SourceFileId, TraitDefinitionId, TypeParamDefinition, Visibility,
+};
+use crate::language::ty::SourceType;
+use crate::utils::Id;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct AliasDefinitionId(u32);
+
+impl AliasDefinitionId {
+    pub fn to_usize(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Id for AliasDefinition {
+    type IdType = AliasDefinitionId;
+
+    fn id_to_usize(id: AliasDefinitionId) -> usize {
+        id.0 as usize
+    }
+
+    fn usize_to_id(value: usize) -> AliasDefinitionId {
+        AliasDefinitionId(value.try_into().unwrap())
+    }
+
+    fn store_id(value: &mut AliasDefinition, id: AliasDefinitionId) {
+        value.id = Some(id);
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AliasParent {
+    None,
+    Trait(TraitDefinitionId),
+    Impl(ImplDefinitionId),
+}
+
+impl AliasParent {
+    pub fn is_none(&self) -> bool {
+        matches!(self, AliasParent::None)
+    }
+}
+
+#[derive(Debug)]
+pub struct AliasDefinition {
+    pub id: Option<AliasDefinitionId>,
+    pub package_id: PackageDefinitionId,
+    pub module_id: ModuleDefinitionId,
+    pub file_id: SourceFileId,
+    pub parent: AliasParent,
+    pub ast: Arc<ast::Alias>,
+    pub pos: Position,
+    pub name: Name,
+    pub visibility: Visibility,
+    pub type_params: Option<TypeParamDefinition>,
+    pub ty: Option<SourceType>,
+    pub resolving: bool,
+}
+
+impl AliasDefinition {
+    pub fn new(
+        package_id: PackageDefinitionId,
+        module_id: ModuleDefinitionId,
+        file_id: SourceFileId,
+        parent: AliasParent,
+        node: &Arc<ast::Alias>,
+    ) -> AliasDefinition {
+        AliasDefinition {
+            id: None,
+            package_id,
+            module_id,
+            file_id,
+            parent,
+            ast: node.clone(),
+            pos: node.pos,
+            name: node.name,
+            visibility: Visibility::from_ast(node.visibility),
+            type_params: None,
+            ty: None,
+            resolving: false,
+        }
+    }
+
+    pub fn id(&self) -> AliasDefinitionId {
+        self.id.expect("id missing")
+    }
+
+    pub fn type_params(&self) -> &TypeParamDefinition {
+        self.type_params.as_ref().expect("uninitialized")
+    }
+
+    // Number of type params is known from the AST already, the aliased
+    // type needs to be expanded before type params are fully checked.
+    pub fn number_type_params(&self) -> usize {
+        self.ast
+            .type_params
+            .as_ref()
+            .map_or(0, |params| params.len())
+    }
+
+    pub fn ty(&self) -> SourceType {
+        self.ty.clone().expect("uninitialized")
+    }
+
+    pub fn name(&self, sa: &SemAnalysis) -> String {
+        module_path(sa, self.module_id, self.name)
+    }
+}
@@ -6,8 +6,9 @@ use core_parser::interner::Name;
 use core_parser::Position;
 
 use crate::language::sem_analysis::{
-    extension_matches, impl_matches, module_path, ExtensionDefinitionId, FctDefinitionId,
-    ImplDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis, SourceFileId,
+    extension_matches, find_default_method, impl_matches, module_path, ExtensionDefinitionId,
+    FctDefinitionId, ImplDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis,
+    SourceFileId,
 };
 use crate::language::specialize::replace_type_param;
 use crate::language::ty::{SourceType, SourceTypeArray};
@@ -55,9 +56,14 @@ pub struct ClassDefinition {
     pub internal: bool,
     pub internal_resolved: bool,
     pub visibility: Visibility,
+    pub is_open: bool,
+    pub parent_class: Option<SourceType>,
 
     pub fields: Vec<Field>,
 
+    // methods dispatched through the vtable, indexed by FctDefinition::vtable_index
+    pub virtual_methods: Vec<FctDefinitionId>,
+
     pub impls: Vec<ImplDefinitionId>,
     pub extensions: Vec<ExtensionDefinitionId>,
 
@@ -87,8 +93,11 @@ impl ClassDefinition {
             internal: ast.internal,
             internal_resolved: false,
             visibility: Visibility::from_ast(ast.visibility),
+            is_open: ast.is_open,
+            parent_class: None,
 
             fields: Vec::new(),
+            virtual_methods: Vec::new(),
 
             impls: Vec::new(),
             extensions: Vec::new(),
@@ -121,8 +130,11 @@ impl ClassDefinition {
             internal: false,
             internal_resolved: false,
             visibility,
+            is_open: false,
+            parent_class: None,
 
             fields,
+            virtual_methods: Vec::new(),
 
             impls: Vec::new(),
             extensions: Vec::new(),
@@ -246,6 +258,17 @@ impl IndexMut<FieldId> for Vec<Field> {
     }
 }
 
+// Returns the parent class of `class` with the type params of `class` applied.
+pub fn parent_class_type(sa: &SemAnalysis, class: &SourceType) -> Option<SourceType> {
+    let cls_id = class.cls_id()?;
+    let cls = sa.classes.idx(cls_id);
+    let cls = cls.read();
+
+    cls.parent_class
+        .clone()
+        .map(|parent| replace_type_param(sa, parent, &class.type_params(), None))
+}
+
 pub fn find_field_in_class(
     sa: &SemAnalysis,
     class: SourceType,
@@ -347,10 +370,32 @@ pub fn find_methods_in_class(
                         fct_id: method_id,
                     });
                 }
+            } else if let Some((method_id, container_type_params)) =
+                find_default_method(sa, &impl_, &bindings, name, is_static)
+            {
+                candidates.push(Candidate {
+                    object_type: object_type.clone(),
+                    container_type_params,
+                    fct_id: method_id,
+                });
             }
         }
     }
 
+    // Methods of the parent class are inherited unless the class defines them itself
+    if candidates.is_empty() {
+        if let Some(parent_type) = parent_class_type(sa, &object_type) {
+            return find_methods_in_class(
+                sa,
+                parent_type,
+                type_param_defs,
+                name,
+                is_static,
+                is_nullary,
+            );
+        }
+    }
+
     candidates
 }
 
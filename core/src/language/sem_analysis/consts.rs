@@ -42,6 +42,8 @@ pub struct ConstDefinition {
     pub ty: SourceType,
     pub expr: Box<ast::Expr>,
     pub value: ConstValue,
+    pub evaluating: bool,
+    pub evaluated: bool,
 }
 
 impl ConstDefinition {
@@ -63,6 +65,8 @@ impl ConstDefinition {
             ty: SourceType::Error,
             expr: node.expr.clone(),
             value: ConstValue::None,
+            evaluating: false,
+            evaluated: false,
         }
     }
 
@@ -82,6 +86,7 @@ pub enum ConstValue {
     Char(char),
     Int(i64),
     Float(f64),
+    String(String),
 }
 
 impl ConstValue {
@@ -112,4 +117,11 @@ impl ConstValue {
             _ => unreachable!(),
         }
     }
+
+    pub fn to_str(&self) -> &str {
+        match self {
+            ConstValue::String(s) => s,
+            _ => unreachable!(),
+        }
+    }
 }
@@ -7,9 +7,9 @@ use core_parser::interner::Name;
 use core_parser::lexer::position::Position;
 
 use crate::language::sem_analysis::{
-    extension_matches, impl_matches, module_path, Candidate, ExtensionDefinitionId,
-    ImplDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis, SourceFileId,
-    TypeParamDefinition, Visibility,
+    extension_matches, find_default_method, impl_matches, module_path, Candidate,
+    ExtensionDefinitionId, ImplDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis,
+    SourceFileId, TypeParamDefinition, Visibility,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::utils::Id;
@@ -94,6 +94,18 @@ impl EnumDefinition {
         module_path(sa, self.module_id, self.name)
     }
 
+    // Only payload-free enums without type params have an ordinal and can be
+    // enumerated with `values()`.
+    pub fn is_ordinal_enum(&self) -> bool {
+        self.simple_enumeration && self.type_params().is_empty()
+    }
+
+    pub fn has_custom_discriminants(&self) -> bool {
+        self.variants
+            .iter()
+            .any(|variant| variant.discriminant != variant.id as i32)
+    }
+
     pub fn name_with_params(&self, sa: &SemAnalysis, type_list: &SourceTypeArray) -> String {
         let name = sa.interner.str(self.name);
 
@@ -116,6 +128,7 @@ pub struct EnumVariant {
     pub id: usize,
     pub name: Name,
     pub types: Vec<SourceType>,
+    pub discriminant: i32,
 }
 
 pub fn find_methods_in_enum(
@@ -174,6 +187,14 @@ pub fn find_methods_in_enum(
                     container_type_params: bindings.clone(),
                     fct_id: method_id,
                 });
+            } else if let Some((method_id, container_type_params)) =
+                find_default_method(sa, &impl_, &bindings, name, is_static)
+            {
+                candidates.push(Candidate {
+                    object_type: object_type.clone(),
+                    container_type_params,
+                    fct_id: method_id,
+                });
             }
         }
     }
@@ -254,11 +254,17 @@ mod matching {
             | SourceType::Bool
             | SourceType::Char
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Int32
             | SourceType::Int64
             | SourceType::Float32
             | SourceType::Float64
-            | SourceType::TypeParam(_) => check_ty == ext_ty,
+            | SourceType::TypeParam(_)
+            | SourceType::Assoc(_) => check_ty == ext_ty,
 
             SourceType::Lambda(_, _) | SourceType::Trait(_, _) => {
                 unimplemented!()
@@ -333,6 +339,27 @@ mod matching {
                 )
             }
 
+            SourceType::Union(check_union_id, _) => {
+                let ext_union_id = if let Some(union_id) = ext_ty.union_id() {
+                    union_id
+                } else {
+                    return false;
+                };
+
+                if check_union_id != ext_union_id {
+                    return false;
+                }
+
+                compare_type_params(
+                    sa,
+                    check_ty,
+                    check_type_param_defs,
+                    ext_ty,
+                    ext_type_param_defs,
+                    bindings,
+                )
+            }
+
             SourceType::Class(check_cls_id, _) => {
                 let ext_cls_id = if let Some(cls_id) = ext_ty.cls_id() {
                     cls_id
@@ -9,9 +9,9 @@ use core_parser::lexer::position::Position;
 use crate::bytecode::{BytecodeFunction, BytecodeType};
 use crate::gc::Address;
 use crate::language::sem_analysis::{
-    module_path, AnalysisData, ExtensionDefinitionId, ImplDefinitionId, ModuleDefinitionId,
-    PackageDefinitionId, SemAnalysis, SourceFileId, TraitDefinitionId, TypeParamDefinition,
-    Visibility,
+    module_path, AnalysisData, ConstValue, ExtensionDefinitionId, ImplDefinitionId,
+    ModuleDefinitionId, PackageDefinitionId, SemAnalysis, SourceFileId, TraitDefinitionId,
+    TypeParamDefinition, Visibility,
 };
 use crate::language::ty::SourceType;
 use crate::utils::GrowableVec;
@@ -54,10 +54,14 @@ pub struct FctDefinition {
     pub internal_resolved: bool,
     pub param_names: Vec<Name>,
     pub param_types: Vec<SourceType>,
+    pub param_defaults: Vec<Option<ConstValue>>,
     pub is_nullary: bool,
     pub return_type: SourceType,
     pub is_constructor: bool,
     pub is_variadic: bool,
+    pub is_open: bool,
+    pub is_override: bool,
+    pub is_final: bool,
 
     pub vtable_index: Option<u32>,
     pub initialized: bool,
@@ -89,6 +93,7 @@ impl FctDefinition {
             name: ast.name,
             param_names: ast.params.iter().map(|param| param.name).collect(),
             param_types: Vec::new(),
+            param_defaults: Vec::new(),
             is_nullary: ast.is_nullary,
             return_type: SourceType::Error,
             parent,
@@ -99,6 +104,9 @@ impl FctDefinition {
             internal: ast.internal,
             internal_resolved: false,
             is_constructor: ast.is_constructor,
+            is_open: ast.is_open,
+            is_override: ast.is_override,
+            is_final: ast.is_final,
             vtable_index: None,
             initialized: false,
             is_variadic: false,
@@ -130,6 +138,12 @@ impl FctDefinition {
         }
     }
 
+    // Class methods that are called through the vtable. Methods of traits
+    // also have a vtable index, but only for calls on trait objects.
+    pub fn is_virtual(&self) -> bool {
+        self.vtable_index.is_some() && !self.in_trait()
+    }
+
     pub fn trait_id(&self) -> TraitDefinitionId {
         match self.parent {
             FctParent::Trait(traitid) => traitid,
@@ -210,6 +224,83 @@ impl FctDefinition {
             &self.param_types
         }
     }
+
+    // Maps the arguments of a call to the parameters of this function. Named
+    // arguments can be given in any order, positional arguments are only
+    // allowed as long as all named arguments before them are in position.
+    // Parameters with a default value can be left out.
+    pub fn bind_arguments(
+        &self,
+        arg_names: &[&Option<Name>],
+    ) -> Result<Vec<CallArgument>, ArgumentError> {
+        if self.is_variadic {
+            // Arguments of variadic functions are always positional.
+            for (idx, arg_name) in arg_names.iter().enumerate() {
+                if arg_name.is_some() && arg_name.as_ref() != self.param_names.get(idx) {
+                    return Err(ArgumentError::UnknownName);
+                }
+            }
+
+            return Ok((0..arg_names.len()).map(CallArgument::Arg).collect());
+        }
+
+        let mut arguments = vec![None; self.param_names.len()];
+        let mut reordered = false;
+
+        for (idx, arg_name) in arg_names.iter().enumerate() {
+            let param_idx = if let Some(arg_name) = arg_name {
+                match self.param_names.iter().position(|name| name == arg_name) {
+                    Some(param_idx) => {
+                        reordered |= param_idx != idx;
+                        param_idx
+                    }
+                    None => return Err(ArgumentError::UnknownName),
+                }
+            } else if reordered {
+                return Err(ArgumentError::PositionalAfterNamed);
+            } else if idx < arguments.len() {
+                idx
+            } else {
+                return Err(ArgumentError::TooMany);
+            };
+
+            if arguments[param_idx].is_some() {
+                return Err(ArgumentError::Duplicate(self.param_names[param_idx]));
+            }
+
+            arguments[param_idx] = Some(CallArgument::Arg(idx));
+        }
+
+        arguments
+            .into_iter()
+            .enumerate()
+            .map(|(param_idx, argument)| match argument {
+                Some(argument) => Ok(argument),
+                None => match self.param_defaults.get(param_idx) {
+                    Some(Some(value)) => Ok(CallArgument::Default(value.clone())),
+                    _ => Err(ArgumentError::Missing(self.param_names[param_idx])),
+                },
+            })
+            .collect()
+    }
+}
+
+// The value passed for a parameter of a function.
+#[derive(Clone, Debug, PartialEq)]
+pub enum CallArgument {
+    // the argument at this index in the call
+    Arg(usize),
+    // the default value of the parameter
+    Default(ConstValue),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ArgumentError {
+    UnknownName,
+    PositionalAfterNamed,
+    TooMany,
+    Duplicate(Name),
+    Missing(Name),
 }
 
 fn path_for_type(sa: &SemAnalysis, ty: SourceType) -> String {
@@ -304,12 +395,61 @@ pub enum Intrinsic {
     UInt8ToInt32,
     UInt8ToInt64,
 
+    Int8ToInt32,
+    Int8ToInt64,
+    Int8Add,
+    Int8Sub,
+    Int8Mul,
+    Int8Div,
+    Int8Rem,
+    Int8Neg,
+
+    Int16ToInt32,
+    Int16ToInt64,
+    Int16Add,
+    Int16Sub,
+    Int16Mul,
+    Int16Div,
+    Int16Rem,
+    Int16Neg,
+
+    UInt16ToInt32,
+    UInt16ToInt64,
+    UInt16Add,
+    UInt16Sub,
+    UInt16Mul,
+    UInt16Div,
+    UInt16Rem,
+
+    UInt32ToInt32,
+    UInt32ToInt64,
+    UInt32Cmp,
+    UInt32Add,
+    UInt32Sub,
+    UInt32Mul,
+    UInt32Div,
+    UInt32Rem,
+
+    UInt64ToInt32,
+    UInt64ToInt64,
+    UInt64Cmp,
+    UInt64Add,
+    UInt64Sub,
+    UInt64Mul,
+    UInt64Div,
+    UInt64Rem,
+
     CharEq,
     CharCmp,
     CharToInt32,
     CharToInt64,
 
+    Int32ToInt8,
+    Int32ToInt16,
     Int32ToUInt8,
+    Int32ToUInt16,
+    Int32ToUInt32,
+    Int32ToUInt64,
     Int32ToChar,
     Int32ToInt64,
     Int32ToFloat32,
@@ -320,16 +460,23 @@ pub enum Intrinsic {
 
     EnumEq,
     EnumNe,
+    EnumOrdinal,
+    EnumValues,
+    EnumFromOrdinal,
 
     Int32Eq,
     Int32Cmp,
 
     Int32Add,
     Int32AddUnchecked,
+    Int32AddOverflows,
     Int32Sub,
     Int32SubUnchecked,
+    Int32SubOverflows,
     Int32Mul,
     Int32MulUnchecked,
+    Int32MulOverflows,
+    Int32WideningMulUnsigned,
     Int32Div,
     Int32Rem,
 
@@ -360,7 +507,12 @@ pub enum Intrinsic {
 
     Int64ToInt32,
     Int64ToChar,
+    Int64ToInt8,
+    Int64ToInt16,
     Int64ToUInt8,
+    Int64ToUInt16,
+    Int64ToUInt32,
+    Int64ToUInt64,
     Int64ToFloat32,
     Int64ToFloat64,
 
@@ -371,10 +523,13 @@ pub enum Intrinsic {
 
     Int64Add,
     Int64AddUnchecked,
+    Int64AddOverflows,
     Int64Sub,
     Int64SubUnchecked,
+    Int64SubOverflows,
     Int64Mul,
     Int64MulUnchecked,
+    Int64MulOverflows,
     Int64Div,
     Int64Rem,
 
@@ -487,6 +642,13 @@ impl Intrinsic {
             | Intrinsic::Int64SubUnchecked
             | Intrinsic::Int32MulUnchecked
             | Intrinsic::Int64MulUnchecked
+            | Intrinsic::Int32AddOverflows
+            | Intrinsic::Int64AddOverflows
+            | Intrinsic::Int32SubOverflows
+            | Intrinsic::Int64SubOverflows
+            | Intrinsic::Int32MulOverflows
+            | Intrinsic::Int64MulOverflows
+            | Intrinsic::Int32WideningMulUnsigned
             | Intrinsic::Int64CountZeroBits
             | Intrinsic::Int64CountZeroBitsLeading
             | Intrinsic::Int64CountZeroBitsTrailing
@@ -562,7 +724,56 @@ impl Intrinsic {
             | Intrinsic::Int32ReverseBytes
             | Intrinsic::Int64ReverseBits
             | Intrinsic::Int64ReverseBytes
-            | Intrinsic::ThreadCurrent => true,
+            | Intrinsic::ThreadCurrent
+            | Intrinsic::Int8ToInt32
+            | Intrinsic::Int8ToInt64
+            | Intrinsic::Int8Add
+            | Intrinsic::Int8Sub
+            | Intrinsic::Int8Mul
+            | Intrinsic::Int8Div
+            | Intrinsic::Int8Rem
+            | Intrinsic::Int8Neg
+            | Intrinsic::Int16ToInt32
+            | Intrinsic::Int16ToInt64
+            | Intrinsic::Int16Add
+            | Intrinsic::Int16Sub
+            | Intrinsic::Int16Mul
+            | Intrinsic::Int16Div
+            | Intrinsic::Int16Rem
+            | Intrinsic::Int16Neg
+            | Intrinsic::UInt16ToInt32
+            | Intrinsic::UInt16ToInt64
+            | Intrinsic::UInt16Add
+            | Intrinsic::UInt16Sub
+            | Intrinsic::UInt16Mul
+            | Intrinsic::UInt16Div
+            | Intrinsic::UInt16Rem
+            | Intrinsic::UInt32ToInt32
+            | Intrinsic::UInt32ToInt64
+            | Intrinsic::UInt32Cmp
+            | Intrinsic::UInt32Add
+            | Intrinsic::UInt32Sub
+            | Intrinsic::UInt32Mul
+            | Intrinsic::UInt32Div
+            | Intrinsic::UInt32Rem
+            | Intrinsic::UInt64ToInt32
+            | Intrinsic::UInt64ToInt64
+            | Intrinsic::UInt64Cmp
+            | Intrinsic::UInt64Add
+            | Intrinsic::UInt64Sub
+            | Intrinsic::UInt64Mul
+            | Intrinsic::UInt64Div
+            | Intrinsic::UInt64Rem
+            | Intrinsic::Int32ToInt8
+            | Intrinsic::Int32ToInt16
+            | Intrinsic::Int32ToUInt16
+            | Intrinsic::Int32ToUInt32
+            | Intrinsic::Int32ToUInt64
+            | Intrinsic::Int64ToInt8
+            | Intrinsic::Int64ToInt16
+            | Intrinsic::Int64ToUInt16
+            | Intrinsic::Int64ToUInt32
+            | Intrinsic::Int64ToUInt64 => true,
             _ => false,
         }
     }
@@ -597,7 +808,14 @@ impl Intrinsic {
             | Intrinsic::Float32ToInt32
             | Intrinsic::Float64ToInt32
             | Intrinsic::BoolToInt32
+            | Intrinsic::Int8ToInt32
+            | Intrinsic::Int16ToInt32
+            | Intrinsic::UInt16ToInt32
+            | Intrinsic::UInt32ToInt32
+            | Intrinsic::UInt64ToInt32
             | Intrinsic::UInt8Cmp
+            | Intrinsic::UInt32Cmp
+            | Intrinsic::UInt64Cmp
             | Intrinsic::CharCmp
             | Intrinsic::Int32Cmp
             | Intrinsic::Int64Cmp
@@ -616,7 +834,8 @@ impl Intrinsic {
             | Intrinsic::Int64CountZeroBitsTrailing
             | Intrinsic::Int64CountOneBits
             | Intrinsic::Int64CountOneBitsLeading
-            | Intrinsic::Int64CountOneBitsTrailing => BytecodeType::Int32,
+            | Intrinsic::Int64CountOneBitsTrailing
+            | Intrinsic::EnumOrdinal => BytecodeType::Int32,
             Intrinsic::Int64Add
             | Intrinsic::Int64AddUnchecked
             | Intrinsic::Int64Sub
@@ -640,6 +859,12 @@ impl Intrinsic {
             | Intrinsic::Int64Neg
             | Intrinsic::CharToInt64
             | Intrinsic::UInt8ToInt64
+            | Intrinsic::Int8ToInt64
+            | Intrinsic::Int16ToInt64
+            | Intrinsic::UInt16ToInt64
+            | Intrinsic::UInt32ToInt64
+            | Intrinsic::UInt64ToInt64
+            | Intrinsic::Int32WideningMulUnsigned
             | Intrinsic::Int32ToInt64
             | Intrinsic::Int32AsInt64
             | Intrinsic::Float32ToInt64
@@ -688,8 +913,51 @@ impl Intrinsic {
             | Intrinsic::Float64Eq
             | Intrinsic::BoolNot
             | Intrinsic::Float64IsNan
-            | Intrinsic::Float32IsNan => BytecodeType::Bool,
+            | Intrinsic::Float32IsNan
+            | Intrinsic::Int32AddOverflows
+            | Intrinsic::Int32SubOverflows
+            | Intrinsic::Int32MulOverflows
+            | Intrinsic::Int64AddOverflows
+            | Intrinsic::Int64SubOverflows
+            | Intrinsic::Int64MulOverflows => BytecodeType::Bool,
+            Intrinsic::Int8Add
+            | Intrinsic::Int8Sub
+            | Intrinsic::Int8Mul
+            | Intrinsic::Int8Div
+            | Intrinsic::Int8Rem
+            | Intrinsic::Int8Neg
+            | Intrinsic::Int32ToInt8
+            | Intrinsic::Int64ToInt8 => BytecodeType::Int8,
+            Intrinsic::Int16Add
+            | Intrinsic::Int16Sub
+            | Intrinsic::Int16Mul
+            | Intrinsic::Int16Div
+            | Intrinsic::Int16Rem
+            | Intrinsic::Int16Neg
+            | Intrinsic::Int32ToInt16
+            | Intrinsic::Int64ToInt16 => BytecodeType::Int16,
             Intrinsic::Int32ToUInt8 | Intrinsic::Int64ToUInt8 => BytecodeType::UInt8,
+            Intrinsic::UInt16Add
+            | Intrinsic::UInt16Sub
+            | Intrinsic::UInt16Mul
+            | Intrinsic::UInt16Div
+            | Intrinsic::UInt16Rem
+            | Intrinsic::Int32ToUInt16
+            | Intrinsic::Int64ToUInt16 => BytecodeType::UInt16,
+            Intrinsic::UInt32Add
+            | Intrinsic::UInt32Sub
+            | Intrinsic::UInt32Mul
+            | Intrinsic::UInt32Div
+            | Intrinsic::UInt32Rem
+            | Intrinsic::Int32ToUInt32
+            | Intrinsic::Int64ToUInt32 => BytecodeType::UInt32,
+            Intrinsic::UInt64Add
+            | Intrinsic::UInt64Sub
+            | Intrinsic::UInt64Mul
+            | Intrinsic::UInt64Div
+            | Intrinsic::UInt64Rem
+            | Intrinsic::Int32ToUInt64
+            | Intrinsic::Int64ToUInt64 => BytecodeType::UInt64,
             Intrinsic::Int32ToChar | Intrinsic::Int64ToChar | Intrinsic::UInt8ToChar => {
                 BytecodeType::Char
             }
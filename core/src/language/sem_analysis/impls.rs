@@ -10,9 +10,10 @@ use core_parser::interner::Name;
 use core_parser::lexer::position::Position;
 
 use crate::language::sem_analysis::{
-    extension_matches_ty, FctDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis,
-    SourceFileId, TraitDefinitionId, TypeParamDefinition,
+    extension_matches_ty, AliasDefinitionId, FctDefinitionId, ModuleDefinitionId,
+    PackageDefinitionId, SemAnalysis, SourceFileId, TraitDefinitionId, TypeParamDefinition,
 };
+use crate::language::specialize::specialize_type;
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::utils::Id;
 
@@ -50,6 +51,7 @@ pub struct ImplDefinition {
     pub instance_names: HashMap<Name, FctDefinitionId>,
     pub static_names: HashMap<Name, FctDefinitionId>,
     pub impl_for: HashMap<FctDefinitionId, FctDefinitionId>,
+    pub aliases: Vec<AliasDefinitionId>,
 }
 
 impl ImplDefinition {
@@ -73,6 +75,7 @@ impl ImplDefinition {
             instance_names: HashMap::new(),
             static_names: HashMap::new(),
             impl_for: HashMap::new(),
+            aliases: Vec::new(),
         }
     }
 
@@ -117,32 +120,107 @@ pub fn impl_matches(
     )
 }
 
+// Returns the method implementing the trait method `fct_id` for
+// `object_type` together with the type params of the impl.
 pub fn find_trait_impl(
     sa: &SemAnalysis,
     fct_id: FctDefinitionId,
     trait_ty: SourceType,
     object_type: SourceType,
-) -> FctDefinitionId {
+) -> (FctDefinitionId, SourceTypeArray) {
     debug_assert!(object_type.is_concrete_type(sa));
     let impl_id = find_impl(
         sa,
-        object_type,
+        object_type.clone(),
         &TypeParamDefinition::new(),
         trait_ty.clone(),
     )
     .expect("no impl found for generic trait method call");
 
+    let impl_type_params =
+        impl_matches(sa, object_type, &TypeParamDefinition::new(), impl_id).expect("no match");
+
     let impl_ = sa.impls[impl_id].read();
     assert_eq!(
         impl_.trait_id(),
         trait_ty.trait_id().expect("trait expected")
     );
 
-    impl_
+    let callee_id = impl_
         .impl_for
         .get(&fct_id)
         .cloned()
-        .expect("no impl method found for generic trait call")
+        .expect("no impl method found for generic trait call");
+
+    (callee_id, impl_type_params)
+}
+
+// Trait type with all associated types bound to their definitions in
+// the impl of the trait for `object_type`.
+pub fn impl_trait_ty(
+    sa: &SemAnalysis,
+    object_type: SourceType,
+    type_param_defs: &TypeParamDefinition,
+    trait_id: TraitDefinitionId,
+) -> Option<SourceType> {
+    let impl_id = find_impl(
+        sa,
+        object_type.clone(),
+        type_param_defs,
+        SourceType::new_trait(trait_id),
+    )?;
+
+    let impl_type_params = impl_matches(sa, object_type, type_param_defs, impl_id)?;
+
+    let impl_ = sa.impls[impl_id].read();
+    Some(specialize_type(sa, impl_.trait_ty(), &impl_type_params))
+}
+
+// Default methods are compiled once per implementing type: after the trait's
+// and the method's own type params they get `Self` and the bindings of the
+// trait's associated types as additional type params.
+pub fn default_method_type_params(
+    sa: &SemAnalysis,
+    impl_trait_ty: &SourceType,
+    object_type: SourceType,
+    fct_type_params: &SourceTypeArray,
+) -> SourceTypeArray {
+    let trait_id = impl_trait_ty.trait_id().expect("trait expected");
+    let number_type_params = sa.traits[trait_id].read().number_type_params();
+    let trait_type_params = impl_trait_ty.type_params();
+    let (container_type_params, bindings) = trait_type_params.types().split_at(number_type_params);
+
+    let mut type_params = container_type_params.to_vec();
+    type_params.extend_from_slice(fct_type_params.types());
+    type_params.push(object_type);
+    type_params.extend_from_slice(bindings);
+
+    SourceTypeArray::with(type_params)
+}
+
+// Finds the default method `name` of the implemented trait, if the impl
+// doesn't override it. Returns the method together with the trait's type
+// params.
+pub fn find_default_method(
+    sa: &SemAnalysis,
+    impl_: &ImplDefinition,
+    impl_type_params: &SourceTypeArray,
+    name: Name,
+    is_static: bool,
+) -> Option<(FctDefinitionId, SourceTypeArray)> {
+    let trait_id = impl_.trait_ty().trait_id()?;
+    let trait_ = sa.traits[trait_id].read();
+    let fct_id = trait_.find_method(sa, name, is_static)?;
+
+    if impl_.impl_for.get(&fct_id) != Some(&fct_id) {
+        return None;
+    }
+
+    let trait_ty = specialize_type(sa, impl_.trait_ty(), impl_type_params);
+    let container_type_params =
+        trait_ty.type_params().types()[..trait_.number_type_params()].to_vec();
+
+    Some((fct_id, SourceTypeArray::with(container_type_params)))
 }
 
 pub fn implements_trait(
@@ -155,6 +233,8 @@ pub fn implements_trait(
         SourceType::Tuple(_)
         | SourceType::Unit
         | SourceType::Trait(_, _)
+        | SourceType::Union(_, _)
+        | SourceType::Assoc(_)
         | SourceType::Lambda(_, _) => false,
 
         SourceType::Enum(enum_id, _) => {
@@ -164,6 +244,11 @@ pub fn implements_trait(
 
         SourceType::Bool
         | SourceType::UInt8
+        | SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt16
+        | SourceType::UInt32
+        | SourceType::UInt64
         | SourceType::Char
         | SourceType::Int32
         | SourceType::Int64
@@ -219,6 +304,8 @@ pub fn find_impl(
         SourceType::Tuple(_)
         | SourceType::Unit
         | SourceType::Trait(_, _)
+        | SourceType::Union(_, _)
+        | SourceType::Assoc(_)
         | SourceType::Lambda(_, _) => None,
 
         SourceType::Enum(enum_id, _) => {
@@ -228,6 +315,11 @@ pub fn find_impl(
 
         SourceType::Bool
         | SourceType::UInt8
+        | SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt16
+        | SourceType::UInt32
+        | SourceType::UInt64
         | SourceType::Char
         | SourceType::Int32
         | SourceType::Int64
@@ -277,14 +369,18 @@ pub fn check_impls(
         let impl_ = &sa.impls[impl_id];
         let impl_ = impl_.read();
 
-        debug_assert!(impl_.trait_ty().is_concrete_type(sa));
-
-        if impl_.trait_ty() != trait_ty {
+        if impl_.trait_ty.trait_id() != trait_ty.trait_id() {
             continue;
         }
 
-        if impl_matches(sa, check_ty.clone(), check_type_param_defs, impl_id).is_some() {
-            return Some(impl_id);
+        if let Some(bindings) = impl_matches(sa, check_ty.clone(), check_type_param_defs, impl_id) {
+            // A trait type without params only asks for the trait itself, otherwise
+            // the params (including associated types) need to match as well.
+            if trait_ty.type_params().is_empty()
+                || specialize_type(sa, impl_.trait_ty(), &bindings) == trait_ty
+            {
+                return Some(impl_id);
+            }
         }
     }
 
@@ -5,9 +5,9 @@ use std::sync::Arc;
 use core_parser::ast;
 
 use crate::language::sem_analysis::{
-    ClassDefinitionId, ConstDefinitionId, EnumDefinitionId, FctDefinitionId, FieldId,
-    GlobalDefinitionId, Intrinsic, TraitDefinitionId, TypeParamId, ValueDefinitionFieldId,
-    ValueDefinitionId,
+    CallArgument, ClassDefinitionId, ConstDefinitionId, EnumDefinitionId, FctDefinitionId, FieldId,
+    GlobalDefinitionId, Intrinsic, TraitDefinitionId, TypeParamId, UnionDefinitionId,
+    ValueDefinitionFieldId, ValueDefinitionId,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
 
@@ -20,7 +20,8 @@ pub struct AnalysisData {
     pub map_cls: NodeMap<ClassDefinitionId>,
     pub map_fors: NodeMap<ForTypeInfo>,
     pub map_lambdas: NodeMap<FctDefinitionId>,
-    pub vars: VarAccess, // variables in functions
+    pub map_arguments: NodeMap<Arc<Vec<CallArgument>>>, // arguments of calls not in parameter order
+    pub vars: VarAccess,                                // variables in functions
     pub context_cls_id: Option<ClassDefinitionId>,
     pub context_has_outer_context_slot: Option<bool>,
     pub outer_context_access: Option<bool>,
@@ -36,6 +37,7 @@ impl AnalysisData {
             map_cls: NodeMap::new(),
             map_fors: NodeMap::new(),
             map_lambdas: NodeMap::new(),
+            map_arguments: NodeMap::new(),
 
             vars: VarAccess::empty(),
             context_cls_id: None,
@@ -145,6 +147,9 @@ pub enum IdentType {
 
     // specific value in enum
     EnumValue(EnumDefinitionId, SourceTypeArray, usize),
+
+    // specific variant in union
+    UnionVariant(UnionDefinitionId, SourceTypeArray, usize),
 }
 
 impl IdentType {
@@ -198,6 +203,7 @@ pub struct ContextIdx(pub usize);
 pub struct ForTypeInfo {
     pub make_iterator: Option<FctDefinitionId>,
     pub next: FctDefinitionId,
+    pub next_type_params: SourceTypeArray,
     pub iterator_type: SourceType,
     pub next_type: SourceType,
     pub value_type: SourceType,
@@ -9,9 +9,10 @@ use core_parser::interner::Name;
 use core_parser::lexer::position::Position;
 
 use crate::language::sem_analysis::{
-    module_path, FctDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis,
-    SourceFileId, TypeParamDefinition, Visibility,
+    module_path, AliasDefinitionId, FctDefinitionId, ModuleDefinitionId, PackageDefinitionId,
+    SemAnalysis, SourceFileId, TypeParamDefinition, Visibility,
 };
+use crate::language::specialize::{replace_assoc_types, replace_type_param};
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::utils::Id;
 
@@ -52,9 +53,12 @@ pub struct TraitDefinition {
     pub name: Name,
     pub is_trait_object: bool,
     pub type_params: Option<TypeParamDefinition>,
+    pub super_traits: Vec<SourceType>,
     pub methods: Vec<FctDefinitionId>,
     pub instance_names: HashMap<Name, FctDefinitionId>,
     pub static_names: HashMap<Name, FctDefinitionId>,
+    pub aliases: Vec<AliasDefinitionId>,
+    pub alias_names: HashMap<Name, AliasDefinitionId>,
 }
 
 impl TraitDefinition {
@@ -75,9 +79,12 @@ impl TraitDefinition {
             name: node.name,
             is_trait_object: false,
             type_params: None,
+            super_traits: Vec::new(),
             methods: Vec::new(),
             instance_names: HashMap::new(),
             static_names: HashMap::new(),
+            aliases: Vec::new(),
+            alias_names: HashMap::new(),
         }
     }
 
@@ -97,11 +104,7 @@ impl TraitDefinition {
         let name = module_path(sa, self.module_id, self.name);
 
         if type_list.len() > 0 {
-            let type_list = type_list
-                .iter()
-                .map(|p| p.name(sa))
-                .collect::<Vec<_>>()
-                .join(", ");
+            let type_list = self.param_names(sa, type_list, |ty| ty.name(sa)).join(", ");
 
             format!("{}[{}]", name, type_list)
         } else {
@@ -109,6 +112,62 @@ impl TraitDefinition {
         }
     }
 
+    // Names all type params of a trait type, bindings for associated types
+    // are stored after the trait's own type params and printed as `Item = T`.
+    pub fn param_names<F>(
+        &self,
+        sa: &SemAnalysis,
+        type_list: &SourceTypeArray,
+        name: F,
+    ) -> Vec<String>
+    where
+        F: Fn(SourceType) -> String,
+    {
+        let number_type_params = self.number_type_params();
+
+        type_list
+            .iter()
+            .enumerate()
+            .map(|(idx, ty)| {
+                let alias_id = idx
+                    .checked_sub(number_type_params)
+                    .and_then(|idx| self.aliases.get(idx));
+
+                if let Some(&alias_id) = alias_id {
+                    let alias_name = sa.aliases[alias_id].read().name;
+                    format!("{} = {}", sa.interner.str(alias_name), name(ty))
+                } else {
+                    name(ty)
+                }
+            })
+            .collect()
+    }
+
+    pub fn number_type_params(&self) -> usize {
+        self.ast
+            .type_params
+            .as_ref()
+            .map_or(0, |params| params.len())
+    }
+
+    // Position of an associated type binding in the type params of a trait type.
+    pub fn binding_index(&self, alias_id: AliasDefinitionId) -> Option<usize> {
+        self.aliases
+            .iter()
+            .position(|&id| id == alias_id)
+            .map(|idx| self.number_type_params() + idx)
+    }
+
+    // Direct supertraits with the type params of `trait_ty` filled in.
+    pub fn super_trait_types(&self, sa: &SemAnalysis, trait_ty: &SourceType) -> Vec<SourceType> {
+        let type_params = trait_ty.type_params();
+
+        self.super_traits
+            .iter()
+            .map(|super_trait| replace_type_param(sa, super_trait.clone(), &type_params, None))
+            .collect()
+    }
+
     pub fn find_method(
         &self,
         sa: &SemAnalysis,
@@ -126,35 +185,118 @@ impl TraitDefinition {
 
         None
     }
+}
 
-    pub fn find_method_with_replace(
-        &self,
-        sa: &SemAnalysis,
-        is_static: bool,
-        name: Name,
-        replace: Option<SourceType>,
-        args: &[SourceType],
-        is_nullary: bool,
-    ) -> Option<FctDefinitionId> {
-        for &method in &self.methods {
-            let method = sa.fcts.idx(method);
-            let method = method.read();
+// Finds the method of the trait type `trait_ty` that accepts `args`, associated
+// types in the method's params are replaced by the bindings of `trait_ty`.
+pub fn find_method_with_replace(
+    sa: &SemAnalysis,
+    trait_ty: &SourceType,
+    is_static: bool,
+    name: Name,
+    replace: Option<SourceType>,
+    args: &[SourceType],
+    is_nullary: bool,
+) -> Option<FctDefinitionId> {
+    let trait_id = trait_ty.trait_id().expect("trait expected");
+    let trait_ = sa.traits[trait_id].read();
 
-            if method.name == name
-                && method.is_static == is_static
-                && method.is_nullary == is_nullary
-                && params_match(replace.clone(), method.params_without_self(), args)
-            {
-                return Some(method.id());
-            }
+    for &method in &trait_.methods {
+        let method = sa.fcts.idx(method);
+        let method = method.read();
+
+        if method.name == name
+            && method.is_static == is_static
+            && method.is_nullary == is_nullary
+            && params_match(
+                sa,
+                replace.clone(),
+                trait_ty,
+                method.params_without_self(),
+                args,
+            )
+        {
+            return Some(method.id());
         }
+    }
 
-        None
+    None
+}
+
+// All supertraits of a trait type in depth-first order, each trait is listed only once.
+pub fn all_super_traits(sa: &SemAnalysis, trait_ty: &SourceType) -> Vec<SourceType> {
+    let mut result = Vec::new();
+    collect_super_traits(sa, trait_ty, &mut result);
+    result
+}
+
+fn collect_super_traits(sa: &SemAnalysis, trait_ty: &SourceType, result: &mut Vec<SourceType>) {
+    let trait_id = trait_ty.trait_id().expect("trait expected");
+    let super_traits = sa.traits[trait_id].read().super_trait_types(sa, trait_ty);
+
+    for super_trait in super_traits {
+        if result
+            .iter()
+            .any(|ty: &SourceType| ty.trait_id() == super_trait.trait_id())
+        {
+            continue;
+        }
+
+        result.push(super_trait.clone());
+        collect_super_traits(sa, &super_trait, result);
+    }
+}
+
+// Traits whose methods make up the vtable of a trait object: the trait itself
+// followed by its supertraits in the same order as `all_super_traits`.
+pub fn vtable_traits(sa: &SemAnalysis, trait_id: TraitDefinitionId) -> Vec<TraitDefinitionId> {
+    let mut result = vec![trait_id];
+    collect_vtable_traits(sa, trait_id, &mut result);
+    result
+}
+
+fn collect_vtable_traits(
+    sa: &SemAnalysis,
+    trait_id: TraitDefinitionId,
+    result: &mut Vec<TraitDefinitionId>,
+) {
+    let super_traits = sa.traits[trait_id].read().super_traits.clone();
+
+    for super_trait in super_traits {
+        let super_trait_id = super_trait.trait_id().expect("trait expected");
+
+        if result.contains(&super_trait_id) {
+            continue;
+        }
+
+        result.push(super_trait_id);
+        collect_vtable_traits(sa, super_trait_id, result);
     }
 }
 
+// Index of the first method of `method_trait_id` in the vtable of trait objects for `trait_id`.
+pub fn vtable_offset(
+    sa: &SemAnalysis,
+    trait_id: TraitDefinitionId,
+    method_trait_id: TraitDefinitionId,
+) -> u32 {
+    let mut offset = 0;
+
+    for vtable_trait_id in vtable_traits(sa, trait_id) {
+        if vtable_trait_id == method_trait_id {
+            return offset;
+        }
+
+        offset += sa.traits[vtable_trait_id].read().methods.len() as u32;
+    }
+
+    panic!("trait not part of vtable")
+}
+
 fn params_match(
+    sa: &SemAnalysis,
     replace: Option<SourceType>,
+    trait_ty: &SourceType,
     trait_args: &[SourceType],
     args: &[SourceType],
 ) -> bool {
@@ -163,7 +305,7 @@ fn params_match(
     }
 
     for (ind, ty) in trait_args.iter().enumerate() {
-        let ty = ty.clone();
+        let ty = replace_assoc_types(sa, ty.clone(), trait_ty);
         let other = args[ind].clone();
 
         let found = if ty.is_self() {
@@ -0,0 +1,124 @@
+use std::convert::TryInto;
+use std::sync::Arc;
+
+use core_parser::ast;
+use core_parser::interner::Name;
+use core_parser::lexer::position::Position;
+
+use crate::language::sem_analysis::{
+    module_path, ClassDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis,
+    SourceFileId, TypeParamDefinition, ValueDefinitionId, Visibility,
+};
+use crate::language::specialize::specialize_type;
+use crate::language::ty::{SourceType, SourceTypeArray};
+use crate::utils::Id;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnionDefinitionId(u32);
+
+impl UnionDefinitionId {
+    pub fn to_usize(self) -> usize {
+        self.0 as usize
+    }
+}
+
+impl Id for UnionDefinition {
+    type IdType = UnionDefinitionId;
+
+    fn id_to_usize(id: UnionDefinitionId) -> usize {
+        id.0 as usize
+    }
+
+    fn usize_to_id(value: usize) -> UnionDefinitionId {
+        UnionDefinitionId(value.try_into().unwrap())
+    }
+
+    fn store_id(value: &mut UnionDefinition, id: UnionDefinitionId) {
+        value.id = Some(id);
+    }
+}
+
+#[derive(Debug)]
+pub struct UnionDefinition {
+    pub id: Option<UnionDefinitionId>,
+    pub package_id: PackageDefinitionId,
+    pub module_id: ModuleDefinitionId,
+    pub file_id: SourceFileId,
+    pub ast: Arc<ast::Union>,
+    pub pos: Position,
+    pub name: Name,
+    pub visibility: Visibility,
+    pub type_params: Option<TypeParamDefinition>,
+    pub variants: Vec<SourceType>,
+}
+
+impl UnionDefinition {
+    pub fn new(
+        package_id: PackageDefinitionId,
+        module_id: ModuleDefinitionId,
+        file_id: SourceFileId,
+        node: &Arc<ast::Union>,
+    ) -> UnionDefinition {
+        UnionDefinition {
+            id: None,
+            package_id,
+            module_id,
+            file_id,
+            ast: node.clone(),
+            pos: node.pos,
+            name: node.name,
+            type_params: None,
+            visibility: Visibility::from_ast(node.visibility),
+            variants: Vec::new(),
+        }
+    }
+
+    pub fn id(&self) -> UnionDefinitionId {
+        self.id.expect("id missing")
+    }
+
+    pub fn type_params(&self) -> &TypeParamDefinition {
+        self.type_params.as_ref().expect("uninitialized")
+    }
+
+    pub fn name(&self, sa: &SemAnalysis) -> String {
+        module_path(sa, self.module_id, self.name)
+    }
+
+    pub fn name_with_params(&self, sa: &SemAnalysis, type_list: &SourceTypeArray) -> String {
+        let name = sa.interner.str(self.name);
+
+        if !type_list.is_empty() {
+            let type_list = type_list
+                .iter()
+                .map(|p| p.name(sa))
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            format!("{}[{}]", name, type_list)
+        } else {
+            name.to_string()
+        }
+    }
+
+    pub fn variant_idx_for_class(&self, cls_id: ClassDefinitionId) -> Option<usize> {
+        self.variants
+            .iter()
+            .position(|variant| variant.cls_id() == Some(cls_id))
+    }
+
+    pub fn variant_idx_for_value(&self, value_id: ValueDefinitionId) -> Option<usize> {
+        self.variants
+            .iter()
+            .position(|variant| variant.value_id() == Some(value_id))
+    }
+
+    pub fn variant_type(
+        &self,
+        sa: &SemAnalysis,
+        type_params: &SourceTypeArray,
+        variant_idx: usize,
+    ) -> SourceType {
+        specialize_type(sa, self.variants[variant_idx].clone(), type_params)
+    }
+}
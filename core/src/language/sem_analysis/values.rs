@@ -7,9 +7,9 @@ use core_parser::interner::Name;
 use core_parser::lexer::position::Position;
 
 use crate::language::sem_analysis::{
-    extension_matches, impl_matches, module_path, Candidate, ExtensionDefinitionId,
-    ImplDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis, SourceFileId,
-    TypeParamDefinition, TypeParamId, Visibility,
+    extension_matches, find_default_method, impl_matches, module_path, Candidate,
+    ExtensionDefinitionId, ImplDefinitionId, ModuleDefinitionId, PackageDefinitionId, SemAnalysis,
+    SourceFileId, TypeParamDefinition, TypeParamId, Visibility,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::utils::Id;
@@ -231,6 +231,14 @@ pub fn find_methods_in_value(
                     container_type_params: bindings.clone(),
                     fct_id: method_id,
                 });
+            } else if let Some((method_id, container_type_params)) =
+                find_default_method(sa, &impl_, &bindings, name, is_static)
+            {
+                candidates.push(Candidate {
+                    object_type: object_type.clone(),
+                    container_type_params,
+                    fct_id: method_id,
+                });
             }
         }
     }
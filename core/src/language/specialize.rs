@@ -1,4 +1,6 @@
-use crate::language::sem_analysis::{create_tuple, SemAnalysis};
+use crate::language::sem_analysis::{
+    create_tuple, impl_trait_ty, AliasDefinitionId, AliasParent, SemAnalysis, TypeParamDefinition,
+};
 use crate::language::ty::{SourceType, SourceTypeArray};
 
 pub fn specialize_type(
@@ -83,8 +85,23 @@ pub fn replace_type_param(
             SourceType::Enum(enum_id, new_type_params)
         }
 
+        SourceType::Union(union_id, old_type_params) => {
+            let new_type_params = SourceTypeArray::with(
+                old_type_params
+                    .iter()
+                    .map(|p| replace_type_param(sa, p, type_params, self_ty.clone()))
+                    .collect::<Vec<_>>(),
+            );
+
+            SourceType::Union(union_id, new_type_params)
+        }
+
         SourceType::This => self_ty.expect("no type for Self given"),
 
+        SourceType::Assoc(alias_id) => self_ty
+            .and_then(|self_ty| resolve_assoc_type(sa, &self_ty, alias_id))
+            .unwrap_or(ty),
+
         SourceType::Lambda(params, return_type) => {
             let new_params = SourceTypeArray::with(
                 params
@@ -114,6 +131,11 @@ pub fn replace_type_param(
 
         SourceType::Unit
         | SourceType::UInt8
+        | SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt16
+        | SourceType::UInt32
+        | SourceType::UInt64
         | SourceType::Bool
         | SourceType::Char
         | SourceType::Int32
@@ -128,3 +150,89 @@ pub fn replace_type_param(
         }
     }
 }
+
+// Replaces associated types like `Self::Item` with their bindings in the
+// given trait type, e.g. with `Int32` for `Iterator[Item = Int32]`.
+pub fn replace_assoc_types(sa: &SemAnalysis, ty: SourceType, trait_ty: &SourceType) -> SourceType {
+    match ty {
+        SourceType::Assoc(alias_id) => resolve_assoc_type(sa, trait_ty, alias_id).unwrap_or(ty),
+
+        SourceType::Class(cls_id, params) => {
+            SourceType::Class(cls_id, replace_assoc_types_list(sa, &params, trait_ty))
+        }
+
+        SourceType::Trait(trait_id, params) => {
+            SourceType::Trait(trait_id, replace_assoc_types_list(sa, &params, trait_ty))
+        }
+
+        SourceType::Value(value_id, params) => {
+            SourceType::Value(value_id, replace_assoc_types_list(sa, &params, trait_ty))
+        }
+
+        SourceType::Enum(enum_id, params) => {
+            SourceType::Enum(enum_id, replace_assoc_types_list(sa, &params, trait_ty))
+        }
+
+        SourceType::Union(union_id, params) => {
+            SourceType::Union(union_id, replace_assoc_types_list(sa, &params, trait_ty))
+        }
+
+        SourceType::Lambda(params, return_type) => {
+            let params = replace_assoc_types_list(sa, &params, trait_ty);
+            let return_type = replace_assoc_types(sa, *return_type, trait_ty);
+            SourceType::Lambda(params, Box::new(return_type))
+        }
+
+        SourceType::Tuple(subtypes) => {
+            let subtypes = replace_assoc_types_list(sa, &subtypes, trait_ty);
+            create_tuple(sa, subtypes.types().to_vec())
+        }
+
+        _ => ty,
+    }
+}
+
+fn replace_assoc_types_list(
+    sa: &SemAnalysis,
+    list: &SourceTypeArray,
+    trait_ty: &SourceType,
+) -> SourceTypeArray {
+    SourceTypeArray::with(
+        list.iter()
+            .map(|ty| replace_assoc_types(sa, ty, trait_ty))
+            .collect::<Vec<_>>(),
+    )
+}
+
+pub fn resolve_assoc_type(
+    sa: &SemAnalysis,
+    self_ty: &SourceType,
+    alias_id: AliasDefinitionId,
+) -> Option<SourceType> {
+    let (trait_id, type_params) = match self_ty {
+        SourceType::Trait(trait_id, type_params) => (*trait_id, type_params.clone()),
+
+        SourceType::TypeParam(_)
+        | SourceType::This
+        | SourceType::Error
+        | SourceType::Any
+        | SourceType::Ptr => return None,
+
+        // Self is an implementing type, the binding is defined in its impl
+        _ => {
+            let trait_id = match sa.aliases[alias_id].read().parent {
+                AliasParent::Trait(trait_id) => trait_id,
+                _ => return None,
+            };
+
+            let impl_trait_ty =
+                impl_trait_ty(sa, self_ty.clone(), &TypeParamDefinition::new(), trait_id)?;
+            (trait_id, impl_trait_ty.type_params())
+        }
+    };
+
+    let trait_ = sa.traits[trait_id].read();
+    let idx = trait_.binding_index(alias_id)?;
+
+    type_params.types().get(idx).cloned()
+}
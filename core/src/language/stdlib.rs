@@ -47,6 +47,25 @@ pub fn resolve_internal_annotations(sa: &mut SemAnalysis) {
         "annotations::optimizeImmediately",
         Modifier::OptimizeImmediately,
     ));
+
+    sa.known.annotations.open = Some(internal_annotation(
+        sa,
+        stdlib_id,
+        "annotations::open",
+        Modifier::Open,
+    ));
+    sa.known.annotations.override_ = Some(internal_annotation(
+        sa,
+        stdlib_id,
+        "annotations::override",
+        Modifier::Override,
+    ));
+    sa.known.annotations.final_ = Some(internal_annotation(
+        sa,
+        stdlib_id,
+        "annotations::final",
+        Modifier::Final,
+    ));
 }
 
 pub fn resolve_internal_classes(sa: &mut SemAnalysis) {
@@ -66,12 +85,42 @@ pub fn resolve_internal_classes(sa: &mut SemAnalysis) {
         Some(SourceType::Bool),
     ));
 
+    sa.known.values.int8 = Some(internal_value(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        Some(SourceType::Int8),
+    ));
+    sa.known.values.int16 = Some(internal_value(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        Some(SourceType::Int16),
+    ));
     sa.known.values.uint8 = Some(internal_value(
         sa,
         stdlib_id,
         "primitives::UInt8",
         Some(SourceType::UInt8),
     ));
+    sa.known.values.uint16 = Some(internal_value(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        Some(SourceType::UInt16),
+    ));
+    sa.known.values.uint32 = Some(internal_value(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        Some(SourceType::UInt32),
+    ));
+    sa.known.values.uint64 = Some(internal_value(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        Some(SourceType::UInt64),
+    ));
     sa.known.values.char = Some(internal_value(
         sa,
         stdlib_id,
@@ -122,6 +171,7 @@ pub fn resolve_internal_classes(sa: &mut SemAnalysis) {
     cls.is_array = true;
 
     sa.known.classes.stacktrace = Some(find_class(sa, stdlib_id, "Stacktrace"));
+    sa.known.classes.panic_info = Some(find_class(sa, stdlib_id, "panic::PanicInfo"));
     sa.known.classes.stacktrace_element = Some(find_class(sa, stdlib_id, "StacktraceElement"));
     sa.known.classes.thread = Some(find_class(sa, stdlib_id, "thread::Thread"));
 
@@ -130,6 +180,7 @@ pub fn resolve_internal_classes(sa: &mut SemAnalysis) {
     sa.known.traits.iterator = Some(find_trait(sa, stdlib_id, "traits::Iterator"));
 
     sa.known.enums.option = Some(find_enum(sa, stdlib_id, "primitives::Option"));
+    sa.known.enums.result = Some(find_enum(sa, stdlib_id, "primitives::Result"));
 }
 
 pub fn fill_prelude(sa: &mut SemAnalysis) {
@@ -138,7 +189,12 @@ pub fn fill_prelude(sa: &mut SemAnalysis) {
     let symbols = [
         "primitives::Unit",
         "primitives::Bool",
+        "primitives::Int8",
+        "primitives::Int16",
         "primitives::UInt8",
+        "primitives::UInt16",
+        "primitives::UInt32",
+        "primitives::UInt64",
         "primitives::Char",
         "primitives::Int32",
         "primitives::Int64",
@@ -238,6 +294,12 @@ pub fn discover_known_methods(sa: &mut SemAnalysis) {
         "string::StringBuffer",
         "toString",
     ));
+    sa.known.functions.string_equals = Some(find_instance_method(
+        sa,
+        stdlib_id,
+        "string::String",
+        "equals",
+    ));
     sa.known.functions.stacktrace_retrieve = Some(find_instance_method(
         sa,
         stdlib_id,
@@ -449,6 +511,318 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         Intrinsic::UInt8Cmp,
     );
 
+    native_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "toString",
+        stdlib::int8_to_string as *const u8,
+    );
+
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "toInt32",
+        Intrinsic::Int8ToInt32,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "toInt64",
+        Intrinsic::Int8ToInt64,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "plus",
+        Intrinsic::Int8Add,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "minus",
+        Intrinsic::Int8Sub,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "times",
+        Intrinsic::Int8Mul,
+    );
+    intrinsic_method(sa, stdlib_id, "primitives::Int8", "div", Intrinsic::Int8Div);
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "remainder",
+        Intrinsic::Int8Rem,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int8",
+        "unaryMinus",
+        Intrinsic::Int8Neg,
+    );
+
+    native_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "toString",
+        stdlib::int16_to_string as *const u8,
+    );
+
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "toInt32",
+        Intrinsic::Int16ToInt32,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "toInt64",
+        Intrinsic::Int16ToInt64,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "plus",
+        Intrinsic::Int16Add,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "minus",
+        Intrinsic::Int16Sub,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "times",
+        Intrinsic::Int16Mul,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "div",
+        Intrinsic::Int16Div,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "remainder",
+        Intrinsic::Int16Rem,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int16",
+        "unaryMinus",
+        Intrinsic::Int16Neg,
+    );
+
+    native_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "toString",
+        stdlib::uint16_to_string as *const u8,
+    );
+
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "toInt32",
+        Intrinsic::UInt16ToInt32,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "toInt64",
+        Intrinsic::UInt16ToInt64,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "plus",
+        Intrinsic::UInt16Add,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "minus",
+        Intrinsic::UInt16Sub,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "times",
+        Intrinsic::UInt16Mul,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "div",
+        Intrinsic::UInt16Div,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt16",
+        "remainder",
+        Intrinsic::UInt16Rem,
+    );
+
+    native_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "toString",
+        stdlib::uint32_to_string as *const u8,
+    );
+
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "toInt32",
+        Intrinsic::UInt32ToInt32,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "toInt64",
+        Intrinsic::UInt32ToInt64,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "compareTo",
+        Intrinsic::UInt32Cmp,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "plus",
+        Intrinsic::UInt32Add,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "minus",
+        Intrinsic::UInt32Sub,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "times",
+        Intrinsic::UInt32Mul,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "div",
+        Intrinsic::UInt32Div,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt32",
+        "remainder",
+        Intrinsic::UInt32Rem,
+    );
+
+    native_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "toString",
+        stdlib::uint64_to_string as *const u8,
+    );
+
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "toInt32",
+        Intrinsic::UInt64ToInt32,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "toInt64",
+        Intrinsic::UInt64ToInt64,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "compareTo",
+        Intrinsic::UInt64Cmp,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "plus",
+        Intrinsic::UInt64Add,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "minus",
+        Intrinsic::UInt64Sub,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "times",
+        Intrinsic::UInt64Mul,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "div",
+        Intrinsic::UInt64Div,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::UInt64",
+        "remainder",
+        Intrinsic::UInt64Rem,
+    );
+
     native_method(
         sa,
         stdlib_id,
@@ -493,6 +867,20 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         stdlib::int32_to_string as *const u8,
     );
 
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "toInt8",
+        Intrinsic::Int32ToInt8,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "toInt16",
+        Intrinsic::Int32ToInt16,
+    );
     intrinsic_method(
         sa,
         stdlib_id,
@@ -500,6 +888,27 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         "toUInt8",
         Intrinsic::Int32ToUInt8,
     );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "toUInt16",
+        Intrinsic::Int32ToUInt16,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "toUInt32",
+        Intrinsic::Int32ToUInt32,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "toUInt64",
+        Intrinsic::Int32ToUInt64,
+    );
     intrinsic_method(
         sa,
         stdlib_id,
@@ -611,6 +1020,34 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         "wrappingMul",
         Intrinsic::Int32MulUnchecked,
     );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "addOverflows",
+        Intrinsic::Int32AddOverflows,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "subOverflows",
+        Intrinsic::Int32SubOverflows,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "mulOverflows",
+        Intrinsic::Int32MulOverflows,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int32",
+        "wideningMulUnsigned",
+        Intrinsic::Int32WideningMulUnsigned,
+    );
 
     intrinsic_method(
         sa,
@@ -773,6 +1210,20 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         "toInt32",
         Intrinsic::Int64ToInt32,
     );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "toInt8",
+        Intrinsic::Int64ToInt8,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "toInt16",
+        Intrinsic::Int64ToInt16,
+    );
     intrinsic_method(
         sa,
         stdlib_id,
@@ -780,6 +1231,27 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         "toUInt8",
         Intrinsic::Int64ToUInt8,
     );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "toUInt16",
+        Intrinsic::Int64ToUInt16,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "toUInt32",
+        Intrinsic::Int64ToUInt32,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "toUInt64",
+        Intrinsic::Int64ToUInt64,
+    );
 
     intrinsic_method(
         sa,
@@ -876,6 +1348,27 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         "wrappingMul",
         Intrinsic::Int64MulUnchecked,
     );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "addOverflows",
+        Intrinsic::Int64AddOverflows,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "subOverflows",
+        Intrinsic::Int64SubOverflows,
+    );
+    intrinsic_method(
+        sa,
+        stdlib_id,
+        "primitives::Int64",
+        "mulOverflows",
+        Intrinsic::Int64MulOverflows,
+    );
 
     intrinsic_method(
         sa,
@@ -1525,6 +2018,21 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         stdlib::join_thread as *const u8,
     );
 
+    native_method(
+        sa,
+        stdlib_id,
+        "thread::Thread",
+        "tryJoinOp",
+        stdlib::try_join_thread as *const u8,
+    );
+
+    native_fct(
+        sa,
+        stdlib_id,
+        "panic::catchPanic",
+        stdlib::catch_panic as *const u8,
+    );
+
     intrinsic_static(
         sa,
         stdlib_id,
@@ -1581,6 +2089,260 @@ pub fn resolve_internal_functions(sa: &mut SemAnalysis) {
         stdlib::condition_wakeup_all as *const u8,
     );
 
+    native_fct(
+        sa,
+        stdlib_id,
+        "io::errorMessage",
+        stdlib::io_error_message as *const u8,
+    );
+
+    native_fct(
+        sa,
+        stdlib_id,
+        "io::stdinReadLine",
+        stdlib::stdin_read_line as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "io::stdinReadAll",
+        stdlib::stdin_read_all as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "io::stdinRead",
+        stdlib::stdin_read as *const u8,
+    );
+
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fileOpen",
+        stdlib::file_open as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fileRead",
+        stdlib::file_read as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fileWrite",
+        stdlib::file_write as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fileSeek",
+        stdlib::file_seek as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fileClose",
+        stdlib::file_close as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fsMetadata",
+        stdlib::fs_metadata as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fsCreateDir",
+        stdlib::fs_create_dir as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fsRemoveFile",
+        stdlib::fs_remove_file as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fsRemoveDir",
+        stdlib::fs_remove_dir as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::fsRename",
+        stdlib::fs_rename as *const u8,
+    );
+    native_fct(sa, stdlib_id, "fs::dirOpen", stdlib::dir_open as *const u8);
+    native_fct(sa, stdlib_id, "fs::dirNext", stdlib::dir_next as *const u8);
+    native_fct(
+        sa,
+        stdlib_id,
+        "fs::dirClose",
+        stdlib::dir_close as *const u8,
+    );
+
+    native_fct(sa, stdlib_id, "math::sin", stdlib::math_sin as *const u8);
+    native_fct(sa, stdlib_id, "math::cos", stdlib::math_cos as *const u8);
+    native_fct(sa, stdlib_id, "math::tan", stdlib::math_tan as *const u8);
+    native_fct(sa, stdlib_id, "math::asin", stdlib::math_asin as *const u8);
+    native_fct(sa, stdlib_id, "math::acos", stdlib::math_acos as *const u8);
+    native_fct(sa, stdlib_id, "math::atan", stdlib::math_atan as *const u8);
+    native_fct(
+        sa,
+        stdlib_id,
+        "math::atan2",
+        stdlib::math_atan2 as *const u8,
+    );
+    native_fct(sa, stdlib_id, "math::sinh", stdlib::math_sinh as *const u8);
+    native_fct(sa, stdlib_id, "math::cosh", stdlib::math_cosh as *const u8);
+    native_fct(sa, stdlib_id, "math::tanh", stdlib::math_tanh as *const u8);
+    native_fct(sa, stdlib_id, "math::exp", stdlib::math_exp as *const u8);
+    native_fct(sa, stdlib_id, "math::exp2", stdlib::math_exp2 as *const u8);
+    native_fct(sa, stdlib_id, "math::ln", stdlib::math_ln as *const u8);
+    native_fct(sa, stdlib_id, "math::log2", stdlib::math_log2 as *const u8);
+    native_fct(
+        sa,
+        stdlib_id,
+        "math::log10",
+        stdlib::math_log10 as *const u8,
+    );
+    native_fct(sa, stdlib_id, "math::pow", stdlib::math_pow as *const u8);
+    native_fct(sa, stdlib_id, "math::cbrt", stdlib::math_cbrt as *const u8);
+    native_fct(
+        sa,
+        stdlib_id,
+        "math::hypot",
+        stdlib::math_hypot as *const u8,
+    );
+    native_fct(sa, stdlib_id, "math::fma", stdlib::math_fma as *const u8);
+    native_fct(sa, stdlib_id, "math::min", stdlib::math_min as *const u8);
+    native_fct(sa, stdlib_id, "math::max", stdlib::math_max as *const u8);
+    native_fct(
+        sa,
+        stdlib_id,
+        "math::copySign",
+        stdlib::math_copy_sign as *const u8,
+    );
+
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envGetVar",
+        stdlib::env_get_var as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envSetVar",
+        stdlib::env_set_var as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envRemoveVar",
+        stdlib::env_remove_var as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envVarsOpen",
+        stdlib::env_vars_open as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envVarsNext",
+        stdlib::env_vars_next as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envVarsClose",
+        stdlib::env_vars_close as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envCurrentDir",
+        stdlib::env_current_dir as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envCurrentDirError",
+        stdlib::env_current_dir_error as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "env::envSetCurrentDir",
+        stdlib::env_set_current_dir as *const u8,
+    );
+
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::processSpawn",
+        stdlib::process_spawn as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childId",
+        stdlib::child_id as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childWriteStdin",
+        stdlib::child_write_stdin as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childCloseStream",
+        stdlib::child_close_stream as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childReadStream",
+        stdlib::child_read_stream as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childTakeOutput",
+        stdlib::child_take_output as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childWait",
+        stdlib::child_wait as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childWaitWithOutput",
+        stdlib::child_wait_with_output as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childKill",
+        stdlib::child_kill as *const u8,
+    );
+    native_fct(
+        sa,
+        stdlib_id,
+        "process::childRelease",
+        stdlib::child_release as *const u8,
+    );
+
     let fct_id = intrinsic_method(sa, stdlib_id, "Option", "isNone", Intrinsic::OptionIsNone);
     sa.known.functions.option_is_none = Some(fct_id);
     let fct_id = intrinsic_method(sa, stdlib_id, "Option", "isSome", Intrinsic::OptionIsSome);
@@ -0,0 +1,465 @@
+use std::collections::HashSet;
+
+use crate::language::error::msg::ErrorMessage;
+use crate::language::sem_analysis::{
+    extension_matches, parent_class_type, ClassDefinitionId, ExtensionDefinition, FctDefinition,
+    FctDefinitionId, Field, SemAnalysis, TypeParamDefinition, TypeParamId,
+};
+use crate::language::specialize::replace_type_param;
+use crate::language::ty::{SourceType, SourceTypeArray};
+
+use core_parser::interner::Name;
+
+pub fn check(sa: &SemAnalysis) {
+    check_cycles(sa);
+
+    let mut done = HashSet::new();
+
+    for cls in sa.classes.iter() {
+        let cls_id = cls.read().id();
+        inherit_fields(sa, cls_id, &mut done);
+    }
+}
+
+fn parent_class_id(sa: &SemAnalysis, cls_id: ClassDefinitionId) -> Option<ClassDefinitionId> {
+    let cls = sa.classes.idx(cls_id);
+    let cls = cls.read();
+
+    cls.parent_class.as_ref().and_then(|parent| parent.cls_id())
+}
+
+fn check_cycles(sa: &SemAnalysis) {
+    for cls in sa.classes.iter() {
+        let cls_id = cls.read().id();
+
+        let mut visited = HashSet::new();
+        let mut current = parent_class_id(sa, cls_id);
+
+        while let Some(parent_id) = current {
+            if parent_id == cls_id {
+                let mut cls = cls.write();
+                cls.parent_class = None;
+
+                sa.diag
+                    .lock()
+                    .report(cls.file_id(), cls.pos(), ErrorMessage::CycleInHierarchy);
+                break;
+            }
+
+            // a cycle that doesn't contain this class is reported for its members
+            if !visited.insert(parent_id) {
+                break;
+            }
+
+            current = parent_class_id(sa, parent_id);
+        }
+    }
+}
+
+// Prepends the fields of the parent class, this way the object layout of a
+// class starts with the layout of its parent class.
+fn inherit_fields(
+    sa: &SemAnalysis,
+    cls_id: ClassDefinitionId,
+    done: &mut HashSet<ClassDefinitionId>,
+) {
+    if !done.insert(cls_id) {
+        return;
+    }
+
+    let parent_class = match sa.classes.idx(cls_id).read().parent_class.clone() {
+        Some(parent_class) => parent_class,
+        None => return,
+    };
+
+    let parent_id = parent_class.cls_id().expect("class expected");
+    inherit_fields(sa, parent_id, done);
+
+    let mut fields = {
+        let parent = sa.classes.idx(parent_id);
+        let parent = parent.read();
+        let type_params = parent_class.type_params();
+
+        parent
+            .fields
+            .iter()
+            .map(|field| Field {
+                id: field.id,
+                name: field.name,
+                ty: replace_type_param(sa, field.ty.clone(), &type_params, None),
+                mutable: field.mutable,
+                visibility: field.visibility,
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let cls = sa.classes.idx(cls_id);
+    let mut cls = cls.write();
+    let own_fields = std::mem::take(&mut cls.fields);
+
+    for (idx, mut field) in own_fields.into_iter().enumerate() {
+        if fields
+            .iter()
+            .any(|parent_field| parent_field.name == field.name)
+        {
+            let name = sa.interner.str(field.name).to_string();
+            let pos = cls.ast().fields[idx].pos;
+            sa.diag
+                .lock()
+                .report(cls.file_id(), pos, ErrorMessage::ShadowField(name));
+        }
+
+        field.id = fields.len().into();
+        fields.push(field);
+    }
+
+    cls.fields = fields;
+}
+
+pub fn check_override(sa: &SemAnalysis) {
+    let mut done = HashSet::new();
+
+    for cls in sa.classes.iter() {
+        let cls_id = cls.read().id();
+        check_class_methods(sa, cls_id, &mut done);
+    }
+
+    for extension in sa.extensions.iter() {
+        let extension = extension.read();
+
+        if extension.ty.cls_id().is_none() {
+            for &method_id in &extension.methods {
+                check_no_virtual_modifiers(sa, method_id);
+            }
+        }
+    }
+
+    for impl_ in sa.impls.iter() {
+        let impl_ = impl_.read();
+
+        for &method_id in &impl_.methods {
+            check_no_virtual_modifiers(sa, method_id);
+        }
+    }
+}
+
+fn check_no_virtual_modifiers(sa: &SemAnalysis, method_id: FctDefinitionId) {
+    let method = sa.fcts.idx(method_id);
+    let method = method.read();
+
+    if method.is_override {
+        report(sa, &method, ErrorMessage::SuperfluousOverride);
+    } else if method.is_open {
+        report(sa, &method, ErrorMessage::SuperfluousOpen);
+    }
+}
+
+fn report(sa: &SemAnalysis, method: &FctDefinition, msg: fn(String) -> ErrorMessage) {
+    let name = sa.interner.str(method.name).to_string();
+    sa.diag.lock().report(method.file_id, method.pos, msg(name));
+}
+
+fn check_class_methods(
+    sa: &SemAnalysis,
+    cls_id: ClassDefinitionId,
+    done: &mut HashSet<ClassDefinitionId>,
+) {
+    if !done.insert(cls_id) {
+        return;
+    }
+
+    let (parent_class, is_open, extensions) = {
+        let cls = sa.classes.idx(cls_id);
+        let cls = cls.read();
+        (
+            cls.parent_class.clone(),
+            cls.is_open,
+            cls.extensions.clone(),
+        )
+    };
+
+    let mut virtual_methods = if let Some(ref parent_class) = parent_class {
+        let parent_id = parent_class.cls_id().expect("class expected");
+        check_class_methods(sa, parent_id, done);
+
+        let parent = sa.classes.idx(parent_id);
+        let parent = parent.read();
+        parent.virtual_methods.clone()
+    } else {
+        Vec::new()
+    };
+
+    for extension_id in extensions {
+        let extension = sa.extensions[extension_id].read();
+
+        for &method_id in &extension.methods {
+            let method = sa.fcts.idx(method_id);
+            let mut method = method.write();
+
+            if method.is_static {
+                drop(method);
+                check_no_virtual_modifiers(sa, method_id);
+                continue;
+            }
+
+            let overridden = parent_class.as_ref().and_then(|parent_class| {
+                find_overridden_method(sa, &extension, parent_class, method.name)
+            });
+
+            if let Some((super_method_id, bindings)) = overridden {
+                let super_method = sa.fcts.idx(super_method_id);
+                let super_method = super_method.read();
+
+                if !super_method.is_virtual() || super_method.is_final {
+                    report(sa, &method, ErrorMessage::MethodNotOverridable);
+                    continue;
+                }
+
+                if !method.is_override {
+                    report(sa, &method, ErrorMessage::MissingOverride);
+                    continue;
+                }
+
+                if !check_signature(sa, &method, &super_method, &bindings) {
+                    continue;
+                }
+
+                let vtable_index = super_method.vtable_index.expect("missing vtable index");
+                method.vtable_index = Some(vtable_index);
+                virtual_methods[vtable_index as usize] = method_id;
+            } else if method.is_override {
+                report(sa, &method, ErrorMessage::SuperfluousOverride);
+            } else if method.is_open {
+                if is_open {
+                    method.vtable_index = Some(virtual_methods.len() as u32);
+                    virtual_methods.push(method_id);
+                } else {
+                    report(sa, &method, ErrorMessage::SuperfluousOpen);
+                }
+            }
+        }
+    }
+
+    let cls = sa.classes.idx(cls_id);
+    let mut cls = cls.write();
+    cls.virtual_methods = virtual_methods;
+}
+
+// Searches the class hierarchy above `parent_class` for a method with the given
+// name. Also returns the type params of the method's extension expressed in the
+// type params of `extension`.
+fn find_overridden_method(
+    sa: &SemAnalysis,
+    extension: &ExtensionDefinition,
+    parent_class: &SourceType,
+    name: Name,
+) -> Option<(FctDefinitionId, SourceTypeArray)> {
+    let type_param_defs: &TypeParamDefinition = extension.type_params();
+    let mut current = Some(replace_type_param(
+        sa,
+        parent_class.clone(),
+        &extension.ty.type_params(),
+        None,
+    ));
+
+    while let Some(class_ty) = current {
+        let cls_id = class_ty.cls_id().expect("class expected");
+        let extensions = sa.classes.idx(cls_id).read().extensions.clone();
+
+        for extension_id in extensions {
+            if let Some(bindings) =
+                extension_matches(sa, class_ty.clone(), type_param_defs, extension_id)
+            {
+                let super_extension = sa.extensions[extension_id].read();
+
+                if let Some(&fct_id) = super_extension.instance_names.get(&name) {
+                    return Some((fct_id, bindings));
+                }
+            }
+        }
+
+        current = parent_class_type(sa, &class_ty);
+    }
+
+    None
+}
+
+fn check_signature(
+    sa: &SemAnalysis,
+    method: &FctDefinition,
+    super_method: &FctDefinition,
+    bindings: &SourceTypeArray,
+) -> bool {
+    let own_type_params = method.type_params.len() - method.container_type_params;
+    let super_own_type_params = super_method.type_params.len() - super_method.container_type_params;
+
+    // type params of the method itself follow the container type params
+    let type_params = bindings.connect(&SourceTypeArray::with(
+        (0..own_type_params)
+            .map(|idx| SourceType::TypeParam(TypeParamId(method.container_type_params + idx)))
+            .collect(),
+    ));
+
+    let params = method.params_without_self();
+    let super_params = super_method.params_without_self();
+
+    if method.is_nullary != super_method.is_nullary
+        || own_type_params != super_own_type_params
+        || params.len() != super_params.len()
+        || params.iter().zip(super_params).any(|(param, super_param)| {
+            *param != replace_type_param(sa, super_param.clone(), &type_params, None)
+        })
+    {
+        sa.diag
+            .lock()
+            .report(method.file_id, method.pos, ErrorMessage::OverrideMismatch);
+        return false;
+    }
+
+    let super_return_type =
+        replace_type_param(sa, super_method.return_type.clone(), &type_params, None);
+
+    if method.return_type != super_return_type {
+        let msg = ErrorMessage::ReturnTypeMismatch(
+            method.return_type.name_fct(sa, method),
+            super_return_type.name_fct(sa, method),
+        );
+        sa.diag.lock().report(method.file_id, method.pos, msg);
+        return false;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::error::msg::ErrorMessage;
+    use crate::language::tests::*;
+
+    #[test]
+    fn test_parent_class() {
+        ok("@open class A class B: A");
+        ok("@open class A(a: Int32) class B(b: Int32): A");
+        ok("@open class A[T](a: T) class B(b: Int32): A[Int32]");
+        ok("@open class A[T] class B[T]: A[T]");
+        err(
+            "class A class B: A",
+            pos(1, 18),
+            ErrorMessage::UnderivableType("A".into()),
+        );
+        err(
+            "class B: Int32",
+            pos(1, 10),
+            ErrorMessage::UnderivableType("Int32".into()),
+        );
+        err(
+            "@open class A: B @open class B: A",
+            pos(1, 7),
+            ErrorMessage::CycleInHierarchy,
+        );
+    }
+
+    #[test]
+    fn test_inherited_field() {
+        ok("@open class A(a: Int32) class B(b: Int32): A
+            fun f(x: B): Int32 { x.a + x.b }");
+        err(
+            "@open class A(a: Int32) class B(a: Int32): A",
+            pos(1, 33),
+            ErrorMessage::ShadowField("a".into()),
+        );
+    }
+
+    #[test]
+    fn test_subclass_assignable() {
+        ok("@open class A class B: A
+            fun f(): A { B() }");
+        ok("@open class A class B: A
+            fun f(x: A): Unit {}
+            fun g(): Unit { f(B()); }");
+        err(
+            "@open class A class B: A
+            fun f(): B { A() }",
+            pos(2, 24),
+            ErrorMessage::ReturnType("B".into(), "A".into()),
+        );
+    }
+
+    #[test]
+    fn test_override_method() {
+        ok("@open class A impl A { @open fun f(): Int32 { 1 } }
+            class B: A impl B { @override fun f(): Int32 { 2 } }");
+        err(
+            "@open class A impl A { fun f(): Int32 { 1 } }
+            class B: A impl B { @override fun f(): Int32 { 2 } }",
+            pos(2, 43),
+            ErrorMessage::MethodNotOverridable("f".into()),
+        );
+        err(
+            "@open class A impl A { @open fun f(): Int32 { 1 } }
+            class B: A impl B { fun f(): Int32 { 2 } }",
+            pos(2, 33),
+            ErrorMessage::MissingOverride("f".into()),
+        );
+        err(
+            "@open class A class B: A impl B { @override fun f(): Int32 { 2 } }",
+            pos(1, 45),
+            ErrorMessage::SuperfluousOverride("f".into()),
+        );
+        err(
+            "class A impl A { @open fun f(): Int32 { 1 } }",
+            pos(1, 24),
+            ErrorMessage::SuperfluousOpen("f".into()),
+        );
+    }
+
+    #[test]
+    fn test_override_final_method() {
+        ok("@open class A impl A { @open fun f(): Int32 { 1 } }
+            @open class B: A impl B { @override fun f(): Int32 { 2 } }
+            class C: B impl C { @override fun f(): Int32 { 3 } }");
+        err(
+            "@open class A impl A { @open fun f(): Int32 { 1 } }
+            @open class B: A impl B { @override @final fun f(): Int32 { 2 } }
+            class C: B impl C { @override fun f(): Int32 { 3 } }",
+            pos(3, 43),
+            ErrorMessage::MethodNotOverridable("f".into()),
+        );
+    }
+
+    #[test]
+    fn test_override_signature() {
+        err(
+            "@open class A impl A { @open fun f(a: Int32): Int32 { a } }
+            class B: A impl B { @override fun f(a: Int64): Int32 { 2 } }",
+            pos(2, 43),
+            ErrorMessage::OverrideMismatch,
+        );
+        err(
+            "@open class A impl A { @open fun f(): Int32 { 1 } }
+            class B: A impl B { @override fun f(): Int64 { 2 } }",
+            pos(2, 43),
+            ErrorMessage::ReturnTypeMismatch("Int64".into(), "Int32".into()),
+        );
+        ok(
+            "@open class A[T] impl[T] A[T] { @open fun f(a: T): T { a } }
+            class B: A[Int32] impl B { @override fun f(a: Int32): Int32 { a } }",
+        );
+    }
+
+    #[test]
+    fn test_super_call() {
+        ok("@open class A impl A { @open fun f(): Int32 { 1 } }
+            class B: A impl B { @override fun f(): Int32 { super.f() + 1i32 } }");
+        err(
+            "class A impl A { fun f(): Int32 { super.f() } }",
+            pos(1, 35),
+            ErrorMessage::SuperUnavailable,
+        );
+        err(
+            "@open class A class B: A impl B { fun f(): Unit { let x = super; } }",
+            pos(1, 59),
+            ErrorMessage::SuperNeedsMethodCall,
+        );
+    }
+}
@@ -6,9 +6,9 @@ use std::sync::Arc;
 use self::Sym::*;
 
 use crate::language::sem_analysis::{
-    AnnotationDefinitionId, ClassDefinitionId, ConstDefinitionId, EnumDefinitionId,
-    FctDefinitionId, FieldId, GlobalDefinitionId, ModuleDefinitionId, NestedVarId, SemAnalysis,
-    TraitDefinitionId, TypeParamId, ValueDefinitionId,
+    AliasDefinitionId, AnnotationDefinitionId, ClassDefinitionId, ConstDefinitionId,
+    EnumDefinitionId, FctDefinitionId, FieldId, GlobalDefinitionId, ModuleDefinitionId,
+    NestedVarId, SemAnalysis, TraitDefinitionId, TypeParamId, UnionDefinitionId, ValueDefinitionId,
 };
 use core_parser::interner::Name;
 
@@ -91,6 +91,14 @@ impl ModuleSymTable {
         self.get(name).and_then(|n| n.to_enum())
     }
 
+    pub fn get_union(&self, name: Name) -> Option<UnionDefinitionId> {
+        self.get(name).and_then(|n| n.to_union())
+    }
+
+    pub fn get_alias(&self, name: Name) -> Option<AliasDefinitionId> {
+        self.get(name).and_then(|n| n.to_alias())
+    }
+
     pub fn get_global(&self, name: Name) -> Option<GlobalDefinitionId> {
         self.get(name).and_then(|n| n.to_global())
     }
@@ -171,6 +179,8 @@ pub enum Sym {
     Trait(TraitDefinitionId),
     TypeParam(TypeParamId),
     Enum(EnumDefinitionId),
+    Union(UnionDefinitionId),
+    Alias(AliasDefinitionId),
     Field(FieldId),
     Fct(FctDefinitionId),
     Var(NestedVarId),
@@ -245,6 +255,34 @@ impl Sym {
         }
     }
 
+    pub fn is_union(&self) -> bool {
+        match *self {
+            Union(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn to_union(&self) -> Option<UnionDefinitionId> {
+        match *self {
+            Union(id) => Some(id),
+            _ => None,
+        }
+    }
+
+    pub fn is_alias(&self) -> bool {
+        match *self {
+            Alias(_) => true,
+            _ => false,
+        }
+    }
+
+    pub fn to_alias(&self) -> Option<AliasDefinitionId> {
+        match *self {
+            Alias(id) => Some(id),
+            _ => None,
+        }
+    }
+
     pub fn is_fct(&self) -> bool {
         match *self {
             Fct(_) => true,
@@ -336,6 +374,8 @@ impl Sym {
             Trait(_) => "trait",
             TypeParam(_) => "type param",
             Enum(_) => "enum",
+            Union(_) => "union",
+            Alias(_) => "type alias",
             Field(_) => "field",
             Fct(_) => "function",
             Var(_) => "variable",
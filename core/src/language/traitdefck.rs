@@ -97,6 +97,22 @@ mod tests {
         );
     }
 
+    #[test]
+    fn trait_method_with_body_using_self() {
+        ok("trait Foo {
+            fun foo(): Int32;
+            fun bar(): Int32 { self.foo() + 1i32 }
+            fun me(): Self { self }
+            fun same(other: Self): Int32 { other.foo() }
+        }");
+
+        err(
+            "trait Foo { fun foo(): Int32; fun bar(): Int32 { self.foo(1i32) } }",
+            pos(1, 58),
+            ErrorMessage::UnknownMethod("Self".into(), "foo".into(), vec!["Int32".into()]),
+        );
+    }
+
     #[test]
     fn trait_definitions() {
         ok("trait Foo {}");
@@ -135,4 +151,52 @@ mod tests {
             ErrorMessage::MethodExists("foo".into(), pos(2, 13)),
         );
     }
+
+    #[test]
+    fn trait_assoc_type_defined_twice() {
+        err(
+            "trait Foo {
+            type Item;
+            type Item;
+        }",
+            pos(3, 13),
+            ErrorMessage::AssocTypeExists("Item".into()),
+        );
+    }
+
+    #[test]
+    fn trait_assoc_type_unknown() {
+        err(
+            "trait Foo { fun get(): Self::Item; }",
+            pos(1, 24),
+            ErrorMessage::UnknownType("Self::Item".into()),
+        );
+    }
+
+    #[test]
+    fn trait_super_traits() {
+        ok("trait Foo {} trait Bar: Foo {} trait Baz: Bar + Foo {}");
+        ok("trait Foo[T] {} trait Bar[T]: Foo[T] {}");
+
+        err(
+            "class Foo trait Bar: Foo {}",
+            pos(1, 22),
+            ErrorMessage::BoundExpected,
+        );
+        err(
+            "trait Foo {} trait Bar: Foo + Foo {}",
+            pos(1, 31),
+            ErrorMessage::DuplicateTraitBound,
+        );
+        err(
+            "trait Foo: Bar {} trait Bar: Foo {}",
+            pos(1, 1),
+            ErrorMessage::CycleInHierarchy,
+        );
+        err(
+            "trait Foo: Foo {}",
+            pos(1, 1),
+            ErrorMessage::CycleInHierarchy,
+        );
+    }
 }
@@ -2,9 +2,9 @@ use std::ops::Index;
 use std::sync::Arc;
 
 use crate::language::sem_analysis::{
-    ClassDefinition, ClassDefinitionId, EnumDefinition, EnumDefinitionId, FctDefinition,
-    SemAnalysis, TraitDefinitionId, TypeParamDefinition, TypeParamId, ValueDefinition,
-    ValueDefinitionId,
+    parent_class_type, AliasDefinitionId, ClassDefinition, ClassDefinitionId, EnumDefinition,
+    EnumDefinitionId, FctDefinition, SemAnalysis, TraitDefinitionId, TypeParamDefinition,
+    TypeParamId, UnionDefinitionId, ValueDefinition, ValueDefinitionId,
 };
 
 #[derive(Debug, Clone, Hash, PartialEq, Eq)]
@@ -21,9 +21,14 @@ pub enum SourceType {
     // primitives
     Bool,
     Char,
+    Int8,
+    Int16,
     UInt8,
+    UInt16,
     Int32,
+    UInt32,
     Int64,
+    UInt64,
     Float32,
     Float64,
 
@@ -48,11 +53,17 @@ pub enum SourceType {
     // some type variable
     TypeParam(TypeParamId),
 
+    // associated type of a trait: Self::Item
+    Assoc(AliasDefinitionId),
+
     // some lambda
     Lambda(SourceTypeArray, Box<SourceType>),
 
     // some enum
     Enum(EnumDefinitionId, SourceTypeArray),
+
+    // some union
+    Union(UnionDefinitionId, SourceTypeArray),
 }
 
 impl SourceType {
@@ -81,6 +92,13 @@ impl SourceType {
         }
     }
 
+    pub fn is_union(&self) -> bool {
+        match self {
+            SourceType::Union(_, _) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_unit(&self) -> bool {
         match self {
             SourceType::Unit => true,
@@ -130,6 +148,16 @@ impl SourceType {
         }
     }
 
+    pub fn is_unsigned_int(&self) -> bool {
+        match self {
+            &SourceType::UInt8
+            | &SourceType::UInt16
+            | &SourceType::UInt32
+            | &SourceType::UInt64 => true,
+            _ => false,
+        }
+    }
+
     pub fn is_bool(&self) -> bool {
         match self {
             &SourceType::Bool => true,
@@ -162,6 +190,11 @@ impl SourceType {
         match self {
             &SourceType::Bool
             | &SourceType::UInt8
+            | &SourceType::Int8
+            | &SourceType::Int16
+            | &SourceType::UInt16
+            | &SourceType::UInt32
+            | &SourceType::UInt64
             | &SourceType::Char
             | &SourceType::Int32
             | &SourceType::Int64
@@ -197,7 +230,12 @@ impl SourceType {
         match self {
             SourceType::Unit => Some(sa.known.values.unit()),
             SourceType::Bool => Some(sa.known.values.bool()),
+            SourceType::Int8 => Some(sa.known.values.int8()),
+            SourceType::Int16 => Some(sa.known.values.int16()),
             SourceType::UInt8 => Some(sa.known.values.uint8()),
+            SourceType::UInt16 => Some(sa.known.values.uint16()),
+            SourceType::UInt32 => Some(sa.known.values.uint32()),
+            SourceType::UInt64 => Some(sa.known.values.uint64()),
             SourceType::Char => Some(sa.known.values.char()),
             SourceType::Int32 => Some(sa.known.values.int32()),
             SourceType::Int64 => Some(sa.known.values.int64()),
@@ -218,6 +256,13 @@ impl SourceType {
         }
     }
 
+    pub fn union_id(&self) -> Option<UnionDefinitionId> {
+        match self {
+            SourceType::Union(union_id, _) => Some(*union_id),
+            _ => None,
+        }
+    }
+
     pub fn value_id(&self) -> Option<ValueDefinitionId> {
         match self {
             SourceType::Value(value_id, _) => Some(*value_id),
@@ -236,6 +281,7 @@ impl SourceType {
         match self {
             SourceType::Class(_, params)
             | SourceType::Enum(_, params)
+            | SourceType::Union(_, params)
             | SourceType::Value(_, params)
             | SourceType::Trait(_, params) => params.clone(),
             _ => SourceTypeArray::empty(),
@@ -247,6 +293,7 @@ impl SourceType {
             SourceType::Ptr => true,
             SourceType::Class(_, _) => true,
             SourceType::Trait(_, _) => true,
+            SourceType::Union(_, _) => true,
             SourceType::Lambda(_, _) => true,
             _ => false,
         }
@@ -257,6 +304,11 @@ impl SourceType {
             SourceType::Unit
             | SourceType::Bool
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Int32
             | SourceType::Int64
             | SourceType::Float32
@@ -358,6 +410,11 @@ impl SourceType {
             SourceType::Unit
             | SourceType::Bool
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Char
             | SourceType::Value(_, _)
             | SourceType::Enum(_, _)
@@ -366,7 +423,8 @@ impl SourceType {
                 *self == other
             }
             SourceType::Ptr => panic!("ptr does not allow any other types"),
-            SourceType::This => unreachable!(),
+            // Self in default methods of traits
+            SourceType::This => *self == other,
             SourceType::Class(self_cls_id, self_list) => {
                 if *self == other {
                     return true;
@@ -379,7 +437,30 @@ impl SourceType {
                     }
                 };
 
-                *self_cls_id == other_cls_id && self_list == &other_list
+                if *self_cls_id == other_cls_id && self_list == &other_list {
+                    return true;
+                }
+
+                // instances of subclasses can be used where the parent class is expected
+                match parent_class_type(sa, &other) {
+                    Some(parent_class) => self.allows(sa, parent_class),
+                    None => false,
+                }
+            }
+            SourceType::Union(union_id, type_params) => {
+                if *self == other {
+                    return true;
+                }
+
+                // every variant of the union can be used where the union is expected
+                if other.is_cls() {
+                    let union_ = sa.unions[*union_id].read();
+
+                    (0..union_.variants.len())
+                        .any(|idx| union_.variant_type(sa, type_params, idx) == other)
+                } else {
+                    false
+                }
             }
             SourceType::Tuple(subtypes) => match other {
                 SourceType::Tuple(other_subtypes) => {
@@ -404,7 +485,7 @@ impl SourceType {
                 _ => false,
             },
 
-            SourceType::TypeParam(_) => *self == other,
+            SourceType::TypeParam(_) | SourceType::Assoc(_) => *self == other,
 
             SourceType::Lambda(_, _) => {
                 // for now expect the exact same params and return types
@@ -421,6 +502,11 @@ impl SourceType {
             SourceType::Unit
             | SourceType::Bool
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Char
             | SourceType::Int32
             | SourceType::Int64
@@ -428,8 +514,10 @@ impl SourceType {
             | SourceType::Float64
             | SourceType::Trait(_, _)
             | SourceType::Lambda(_, _)
-            | SourceType::TypeParam(_) => true,
+            | SourceType::TypeParam(_)
+            | SourceType::Assoc(_) => true,
             SourceType::Enum(_, params)
+            | SourceType::Union(_, params)
             | SourceType::Class(_, params)
             | SourceType::Value(_, params) => {
                 for param in params.iter() {
@@ -458,6 +546,11 @@ impl SourceType {
             SourceType::Unit
             | SourceType::Bool
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Char
             | SourceType::Int32
             | SourceType::Int64
@@ -466,6 +559,7 @@ impl SourceType {
             | SourceType::Ptr => true,
             SourceType::Class(_, params)
             | SourceType::Enum(_, params)
+            | SourceType::Union(_, params)
             | SourceType::Value(_, params)
             | SourceType::Trait(_, params) => {
                 for param in params.iter() {
@@ -495,7 +589,7 @@ impl SourceType {
 
                 return_type.is_concrete_type(sa)
             }
-            SourceType::TypeParam(_) => false,
+            SourceType::TypeParam(_) | SourceType::Assoc(_) => false,
         }
     }
 }
@@ -663,7 +757,12 @@ impl<'a> SourceTypePrinter<'a> {
             SourceType::Error => "<error>".into(),
             SourceType::Any => "Any".into(),
             SourceType::Unit => "()".into(),
+            SourceType::Int8 => "Int8".into(),
+            SourceType::Int16 => "Int16".into(),
             SourceType::UInt8 => "UInt8".into(),
+            SourceType::UInt16 => "UInt16".into(),
+            SourceType::UInt32 => "UInt32".into(),
+            SourceType::UInt64 => "UInt64".into(),
             SourceType::Char => "Char".into(),
             SourceType::Int32 => "Int32".into(),
             SourceType::Int64 => "Int64".into(),
@@ -711,6 +810,20 @@ impl<'a> SourceTypePrinter<'a> {
                 let trait_ = self.sa.traits[tid].read();
                 let name = self.sa.interner.str(trait_.name).to_string();
 
+                if type_params.len() == 0 {
+                    name
+                } else {
+                    let params = trait_
+                        .param_names(self.sa, &type_params, |ty| self.name(ty))
+                        .join(", ");
+
+                    format!("{}[{}]", name, params)
+                }
+            }
+            SourceType::Enum(id, type_params) => {
+                let enum_ = self.sa.enums[id].read();
+                let name = self.sa.interner.str(enum_.name).to_string();
+
                 if type_params.len() == 0 {
                     name
                 } else {
@@ -723,9 +836,9 @@ impl<'a> SourceTypePrinter<'a> {
                     format!("{}[{}]", name, params)
                 }
             }
-            SourceType::Enum(id, type_params) => {
-                let enum_ = self.sa.enums[id].read();
-                let name = self.sa.interner.str(enum_.name).to_string();
+            SourceType::Union(id, type_params) => {
+                let union_ = self.sa.unions[id].read();
+                let name = self.sa.interner.str(union_.name).to_string();
 
                 if type_params.len() == 0 {
                     name
@@ -748,6 +861,11 @@ impl<'a> SourceTypePrinter<'a> {
                 }
             }
 
+            SourceType::Assoc(alias_id) => {
+                let alias = self.sa.aliases[alias_id].read();
+                format!("Self::{}", self.sa.interner.str(alias.name))
+            }
+
             SourceType::Lambda(params, return_type) => {
                 let params = params
                     .iter()
@@ -5,19 +5,120 @@ use core_parser::Position;
 
 use crate::language::error::msg::ErrorMessage;
 use crate::language::readty::read_type_unchecked;
-use crate::language::sem_analysis::{SemAnalysis, SourceFileId, TypeParamDefinition, TypeParamId};
+use crate::language::sem_analysis::{
+    all_super_traits, SemAnalysis, SourceFileId, TraitDefinitionId, TypeParamDefinition,
+    TypeParamId,
+};
 use crate::language::sym::{ModuleSymTable, Sym};
 use crate::language::ty::{SourceType, SourceTypeArray};
 
 pub fn check(sa: &SemAnalysis) {
+    check_super_traits(sa);
     check_traits(sa);
     check_impls(sa);
     check_classes(sa);
     check_enums(sa);
+    check_unions(sa);
+    check_aliases(sa);
     check_values(sa);
     check_extensions(sa);
 }
 
+fn check_super_traits(sa: &SemAnalysis) {
+    for trait_ in sa.traits.iter() {
+        let mut super_traits = Vec::new();
+
+        {
+            let trait_ = trait_.read();
+            let mut symtable = ModuleSymTable::new(sa, trait_.module_id);
+            symtable.push_level();
+
+            if let Some(ref type_params) = trait_.ast.type_params {
+                for (id, type_param) in type_params.iter().enumerate() {
+                    symtable.insert(type_param.name, Sym::TypeParam(TypeParamId(id)));
+                }
+            }
+
+            for super_trait in &trait_.ast.super_traits {
+                let ty = read_type_unchecked(sa, &symtable, trait_.file_id, super_trait);
+
+                if ty.is_trait() {
+                    if super_traits.contains(&ty) {
+                        let msg = ErrorMessage::DuplicateTraitBound;
+                        sa.diag
+                            .lock()
+                            .report(trait_.file_id, super_trait.pos(), msg);
+                    } else {
+                        super_traits.push(ty);
+                    }
+                } else if !ty.is_error() {
+                    let msg = ErrorMessage::BoundExpected;
+                    sa.diag
+                        .lock()
+                        .report(trait_.file_id, super_trait.pos(), msg);
+                }
+            }
+
+            symtable.pop_level();
+        }
+
+        trait_.write().super_traits = super_traits;
+    }
+
+    for trait_ in sa.traits.iter() {
+        let trait_id = trait_.read().id();
+        let mut visited = HashSet::new();
+
+        if reaches_trait(sa, trait_id, trait_id, &mut visited) {
+            let mut trait_ = trait_.write();
+            trait_.super_traits.clear();
+
+            sa.diag
+                .lock()
+                .report(trait_.file_id, trait_.pos, ErrorMessage::CycleInHierarchy);
+        }
+    }
+}
+
+fn reaches_trait(
+    sa: &SemAnalysis,
+    trait_id: TraitDefinitionId,
+    target_id: TraitDefinitionId,
+    visited: &mut HashSet<TraitDefinitionId>,
+) -> bool {
+    let super_traits = sa.traits[trait_id].read().super_traits.clone();
+
+    for super_trait in super_traits {
+        let super_trait_id = super_trait.trait_id().expect("trait expected");
+
+        if super_trait_id == target_id {
+            return true;
+        }
+
+        if visited.insert(super_trait_id) && reaches_trait(sa, super_trait_id, target_id, visited) {
+            return true;
+        }
+    }
+
+    false
+}
+
+// A trait bound implies bounds for all supertraits of that trait. Implied bounds
+// are added after all explicit bounds have been read, so they don't count as duplicates.
+pub fn add_implied_bounds(
+    sa: &SemAnalysis,
+    type_params: &mut TypeParamDefinition,
+    id: TypeParamId,
+) {
+    let bounds = type_params.bounds_for_type_param(id).collect::<Vec<_>>();
+
+    for bound in bounds {
+        for super_trait in all_super_traits(sa, &bound) {
+            type_params.add_bound(id, super_trait);
+        }
+    }
+}
+
 fn check_traits(sa: &SemAnalysis) {
     for trait_ in sa.traits.iter() {
         let type_param_definition;
@@ -123,6 +224,54 @@ fn check_enums(sa: &SemAnalysis) {
     }
 }
 
+fn check_unions(sa: &SemAnalysis) {
+    for union_ in sa.unions.iter() {
+        let type_param_definition;
+
+        {
+            let union_ = union_.read();
+            let mut symtable = ModuleSymTable::new(sa, union_.module_id);
+            symtable.push_level();
+
+            type_param_definition = read_type_param_definition(
+                sa,
+                union_.ast.type_params.as_ref(),
+                &mut symtable,
+                union_.file_id,
+                union_.pos,
+            );
+
+            symtable.pop_level();
+        }
+
+        union_.write().type_params = Some(type_param_definition);
+    }
+}
+
+fn check_aliases(sa: &SemAnalysis) {
+    for alias in sa.aliases.iter() {
+        let type_param_definition;
+
+        {
+            let alias = alias.read();
+            let mut symtable = ModuleSymTable::new(sa, alias.module_id);
+            symtable.push_level();
+
+            type_param_definition = read_type_param_definition(
+                sa,
+                alias.ast.type_params.as_ref(),
+                &mut symtable,
+                alias.file_id,
+                alias.pos,
+            );
+
+            symtable.pop_level();
+        }
+
+        alias.write().type_params = Some(type_param_definition);
+    }
+}
+
 fn check_values(sa: &SemAnalysis) {
     for value in sa.values.iter() {
         let type_param_definition;
@@ -237,6 +386,8 @@ fn read_type_param_definition(
                 sa.diag.lock().report(file_id, bound.pos(), msg);
             }
         }
+
+        add_implied_bounds(sa, &mut result_type_params, id);
     }
 
     result_type_params
@@ -114,7 +114,7 @@ impl<'a> TypeParamCheck<'a> {
 
         for bound in self.callee_type_param_defs.bounds() {
             let tp_ty = bound.ty();
-            let trait_ty = bound.trait_ty();
+            let trait_ty = specialize_type(self.sa, bound.trait_ty(), tps);
 
             let tp_ty = specialize_type(self.sa, tp_ty, tps);
 
@@ -0,0 +1,170 @@
+use std::collections::HashSet;
+
+use crate::language::error::msg::ErrorMessage;
+use crate::language::sem_analysis::SemAnalysis;
+use crate::language::sym::{ModuleSymTable, Sym};
+use crate::language::ty::SourceType;
+use crate::language::{read_type, AllowSelf, TypeParamContext};
+
+pub fn check(sa: &SemAnalysis) {
+    for union_ in sa.unions.iter() {
+        let (union_id, module_id, file_id, ast) = {
+            let union_ = union_.read();
+            (
+                union_.id(),
+                union_.module_id,
+                union_.file_id,
+                union_.ast.clone(),
+            )
+        };
+
+        let mut symtable = ModuleSymTable::new(sa, module_id);
+        symtable.push_level();
+
+        for (id, name) in union_.read().type_params().names() {
+            symtable.insert(name, Sym::TypeParam(id));
+        }
+
+        let mut variants = Vec::with_capacity(ast.variants.len());
+        let mut classes = HashSet::new();
+        let mut values = HashSet::new();
+
+        for variant in &ast.variants {
+            let ty = read_type(
+                sa,
+                &symtable,
+                file_id,
+                &variant.data_type,
+                TypeParamContext::Union(union_id),
+                AllowSelf::No,
+            )
+            .unwrap_or(SourceType::Error);
+
+            let unique = match ty {
+                SourceType::Class(cls_id, _) => classes.insert(cls_id),
+                SourceType::Value(value_id, _) => values.insert(value_id),
+                _ => true,
+            };
+
+            if !unique {
+                let name = ty.name_with_type_params(sa, union_.read().type_params());
+                let msg = ErrorMessage::UnionVariantDuplicate(name);
+                sa.diag.lock().report(file_id, variant.pos, msg);
+            }
+
+            match ty {
+                // Variants are matched by their exact class at runtime, instances of
+                // subclasses would be dispatched to the wrong variant.
+                SourceType::Class(cls_id, _) if sa.classes.idx(cls_id).read().is_open => {
+                    let name = ty.name_with_type_params(sa, union_.read().type_params());
+                    let msg = ErrorMessage::UnionVariantOpenClass(name);
+                    sa.diag.lock().report(file_id, variant.pos, msg);
+                }
+
+                SourceType::Class(_, _) | SourceType::Value(_, _) => {}
+
+                SourceType::Error => {}
+
+                _ => {
+                    let name = ty.name_with_type_params(sa, union_.read().type_params());
+                    let msg = ErrorMessage::UnionVariantClassOrValueExpected(name);
+                    sa.diag.lock().report(file_id, variant.pos, msg);
+                }
+            }
+
+            variants.push(ty);
+        }
+
+        if ast.variants.is_empty() {
+            sa.diag
+                .lock()
+                .report(file_id, ast.pos, ErrorMessage::NoUnionVariant);
+        }
+
+        symtable.pop_level();
+
+        union_.write().variants = variants;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::language::error::msg::ErrorMessage;
+    use crate::language::tests::*;
+
+    #[test]
+    fn union_definitions() {
+        ok("class A class B union Foo of A, B;");
+        ok("class A[T] class B union Foo[T] of A[T], B;");
+        ok("class A value V(x: Int32) union Foo of A, V;");
+        ok("value V[T](x: T) union Foo[T] of V[T];");
+        err("union Foo", pos(1, 1), ErrorMessage::NoUnionVariant);
+        err(
+            "class A union Foo of A, Bar;",
+            pos(1, 25),
+            ErrorMessage::UnknownIdentifier("Bar".into()),
+        );
+        err(
+            "class A class A1 union Foo of A, A1, A;",
+            pos(1, 38),
+            ErrorMessage::UnionVariantDuplicate("A".into()),
+        );
+        err(
+            "class A[T] union Foo of A[Int32], A[Int64];",
+            pos(1, 35),
+            ErrorMessage::UnionVariantDuplicate("A[Int64]".into()),
+        );
+        err(
+            "value V(x: Int32) union Foo of V, V;",
+            pos(1, 35),
+            ErrorMessage::UnionVariantDuplicate("V".into()),
+        );
+    }
+
+    #[test]
+    fn union_variant_needs_to_be_class_or_value() {
+        err(
+            "class A union Foo of A, Int32;",
+            pos(1, 25),
+            ErrorMessage::UnionVariantClassOrValueExpected("Int32".into()),
+        );
+        err(
+            "union Foo[T] of T;",
+            pos(1, 17),
+            ErrorMessage::UnionVariantClassOrValueExpected("T".into()),
+        );
+        err(
+            "class A union Foo of A; union Bar of Foo;",
+            pos(1, 38),
+            ErrorMessage::UnionVariantClassOrValueExpected("Foo".into()),
+        );
+    }
+
+    #[test]
+    fn union_variant_open_class() {
+        err(
+            "@open class A class B union Foo of A, B;",
+            pos(1, 36),
+            ErrorMessage::UnionVariantOpenClass("A".into()),
+        );
+        ok("@open class A class C: A union Foo of C;");
+    }
+
+    #[test]
+    fn union_shadows() {
+        err(
+            "class A union Foo of A; class Foo",
+            pos(1, 25),
+            ErrorMessage::ShadowUnion("Foo".into()),
+        );
+    }
+
+    #[test]
+    fn union_wrong_number_of_type_params() {
+        err(
+            "class A[T] union Foo of A;",
+            pos(1, 25),
+            ErrorMessage::WrongNumberTypeParams(1, 0),
+        );
+    }
+}
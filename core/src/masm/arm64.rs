@@ -180,7 +180,9 @@ impl MacroAssembler {
 
     pub fn cmp_reg(&mut self, mode: MachineMode, lhs: Reg, rhs: Reg) {
         match mode {
-            MachineMode::Int8 | MachineMode::Int32 => self.asm.cmp_w(lhs.into(), rhs.into()),
+            MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => {
+                self.asm.cmp_w(lhs.into(), rhs.into())
+            }
             MachineMode::IntPtr | MachineMode::Ptr | MachineMode::Int64 => {
                 self.asm.cmp(lhs.into(), rhs.into())
             }
@@ -196,7 +198,9 @@ impl MacroAssembler {
 
     pub fn cmp_zero(&mut self, mode: MachineMode, lhs: Reg) {
         match mode {
-            MachineMode::Int8 | MachineMode::Int32 => self.asm.cmp_imm_w(lhs.into(), 0, 0),
+            MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => {
+                self.asm.cmp_imm_w(lhs.into(), 0, 0)
+            }
             MachineMode::Int64 | MachineMode::Ptr => self.asm.cmp_imm(lhs.into(), 0, 0),
             _ => unreachable!(),
         }
@@ -263,6 +267,65 @@ impl MacroAssembler {
         self.divmod_common(mode, dest, lhs, rhs, pos, false);
     }
 
+    pub fn int_div_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        self.divmod_unsigned_common(mode, dest, lhs, rhs, pos, true);
+    }
+
+    pub fn int_mod_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        self.divmod_unsigned_common(mode, dest, lhs, rhs, pos, false);
+    }
+
+    fn divmod_unsigned_common(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+        is_div: bool,
+    ) {
+        self.cmp_zero(mode, rhs);
+        self.bailout_if(CondCode::Zero, Trap::DIV0, pos);
+
+        if is_div {
+            match mode {
+                MachineMode::Int32 => self.asm.udiv_w(dest.into(), lhs.into(), rhs.into()),
+                MachineMode::Int64 => self.asm.udiv(dest.into(), lhs.into(), rhs.into()),
+                _ => unreachable!(),
+            }
+        } else {
+            let scratch = self.get_scratch();
+
+            match mode {
+                MachineMode::Int32 => {
+                    self.asm.udiv_w((*scratch).into(), lhs.into(), rhs.into());
+                    self.asm
+                        .msub_w(dest.into(), (*scratch).into(), rhs.into(), lhs.into());
+                }
+                MachineMode::Int64 => {
+                    self.asm.udiv((*scratch).into(), lhs.into(), rhs.into());
+                    self.asm
+                        .msub(dest.into(), (*scratch).into(), rhs.into(), lhs.into());
+                }
+                _ => unreachable!(),
+            }
+        }
+    }
+
     fn divmod_common(
         &mut self,
         mode: MachineMode,
@@ -402,6 +465,52 @@ impl MacroAssembler {
         }
     }
 
+    pub fn int_add_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        let scratch = self.get_scratch();
+
+        match mode {
+            MachineMode::Int32 => self.asm.adds_w((*scratch).into(), lhs.into(), rhs.into()),
+            MachineMode::Int64 => self.asm.adds((*scratch).into(), lhs.into(), rhs.into()),
+            _ => panic!("unimplemented mode {:?}", mode),
+        }
+
+        self.asm.cset_w(dest.into(), Cond::VS);
+    }
+
+    pub fn int_sub_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        let scratch = self.get_scratch();
+
+        match mode {
+            MachineMode::Int32 => self.asm.subs_w((*scratch).into(), lhs.into(), rhs.into()),
+            MachineMode::Int64 => self.asm.subs((*scratch).into(), lhs.into(), rhs.into()),
+            _ => panic!("unimplemented mode {:?}", mode),
+        }
+
+        self.asm.cset_w(dest.into(), Cond::VS);
+    }
+
+    pub fn int_mul_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        let low = self.get_scratch();
+
+        match mode {
+            MachineMode::Int32 => {
+                self.asm.smull((*low).into(), lhs.into(), rhs.into());
+                self.asm
+                    .cmp_ext((*low).into(), (*low).into(), Extend::SXTW, 0);
+            }
+            MachineMode::Int64 => {
+                let high = self.get_scratch();
+                self.asm.mul((*low).into(), lhs.into(), rhs.into());
+                self.asm.smulh((*high).into(), lhs.into(), rhs.into());
+                self.asm
+                    .cmp_sh((*high).into(), (*low).into(), Shift::ASR, 63);
+            }
+            _ => panic!("unimplemented mode {:?}", mode),
+        }
+
+        self.asm.cset_w(dest.into(), Cond::NE);
+    }
+
     pub fn int_add_checked(
         &mut self,
         mode: MachineMode,
@@ -837,8 +946,16 @@ impl MacroAssembler {
     }
 
     pub fn cmp_int(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.cmp_int_common(mode, dest, lhs, rhs, Cond::GE);
+    }
+
+    pub fn cmp_int_unsigned(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.cmp_int_common(mode, dest, lhs, rhs, Cond::HS);
+    }
+
+    fn cmp_int_common(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg, ge: Cond) {
         match mode {
-            MachineMode::Int8 | MachineMode::Int32 => {
+            MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => {
                 self.asm.cmp_w(lhs.into(), rhs.into());
             }
 
@@ -851,7 +968,7 @@ impl MacroAssembler {
 
         self.asm.cset_w(dest.into(), Cond::NE);
         self.asm
-            .csinv_w(dest.into(), dest.into(), REG_ZERO.into(), Cond::GE);
+            .csinv_w(dest.into(), dest.into(), REG_ZERO.into(), ge);
     }
 
     pub fn float_cmp_int(&mut self, mode: MachineMode, dest: Reg, lhs: FReg, rhs: FReg) {
@@ -1175,6 +1292,13 @@ impl MacroAssembler {
                         Extend::LSL,
                         0,
                     ),
+                    MachineMode::Int16 => self.asm.ldr_ind_h(
+                        dest.reg().into(),
+                        (*scratch).into(),
+                        index.into(),
+                        Extend::LSL,
+                        1,
+                    ),
                     MachineMode::Int32 => self.asm.ldr_ind_w(
                         dest.reg().into(),
                         (*scratch).into(),
@@ -1224,6 +1348,7 @@ impl MacroAssembler {
             let disp = (disp / mode.size()) as u32;
             match mode {
                 MachineMode::Int8 => self.asm.ldr_imm_b(dest.reg().into(), base.into(), disp),
+                MachineMode::Int16 => self.asm.ldr_imm_h(dest.reg().into(), base.into(), disp),
                 MachineMode::Int32 => self.asm.ldr_imm_w(dest.reg().into(), base.into(), disp),
                 MachineMode::IntPtr | MachineMode::Int64 | MachineMode::Ptr => {
                     self.asm.ldr_imm(dest.reg().into(), base.into(), disp)
@@ -1236,6 +1361,9 @@ impl MacroAssembler {
                 MachineMode::Int8 => self
                     .asm
                     .ldr_unscaled_b(dest.reg().into(), base.into(), disp),
+                MachineMode::Int16 => self
+                    .asm
+                    .ldr_unscaled_h(dest.reg().into(), base.into(), disp),
                 MachineMode::Int32 => self
                     .asm
                     .ldr_unscaled_w(dest.reg().into(), base.into(), disp),
@@ -1262,6 +1390,13 @@ impl MacroAssembler {
                     Extend::LSL,
                     0,
                 ),
+                MachineMode::Int16 => self.asm.ldr_ind_h(
+                    dest.reg().into(),
+                    base.into(),
+                    (*scratch).into(),
+                    Extend::LSL,
+                    0,
+                ),
                 MachineMode::Int32 => self.asm.ldr_ind_w(
                     dest.reg().into(),
                     base.into(),
@@ -1391,6 +1526,13 @@ impl MacroAssembler {
                         Extend::LSL,
                         0,
                     ),
+                    MachineMode::Int16 => self.asm.str_ind_h(
+                        src.reg().into(),
+                        (*scratch).into(),
+                        index.into(),
+                        Extend::LSL,
+                        1,
+                    ),
                     MachineMode::Int32 => self.asm.str_ind_w(
                         src.reg().into(),
                         (*scratch).into(),
@@ -1446,6 +1588,7 @@ impl MacroAssembler {
             let offset = (offset / mode.size()) as u32;
             match mode {
                 MachineMode::Int8 => self.asm.str_imm_b(src.reg().into(), base.into(), offset),
+                MachineMode::Int16 => self.asm.str_imm_h(src.reg().into(), base.into(), offset),
                 MachineMode::Int32 => self.asm.str_imm_w(src.reg().into(), base.into(), offset),
                 MachineMode::IntPtr | MachineMode::Int64 | MachineMode::Ptr => {
                     self.asm.str_imm(src.reg().into(), base.into(), offset)
@@ -1458,6 +1601,10 @@ impl MacroAssembler {
                 MachineMode::Int8 => self
                     .asm
                     .str_unscaled_b(src.reg().into(), base.into(), offset),
+                MachineMode::Int16 => {
+                    self.asm
+                        .str_unscaled_h(src.reg().into(), base.into(), offset)
+                }
                 MachineMode::Int32 => {
                     self.asm
                         .str_unscaled_w(src.reg().into(), base.into(), offset)
@@ -1485,6 +1632,13 @@ impl MacroAssembler {
                     Extend::LSL,
                     0,
                 ),
+                MachineMode::Int16 => self.asm.str_ind_h(
+                    src.reg().into(),
+                    base.into(),
+                    (*scratch).into(),
+                    Extend::LSL,
+                    0,
+                ),
                 MachineMode::Int32 => self.asm.str_ind_w(
                     src.reg().into(),
                     base.into(),
@@ -1519,7 +1673,7 @@ impl MacroAssembler {
 
     pub fn copy_reg(&mut self, mode: MachineMode, dest: Reg, src: Reg) {
         match mode {
-            MachineMode::Int32 | MachineMode::Int8 => {
+            MachineMode::Int32 | MachineMode::Int16 | MachineMode::Int8 => {
                 self.asm.mov_w(dest.into(), src.into());
             }
             MachineMode::Ptr | MachineMode::Int64 => {
@@ -1557,6 +1711,15 @@ impl MacroAssembler {
         self.asm.sxtw(dest.into(), src.into());
     }
 
+    pub fn sign_extend(&mut self, mode: MachineMode, dest: Reg, src: Reg) {
+        match mode {
+            MachineMode::Int8 => self.asm.sbfm(dest.into(), src.into(), 0, 7),
+            MachineMode::Int16 => self.asm.sbfm(dest.into(), src.into(), 0, 15),
+            MachineMode::Int32 => self.asm.sxtw(dest.into(), src.into()),
+            _ => unreachable!(),
+        }
+    }
+
     pub fn extend_byte(&mut self, mode: MachineMode, dest: Reg, src: Reg) {
         match mode {
             MachineMode::Int32 => {}
@@ -1582,6 +1745,7 @@ impl MacroAssembler {
         let sf = size_flag(mode);
         let register_size = match mode {
             MachineMode::Int8 => 32,
+            MachineMode::Int16 => 32,
             MachineMode::Int32 => 32,
             MachineMode::IntPtr | MachineMode::Ptr | MachineMode::Int64 => 64,
             MachineMode::Float32 | MachineMode::Float64 => unreachable!(),
@@ -1716,7 +1880,7 @@ enum JumpType {
 
 fn size_flag(mode: MachineMode) -> u32 {
     match mode {
-        MachineMode::Int8 | MachineMode::Int32 => 0,
+        MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => 0,
         MachineMode::IntPtr | MachineMode::Ptr | MachineMode::Int64 => 1,
         MachineMode::Float32 | MachineMode::Float64 => unimplemented!(),
     }
@@ -86,6 +86,8 @@ impl MacroAssembler {
 
     pub fn cmp_int(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {}
 
+    pub fn cmp_int_unsigned(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {}
+
     pub fn test_and_jump_if(&mut self, cond: CondCode, reg: Reg, lbl: Label) {}
 
     pub fn jump_if(&mut self, cond: CondCode, target: Label) {}
@@ -111,6 +113,36 @@ impl MacroAssembler {
         }
     }
 
+    pub fn int_div_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        match mode {
+            MachineMode::Int64 => self.asm.divu(dest.into(), lhs.into(), rhs.into()),
+            MachineMode::Int32 => self.asm.divuw(dest.into(), lhs.into(), rhs.into()),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn int_mod_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        match mode {
+            MachineMode::Int64 => self.asm.remu(dest.into(), lhs.into(), rhs.into()),
+            MachineMode::Int32 => self.asm.remuw(dest.into(), lhs.into(), rhs.into()),
+            _ => unreachable!(),
+        }
+    }
+
     pub fn int_mul(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
         match mode {
             MachineMode::Int64 => self.asm.mul(dest.into(), lhs.into(), rhs.into()),
@@ -143,6 +175,12 @@ impl MacroAssembler {
         }
     }
 
+    pub fn int_add_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {}
+
+    pub fn int_sub_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {}
+
+    pub fn int_mul_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {}
+
     pub fn int_add_checked(
         &mut self,
         mode: MachineMode,
@@ -676,6 +714,8 @@ impl MacroAssembler {
 
     pub fn int32_to_int64(&mut self, dest: Reg, src: Reg) {}
 
+    pub fn sign_extend(&mut self, mode: MachineMode, dest: Reg, src: Reg) {}
+
     pub fn extend_byte(&mut self, mode: MachineMode, dest: Reg, src: Reg) {}
 
     pub fn load_constpool(&mut self, dest: Reg, disp: i32) {}
@@ -736,7 +776,7 @@ enum JumpType {
 
 fn size_flag(mode: MachineMode) -> u32 {
     match mode {
-        MachineMode::Int8 | MachineMode::Int32 => 0,
+        MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => 0,
         MachineMode::IntPtr | MachineMode::Ptr | MachineMode::Int64 => 1,
         MachineMode::Float32 | MachineMode::Float64 => unimplemented!(),
     }
@@ -201,18 +201,35 @@ impl MacroAssembler {
     }
 
     pub fn cmp_int(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.cmp_int_common(mode, dest, lhs, rhs, Condition::Greater, Condition::Less);
+    }
+
+    pub fn cmp_int_unsigned(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        self.cmp_int_common(mode, dest, lhs, rhs, Condition::Above, Condition::Below);
+    }
+
+    fn cmp_int_common(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        greater: Condition,
+        less: Condition,
+    ) {
         self.asm.xorl_rr(dest.into(), dest.into());
         match mode {
             MachineMode::Int64 => self.asm.cmpq_rr(lhs.into(), rhs.into()),
-            MachineMode::Int8 | MachineMode::Int32 => self.asm.cmpl_rr(lhs.into(), rhs.into()),
+            MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => {
+                self.asm.cmpl_rr(lhs.into(), rhs.into())
+            }
             _ => unreachable!(),
         }
-        self.asm.setcc_r(Condition::Above, dest.into());
+        self.asm.setcc_r(greater, dest.into());
 
         let scratch = self.get_scratch();
         self.asm.movl_ri((*scratch).into(), Immediate(-1));
-        self.asm
-            .cmovl(Condition::Below, dest.into(), (*scratch).into());
+        self.asm.cmovl(less, dest.into(), (*scratch).into());
     }
 
     pub fn float_cmp_int(&mut self, mode: MachineMode, dest: Reg, lhs: FReg, rhs: FReg) {
@@ -409,6 +426,59 @@ impl MacroAssembler {
         self.div_common(mode, dest, lhs, rhs, RDX, pos);
     }
 
+    pub fn int_div_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        self.div_unsigned_common(mode, dest, lhs, rhs, RAX, pos);
+    }
+
+    pub fn int_mod_unsigned(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        pos: Position,
+    ) {
+        self.div_unsigned_common(mode, dest, lhs, rhs, RDX, pos);
+    }
+
+    fn div_unsigned_common(
+        &mut self,
+        mode: MachineMode,
+        dest: Reg,
+        lhs: Reg,
+        rhs: Reg,
+        result: Reg,
+        pos: Position,
+    ) {
+        self.cmp_zero(mode, rhs);
+        self.bailout_if(CondCode::Zero, Trap::DIV0, pos);
+
+        if lhs != RAX {
+            assert!(rhs != RAX);
+            self.mov_rr(mode.is64(), RAX.into(), lhs.into());
+        }
+
+        assert!(rhs != RDX);
+        self.asm.xorl_rr(RDX.into(), RDX.into());
+
+        if mode.is64() {
+            self.asm.divq_r(rhs.into());
+        } else {
+            self.asm.divl_r(rhs.into());
+        }
+
+        if dest != result {
+            self.mov_rr(mode.is64(), dest.into(), result.into());
+        }
+    }
+
     fn div_common(
         &mut self,
         mode: MachineMode,
@@ -566,6 +636,50 @@ impl MacroAssembler {
         }
     }
 
+    pub fn int_add_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        let scratch = self.get_scratch();
+        self.mov_rr(mode.is64(), (*scratch).into(), lhs.into());
+
+        if mode.is64() {
+            self.asm.addq_rr((*scratch).into(), rhs.into());
+        } else {
+            self.asm.addl_rr((*scratch).into(), rhs.into());
+        }
+
+        self.set_overflow(dest);
+    }
+
+    pub fn int_sub_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        let scratch = self.get_scratch();
+        self.mov_rr(mode.is64(), (*scratch).into(), lhs.into());
+
+        if mode.is64() {
+            self.asm.subq_rr((*scratch).into(), rhs.into());
+        } else {
+            self.asm.subl_rr((*scratch).into(), rhs.into());
+        }
+
+        self.set_overflow(dest);
+    }
+
+    pub fn int_mul_overflow(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
+        let scratch = self.get_scratch();
+        self.mov_rr(mode.is64(), (*scratch).into(), lhs.into());
+
+        if mode.is64() {
+            self.asm.imulq_rr((*scratch).into(), rhs.into());
+        } else {
+            self.asm.imull_rr((*scratch).into(), rhs.into());
+        }
+
+        self.set_overflow(dest);
+    }
+
+    fn set_overflow(&mut self, dest: Reg) {
+        self.asm.setcc_r(Condition::Overflow, dest.into());
+        self.asm.movzxb_rr(dest.into(), dest.into());
+    }
+
     pub fn int_sub(&mut self, mode: MachineMode, dest: Reg, lhs: Reg, rhs: Reg) {
         if mode.is64() {
             self.asm.subq_rr(lhs.into(), rhs.into());
@@ -1169,6 +1283,7 @@ impl MacroAssembler {
     pub fn load_mem(&mut self, mode: MachineMode, dest: AnyReg, mem: Mem) {
         match mode {
             MachineMode::Int8 => self.asm.movzxb_ra(dest.reg().into(), address_from_mem(mem)),
+            MachineMode::Int16 => self.asm.movzxw_ra(dest.reg().into(), address_from_mem(mem)),
             MachineMode::Int32 => self.asm.movl_ra(dest.reg().into(), address_from_mem(mem)),
             MachineMode::Int64 | MachineMode::Ptr | MachineMode::IntPtr => {
                 self.asm.movq_ra(dest.reg().into(), address_from_mem(mem))
@@ -1206,6 +1321,7 @@ impl MacroAssembler {
     pub fn store_mem(&mut self, mode: MachineMode, mem: Mem, src: AnyReg) {
         match mode {
             MachineMode::Int8 => self.asm.movb_ar(address_from_mem(mem), src.reg().into()),
+            MachineMode::Int16 => self.asm.movw_ar(address_from_mem(mem), src.reg().into()),
             MachineMode::Int32 => self.asm.movl_ar(address_from_mem(mem), src.reg().into()),
             MachineMode::Int64 | MachineMode::Ptr | MachineMode::IntPtr => {
                 self.asm.movq_ar(address_from_mem(mem), src.reg().into())
@@ -1218,6 +1334,7 @@ impl MacroAssembler {
     pub fn store_zero(&mut self, mode: MachineMode, mem: Mem) {
         match mode {
             MachineMode::Int8 => self.asm.movb_ai(address_from_mem(mem), Immediate(0)),
+            MachineMode::Int16 => self.asm.movw_ai(address_from_mem(mem), Immediate(0)),
             MachineMode::Float32 | MachineMode::Int32 => {
                 self.asm.movl_ai(address_from_mem(mem), Immediate(0))
             }
@@ -1259,6 +1376,15 @@ impl MacroAssembler {
         self.asm.movsxlq_rr(dest.into(), src.into());
     }
 
+    pub fn sign_extend(&mut self, mode: MachineMode, dest: Reg, src: Reg) {
+        match mode {
+            MachineMode::Int8 => self.asm.movsxbq_rr(dest.into(), src.into()),
+            MachineMode::Int16 => self.asm.movsxwq_rr(dest.into(), src.into()),
+            MachineMode::Int32 => self.asm.movsxlq_rr(dest.into(), src.into()),
+            _ => unreachable!(),
+        }
+    }
+
     pub fn extend_byte(&mut self, _mode: MachineMode, dest: Reg, src: Reg) {
         self.asm.movzxb_rr(dest.into(), src.into());
     }
@@ -1286,7 +1412,7 @@ impl MacroAssembler {
         }
 
         match mode {
-            MachineMode::Int8 | MachineMode::Int32 => {
+            MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => {
                 self.asm.movl_ri(dest.into(), Immediate(imm));
             }
             MachineMode::Int64 | MachineMode::Ptr | MachineMode::IntPtr => {
@@ -1559,7 +1685,7 @@ impl From<FReg> for XmmRegister {
 impl MachineMode {
     pub fn is64(self) -> bool {
         match self {
-            MachineMode::Int8 | MachineMode::Int32 => false,
+            MachineMode::Int8 | MachineMode::Int16 | MachineMode::Int32 => false,
             MachineMode::Int64 | MachineMode::Ptr => true,
             _ => unreachable!(),
         }
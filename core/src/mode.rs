@@ -3,6 +3,7 @@ use crate::mem;
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub enum MachineMode {
     Int8,
+    Int16,
     Int32,
     Int64,
     IntPtr,
@@ -15,6 +16,7 @@ impl MachineMode {
     pub fn size(self) -> i32 {
         match self {
             MachineMode::Int8 => 1,
+            MachineMode::Int16 => 2,
             MachineMode::Int32 => 4,
             MachineMode::Int64 => 8,
             MachineMode::IntPtr | MachineMode::Ptr => mem::ptr_width(),
@@ -837,6 +837,7 @@ pub fn offset_of_string_data() -> i32 {
 
 pub type UInt8Array = Array<u8>;
 pub type Int32Array = Array<i32>;
+pub type Int64Array = Array<i64>;
 pub type StrArray = Array<Ref<Str>>;
 
 pub fn alloc(vm: &VM, clsid: ClassInstanceId) -> Ref<Obj> {
@@ -871,6 +872,12 @@ pub struct StacktraceElement {
     pub line: i32,
 }
 
+pub struct PanicInfo {
+    pub header: Header,
+    pub message: Ref<Str>,
+    pub stacktrace: Ref<Stacktrace>,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::object::Header;
@@ -214,17 +214,25 @@ fn set_backtrace(vm: &VM, mut obj: Handle<Stacktrace>, via_retrieve: bool) {
         }
     }
 
-    let len = stacktrace.len() - skip;
+    obj.backtrace = backtrace_array(vm, &stacktrace.elems[skip..]);
+}
+
+// Allocates the backtrace for a managed Stacktrace object.
+pub fn alloc_backtrace(vm: &VM, stacktrace: &NativeStacktrace) -> Ref<Int32Array> {
+    backtrace_array(vm, &stacktrace.elems)
+}
 
+fn backtrace_array(vm: &VM, elems: &[StackElem]) -> Ref<Int32Array> {
     let cls_id = vm.known.int_array(vm);
-    let array: Ref<Int32Array> = Array::alloc(vm, len * 2, 0, cls_id);
+    let array: Ref<Int32Array> = Array::alloc(vm, elems.len() * 2, 0, cls_id);
     let mut array = handle(array);
     let mut i = 0;
 
-    for elem in stacktrace.elems.iter().skip(skip) {
+    for elem in elems {
         array.set_at(i, elem.lineno as i32);
         array.set_at(i + 1, elem.fct_id.idx() as i32);
         i += 2;
     }
-    obj.backtrace = array.direct();
+
+    array.direct()
 }
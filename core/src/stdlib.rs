@@ -1,21 +1,30 @@
 use libc;
 
 use std::char;
-use std::io::{self, Write};
+use std::env;
+use std::fs::{self, File, OpenOptions, ReadDir};
+use std::io::{self, BufRead, Read, Seek, SeekFrom, Write};
 use std::mem;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::slice;
 use std::str;
+use std::sync::Arc;
 use std::thread;
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use crate::gc::{Address, GcReason};
 use crate::handle::{handle, handle_scope, Handle};
-use crate::object::{Obj, Ref, Str, UInt8Array};
-use crate::stack::stacktrace_from_last_dtn;
+use crate::object::{
+    alloc, Int64Array, Obj, PanicInfo, Ref, Stacktrace, Str, StrArray, UInt8Array,
+};
+use crate::stack::{alloc_backtrace, stacktrace_from_last_dtn};
 use crate::threads::{
-    current_thread, deinit_current_thread, init_current_thread, CoreThread, ManagedThread,
-    ThreadState, STACK_SIZE,
+    current_thread, deinit_current_thread, init_current_thread, parked_scope, CatchFrame,
+    CoreThread, ManagedThread, PanicData, ThreadState, STACK_SIZE,
+};
+use crate::vm::{
+    get_vm, specialize_class_id, stack_pointer, ManagedCondition, ManagedMutex, ShapeKind, Trap, VM,
 };
-use crate::vm::{get_vm, stack_pointer, ManagedCondition, ManagedMutex, ShapeKind, Trap};
 
 pub extern "C" fn uint8_to_string(val: u8) -> Ref<Str> {
     handle_scope(|| {
@@ -26,6 +35,51 @@ pub extern "C" fn uint8_to_string(val: u8) -> Ref<Str> {
     })
 }
 
+pub extern "C" fn int8_to_string(val: u8) -> Ref<Str> {
+    handle_scope(|| {
+        let buffer = (val as i8).to_string();
+        let vm = get_vm();
+
+        Str::from_buffer(vm, buffer.as_bytes())
+    })
+}
+
+pub extern "C" fn int16_to_string(val: u16) -> Ref<Str> {
+    handle_scope(|| {
+        let buffer = (val as i16).to_string();
+        let vm = get_vm();
+
+        Str::from_buffer(vm, buffer.as_bytes())
+    })
+}
+
+pub extern "C" fn uint16_to_string(val: u16) -> Ref<Str> {
+    handle_scope(|| {
+        let buffer = val.to_string();
+        let vm = get_vm();
+
+        Str::from_buffer(vm, buffer.as_bytes())
+    })
+}
+
+pub extern "C" fn uint32_to_string(val: u32) -> Ref<Str> {
+    handle_scope(|| {
+        let buffer = val.to_string();
+        let vm = get_vm();
+
+        Str::from_buffer(vm, buffer.as_bytes())
+    })
+}
+
+pub extern "C" fn uint64_to_string(val: u64) -> Ref<Str> {
+    handle_scope(|| {
+        let buffer = val.to_string();
+        let vm = get_vm();
+
+        Str::from_buffer(vm, buffer.as_bytes())
+    })
+}
+
 pub extern "C" fn char_to_string(val: u32) -> Ref<Str> {
     handle_scope(|| {
         let buffer = unsafe { char::from_u32_unchecked(val) }.to_string();
@@ -71,20 +125,104 @@ pub extern "C" fn float64_to_string(val: f64) -> Ref<Str> {
     })
 }
 
+pub extern "C" fn math_sin(x: f64) -> f64 {
+    x.sin()
+}
+
+pub extern "C" fn math_cos(x: f64) -> f64 {
+    x.cos()
+}
+
+pub extern "C" fn math_tan(x: f64) -> f64 {
+    x.tan()
+}
+
+pub extern "C" fn math_asin(x: f64) -> f64 {
+    x.asin()
+}
+
+pub extern "C" fn math_acos(x: f64) -> f64 {
+    x.acos()
+}
+
+pub extern "C" fn math_atan(x: f64) -> f64 {
+    x.atan()
+}
+
+pub extern "C" fn math_sinh(x: f64) -> f64 {
+    x.sinh()
+}
+
+pub extern "C" fn math_cosh(x: f64) -> f64 {
+    x.cosh()
+}
+
+pub extern "C" fn math_tanh(x: f64) -> f64 {
+    x.tanh()
+}
+
+pub extern "C" fn math_exp(x: f64) -> f64 {
+    x.exp()
+}
+
+pub extern "C" fn math_exp2(x: f64) -> f64 {
+    x.exp2()
+}
+
+pub extern "C" fn math_ln(x: f64) -> f64 {
+    x.ln()
+}
+
+pub extern "C" fn math_log2(x: f64) -> f64 {
+    x.log2()
+}
+
+pub extern "C" fn math_log10(x: f64) -> f64 {
+    x.log10()
+}
+
+pub extern "C" fn math_cbrt(x: f64) -> f64 {
+    x.cbrt()
+}
+
+pub extern "C" fn math_atan2(y: f64, x: f64) -> f64 {
+    y.atan2(x)
+}
+
+pub extern "C" fn math_pow(base: f64, exponent: f64) -> f64 {
+    base.powf(exponent)
+}
+
+pub extern "C" fn math_hypot(x: f64, y: f64) -> f64 {
+    x.hypot(y)
+}
+
+pub extern "C" fn math_min(lhs: f64, rhs: f64) -> f64 {
+    lhs.min(rhs)
+}
+
+pub extern "C" fn math_max(lhs: f64, rhs: f64) -> f64 {
+    lhs.max(rhs)
+}
+
+pub extern "C" fn math_copy_sign(magnitude: f64, sign: f64) -> f64 {
+    magnitude.copysign(sign)
+}
+
+pub extern "C" fn math_fma(x: f64, y: f64, z: f64) -> f64 {
+    x.mul_add(y, z)
+}
+
 pub extern "C" fn print(val: Handle<Str>) {
     io::stdout().write(val.content()).unwrap();
 }
 
 pub extern "C" fn fatal_error(msg: Handle<Str>) {
-    eprint!("fatal error: ");
-    io::stderr().write(msg.content()).unwrap();
-    eprintln!("");
-
     let vm = get_vm();
-    let stacktrace = stacktrace_from_last_dtn(vm);
-    let stderr = std::io::stderr();
-    let mut stderr = stderr.lock();
-    stacktrace.dump(vm, &mut stderr).expect("output broken");
+    let message = String::from_utf8_lossy(msg.content()).into_owned();
+    let report = format!("fatal error: {}", message);
+
+    raise_panic(vm, report, message, 1);
 
     std::process::exit(1);
 }
@@ -100,13 +238,9 @@ pub extern "C" fn exit(status: i32) {
 
 pub extern "C" fn unreachable() {
     let vm = get_vm();
+    let message = "unreachable code executed.";
 
-    eprintln!("unreachable code executed.");
-
-    let stacktrace = stacktrace_from_last_dtn(vm);
-    let stderr = std::io::stderr();
-    let mut stderr = stderr.lock();
-    stacktrace.dump(vm, &mut stderr).expect("output broken");
+    raise_panic(vm, message.into(), message.into(), 1);
 
     std::process::exit(1);
 }
@@ -279,16 +413,134 @@ pub extern "C" fn trap(trap_id: u32) {
         Trap::OVERFLOW => "overflow",
     };
 
-    eprintln!("{}", msg);
-    let stacktrace = stacktrace_from_last_dtn(vm);
-    let stderr = std::io::stderr();
-    let mut stderr = stderr.lock();
-    stacktrace.dump(vm, &mut stderr).expect("output broken");
+    raise_panic(vm, msg.into(), msg.into(), 100 + trap_id as i32);
+
     unsafe {
         libc::_exit(100 + trap_id as i32);
     }
 }
 
+fn raise_panic(vm: &VM, report: String, message: String, exit_code: i32) {
+    let panic = PanicData {
+        report,
+        message,
+        stacktrace: stacktrace_from_last_dtn(vm),
+        exit_code,
+    };
+
+    unwind_panic(vm, Arc::new(panic));
+}
+
+// Unwinds to the innermost catch frame of the current thread. Without a catch
+// frame the panic is reported on stderr and this function returns, the caller
+// then terminates the process.
+fn unwind_panic(vm: &VM, panic: Arc<PanicData>) {
+    let thread = current_thread();
+
+    let frame = match thread.pop_catch_frame() {
+        Some(frame) => frame,
+        None => {
+            report_panic(vm, &panic);
+            return;
+        }
+    };
+
+    thread.set_panic(panic);
+
+    // Frames between the catch entry stub and this function are dropped without
+    // running any destructors, so nothing may be left on the stack that needs one.
+    thread.set_dtn(frame.dtn);
+    thread.handles.pop_borders_to(frame.handle_borders);
+
+    let resume: extern "C" fn(&CatchFrame) -> ! =
+        unsafe { mem::transmute(vm.stubs.resume_catch()) };
+    resume(frame)
+}
+
+fn report_panic(vm: &VM, panic: &PanicData) {
+    eprintln!("{}", panic.report);
+    let stderr = std::io::stderr();
+    let mut stderr = stderr.lock();
+    panic
+        .stacktrace
+        .dump(vm, &mut stderr)
+        .expect("output broken");
+}
+
+// Reports the first panic of a thread that was never joined, returns its exit code.
+pub fn report_uncollected_panic(vm: &VM) -> Option<i32> {
+    let panic = vm.threads.first_uncollected_panic()?;
+    report_panic(vm, &panic);
+    Some(panic.exit_code)
+}
+
+// Runs the compiled lambda behind a catch frame, returns whether it panicked.
+fn run_catching(vm: &VM, thread: &CoreThread, fct_ptr: Address, lambda: Ref<Obj>) -> bool {
+    thread.handles.push_border();
+    let frame = CatchFrame::new(thread);
+    thread.push_catch_frame(&frame);
+
+    let catch_entry: extern "C" fn(Address, Address, Ref<Obj>, &CatchFrame) -> bool =
+        unsafe { mem::transmute(vm.stubs.catch_entry()) };
+    let panicked = catch_entry(thread.tld_address(), fct_ptr, lambda, &frame);
+
+    if !panicked {
+        thread.pop_catch_frame();
+    }
+
+    thread.handles.pop_border();
+    panicked
+}
+
+fn compile_lambda(vm: &VM, thread: &CoreThread, lambda: Handle<Obj>) -> Address {
+    use crate::compiler;
+    use crate::stack::CoreToNativeInfo;
+
+    let vtable = lambda.header().vtbl();
+    let class_instance = vtable.class_instance();
+
+    let (lambda_id, type_params) = match &class_instance.kind {
+        ShapeKind::Lambda(lambda_id, type_params) => (*lambda_id, type_params.clone()),
+        _ => unreachable!(),
+    };
+
+    let mut dtn = CoreToNativeInfo::new();
+    thread.use_dtn(&mut dtn, || compiler::generate(vm, lambda_id, &type_params))
+}
+
+fn alloc_panic_info(vm: &VM, panic: &PanicData) -> Ref<PanicInfo> {
+    handle_scope(|| {
+        let message = handle(Str::from_buffer(vm, panic.message.as_bytes()));
+
+        let cls_id = specialize_class_id(vm, vm.known.classes.stacktrace());
+        let stacktrace: Ref<Stacktrace> = alloc(vm, cls_id).cast();
+        let mut stacktrace = handle(stacktrace);
+        stacktrace.backtrace = alloc_backtrace(vm, &panic.stacktrace);
+        stacktrace.elements = Ref::null();
+
+        let cls_id = specialize_class_id(vm, vm.known.classes.panic_info());
+        let panic_info: Ref<PanicInfo> = alloc(vm, cls_id).cast();
+        let mut panic_info = handle(panic_info);
+        panic_info.message = message.direct();
+        panic_info.stacktrace = stacktrace.direct();
+
+        panic_info.direct()
+    })
+}
+
+pub extern "C" fn catch_panic(lambda: Handle<Obj>) -> Ref<PanicInfo> {
+    let vm = get_vm();
+    let thread = current_thread();
+    let fct_ptr = compile_lambda(vm, thread, lambda);
+
+    if run_catching(vm, thread, fct_ptr, lambda.direct()) {
+        let panic = thread.take_panic().expect("panic missing");
+        alloc_panic_info(vm, &panic)
+    } else {
+        Ref::null()
+    }
+}
+
 pub extern "C" fn spawn_thread(runner: Handle<Obj>) -> Address {
     let vm = get_vm();
 
@@ -330,9 +582,6 @@ pub extern "C" fn spawn_thread(runner: Handle<Obj>) -> Address {
 }
 
 fn thread_main(thread: &CoreThread, thread_location: Address, runner_location: Address) {
-    use crate::compiler;
-    use crate::stack::CoreToNativeInfo;
-
     let vm = get_vm();
     let _thread_handle: Handle<ManagedThread> = Handle::from_address(thread_location);
     let runner_handle: Handle<Obj> = Handle::from_address(runner_location);
@@ -347,27 +596,14 @@ fn thread_main(thread: &CoreThread, thread_location: Address, runner_location: A
     // before we dereference handle.
     thread.unpark(vm);
 
-    let vtable = runner_handle.header().vtbl();
-    let class_instance = vtable.class_instance();
-
-    let (lambda_id, type_params) = match &class_instance.kind {
-        ShapeKind::Lambda(lambda_id, type_params) => (*lambda_id, type_params.clone()),
-        _ => unreachable!(),
-    };
-
-    let tld = thread.tld_address();
-
-    let fct_ptr = {
-        let mut dtn = CoreToNativeInfo::new();
+    let fct_ptr = compile_lambda(vm, thread, runner_handle);
 
-        thread.use_dtn(&mut dtn, || compiler::generate(vm, lambda_id, &type_params))
-    };
-
-    // execute the runner/lambda
-    let core_stub_address = vm.stubs.core_entry();
-    let fct: extern "C" fn(Address, Address, Ref<Obj>) =
-        unsafe { mem::transmute(core_stub_address) };
-    fct(tld, fct_ptr, runner_handle.direct());
+    // execute the runner/lambda, a panic only terminates this thread and is
+    // reported at exit unless the thread gets joined
+    if run_catching(vm, thread, fct_ptr, runner_handle.direct()) {
+        let panic = thread.panic().expect("panic missing");
+        vm.threads.add_uncollected_panic(panic);
+    }
 
     // remove thread from list of all threads
     vm.threads.remove_current_thread();
@@ -376,9 +612,33 @@ fn thread_main(thread: &CoreThread, thread_location: Address, runner_location: A
     thread.stop();
 }
 
+// A panic that terminated the joined thread is raised again in the current thread.
 pub extern "C" fn join_thread(managed_thread: Handle<ManagedThread>) {
+    let vm = get_vm();
+    let native_thread = managed_thread.native_thread();
+    native_thread.join();
+
+    if let Some(panic) = native_thread.panic() {
+        vm.threads.collect_panic(&panic);
+        let exit_code = panic.exit_code;
+        unwind_panic(vm, panic);
+
+        std::process::exit(exit_code);
+    }
+}
+
+pub extern "C" fn try_join_thread(managed_thread: Handle<ManagedThread>) -> Ref<PanicInfo> {
+    let vm = get_vm();
     let native_thread = managed_thread.native_thread();
     native_thread.join();
+
+    match native_thread.panic() {
+        Some(panic) => {
+            vm.threads.collect_panic(&panic);
+            alloc_panic_info(vm, &panic)
+        }
+        None => Ref::null(),
+    }
 }
 
 pub extern "C" fn mutex_wait(mutex: Handle<ManagedMutex>, value: i32) {
@@ -410,3 +670,549 @@ pub extern "C" fn condition_wakeup_all(cond: Handle<Obj>) {
     let vm = get_vm();
     vm.wait_lists.wakeup_all(cond.direct_ptr());
 }
+
+pub extern "C" fn io_error_message(code: i32) -> Ref<Str> {
+    handle_scope(|| {
+        let vm = get_vm();
+        let message = io::Error::from_raw_os_error(code).to_string();
+
+        Str::from_buffer(vm, message.as_bytes())
+    })
+}
+
+// Errors are passed to Core as error codes of the operating system.
+// Errors not originating in the OS get a code with a similar meaning.
+fn io_error_code(err: io::Error) -> i32 {
+    match err.raw_os_error() {
+        Some(code) => code,
+        None if err.kind() == io::ErrorKind::InvalidInput => libc::EINVAL,
+        None => libc::EIO,
+    }
+}
+
+fn io_result(result: io::Result<u64>) -> i64 {
+    match result {
+        Ok(value) => value as i64,
+        Err(err) => -(io_error_code(err) as i64),
+    }
+}
+
+fn io_status(result: io::Result<()>) -> i32 {
+    match result {
+        Ok(()) => 0,
+        Err(err) => io_error_code(err),
+    }
+}
+
+// Strings aren't guaranteed to be valid UTF-8, the OS APIs reject those as invalid arguments.
+fn path_str(path: &Handle<Str>) -> io::Result<&str> {
+    str::from_utf8(path.content()).map_err(|_| io::Error::from_raw_os_error(libc::EINVAL))
+}
+
+fn with_file<F>(file: i64, f: F) -> i64
+where
+    F: FnOnce(&mut File) -> io::Result<u64>,
+{
+    // the file was already closed
+    if file == 0 {
+        return -(libc::EBADF as i64);
+    }
+
+    let file = unsafe { &mut *(file as *mut File) };
+    io_result(f(file))
+}
+
+pub extern "C" fn file_open(path: Handle<Str>, mode: i32) -> i64 {
+    let mut options = OpenOptions::new();
+
+    match mode {
+        0 => options.read(true),
+        1 => options.write(true).create(true).truncate(true),
+        2 => options.append(true).create(true),
+        3 => options.read(true).write(true),
+        _ => unreachable!(),
+    };
+
+    match path_str(&path).and_then(|path| options.open(path)) {
+        Ok(file) => Box::into_raw(Box::new(file)) as i64,
+        Err(err) => -(io_error_code(err) as i64),
+    }
+}
+
+// Files may be FIFOs or devices that block, reads and writes therefore park the
+// thread and go through a native buffer like the stdin natives below.
+pub extern "C" fn file_read(
+    file: i64,
+    mut buffer: Handle<UInt8Array>,
+    offset: i64,
+    len: i64,
+) -> i64 {
+    assert!(offset >= 0 && len >= 0 && (offset + len) as usize <= buffer.len());
+
+    let mut data = vec![0; len as usize];
+    let result =
+        parked_scope(|| with_file(file, |file| file.read(&mut data).map(|read| read as u64)));
+
+    if result > 0 {
+        let target = unsafe {
+            slice::from_raw_parts_mut(buffer.data_mut().offset(offset as isize), result as usize)
+        };
+        target.copy_from_slice(&data[0..result as usize]);
+    }
+
+    result
+}
+
+pub extern "C" fn file_write(file: i64, data: Handle<UInt8Array>, offset: i64, len: i64) -> i64 {
+    assert!(offset >= 0 && len >= 0 && (offset + len) as usize <= data.len());
+
+    let data = unsafe { slice::from_raw_parts(data.data().offset(offset as isize), len as usize) }
+        .to_vec();
+
+    parked_scope(|| with_file(file, |file| file.write(&data).map(|written| written as u64)))
+}
+
+pub extern "C" fn file_seek(file: i64, offset: i64, origin: i32) -> i64 {
+    let position = match origin {
+        0 if offset < 0 => return -(libc::EINVAL as i64),
+        0 => SeekFrom::Start(offset as u64),
+        1 => SeekFrom::Current(offset),
+        2 => SeekFrom::End(offset),
+        _ => unreachable!(),
+    };
+
+    with_file(file, |file| file.seek(position))
+}
+
+pub extern "C" fn file_close(file: i64) {
+    if file != 0 {
+        mem::drop(unsafe { Box::from_raw(file as *mut File) });
+    }
+}
+
+pub extern "C" fn fs_metadata(path: Handle<Str>, mut data: Handle<Int64Array>) -> i32 {
+    let metadata = match path_str(&path).and_then(fs::metadata) {
+        Ok(metadata) => metadata,
+        Err(err) => return io_error_code(err),
+    };
+
+    let kind = if metadata.is_file() {
+        0
+    } else if metadata.is_dir() {
+        1
+    } else {
+        2
+    };
+
+    let modified = metadata
+        .modified()
+        .ok()
+        .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+        .map(|duration| duration.as_millis() as i64)
+        .unwrap_or(0);
+
+    data.set_at(0, metadata.len() as i64);
+    data.set_at(1, kind);
+    data.set_at(2, modified);
+    data.set_at(3, metadata.permissions().readonly() as i64);
+
+    0
+}
+
+pub extern "C" fn fs_create_dir(path: Handle<Str>) -> i32 {
+    io_status(path_str(&path).and_then(fs::create_dir))
+}
+
+pub extern "C" fn fs_remove_file(path: Handle<Str>) -> i32 {
+    io_status(path_str(&path).and_then(fs::remove_file))
+}
+
+pub extern "C" fn fs_remove_dir(path: Handle<Str>) -> i32 {
+    io_status(path_str(&path).and_then(fs::remove_dir))
+}
+
+pub extern "C" fn fs_rename(source: Handle<Str>, target: Handle<Str>) -> i32 {
+    io_status(path_str(&source).and_then(|source| fs::rename(source, path_str(&target)?)))
+}
+
+pub extern "C" fn dir_open(path: Handle<Str>) -> i64 {
+    match path_str(&path).and_then(fs::read_dir) {
+        Ok(dir) => Box::into_raw(Box::new(dir)) as i64,
+        Err(err) => -(io_error_code(err) as i64),
+    }
+}
+
+pub extern "C" fn dir_next(dir: i64) -> Ref<Str> {
+    let dir = unsafe { &mut *(dir as *mut ReadDir) };
+
+    // entries that can't be read are skipped
+    match dir.flatten().next() {
+        Some(entry) => {
+            let name = entry.file_name();
+            let name = name.to_string_lossy();
+
+            handle_scope(|| {
+                let vm = get_vm();
+                Str::from_buffer(vm, name.as_bytes())
+            })
+        }
+
+        None => Ref::null(),
+    }
+}
+
+pub extern "C" fn dir_close(dir: i64) {
+    mem::drop(unsafe { Box::from_raw(dir as *mut ReadDir) });
+}
+
+// Reading from stdin may block for an arbitrary amount of time, therefore all
+// stdin natives park the thread while reading so that the GC can run in the
+// meantime. Objects may move while parked, which is why data is read into a
+// native buffer first.
+
+pub extern "C" fn stdin_read_line() -> Ref<Str> {
+    let mut line = Vec::new();
+
+    let result = parked_scope(|| io::stdin().lock().read_until(b'\n', &mut line));
+
+    match result {
+        Ok(0) | Err(_) => Ref::null(),
+        Ok(_) => {
+            if line.last() == Some(&b'\n') {
+                line.pop();
+
+                if line.last() == Some(&b'\r') {
+                    line.pop();
+                }
+            }
+
+            handle_scope(|| {
+                let vm = get_vm();
+                let line = String::from_utf8_lossy(&line);
+                Str::from_buffer(vm, line.as_bytes())
+            })
+        }
+    }
+}
+
+pub extern "C" fn stdin_read_all() -> Ref<Str> {
+    let mut content = Vec::new();
+
+    // everything read up to an error is returned
+    let _ = parked_scope(|| io::stdin().lock().read_to_end(&mut content));
+
+    handle_scope(|| {
+        let vm = get_vm();
+        let content = String::from_utf8_lossy(&content);
+        Str::from_buffer(vm, content.as_bytes())
+    })
+}
+
+pub extern "C" fn stdin_read(mut buffer: Handle<UInt8Array>, offset: i64, len: i64) -> i64 {
+    assert!(offset >= 0 && len >= 0 && (offset + len) as usize <= buffer.len());
+
+    let mut data = vec![0; len as usize];
+    let result = parked_scope(|| io::stdin().lock().read(&mut data));
+
+    if let Ok(read) = result {
+        let target =
+            unsafe { slice::from_raw_parts_mut(buffer.data_mut().offset(offset as isize), read) };
+        target.copy_from_slice(&data[0..read]);
+    }
+
+    io_result(result.map(|read| read as u64))
+}
+
+fn str_from_bytes_lossy(bytes: &[u8]) -> Ref<Str> {
+    handle_scope(|| {
+        let vm = get_vm();
+        let value = String::from_utf8_lossy(bytes);
+        Str::from_buffer(vm, value.as_bytes())
+    })
+}
+
+pub extern "C" fn env_get_var(name: Handle<Str>) -> Ref<Str> {
+    match path_str(&name).ok().and_then(|name| env::var(name).ok()) {
+        Some(value) => str_from_bytes_lossy(value.as_bytes()),
+        None => Ref::null(),
+    }
+}
+
+// The operating system can't represent these variables, std::env would panic on them.
+fn is_valid_env_var(name: &str, value: &str) -> bool {
+    !name.is_empty() && !name.contains(['=', '\0']) && !value.contains('\0')
+}
+
+pub extern "C" fn env_set_var(name: Handle<Str>, value: Handle<Str>) -> i32 {
+    let (name, value) = match (path_str(&name), path_str(&value)) {
+        (Ok(name), Ok(value)) => (name, value),
+        _ => return libc::EINVAL,
+    };
+
+    if !is_valid_env_var(name, value) {
+        return libc::EINVAL;
+    }
+
+    // Not synchronized with getenv in other threads, env.core documents the restriction.
+    env::set_var(name, value);
+    0
+}
+
+pub extern "C" fn env_remove_var(name: Handle<Str>) -> i32 {
+    let name = match path_str(&name) {
+        Ok(name) => name,
+        Err(_) => return libc::EINVAL,
+    };
+
+    if !is_valid_env_var(name, "") {
+        return libc::EINVAL;
+    }
+
+    env::remove_var(name);
+    0
+}
+
+pub extern "C" fn env_vars_open() -> i64 {
+    let vars = env::vars_os()
+        .filter_map(|(name, value)| Some(format!("{}={}", name.to_str()?, value.to_str()?)))
+        .collect::<Vec<_>>();
+
+    Box::into_raw(Box::new(vars.into_iter())) as i64
+}
+
+pub extern "C" fn env_vars_next(vars: i64) -> Ref<Str> {
+    let vars = unsafe { &mut *(vars as *mut std::vec::IntoIter<String>) };
+
+    match vars.next() {
+        Some(var) => str_from_bytes_lossy(var.as_bytes()),
+        None => Ref::null(),
+    }
+}
+
+pub extern "C" fn env_vars_close(vars: i64) {
+    mem::drop(unsafe { Box::from_raw(vars as *mut std::vec::IntoIter<String>) });
+}
+
+pub extern "C" fn env_current_dir() -> Ref<Str> {
+    match env::current_dir().map(|path| path.into_os_string().into_string()) {
+        Ok(Ok(path)) => str_from_bytes_lossy(path.as_bytes()),
+        _ => Ref::null(),
+    }
+}
+
+pub extern "C" fn env_current_dir_error() -> i32 {
+    match env::current_dir() {
+        Ok(_) => libc::EILSEQ,
+        Err(err) => io_error_code(err),
+    }
+}
+
+pub extern "C" fn env_set_current_dir(path: Handle<Str>) -> i32 {
+    io_status(path_str(&path).and_then(env::set_current_dir))
+}
+
+// Native state of a child process in Core.
+pub struct ChildProcess {
+    child: Child,
+
+    // output of streams that were read to their end
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+
+const STREAM_STDIN: i32 = 0;
+const STREAM_STDOUT: i32 = 1;
+const STREAM_STDERR: i32 = 2;
+
+fn child_process(child: i64) -> &'static mut ChildProcess {
+    unsafe { &mut *(child as *mut ChildProcess) }
+}
+
+fn stdio_config(config: i32) -> Stdio {
+    match config & 3 {
+        0 => Stdio::inherit(),
+        1 => Stdio::piped(),
+        2 => Stdio::null(),
+        _ => unreachable!(),
+    }
+}
+
+fn str_array_to_vec(array: &Handle<StrArray>) -> Vec<String> {
+    (0..array.len())
+        .map(|idx| String::from_utf8_lossy(array.get_at(idx).content()).into_owned())
+        .collect()
+}
+
+#[cfg(unix)]
+fn exit_status_code(status: ExitStatus) -> i64 {
+    use std::os::unix::process::ExitStatusExt;
+
+    match status.code() {
+        Some(code) => code as i64,
+        None => 128 + status.signal().expect("neither exit code nor signal") as i64,
+    }
+}
+
+#[cfg(windows)]
+fn exit_status_code(status: ExitStatus) -> i64 {
+    status.code().expect("missing exit code") as i64
+}
+
+pub extern "C" fn process_spawn(
+    arguments: Handle<StrArray>,
+    env_names: Handle<StrArray>,
+    env_values: Handle<StrArray>,
+    directory: Handle<Str>,
+    stdio: i32,
+) -> i64 {
+    let arguments = str_array_to_vec(&arguments);
+    let mut command = Command::new(&arguments[0]);
+    command.args(&arguments[1..]);
+
+    let env_names = str_array_to_vec(&env_names);
+    let env_values = str_array_to_vec(&env_values);
+
+    for (name, value) in env_names.iter().zip(env_values.iter()) {
+        if !is_valid_env_var(name, value) {
+            return -(libc::EINVAL as i64);
+        }
+
+        command.env(name, value);
+    }
+
+    if !directory.content().is_empty() {
+        match path_str(&directory) {
+            Ok(directory) => command.current_dir(directory),
+            Err(err) => return -(io_error_code(err) as i64),
+        };
+    }
+
+    command
+        .stdin(stdio_config(stdio))
+        .stdout(stdio_config(stdio >> 2))
+        .stderr(stdio_config(stdio >> 4));
+
+    match command.spawn() {
+        Ok(child) => {
+            let process = ChildProcess {
+                child,
+                stdout: Vec::new(),
+                stderr: Vec::new(),
+            };
+
+            Box::into_raw(Box::new(process)) as i64
+        }
+
+        Err(err) => -(io_error_code(err) as i64),
+    }
+}
+
+pub extern "C" fn child_id(child: i64) -> i32 {
+    child_process(child).child.id() as i32
+}
+
+// All blocking operations on child processes park the thread, see stdin natives.
+
+pub extern "C" fn child_write_stdin(child: i64, data: Handle<Str>) -> i32 {
+    let process = child_process(child);
+    let data = data.content().to_vec();
+
+    match process.child.stdin.as_mut() {
+        Some(stdin) => io_status(parked_scope(|| stdin.write_all(&data))),
+        None => libc::EBADF,
+    }
+}
+
+pub extern "C" fn child_close_stream(child: i64, stream: i32) {
+    let child = &mut child_process(child).child;
+
+    match stream {
+        STREAM_STDIN => mem::drop(child.stdin.take()),
+        STREAM_STDOUT => mem::drop(child.stdout.take()),
+        STREAM_STDERR => mem::drop(child.stderr.take()),
+        _ => unreachable!(),
+    }
+}
+
+pub extern "C" fn child_read_stream(child: i64, stream: i32) -> i32 {
+    let process = child_process(child);
+
+    let result = match stream {
+        STREAM_STDOUT => match process.child.stdout.as_mut() {
+            Some(stdout) => parked_scope(|| stdout.read_to_end(&mut process.stdout)),
+            None => return libc::EBADF,
+        },
+
+        STREAM_STDERR => match process.child.stderr.as_mut() {
+            Some(stderr) => parked_scope(|| stderr.read_to_end(&mut process.stderr)),
+            None => return libc::EBADF,
+        },
+
+        _ => unreachable!(),
+    };
+
+    io_status(result.map(|_| ()))
+}
+
+pub extern "C" fn child_take_output(child: i64, stream: i32) -> Ref<Str> {
+    let process = child_process(child);
+
+    let output = match stream {
+        STREAM_STDOUT => mem::take(&mut process.stdout),
+        STREAM_STDERR => mem::take(&mut process.stderr),
+        _ => unreachable!(),
+    };
+
+    str_from_bytes_lossy(&output)
+}
+
+pub extern "C" fn child_wait(child: i64) -> i64 {
+    let child = &mut child_process(child).child;
+
+    match parked_scope(|| child.wait()) {
+        Ok(status) => exit_status_code(status),
+        Err(err) => -(io_error_code(err) as i64),
+    }
+}
+
+pub extern "C" fn child_wait_with_output(child: i64) -> i64 {
+    let process = child_process(child);
+    mem::drop(process.child.stdin.take());
+
+    let mut stdout = process.child.stdout.take();
+    let mut stderr = process.child.stderr.take();
+
+    let result = parked_scope(|| -> io::Result<ExitStatus> {
+        // Both pipes are read concurrently, otherwise the child could block
+        // on a full stderr pipe while stdout is read.
+        let stderr_reader = thread::spawn(move || {
+            let mut data = Vec::new();
+
+            if let Some(stderr) = stderr.as_mut() {
+                stderr.read_to_end(&mut data)?;
+            }
+
+            Ok::<_, io::Error>(data)
+        });
+
+        if let Some(stdout) = stdout.as_mut() {
+            stdout.read_to_end(&mut process.stdout)?;
+        }
+
+        process.stderr = stderr_reader.join().expect("reader thread failed")?;
+        process.child.wait()
+    });
+
+    match result {
+        Ok(status) => exit_status_code(status),
+        Err(err) => -(io_error_code(err) as i64),
+    }
+}
+
+pub extern "C" fn child_kill(child: i64) -> i32 {
+    io_status(child_process(child).child.kill())
+}
+
+pub extern "C" fn child_release(child: i64) {
+    mem::drop(unsafe { Box::from_raw(child as *mut ChildProcess) });
+}
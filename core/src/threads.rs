@@ -1,5 +1,5 @@
 use parking_lot::{Condvar, Mutex};
-use std::cell::RefCell;
+use std::cell::{Cell, RefCell};
 use std::convert::From;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
@@ -8,7 +8,7 @@ use std::sync::Arc;
 use crate::gc::{tlab, Address, Region, K};
 use crate::handle::HandleMemory;
 use crate::object::{alloc, Header, Ref};
-use crate::stack::CoreToNativeInfo;
+use crate::stack::{CoreToNativeInfo, NativeStacktrace};
 use crate::vm::{get_vm, VM};
 
 pub const STACK_SIZE: usize = 500 * K;
@@ -53,6 +53,9 @@ pub struct Threads {
     pub next_thread_id: AtomicUsize,
 
     pub barrier: Barrier,
+
+    // panics that terminated a thread and weren't collected by joining it yet
+    uncollected_panics: Mutex<Vec<Arc<PanicData>>>,
 }
 
 impl Threads {
@@ -62,6 +65,7 @@ impl Threads {
             cv_join: Condvar::new(),
             next_thread_id: AtomicUsize::new(1),
             barrier: Barrier::new(),
+            uncollected_panics: Mutex::new(Vec::new()),
         }
     }
 
@@ -107,6 +111,20 @@ impl Threads {
             self.cv_join.wait(&mut threads);
         }
     }
+
+    pub fn add_uncollected_panic(&self, panic: Arc<PanicData>) {
+        self.uncollected_panics.lock().push(panic);
+    }
+
+    pub fn collect_panic(&self, panic: &Arc<PanicData>) {
+        self.uncollected_panics
+            .lock()
+            .retain(|elem| !Arc::ptr_eq(elem, panic));
+    }
+
+    pub fn first_uncollected_panic(&self) -> Option<Arc<PanicData>> {
+        self.uncollected_panics.lock().first().cloned()
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug)]
@@ -137,6 +155,8 @@ pub struct CoreThread {
     pub state: AtomicUsize,
     join_data: JoinData,
     blocking_data: BlockingData,
+    catch_frames: Mutex<Vec<*const CatchFrame>>,
+    panic: Mutex<Option<Arc<PanicData>>>,
 }
 
 unsafe impl Sync for CoreThread {}
@@ -155,6 +175,8 @@ impl CoreThread {
             state: AtomicUsize::new(initial_state as usize),
             join_data: JoinData::new(),
             blocking_data: BlockingData::new(),
+            catch_frames: Mutex::new(Vec::new()),
+            panic: Mutex::new(None),
         })
     }
 
@@ -197,6 +219,28 @@ impl CoreThread {
         self.set_dtn(dtn.last);
     }
 
+    pub fn push_catch_frame(&self, frame: &CatchFrame) {
+        self.catch_frames.lock().push(frame as *const _);
+    }
+
+    pub fn pop_catch_frame(&self) -> Option<&CatchFrame> {
+        let frame = self.catch_frames.lock().pop()?;
+        Some(unsafe { &*frame })
+    }
+
+    // The panic that was last caught on this thread or terminated it.
+    pub fn panic(&self) -> Option<Arc<PanicData>> {
+        self.panic.lock().clone()
+    }
+
+    pub fn take_panic(&self) -> Option<Arc<PanicData>> {
+        self.panic.lock().take()
+    }
+
+    pub fn set_panic(&self, panic: Arc<PanicData>) {
+        *self.panic.lock() = Some(panic);
+    }
+
     pub fn state_relaxed(&self) -> ThreadState {
         self.state.load(Ordering::Relaxed).into()
     }
@@ -327,6 +371,45 @@ impl CoreThread {
     }
 }
 
+// State of a thread when entering the catch entry stub, a panic drops all frames
+// above that stub and restores this state.
+#[repr(C)]
+pub struct CatchFrame {
+    // stack and frame pointer of catch entry stub, set by the stub itself
+    sp: Cell<usize>,
+    fp: Cell<usize>,
+
+    pub dtn: *const CoreToNativeInfo,
+    pub handle_borders: usize,
+}
+
+impl CatchFrame {
+    pub fn new(thread: &CoreThread) -> CatchFrame {
+        CatchFrame {
+            sp: Cell::new(0),
+            fp: Cell::new(0),
+            dtn: thread.dtn(),
+            handle_borders: thread.handles.borders(),
+        }
+    }
+
+    pub fn sp_offset() -> i32 {
+        offset_of!(CatchFrame, sp) as i32
+    }
+
+    pub fn fp_offset() -> i32 {
+        offset_of!(CatchFrame, fp) as i32
+    }
+}
+
+pub struct PanicData {
+    // printed on stderr when nothing catches the panic
+    pub report: String,
+    pub message: String,
+    pub stacktrace: NativeStacktrace,
+    pub exit_code: i32,
+}
+
 pub fn parked_scope<F, R>(callback: F) -> R
 where
     F: FnOnce() -> R,
@@ -10,13 +10,13 @@ use crate::driver::cmd::Args;
 use crate::gc::{Address, Gc};
 use crate::language::error::diag::Diagnostic;
 use crate::language::sem_analysis::{
-    AnnotationDefinition, AnnotationDefinitionId, ClassDefinition, ClassDefinitionId,
-    ConstDefinition, EnumDefinition, EnumDefinitionId, ExtensionDefinition, FctDefinition,
-    FctDefinitionId, GlobalDefinition, ImplDefinition, ModuleDefinition, ModuleDefinitionId,
-    PackageDefinition, PackageDefinitionId, SourceFile, TraitDefinition, TraitDefinitionId,
-    UseDefinition, ValueDefinition, ValueDefinitionId,
+    AliasDefinition, AnnotationDefinition, AnnotationDefinitionId, ClassDefinition,
+    ClassDefinitionId, ConstDefinition, EnumDefinition, EnumDefinitionId, ExtensionDefinition,
+    FctDefinition, FctDefinitionId, GlobalDefinition, ImplDefinition, ModuleDefinition,
+    ModuleDefinitionId, PackageDefinition, PackageDefinitionId, SourceFile, TraitDefinition,
+    TraitDefinitionId, UnionDefinition, UseDefinition, ValueDefinition, ValueDefinitionId,
 };
-use crate::language::ty::SourceTypeArray;
+use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::stack::CoreToNativeInfo;
 use crate::threads::ManagedThread;
 use crate::threads::{
@@ -42,9 +42,10 @@ pub use self::enums::{EnumInstance, EnumInstanceId, EnumLayout};
 use self::globals::GlobalVariableMemory;
 pub use self::known::KnownElements;
 pub use self::specialize::{
-    add_ref_fields, specialize_class_id, specialize_class_id_params, specialize_enum_class,
-    specialize_enum_id_params, specialize_lambda, specialize_trait_object, specialize_tuple_array,
-    specialize_tuple_bty, specialize_tuple_ty, specialize_type, specialize_type_list,
+    add_ref_fields, specialize_class_id, specialize_class_id_params,
+    specialize_default_method_type, specialize_enum_class, specialize_enum_id_params,
+    specialize_lambda, specialize_trait_object, specialize_tuple_array, specialize_tuple_bty,
+    specialize_tuple_ty, specialize_type, specialize_type_list, specialize_union_value,
     value_instance,
 };
 pub use self::stubs::{setup_stubs, Stubs};
@@ -113,6 +114,8 @@ pub struct FullSemAnalysis {
     pub modules: MutableVec<ModuleDefinition>, // stores all module definitions
     pub fcts: GrowableVec<RwLock<FctDefinition>>, // stores all function source definitions
     pub enums: MutableVec<EnumDefinition>,   // stores all enum source definitions
+    pub unions: MutableVec<UnionDefinition>, // stores all union source definitions
+    pub aliases: MutableVec<AliasDefinition>, // stores all type alias definitions
     pub traits: MutableVec<TraitDefinition>, // stores all trait definitions
     pub impls: MutableVec<ImplDefinition>,   // stores all impl definitions
     pub globals: MutableVec<GlobalDefinition>, // stores all global variables
@@ -140,6 +143,8 @@ impl FullSemAnalysis {
             annotations: MutableVec::new(),
             modules: MutableVec::new(),
             enums: MutableVec::new(),
+            unions: MutableVec::new(),
+            aliases: MutableVec::new(),
             traits: MutableVec::new(),
             impls: MutableVec::new(),
             globals: MutableVec::new(),
@@ -203,6 +208,9 @@ pub struct VM {
     pub enums: MutableVec<EnumDefinition>, // store all enum source definitions
     pub enum_specializations: RwLock<HashMap<(EnumDefinitionId, SourceTypeArray), EnumInstanceId>>,
     pub enum_instances: GrowableVecNonIter<EnumInstance>, // stores all enum definitions
+    pub unions: MutableVec<UnionDefinition>,              // stores all union source definitions
+    pub union_values: RwLock<HashMap<SourceType, ClassInstanceId>>, // boxes for value variants
+    pub aliases: MutableVec<AliasDefinition>,             // stores all type alias definitions
     pub traits: MutableVec<TraitDefinition>,              // stores all trait definitions
     pub trait_vtables: RwLock<HashMap<(TraitDefinitionId, SourceTypeArray), ClassInstanceId>>,
     pub impls: MutableVec<ImplDefinition>, // stores all impl definitions
@@ -245,6 +253,9 @@ impl VM {
             enums: MutableVec::new(),
             enum_specializations: RwLock::new(HashMap::new()),
             enum_instances: GrowableVecNonIter::new(),
+            unions: MutableVec::new(),
+            union_values: RwLock::new(HashMap::new()),
+            aliases: MutableVec::new(),
             traits: MutableVec::new(),
             trait_vtables: RwLock::new(HashMap::new()),
             impls: MutableVec::new(),
@@ -299,6 +310,9 @@ impl VM {
             enums: sa.enums,
             enum_specializations: RwLock::new(HashMap::new()),
             enum_instances: GrowableVecNonIter::new(),
+            unions: sa.unions,
+            union_values: RwLock::new(HashMap::new()),
+            aliases: sa.aliases,
             traits: sa.traits,
             trait_vtables: RwLock::new(HashMap::new()),
             impls: sa.impls,
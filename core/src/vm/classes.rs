@@ -43,6 +43,7 @@ pub enum ShapeKind {
         trait_id: TraitDefinitionId,
         combined_type_params: SourceTypeArray,
     },
+    UnionValue(SourceType),
     Enum(EnumDefinitionId, SourceTypeArray),
     Builtin,
 }
@@ -67,16 +67,24 @@ impl KnownElements {
 #[derive(Debug)]
 pub struct KnownEnums {
     pub option: Option<EnumDefinitionId>,
+    pub result: Option<EnumDefinitionId>,
 }
 
 impl KnownEnums {
     pub fn new() -> KnownEnums {
-        KnownEnums { option: None }
+        KnownEnums {
+            option: None,
+            result: None,
+        }
     }
 
     pub fn option(&self) -> EnumDefinitionId {
         self.option.expect("uninitialized")
     }
+
+    pub fn result(&self) -> EnumDefinitionId {
+        self.result.expect("uninitialized")
+    }
 }
 
 #[derive(Debug)]
@@ -86,6 +94,7 @@ pub struct KnownClasses {
     pub array: Option<ClassDefinitionId>,
     pub string: Option<ClassDefinitionId>,
     pub string_buffer: Option<ClassDefinitionId>,
+    pub panic_info: Option<ClassDefinitionId>,
     pub stacktrace: Option<ClassDefinitionId>,
     pub stacktrace_element: Option<ClassDefinitionId>,
     pub thread: Option<ClassDefinitionId>,
@@ -102,6 +111,7 @@ impl KnownClasses {
             string_buffer: None,
             stacktrace: None,
             stacktrace_element: None,
+            panic_info: None,
             thread: None,
             lambda: None,
         }
@@ -127,6 +137,10 @@ impl KnownClasses {
         self.string_buffer.expect("uninitialized")
     }
 
+    pub fn panic_info(&self) -> ClassDefinitionId {
+        self.panic_info.expect("uninitialized")
+    }
+
     pub fn stacktrace(&self) -> ClassDefinitionId {
         self.stacktrace.expect("uninitialized")
     }
@@ -152,7 +166,12 @@ impl KnownClasses {
 pub struct KnownValue {
     pub unit: Option<ValueDefinitionId>,
     pub bool: Option<ValueDefinitionId>,
+    pub int8: Option<ValueDefinitionId>,
+    pub int16: Option<ValueDefinitionId>,
     pub uint8: Option<ValueDefinitionId>,
+    pub uint16: Option<ValueDefinitionId>,
+    pub uint32: Option<ValueDefinitionId>,
+    pub uint64: Option<ValueDefinitionId>,
     pub char: Option<ValueDefinitionId>,
     pub int32: Option<ValueDefinitionId>,
     pub int64: Option<ValueDefinitionId>,
@@ -165,7 +184,12 @@ impl KnownValue {
         KnownValue {
             unit: None,
             bool: None,
+            int8: None,
+            int16: None,
             uint8: None,
+            uint16: None,
+            uint32: None,
+            uint64: None,
             char: None,
             int32: None,
             int64: None,
@@ -182,10 +206,30 @@ impl KnownValue {
         self.bool.expect("uninitialized")
     }
 
+    pub fn int8(&self) -> ValueDefinitionId {
+        self.int8.expect("uninitialized")
+    }
+
+    pub fn int16(&self) -> ValueDefinitionId {
+        self.int16.expect("uninitialized")
+    }
+
     pub fn uint8(&self) -> ValueDefinitionId {
         self.uint8.expect("uninitialized")
     }
 
+    pub fn uint16(&self) -> ValueDefinitionId {
+        self.uint16.expect("uninitialized")
+    }
+
+    pub fn uint32(&self) -> ValueDefinitionId {
+        self.uint32.expect("uninitialized")
+    }
+
+    pub fn uint64(&self) -> ValueDefinitionId {
+        self.uint64.expect("uninitialized")
+    }
+
     pub fn char(&self) -> ValueDefinitionId {
         self.char.expect("uninitialized")
     }
@@ -256,6 +300,9 @@ pub struct KnownAnnotations {
     pub test: Option<AnnotationDefinitionId>,
     pub cannon: Option<AnnotationDefinitionId>,
     pub optimize_immediately: Option<AnnotationDefinitionId>,
+    pub open: Option<AnnotationDefinitionId>,
+    pub override_: Option<AnnotationDefinitionId>,
+    pub final_: Option<AnnotationDefinitionId>,
 }
 
 impl KnownAnnotations {
@@ -267,6 +314,9 @@ impl KnownAnnotations {
             test: None,
             cannon: None,
             optimize_immediately: None,
+            open: None,
+            override_: None,
+            final_: None,
         }
     }
 
@@ -293,6 +343,18 @@ impl KnownAnnotations {
     pub fn optimize_immediately(&self) -> AnnotationDefinitionId {
         self.optimize_immediately.expect("uninitialized")
     }
+
+    pub fn open(&self) -> AnnotationDefinitionId {
+        self.open.expect("uninitialized")
+    }
+
+    pub fn override_(&self) -> AnnotationDefinitionId {
+        self.override_.expect("uninitialized")
+    }
+
+    pub fn final_(&self) -> AnnotationDefinitionId {
+        self.final_.expect("uninitialized")
+    }
 }
 
 #[derive(Debug)]
@@ -300,6 +362,7 @@ pub struct KnownFunctions {
     pub string_buffer_empty: Option<FctDefinitionId>,
     pub string_buffer_append: Option<FctDefinitionId>,
     pub string_buffer_to_string: Option<FctDefinitionId>,
+    pub string_equals: Option<FctDefinitionId>,
     pub assert: Option<FctDefinitionId>,
     pub option_is_some: Option<FctDefinitionId>,
     pub option_is_none: Option<FctDefinitionId>,
@@ -314,6 +377,7 @@ impl KnownFunctions {
             string_buffer_empty: None,
             string_buffer_append: None,
             string_buffer_to_string: None,
+            string_equals: None,
             assert: None,
             option_is_none: None,
             option_is_some: None,
@@ -335,6 +399,10 @@ impl KnownFunctions {
         self.string_buffer_to_string.expect("uninitialized")
     }
 
+    pub fn string_equals(&self) -> FctDefinitionId {
+        self.string_equals.expect("uninitialized")
+    }
+
     pub fn assert(&self) -> FctDefinitionId {
         self.assert.expect("uninitialized")
     }
@@ -3,8 +3,10 @@ use std::cmp::max;
 use std::sync::Arc;
 
 use crate::bytecode::BytecodeType;
+use crate::language::resolve_assoc_type;
 use crate::language::sem_analysis::{
-    create_tuple, ClassDefinitionId, FctDefinitionId, TraitDefinitionId,
+    create_tuple, vtable_traits, ClassDefinitionId, FctDefinition, FctDefinitionId,
+    TraitDefinitionId,
 };
 use crate::language::ty::{SourceType, SourceTypeArray};
 use crate::mem;
@@ -21,6 +23,18 @@ pub fn specialize_type(vm: &VM, ty: SourceType, type_params: &SourceTypeArray) -
     replace_type_param(vm, ty, type_params, None)
 }
 
+// Default methods of traits are compiled for each implementing type, which is
+// passed after the declared type params. Their signature refers to it as Self.
+pub fn specialize_default_method_type(
+    vm: &VM,
+    fct: &FctDefinition,
+    ty: SourceType,
+    type_params: &SourceTypeArray,
+) -> SourceType {
+    let self_ty = type_params[fct.type_params.len()].clone();
+    replace_type_param(vm, ty, type_params, Some(self_ty))
+}
+
 pub fn specialize_type_list(
     vm: &VM,
     list: &SourceTypeArray,
@@ -392,7 +406,7 @@ fn create_specialized_class_regular(
         ShapeKind::Class(cls.id(), type_params.clone()),
         size,
         fields,
-        0,
+        cls.virtual_methods.len(),
     );
 
     let old = specializations.insert((cls.id(), type_params.clone()), class_instance_id);
@@ -418,6 +432,7 @@ fn create_specialized_class_array(
             SourceType::Ptr
             | SourceType::Class(_, _)
             | SourceType::Trait(_, _)
+            | SourceType::Union(_, _)
             | SourceType::Lambda(_, _) => InstanceSize::ObjArray,
 
             SourceType::Tuple(_) => {
@@ -442,15 +457,22 @@ fn create_specialized_class_array(
 
             SourceType::Bool
             | SourceType::UInt8
+            | SourceType::Int8
+            | SourceType::Int16
+            | SourceType::UInt16
+            | SourceType::UInt32
+            | SourceType::UInt64
             | SourceType::Char
             | SourceType::Int32
             | SourceType::Int64
             | SourceType::Float32
             | SourceType::Float64 => InstanceSize::PrimitiveArray(element_ty.size(vm)),
 
-            SourceType::Any | SourceType::Error | SourceType::This | SourceType::TypeParam(_) => {
-                unreachable!()
-            }
+            SourceType::Any
+            | SourceType::Error
+            | SourceType::This
+            | SourceType::Assoc(_)
+            | SourceType::TypeParam(_) => unreachable!(),
         }
     } else {
         InstanceSize::Str
@@ -553,6 +575,12 @@ fn create_specialized_class_for_trait_object(
         return id;
     }
 
+    // methods of supertraits follow the methods of the trait itself
+    let vtable_entries = vtable_traits(vm, trait_.id())
+        .into_iter()
+        .map(|trait_id| vm.traits[trait_id].read().methods.len())
+        .sum();
+
     let class_instance_id = create_class_instance_with_vtable(
         vm,
         ShapeKind::TraitObject {
@@ -562,7 +590,7 @@ fn create_specialized_class_for_trait_object(
         },
         size,
         fields,
-        trait_.methods.len(),
+        vtable_entries,
     );
 
     let old = vtables.insert((trait_.id(), combined_type_params), class_instance_id);
@@ -571,6 +599,42 @@ fn create_specialized_class_for_trait_object(
     class_instance_id
 }
 
+// Value variants of unions are stored in a box with the value as its only field,
+// the box's vtable identifies the variant.
+pub fn specialize_union_value(vm: &VM, value_type: SourceType) -> ClassInstanceId {
+    debug_assert!(value_type.is_value() && value_type.is_concrete_type(vm));
+
+    if let Some(&id) = vm.union_values.read().get(&value_type) {
+        return id;
+    }
+
+    let offset = mem::align_i32(Header::size(), value_type.align(vm));
+    let fields = vec![FieldInstance {
+        offset,
+        ty: value_type.clone(),
+    }];
+    let csize = mem::align_i32(offset + value_type.size(vm), mem::ptr_width());
+
+    let mut union_values = vm.union_values.write();
+
+    if let Some(&id) = union_values.get(&value_type) {
+        return id;
+    }
+
+    let class_instance_id = create_class_instance_with_vtable(
+        vm,
+        ShapeKind::UnionValue(value_type.clone()),
+        InstanceSize::Fixed(csize),
+        fields,
+        0,
+    );
+
+    let old = union_values.insert(value_type, class_instance_id);
+    assert!(old.is_none());
+
+    class_instance_id
+}
+
 pub fn specialize_tuple_ty(
     vm: &VM,
     tuple_ty: SourceType,
@@ -657,8 +721,24 @@ pub fn replace_type_param(
             SourceType::Enum(enum_id, new_type_params)
         }
 
+        SourceType::Union(union_id, old_type_params) => {
+            let new_type_params = SourceTypeArray::with(
+                old_type_params
+                    .iter()
+                    .map(|p| replace_type_param(vm, p, type_params, self_ty.clone()))
+                    .collect::<Vec<_>>(),
+            );
+
+            SourceType::Union(union_id, new_type_params)
+        }
+
         SourceType::This => self_ty.expect("no type for Self given"),
 
+        SourceType::Assoc(alias_id) => {
+            let self_ty = self_ty.expect("no type for Self given");
+            resolve_assoc_type(vm, &self_ty, alias_id).expect("associated type not bound")
+        }
+
         SourceType::Lambda(params, return_type) => {
             let new_params = SourceTypeArray::with(
                 params
@@ -688,6 +768,11 @@ pub fn replace_type_param(
 
         SourceType::Unit
         | SourceType::UInt8
+        | SourceType::Int8
+        | SourceType::Int16
+        | SourceType::UInt16
+        | SourceType::UInt32
+        | SourceType::UInt64
         | SourceType::Bool
         | SourceType::Char
         | SourceType::Int32
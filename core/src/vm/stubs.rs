@@ -1,3 +1,4 @@
+use crate::compiler::catch_entry_stub;
 use crate::compiler::core_entry_stub;
 use crate::compiler::core_exit_stubs::{self, NativeFct, NativeFctKind};
 use crate::compiler::lazy_compilation_stub;
@@ -10,6 +11,8 @@ use crate::vm::VM;
 pub struct Stubs {
     compile: Option<Address>,
     core_entry: Option<Address>,
+    catch_entry: Option<Address>,
+    resume_catch: Option<Address>,
     trap: Option<Address>,
     stack_overflow: Option<Address>,
     safepoint: Option<Address>,
@@ -20,6 +23,8 @@ impl Stubs {
         Stubs {
             compile: None,
             core_entry: None,
+            catch_entry: None,
+            resume_catch: None,
             trap: None,
             stack_overflow: None,
             safepoint: None,
@@ -34,6 +39,14 @@ impl Stubs {
         self.core_entry.expect("uninitialized field")
     }
 
+    pub fn catch_entry(&self) -> Address {
+        self.catch_entry.expect("uninitialized field")
+    }
+
+    pub fn resume_catch(&self) -> Address {
+        self.resume_catch.expect("uninitialized field")
+    }
+
     pub fn trap(&self) -> Address {
         self.trap.expect("uninitialized field")
     }
@@ -49,6 +62,8 @@ impl Stubs {
 
 pub fn setup_stubs(vm: &mut VM) {
     vm.stubs.core_entry = Some(core_entry_stub::install(vm).instruction_start());
+    vm.stubs.catch_entry = Some(catch_entry_stub::install_catch_entry(vm).instruction_start());
+    vm.stubs.resume_catch = Some(catch_entry_stub::install_resume_catch(vm).instruction_start());
 
     let ifct = NativeFct {
         fctptr: Address::from_ptr(stdlib::trap as *const u8),
@@ -9,10 +9,11 @@ impl SourceType {
             SourceType::Error => panic!("no size for error."),
             SourceType::Unit => 0,
             SourceType::Bool => 1,
-            SourceType::UInt8 => 1,
+            SourceType::Int8 | SourceType::UInt8 => 1,
+            SourceType::Int16 | SourceType::UInt16 => 2,
             SourceType::Char => 4,
-            SourceType::Int32 => 4,
-            SourceType::Int64 => 8,
+            SourceType::Int32 | SourceType::UInt32 => 4,
+            SourceType::Int64 | SourceType::UInt64 => 8,
             SourceType::Float32 => 4,
             SourceType::Float64 => 8,
             SourceType::Enum(eid, params) => {
@@ -33,8 +34,9 @@ impl SourceType {
                 let value_instance = value_instance(vm, *value_id, type_params.clone());
                 value_instance.size
             }
-            SourceType::Trait(_, _) => mem::ptr_width(),
+            SourceType::Trait(_, _) | SourceType::Union(_, _) => mem::ptr_width(),
             SourceType::TypeParam(_) => panic!("no size for type variable."),
+            SourceType::Assoc(_) => panic!("no size for associated type."),
             SourceType::Tuple(_) => get_concrete_tuple_ty(vm, self).size(),
         }
     }
@@ -44,10 +46,11 @@ impl SourceType {
             SourceType::Error => panic!("no alignment for error."),
             SourceType::Unit => 0,
             SourceType::Bool => 1,
-            SourceType::UInt8 => 1,
+            SourceType::Int8 | SourceType::UInt8 => 1,
+            SourceType::Int16 | SourceType::UInt16 => 2,
             SourceType::Char => 4,
-            SourceType::Int32 => 4,
-            SourceType::Int64 => 8,
+            SourceType::Int32 | SourceType::UInt32 => 4,
+            SourceType::Int64 | SourceType::UInt64 => 8,
             SourceType::Float32 => 4,
             SourceType::Float64 => 8,
             SourceType::This => panic!("no alignment for Self."),
@@ -68,8 +71,9 @@ impl SourceType {
                 let value_instance = value_instance(vm, *value_id, type_params.clone());
                 value_instance.align
             }
-            SourceType::Trait(_, _) => mem::ptr_width(),
+            SourceType::Trait(_, _) | SourceType::Union(_, _) => mem::ptr_width(),
             SourceType::TypeParam(_) => panic!("no alignment for type variable."),
+            SourceType::Assoc(_) => panic!("no alignment for associated type."),
             SourceType::Tuple(_) => get_concrete_tuple_ty(vm, self).align(),
         }
     }
@@ -79,10 +83,11 @@ impl SourceType {
             SourceType::Error => panic!("no machine mode for error."),
             SourceType::Unit => panic!("no machine mode for unit."),
             SourceType::Bool => MachineMode::Int8,
-            SourceType::UInt8 => MachineMode::Int8,
+            SourceType::Int8 | SourceType::UInt8 => MachineMode::Int8,
+            SourceType::Int16 | SourceType::UInt16 => MachineMode::Int16,
             SourceType::Char => MachineMode::Int32,
-            SourceType::Int32 => MachineMode::Int32,
-            SourceType::Int64 => MachineMode::Int64,
+            SourceType::Int32 | SourceType::UInt32 => MachineMode::Int32,
+            SourceType::Int64 | SourceType::UInt64 => MachineMode::Int64,
             SourceType::Float32 => MachineMode::Float32,
             SourceType::Float64 => MachineMode::Float64,
             SourceType::Enum(_, _) => MachineMode::Int32,
@@ -92,8 +97,9 @@ impl SourceType {
                 MachineMode::Ptr
             }
             SourceType::Value(_, _) => panic!("no machine mode for value type."),
-            SourceType::Trait(_, _) => MachineMode::Ptr,
+            SourceType::Trait(_, _) | SourceType::Union(_, _) => MachineMode::Ptr,
             SourceType::TypeParam(_) => panic!("no machine mode for type variable."),
+            SourceType::Assoc(_) => panic!("no machine mode for associated type."),
             SourceType::Tuple(_) => unimplemented!(),
         }
     }
@@ -126,7 +126,7 @@ def binary_path
 end
 
 class TestUtility
-  def self.spawn_with_timeout(env, cmd, timeout)
+  def self.spawn_with_timeout(env, cmd, timeout, input = nil, input_delay = nil)
     result = {
       :pid     => nil,
       :status  => nil,
@@ -142,6 +142,9 @@ class TestUtility
       Timeout.timeout(timeout) do
         result[:pid] = wait_thr.pid
 
+        # lets the process block on stdin before any input arrives
+        sleep(input_delay) if input_delay
+        stdin.write(input) if input
         stdin.close
         out_reader = Thread.new { stdout.read }
         err_reader = Thread.new { stderr.read }
@@ -184,6 +187,8 @@ class TestCase
                 :test_file,
                 :vm_args,
                 :args,
+                :stdin,
+                :stdin_delay,
                 :expectation,
                 :result,
                 :timeout,
@@ -437,7 +442,7 @@ def run_test(test_case, config, mutex)
 
   puts cmdline if $verbose
 
-  process_result = TestUtility.spawn_with_timeout($env, cmdline, test_case.get_timeout)
+  process_result = TestUtility.spawn_with_timeout($env, cmdline, test_case.get_timeout, test_case.stdin, test_case.stdin_delay)
   result = check_test_run_result(test_case, process_result)
 
   if !$capture || result != true
@@ -664,6 +669,16 @@ def parse_test_file(file)
       when "stderr"
         test_case.expectation.stderr = arguments[1]
 
+      when "stdin"
+        case arguments[1]
+        when "file" then test_case.stdin = IO.read(file.sub(".core", ".stdin"))
+        else
+          test_case.stdin = arguments[1]
+        end
+
+      when "stdin-delay"
+        test_case.stdin_delay = arguments[1].to_f
+
       when "config"
         config = arguments[1].intern
         raise "unknown config #{arguments[1]}" unless $all_configs.include?(config)